@@ -1,5 +1,6 @@
 use base64::Engine as _;
 use serde_json::{json, Value};
+use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::config::{ConfigError, DEEP_SCALAR, FLOAT_SCALAR, DeepBookConfig};
@@ -10,7 +11,11 @@ use crate::contracts::{
 use crate::encode::encode_vec_u128;
 use sui::jsonrpc;
 use sui::utils::normalize_sui_address;
-use sui::transactions::Transaction;
+use sui::transactions::plugins::ResolveInputsPlugin;
+use sui::transactions::resolve::CachingObjectProvider;
+use sui::transactions::{
+    PluginError, Transaction, TransactionBuilder, TransactionError, TransactionPlugin,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum ClientError {
@@ -20,9 +25,9 @@ pub enum ClientError {
     Contract(#[from] ContractError),
     #[error(transparent)]
     JsonRpc(#[from] jsonrpc::JsonRpcError),
-    #[error("missing commandResults[{0}]")]
+    #[error("missing results[{0}]")]
     MissingCommandResult(usize),
-    #[error("missing returnValues[{return_index}] in commandResults[{command_index}]")]
+    #[error("missing returnValues[{return_index}] in results[{command_index}]")]
     MissingReturnValue { command_index: usize, return_index: usize },
     #[error("invalid bcs return value")]
     InvalidBcsValue,
@@ -30,11 +35,18 @@ pub enum ClientError {
     MissingPriceInfoObject(String),
     #[error(transparent)]
     Base64(#[from] base64::DecodeError),
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
+    #[error(transparent)]
+    Plugin(#[from] PluginError),
+    #[error("dev inspect failed: {0}")]
+    DevInspect(String),
 }
 
 pub struct DeepBookClient {
     pub client: jsonrpc::Client,
     pub config: DeepBookConfig,
+    input_resolver: Arc<dyn TransactionPlugin>,
 }
 
 impl DeepBookClient {
@@ -146,7 +158,21 @@ impl DeepBookClient {
     }
 
     pub fn new(client: jsonrpc::Client, config: DeepBookConfig) -> Self {
-        Self { client, config }
+        let provider = CachingObjectProvider::new(client.clone());
+        let input_resolver = Arc::new(ResolveInputsPlugin::new(Arc::new(provider)));
+        Self {
+            client,
+            config,
+            input_resolver,
+        }
+    }
+
+    /// Replaces the plugin that resolves the inputs of simulated transactions,
+    /// which defaults to fetching objects and Move signatures through `client`,
+    /// caching the signatures for the lifetime of this client.
+    pub fn with_input_resolver(mut self, input_resolver: Arc<dyn TransactionPlugin>) -> Self {
+        self.input_resolver = input_resolver;
+        self
     }
 
     pub async fn check_manager_balance(
@@ -166,7 +192,7 @@ impl DeepBookClient {
             ),
             vec![manager_obj],
             vec![coin.type_tag.clone()],
-        );
+        )?;
 
         let sim = self.simulate(&tx).await?;
         let balance = self.read_u64(&sim, 0, 0)?;
@@ -188,7 +214,7 @@ impl DeepBookClient {
             &format!("{}::pool::whitelisted", self.config.package_ids.deepbook_package_id),
             vec![pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        );
+        )?;
 
         let sim = self.simulate(&tx).await?;
         let b = self.return_bcs(&sim, 0, 0)?;
@@ -247,7 +273,7 @@ impl DeepBookClient {
             ),
             vec![pool_obj, qty_arg, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        );
+        )?;
 
         let sim = self.simulate(&tx).await?;
         let base_out = self.read_u64(&sim, 0, 0)?;
@@ -286,7 +312,7 @@ impl DeepBookClient {
             ),
             vec![pool_obj, base_arg, quote_arg, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        );
+        )?;
 
         let sim = self.simulate(&tx).await?;
         let base_out = self.read_u64(&sim, 0, 0)?;
@@ -313,7 +339,7 @@ impl DeepBookClient {
             &format!("{}::pool::mid_price", self.config.package_ids.deepbook_package_id),
             vec![pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        );
+        )?;
 
         let sim = self.simulate(&tx).await?;
         let value = self.read_u64(&sim, 0, 0)?;
@@ -332,7 +358,7 @@ impl DeepBookClient {
             &format!("{}::pool::get_order", self.config.package_ids.deepbook_package_id),
             vec![pool_obj, order_arg],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        );
+        )?;
 
         let sim = self.simulate(&tx).await?;
         self.read_return_bcs_base64(&sim, 0, 0)
@@ -350,7 +376,7 @@ impl DeepBookClient {
             &format!("{}::pool::get_orders", self.config.package_ids.deepbook_package_id),
             vec![pool_obj, order_ids_arg],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        );
+        )?;
         let sim = self.simulate(&tx).await?;
         self.read_return_bcs_base64(&sim, 0, 0)
     }
@@ -396,7 +422,7 @@ impl DeepBookClient {
             ),
             vec![pool_obj, manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        );
+        )?;
         let sim = self.simulate(&tx).await?;
         self.read_return_bcs_base64(&sim, 0, 0)
     }
@@ -412,7 +438,7 @@ impl DeepBookClient {
             &format!("{}::pool::vault_balances", self.config.package_ids.deepbook_package_id),
             vec![pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        );
+        )?;
         let sim = self.simulate(&tx).await?;
         self.read_return_bcs_base64(&sim, 0, 0)
     }
@@ -430,7 +456,7 @@ impl DeepBookClient {
             ),
             vec![],
             vec![base_type.to_string(), quote_type.to_string()],
-        );
+        )?;
         let sim = self.simulate(&tx).await?;
         self.read_return_bcs_base64(&sim, 0, 0)
     }
@@ -449,7 +475,7 @@ impl DeepBookClient {
             ),
             vec![pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        );
+        )?;
         let sim = self.simulate(&tx).await?;
         self.read_return_bcs_base64(&sim, 0, 0)
     }
@@ -468,7 +494,7 @@ impl DeepBookClient {
             ),
             vec![pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        );
+        )?;
         let sim = self.simulate(&tx).await?;
         self.read_return_bcs_base64(&sim, 0, 0)
     }
@@ -486,7 +512,7 @@ impl DeepBookClient {
             &format!("{}::pool::account", self.config.package_ids.deepbook_package_id),
             vec![pool_obj, manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        );
+        )?;
         let sim = self.simulate(&tx).await?;
         self.read_return_bcs_base64(&sim, 0, 0)
     }
@@ -511,7 +537,7 @@ impl DeepBookClient {
             ),
             vec![pool_obj, manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        );
+        )?;
         let sim = self.simulate(&tx).await?;
         self.read_return_bcs_base64(&sim, 0, 0)
     }
@@ -530,7 +556,7 @@ impl DeepBookClient {
             ),
             vec![pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        );
+        )?;
         let sim = self.simulate(&tx).await?;
         self.read_return_bcs_base64(&sim, 0, 0)
     }
@@ -831,7 +857,7 @@ impl DeepBookClient {
             ),
             vec![manager_obj, registry_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        );
+        )?;
         let pool_obj = tx.object(pool.address.clone());
         tx.move_call(
             &format!(
@@ -840,7 +866,7 @@ impl DeepBookClient {
             ),
             vec![pool_obj, bm],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        );
+        )?;
 
         let sim = self.simulate(&tx).await?;
         self.read_return_bcs_base64(&sim, 1, 0)
@@ -1769,59 +1795,55 @@ impl DeepBookClient {
         self.read_vec_set_addresses(&sim, 0, 0)
     }
 
+    /// Runs `tx` through `sui_devInspectTransactionBlock` as the configured
+    /// address, after resolving its inputs, and returns the inspect results.
     pub async fn simulate(&self, tx: &Transaction) -> Result<Value, ClientError> {
-        let tx_bytes_base64 = tx.build_base64().map_err(|_| ClientError::InvalidBcsValue)?;
-        let out: Value = self
+        let mut tx = tx.clone();
+        tx.add_plugin(Box::new(self.input_resolver.clone()));
+        tx.prepare().await?;
+        let result = self
             .client
-            .call(
-                "sui_dryRunTransactionBlock",
-                vec![Value::String(tx_bytes_base64)],
-            )
+            .dev_inspect_transaction_block(&self.config.address, &tx.build_kind_base64()?)
             .await?;
-
-        if let Some(result) = out.get("result") {
-            Ok(result.clone())
-        } else {
-            Ok(out)
+        if let Some(error) = result.get("error").and_then(Value::as_str) {
+            return Err(ClientError::DevInspect(error.to_string()));
         }
+        Ok(result)
     }
 
+    /// BCS bytes of `results[command_index].returnValues[return_index]`.
     pub fn return_bcs(
         &self,
         sim: &Value,
         command_index: usize,
         return_index: usize,
     ) -> Result<Vec<u8>, ClientError> {
-        let command_results = sim
-            .get("commandResults")
+        let command = sim
+            .get("results")
             .and_then(Value::as_array)
+            .and_then(|results| results.get(command_index))
             .ok_or(ClientError::MissingCommandResult(command_index))?;
 
-        let command = command_results
-            .get(command_index)
-            .ok_or(ClientError::MissingCommandResult(command_index))?;
-
-        let return_values = command
+        let ret = command
             .get("returnValues")
             .and_then(Value::as_array)
+            .and_then(|values| values.get(return_index))
             .ok_or(ClientError::MissingReturnValue {
                 command_index,
                 return_index,
             })?;
 
-        let ret = return_values
-            .get(return_index)
-            .ok_or(ClientError::MissingReturnValue {
-                command_index,
-                return_index,
-            })?;
-
-        let b64 = ret
-            .get("bcs")
-            .and_then(Value::as_str)
-            .ok_or(ClientError::InvalidBcsValue)?;
-
-        Ok(base64::engine::general_purpose::STANDARD.decode(b64)?)
+        // Each return value is a `[bytes, type]` pair.
+        ret.get(0)
+            .and_then(Value::as_array)
+            .ok_or(ClientError::InvalidBcsValue)?
+            .iter()
+            .map(|byte| {
+                byte.as_u64()
+                    .and_then(|byte| u8::try_from(byte).ok())
+                    .ok_or(ClientError::InvalidBcsValue)
+            })
+            .collect()
     }
 
     pub fn read_u64(
@@ -16,12 +16,14 @@ pub mod pool_proxy;
 pub mod pyth_oracle;
 pub mod tpsl;
 pub mod wormhole;
-use sui::transactions::Transaction;
+use sui::transactions::{Argument, Transaction, TransactionError};
 
 #[derive(Debug, thiserror::Error)]
 pub enum ContractError {
     #[error(transparent)]
     Config(#[from] ConfigError),
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
 }
 
 pub struct BalanceManagerContract<'a> {
@@ -32,7 +34,7 @@ impl<'a> BalanceManagerContract<'a> {
     pub fn create_balance_manager(
         &self,
         tx: &mut Transaction,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         Ok(tx.move_call(
             &format!(
                 "{}::balance_manager::new",
@@ -40,14 +42,14 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![],
             vec![],
-        ))
+        )?)
     }
 
     pub fn new_with_custom_owner(
         &self,
         tx: &mut Transaction,
         owner_address: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let owner_obj = tx.object(owner_address.to_string());
         Ok(tx.move_call(
             &format!(
@@ -56,7 +58,7 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![owner_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn deposit(
@@ -65,7 +67,7 @@ impl<'a> BalanceManagerContract<'a> {
         manager_key: &str,
         coin_key: &str,
         coin_object_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_balance_manager(manager_key)?;
         let coin = self.config.get_coin(coin_key)?;
         let manager_obj = tx.object(manager.address.clone());
@@ -77,7 +79,7 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![manager_obj, coin_obj],
             vec![coin.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn withdraw(
@@ -86,7 +88,7 @@ impl<'a> BalanceManagerContract<'a> {
         manager_key: &str,
         coin_key: &str,
         amount: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_balance_manager(manager_key)?;
         let coin = self.config.get_coin(coin_key)?;
         let input_amount = (amount * coin.scalar as f64).round() as u64;
@@ -99,7 +101,7 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![manager_obj, amount_arg],
             vec![coin.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn deposit_with_cap(
@@ -109,7 +111,7 @@ impl<'a> BalanceManagerContract<'a> {
         coin_key: &str,
         deposit_cap_id: &str,
         coin_object_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_balance_manager(manager_key)?;
         let coin = self.config.get_coin(coin_key)?;
         let manager_obj = tx.object(manager.address.clone());
@@ -122,7 +124,7 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![manager_obj, deposit_cap_obj, coin_obj],
             vec![coin.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn withdraw_with_cap(
@@ -132,7 +134,7 @@ impl<'a> BalanceManagerContract<'a> {
         coin_key: &str,
         withdraw_cap_id: &str,
         amount: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_balance_manager(manager_key)?;
         let coin = self.config.get_coin(coin_key)?;
         let input_amount = (amount * coin.scalar as f64).round() as u64;
@@ -146,7 +148,7 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![manager_obj, withdraw_cap_obj, amount_arg],
             vec![coin.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn withdraw_all(
@@ -154,7 +156,7 @@ impl<'a> BalanceManagerContract<'a> {
         tx: &mut Transaction,
         manager_key: &str,
         coin_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_balance_manager(manager_key)?;
         let coin = self.config.get_coin(coin_key)?;
         let manager_obj = tx.object(manager.address.clone());
@@ -165,14 +167,14 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![manager_obj],
             vec![coin.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn mint_trade_cap(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_balance_manager(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         Ok(tx.move_call(
@@ -182,14 +184,14 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![manager_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn mint_deposit_cap(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_balance_manager(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         Ok(tx.move_call(
@@ -199,14 +201,14 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![manager_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn mint_withdraw_cap(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_balance_manager(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         Ok(tx.move_call(
@@ -216,14 +218,14 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![manager_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn register_balance_manager(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_balance_manager(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         let registry_obj = tx.object(self.config.package_ids.registry_id.clone());
@@ -234,14 +236,14 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![manager_obj, registry_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn owner(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_balance_manager(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         Ok(tx.move_call(
@@ -251,14 +253,14 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![manager_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn id(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_balance_manager(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         Ok(tx.move_call(
@@ -268,14 +270,14 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![manager_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn generate_proof(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_balance_manager(manager_key)?;
         if let Some(trade_cap) = &manager.trade_cap {
             let manager_obj = tx.object(manager.address.clone());
@@ -287,7 +289,7 @@ impl<'a> BalanceManagerContract<'a> {
                 ),
                 vec![manager_obj, trade_cap_obj],
                 vec![],
-            ))
+            )?)
         } else {
             let manager_obj = tx.object(manager.address.clone());
             Ok(tx.move_call(
@@ -297,7 +299,7 @@ impl<'a> BalanceManagerContract<'a> {
                 ),
                 vec![manager_obj],
                 vec![],
-            ))
+            )?)
         }
     }
 
@@ -305,7 +307,7 @@ impl<'a> BalanceManagerContract<'a> {
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_balance_manager(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         Ok(tx.move_call(
@@ -315,7 +317,7 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![manager_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn generate_proof_as_trader(
@@ -323,7 +325,7 @@ impl<'a> BalanceManagerContract<'a> {
         tx: &mut Transaction,
         manager_key: &str,
         trade_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_balance_manager(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         let trade_cap_obj = tx.object(trade_cap_id.to_string());
@@ -334,14 +336,14 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![manager_obj, trade_cap_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn balance_manager_referral_owner(
         &self,
         tx: &mut Transaction,
         referral_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let referral_obj = tx.object(referral_id.to_string());
         Ok(tx.move_call(
             &format!(
@@ -350,14 +352,14 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![referral_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn balance_manager_referral_pool_id(
         &self,
         tx: &mut Transaction,
         referral_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let referral_obj = tx.object(referral_id.to_string());
         Ok(tx.move_call(
             &format!(
@@ -366,7 +368,7 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![referral_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn get_balance_manager_referral_id(
@@ -374,7 +376,7 @@ impl<'a> BalanceManagerContract<'a> {
         tx: &mut Transaction,
         manager_key: &str,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_balance_manager(manager_key)?;
         let pool = self.config.get_pool(pool_key)?;
         let manager_obj = tx.object(manager.address.clone());
@@ -386,7 +388,7 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![manager_obj, pool_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn set_balance_manager_referral(
@@ -394,7 +396,7 @@ impl<'a> BalanceManagerContract<'a> {
         tx: &mut Transaction,
         manager_key: &str,
         referral_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_balance_manager(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         let referral_obj = tx.object(referral_id.to_string());
@@ -406,7 +408,7 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![manager_obj, referral_obj, proof],
             vec![],
-        ))
+        )?)
     }
 
     pub fn unset_balance_manager_referral(
@@ -414,7 +416,7 @@ impl<'a> BalanceManagerContract<'a> {
         tx: &mut Transaction,
         manager_key: &str,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_balance_manager(manager_key)?;
         let pool = self.config.get_pool(pool_key)?;
         let manager_obj = tx.object(manager.address.clone());
@@ -427,7 +429,7 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![manager_obj, pool_obj, proof],
             vec![],
-        ))
+        )?)
     }
 
     pub fn revoke_trade_cap(
@@ -435,7 +437,7 @@ impl<'a> BalanceManagerContract<'a> {
         tx: &mut Transaction,
         manager_key: &str,
         trade_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_balance_manager(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         let trade_cap_obj = tx.object(trade_cap_id.to_string());
@@ -446,7 +448,7 @@ impl<'a> BalanceManagerContract<'a> {
             ),
             vec![manager_obj, trade_cap_obj],
             vec![],
-        ))
+        )?)
     }
 }
 
@@ -485,7 +487,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         base_quantity: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -498,7 +500,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("get_quote_quantity_out"),
             vec![pool_obj, qty_arg, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_base_quantity_out(
@@ -506,7 +508,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         quote_quantity: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let quantity = (quote_quantity * quote.scalar as f64).round() as u64;
         let pool_obj = tx.object(pool.address.clone());
@@ -517,7 +519,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("get_base_quantity_out"),
             vec![pool_obj, qty_arg, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_quantity_out(
@@ -526,7 +528,7 @@ impl<'a> DeepBookContract<'a> {
         pool_key: &str,
         base_quantity: f64,
         quote_quantity: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let base_qty = (base_quantity * base.scalar as f64).round() as u64;
         let quote_qty = (quote_quantity * quote.scalar as f64).round() as u64;
@@ -539,7 +541,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("get_quantity_out"),
             vec![pool_obj, base_arg, quote_arg, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_quote_quantity_out_input_fee(
@@ -547,7 +549,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         base_quantity: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let quantity = (base_quantity * base.scalar as f64).round() as u64;
         let pool_obj = tx.object(pool.address.clone());
@@ -558,7 +560,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("get_quote_quantity_out_input_fee"),
             vec![pool_obj, qty_arg, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_base_quantity_out_input_fee(
@@ -566,7 +568,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         quote_quantity: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let quantity = (quote_quantity * quote.scalar as f64).round() as u64;
         let pool_obj = tx.object(pool.address.clone());
@@ -577,7 +579,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("get_base_quantity_out_input_fee"),
             vec![pool_obj, qty_arg, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_quantity_out_input_fee(
@@ -586,7 +588,7 @@ impl<'a> DeepBookContract<'a> {
         pool_key: &str,
         base_quantity: f64,
         quote_quantity: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let base_qty = (base_quantity * base.scalar as f64).round() as u64;
         let quote_qty = (quote_quantity * quote.scalar as f64).round() as u64;
@@ -599,7 +601,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("get_quantity_out_input_fee"),
             vec![pool_obj, base_arg, quote_arg, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_base_quantity_in(
@@ -608,7 +610,7 @@ impl<'a> DeepBookContract<'a> {
         pool_key: &str,
         target_quote_quantity: f64,
         pay_with_deep: bool,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let quantity = (target_quote_quantity * quote.scalar as f64).round() as u64;
         let pool_obj = tx.object(pool.address.clone());
@@ -620,7 +622,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("get_base_quantity_in"),
             vec![pool_obj, qty_arg, pay_with_deep_arg, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_quote_quantity_in(
@@ -629,7 +631,7 @@ impl<'a> DeepBookContract<'a> {
         pool_key: &str,
         target_base_quantity: f64,
         pay_with_deep: bool,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let quantity = (target_base_quantity * base.scalar as f64).round() as u64;
         let pool_obj = tx.object(pool.address.clone());
@@ -641,7 +643,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("get_quote_quantity_in"),
             vec![pool_obj, qty_arg, pay_with_deep_arg, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_level2_range(
@@ -651,7 +653,7 @@ impl<'a> DeepBookContract<'a> {
         price_low: f64,
         price_high: f64,
         is_bid: bool,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let price_low_encoded =
             ((price_low * FLOAT_SCALAR * quote.scalar as f64) / base.scalar as f64).round() as u64;
@@ -666,7 +668,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("get_level2_range"),
             vec![pool_obj, price_low_arg, price_high_arg, is_bid_arg],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_level2_ticks_from_mid(
@@ -674,7 +676,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         tick_from_mid: u64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let pool_obj = tx.object(pool.address.clone());
         let tick_arg = tx.pure_bytes(&encode_u64(tick_from_mid));
@@ -682,7 +684,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("get_level2_ticks_from_mid"),
             vec![pool_obj, tick_arg],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn cancel_orders(
@@ -691,7 +693,7 @@ impl<'a> DeepBookContract<'a> {
         pool_key: &str,
         balance_manager_key: &str,
         order_ids: &[u128],
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let manager = self.config.get_balance_manager(balance_manager_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -708,7 +710,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("cancel_orders"),
             vec![pool_obj, manager_obj, proof, order_ids_arg, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn place_limit_order(
@@ -724,7 +726,7 @@ impl<'a> DeepBookContract<'a> {
         is_bid: bool,
         pay_with_deep: bool,
         expire_timestamp: Option<u64>,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let manager = self.config.get_balance_manager(balance_manager_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -766,7 +768,7 @@ impl<'a> DeepBookContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn place_market_order(
@@ -779,7 +781,7 @@ impl<'a> DeepBookContract<'a> {
         quantity: f64,
         is_bid: bool,
         pay_with_deep: bool,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let manager = self.config.get_balance_manager(balance_manager_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -812,7 +814,7 @@ impl<'a> DeepBookContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn modify_order(
@@ -822,7 +824,7 @@ impl<'a> DeepBookContract<'a> {
         balance_manager_key: &str,
         order_id: u128,
         new_quantity: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let manager = self.config.get_balance_manager(balance_manager_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -849,7 +851,7 @@ impl<'a> DeepBookContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn cancel_order(
@@ -858,7 +860,7 @@ impl<'a> DeepBookContract<'a> {
         pool_key: &str,
         balance_manager_key: &str,
         order_id: u128,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let manager = self.config.get_balance_manager(balance_manager_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -876,7 +878,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("cancel_order"),
             vec![pool_obj, manager_obj, proof, order_id_arg, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn cancel_all_orders(
@@ -884,7 +886,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         balance_manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let manager = self.config.get_balance_manager(balance_manager_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -901,7 +903,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("cancel_all_orders"),
             vec![pool_obj, manager_obj, proof, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn withdraw_settled_amounts(
@@ -909,7 +911,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         balance_manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let manager = self.config.get_balance_manager(balance_manager_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -925,7 +927,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("withdraw_settled_amounts"),
             vec![pool_obj, manager_obj, proof],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn withdraw_settled_amounts_permissionless(
@@ -933,7 +935,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         balance_manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let manager = self.config.get_balance_manager(balance_manager_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -946,7 +948,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("withdraw_settled_amounts_permissionless"),
             vec![pool_obj, manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn claim_rebates(
@@ -954,7 +956,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         balance_manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let manager = self.config.get_balance_manager(balance_manager_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -970,14 +972,14 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("claim_rebates"),
             vec![pool_obj, manager_obj, proof],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn update_pool_allowed_versions(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -987,7 +989,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("update_pool_allowed_versions"),
             vec![pool_obj, registry_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn add_deep_price_point(
@@ -995,7 +997,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         target_pool_key: &str,
         reference_pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let target_pool = self.config.get_pool(target_pool_key)?;
         let reference_pool = self.config.get_pool(reference_pool_key)?;
         let target_base = self.config.get_coin(&target_pool.base_coin)?;
@@ -1014,7 +1016,7 @@ impl<'a> DeepBookContract<'a> {
                 reference_base.type_tag.clone(),
                 reference_quote.type_tag.clone(),
             ],
-        ))
+        )?)
     }
 
     pub fn mint_referral(
@@ -1022,7 +1024,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         multiplier: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -1033,7 +1035,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("mint_referral"),
             vec![pool_obj, multiplier_arg],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn update_pool_referral_multiplier(
@@ -1042,7 +1044,7 @@ impl<'a> DeepBookContract<'a> {
         pool_key: &str,
         referral_id: &str,
         multiplier: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -1054,7 +1056,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("update_pool_referral_multiplier"),
             vec![pool_obj, referral_obj, multiplier_arg],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn claim_pool_referral_rewards(
@@ -1062,7 +1064,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         referral_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -1072,14 +1074,14 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("claim_pool_referral_rewards"),
             vec![pool_obj, referral_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn burn_deep(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -1089,14 +1091,14 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("burn_deep"),
             vec![pool_obj, deep_treasury_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn mid_price(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -1106,14 +1108,14 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("mid_price"),
             vec![pool_obj, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn whitelisted(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -1122,7 +1124,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("whitelisted"),
             vec![pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn create_permissionless_pool(
@@ -1134,7 +1136,7 @@ impl<'a> DeepBookContract<'a> {
         lot_size: f64,
         min_size: f64,
         deep_coin_object_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let base_coin = self.config.get_coin(base_coin_key)?;
         let quote_coin = self.config.get_coin(quote_coin_key)?;
         let adjusted_tick_size = ((tick_size * FLOAT_SCALAR * quote_coin.scalar as f64)
@@ -1151,7 +1153,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("create_permissionless_pool"),
             vec![registry_obj, tick_arg, lot_arg, min_arg, deep_coin_obj],
             vec![base_coin.type_tag.clone(), quote_coin.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn swap_exact_base_for_quote(
@@ -1161,7 +1163,7 @@ impl<'a> DeepBookContract<'a> {
         base_coin_object_id: &str,
         deep_coin_object_id: &str,
         min_quote_out: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -1181,7 +1183,7 @@ impl<'a> DeepBookContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn swap_exact_quote_for_base(
@@ -1191,7 +1193,7 @@ impl<'a> DeepBookContract<'a> {
         quote_coin_object_id: &str,
         deep_coin_object_id: &str,
         min_base_out: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -1211,7 +1213,7 @@ impl<'a> DeepBookContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn swap_exact_quantity(
@@ -1223,7 +1225,7 @@ impl<'a> DeepBookContract<'a> {
         deep_coin_object_id: &str,
         min_out: f64,
         is_base_to_quote: bool,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -1250,7 +1252,7 @@ impl<'a> DeepBookContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn swap_exact_base_for_quote_with_manager(
@@ -1263,7 +1265,7 @@ impl<'a> DeepBookContract<'a> {
         withdraw_cap_id: &str,
         base_coin_object_id: &str,
         min_quote_out: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let manager = self.config.get_balance_manager(balance_manager_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -1290,7 +1292,7 @@ impl<'a> DeepBookContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn swap_exact_quote_for_base_with_manager(
@@ -1303,7 +1305,7 @@ impl<'a> DeepBookContract<'a> {
         withdraw_cap_id: &str,
         quote_coin_object_id: &str,
         min_base_out: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let manager = self.config.get_balance_manager(balance_manager_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -1330,7 +1332,7 @@ impl<'a> DeepBookContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn swap_exact_quantity_with_manager(
@@ -1345,7 +1347,7 @@ impl<'a> DeepBookContract<'a> {
         quote_coin_object_id: &str,
         min_out: f64,
         is_base_to_quote: bool,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let manager = self.config.get_balance_manager(balance_manager_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -1379,7 +1381,7 @@ impl<'a> DeepBookContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn can_place_limit_order(
@@ -1392,7 +1394,7 @@ impl<'a> DeepBookContract<'a> {
         is_bid: bool,
         pay_with_deep: bool,
         expire_timestamp: Option<u64>,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let manager = self.config.get_balance_manager(balance_manager_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -1424,7 +1426,7 @@ impl<'a> DeepBookContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn can_place_market_order(
@@ -1435,7 +1437,7 @@ impl<'a> DeepBookContract<'a> {
         quantity: f64,
         is_bid: bool,
         pay_with_deep: bool,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let manager = self.config.get_balance_manager(balance_manager_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -1460,7 +1462,7 @@ impl<'a> DeepBookContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn check_market_order_params(
@@ -1468,7 +1470,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         quantity: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let encoded_quantity = (quantity * base.scalar as f64).round() as u64;
         let pool_obj = tx.object(pool.address.clone());
@@ -1477,7 +1479,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("check_market_order_params"),
             vec![pool_obj, quantity_arg],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn check_limit_order_params(
@@ -1487,7 +1489,7 @@ impl<'a> DeepBookContract<'a> {
         price: f64,
         quantity: f64,
         expire_timestamp: u64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let encoded_price =
             ((price * FLOAT_SCALAR * quote.scalar as f64) / base.scalar as f64).round() as u64;
@@ -1501,7 +1503,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("check_limit_order_params"),
             vec![pool_obj, price_arg, quantity_arg, expire_arg, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_order(
@@ -1509,7 +1511,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         order_id: u128,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let pool_obj = tx.object(pool.address.clone());
         let order_id_arg = tx.pure_bytes(&encode_u128(order_id));
@@ -1517,7 +1519,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("get_order"),
             vec![pool_obj, order_id_arg],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_orders(
@@ -1525,7 +1527,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         order_ids: &[u128],
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let pool_obj = tx.object(pool.address.clone());
         let order_ids_arg = tx.pure_bytes(&encode_vec_u128(order_ids));
@@ -1533,7 +1535,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("get_orders"),
             vec![pool_obj, order_ids_arg],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn account_open_orders(
@@ -1541,7 +1543,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let manager = self.config.get_balance_manager(manager_key)?;
         let pool_obj = tx.object(pool.address.clone());
@@ -1550,21 +1552,21 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("account_open_orders"),
             vec![pool_obj, manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn vault_balances(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let pool_obj = tx.object(pool.address.clone());
         Ok(tx.move_call(
             &self.pool_target("vault_balances"),
             vec![pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_pool_id_by_assets(
@@ -1572,12 +1574,12 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         base_type: &str,
         quote_type: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         Ok(tx.move_call(
             &self.pool_target("get_pool_id_by_asset"),
             vec![],
             vec![base_type.to_string(), quote_type.to_string()],
-        ))
+        )?)
     }
 
     pub fn get_pool_id_by_asset(
@@ -1585,7 +1587,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         base_type: &str,
         quote_type: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         self.get_pool_id_by_assets(tx, base_type, quote_type)
     }
 
@@ -1593,28 +1595,28 @@ impl<'a> DeepBookContract<'a> {
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let pool_obj = tx.object(pool.address.clone());
         Ok(tx.move_call(
             &self.pool_target("pool_trade_params"),
             vec![pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn pool_book_params(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let pool_obj = tx.object(pool.address.clone());
         Ok(tx.move_call(
             &self.pool_target("pool_book_params"),
             vec![pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn account(
@@ -1622,7 +1624,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let manager = self.config.get_balance_manager(manager_key)?;
         let pool_obj = tx.object(pool.address.clone());
@@ -1631,7 +1633,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("account"),
             vec![pool_obj, manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn locked_balance(
@@ -1639,7 +1641,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let manager = self.config.get_balance_manager(manager_key)?;
         let pool_obj = tx.object(pool.address.clone());
@@ -1648,28 +1650,28 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("locked_balance"),
             vec![pool_obj, manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_pool_deep_price(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let pool_obj = tx.object(pool.address.clone());
         Ok(tx.move_call(
             &self.pool_target("get_order_deep_price"),
             vec![pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_order_deep_price(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         self.get_pool_deep_price(tx, pool_key)
     }
 
@@ -1678,7 +1680,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let manager = self.config.get_balance_manager(manager_key)?;
         let pool_obj = tx.object(pool.address.clone());
@@ -1687,7 +1689,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("get_account_order_details"),
             vec![pool_obj, manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_order_deep_required(
@@ -1696,7 +1698,7 @@ impl<'a> DeepBookContract<'a> {
         pool_key: &str,
         base_quantity: f64,
         price: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let quantity = (base_quantity * base.scalar as f64).round() as u64;
         let encoded_price =
@@ -1708,21 +1710,21 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("get_order_deep_required"),
             vec![pool_obj, quantity_arg, price_arg],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn pool_trade_params_next(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let pool_obj = tx.object(pool.address.clone());
         Ok(tx.move_call(
             &self.pool_target("pool_trade_params_next"),
             vec![pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn account_exists(
@@ -1730,7 +1732,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let manager = self.config.get_balance_manager(manager_key)?;
         let pool_obj = tx.object(pool.address.clone());
@@ -1739,63 +1741,63 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("account_exists"),
             vec![pool_obj, manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn quorum(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let pool_obj = tx.object(pool.address.clone());
         Ok(tx.move_call(
             &self.pool_target("quorum"),
             vec![pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn pool_id(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let pool_obj = tx.object(pool.address.clone());
         Ok(tx.move_call(
             &self.pool_target("id"),
             vec![pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn stable_pool(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let pool_obj = tx.object(pool.address.clone());
         Ok(tx.move_call(
             &self.pool_target("stable_pool"),
             vec![pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn registered_pool(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let pool_obj = tx.object(pool.address.clone());
         Ok(tx.move_call(
             &self.pool_target("registered_pool"),
             vec![pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_pool_referral_balances(
@@ -1803,7 +1805,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         referral_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let pool_obj = tx.object(pool.address.clone());
         let referral_obj = tx.object(referral_id.to_string());
@@ -1811,14 +1813,14 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("get_pool_referral_balances"),
             vec![pool_obj, referral_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_balance_manager_ids(
         &self,
         tx: &mut Transaction,
         owner: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let registry_obj = tx.object(self.config.package_ids.registry_id.clone());
         let owner_obj = tx.object(owner.to_string());
         Ok(tx.move_call(
@@ -1828,7 +1830,7 @@ impl<'a> DeepBookContract<'a> {
             ),
             vec![registry_obj, owner_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn pool_referral_multiplier(
@@ -1836,7 +1838,7 @@ impl<'a> DeepBookContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         referral_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let pool_obj = tx.object(pool.address.clone());
         let referral_obj = tx.object(referral_id.to_string());
@@ -1844,7 +1846,7 @@ impl<'a> DeepBookContract<'a> {
             &self.pool_target("pool_referral_multiplier"),
             vec![pool_obj, referral_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 }
 
@@ -1886,14 +1888,14 @@ impl<'a> MarginTPSLContract<'a> {
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, base, quote) = self.manager_types(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         Ok(tx.move_call(
             &self.margin_manager_target("conditional_order_ids"),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn conditional_order(
@@ -1901,7 +1903,7 @@ impl<'a> MarginTPSLContract<'a> {
         tx: &mut Transaction,
         manager_key: &str,
         conditional_order_id: u64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, base, quote) = self.manager_types(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         let id_arg = tx.pure_bytes(&encode_u64(conditional_order_id));
@@ -1909,35 +1911,35 @@ impl<'a> MarginTPSLContract<'a> {
             &self.margin_manager_target("conditional_order"),
             vec![manager_obj, id_arg],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn lowest_trigger_above_price(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, base, quote) = self.manager_types(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         Ok(tx.move_call(
             &self.margin_manager_target("lowest_trigger_above_price"),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn highest_trigger_below_price(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, base, quote) = self.manager_types(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         Ok(tx.move_call(
             &self.margin_manager_target("highest_trigger_below_price"),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn new_condition(
@@ -1946,7 +1948,7 @@ impl<'a> MarginTPSLContract<'a> {
         pool_key: &str,
         trigger_below_price: bool,
         trigger_price: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -1962,7 +1964,7 @@ impl<'a> MarginTPSLContract<'a> {
             ),
             vec![trigger_below_arg, trigger_price_arg],
             vec![],
-        ))
+        )?)
     }
 
     pub fn new_pending_limit_order(
@@ -1977,7 +1979,7 @@ impl<'a> MarginTPSLContract<'a> {
         is_bid: bool,
         pay_with_deep: bool,
         expire_timestamp: Option<u64>,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -2011,7 +2013,7 @@ impl<'a> MarginTPSLContract<'a> {
                 expire_arg,
             ],
             vec![],
-        ))
+        )?)
     }
 
     pub fn new_pending_market_order(
@@ -2023,7 +2025,7 @@ impl<'a> MarginTPSLContract<'a> {
         quantity: f64,
         is_bid: bool,
         pay_with_deep: bool,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let input_quantity = (quantity * base.scalar as f64).round() as u64;
@@ -2047,7 +2049,7 @@ impl<'a> MarginTPSLContract<'a> {
                 pay_with_deep_arg,
             ],
             vec![],
-        ))
+        )?)
     }
 
     pub fn add_conditional_limit_order(
@@ -2065,7 +2067,7 @@ impl<'a> MarginTPSLContract<'a> {
         is_bid: bool,
         pay_with_deep: bool,
         expire_timestamp: Option<u64>,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -2117,7 +2119,7 @@ impl<'a> MarginTPSLContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn add_conditional_market_order(
@@ -2132,7 +2134,7 @@ impl<'a> MarginTPSLContract<'a> {
         quantity: f64,
         is_bid: bool,
         pay_with_deep: bool,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -2181,7 +2183,7 @@ impl<'a> MarginTPSLContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn add_conditional_order(
@@ -2189,9 +2191,9 @@ impl<'a> MarginTPSLContract<'a> {
         tx: &mut Transaction,
         margin_manager_key: &str,
         conditional_order_id: u64,
-        condition: serde_json::Value,
-        pending_order: serde_json::Value,
-    ) -> Result<serde_json::Value, ContractError> {
+        condition: Argument,
+        pending_order: Argument,
+    ) -> Result<Argument, ContractError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -2226,14 +2228,14 @@ impl<'a> MarginTPSLContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn cancel_all_conditional_orders(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, base, quote) = self.manager_types(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         let clock_obj = tx.object("0x6");
@@ -2241,7 +2243,7 @@ impl<'a> MarginTPSLContract<'a> {
             &self.margin_manager_target("cancel_all_conditional_orders"),
             vec![manager_obj, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn cancel_conditional_order(
@@ -2249,7 +2251,7 @@ impl<'a> MarginTPSLContract<'a> {
         tx: &mut Transaction,
         manager_key: &str,
         conditional_order_id: u64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, base, quote) = self.manager_types(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         let id_arg = tx.pure_bytes(&encode_u64(conditional_order_id));
@@ -2258,7 +2260,7 @@ impl<'a> MarginTPSLContract<'a> {
             &self.margin_manager_target("cancel_conditional_order"),
             vec![manager_obj, id_arg, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn execute_conditional_orders(
@@ -2267,7 +2269,7 @@ impl<'a> MarginTPSLContract<'a> {
         manager_address: &str,
         pool_key: &str,
         max_orders_to_execute: u64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -2299,7 +2301,7 @@ impl<'a> MarginTPSLContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 }
 
@@ -2319,7 +2321,7 @@ impl<'a> MarginRegistryContract<'a> {
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -2329,42 +2331,42 @@ impl<'a> MarginRegistryContract<'a> {
             &self.margin_registry_target("pool_enabled"),
             vec![registry_obj, pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_margin_manager_ids(
         &self,
         tx: &mut Transaction,
         owner: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let owner_arg = tx.object(owner.to_string());
         Ok(tx.move_call(
             &self.margin_registry_target("get_margin_manager_ids"),
             vec![registry_obj, owner_arg],
             vec![],
-        ))
+        )?)
     }
 
     pub fn get_margin_pool_id(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let coin = self.config.get_coin(coin_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         Ok(tx.move_call(
             &self.margin_registry_target("get_margin_pool_id"),
             vec![registry_obj],
             vec![coin.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_deepbook_pool_margin_pool_ids(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let pool_obj = tx.object(pool.address.clone());
@@ -2372,14 +2374,14 @@ impl<'a> MarginRegistryContract<'a> {
             &self.margin_registry_target("get_deepbook_pool_margin_pool_ids"),
             vec![registry_obj, pool_obj],
             vec![],
-        ))
+        )?)
     }
 
     fn pool_id_arg(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<(serde_json::Value, serde_json::Value), ContractError> {
+    ) -> Result<(Argument, Argument), ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let pool_obj = tx.object(pool.address.clone());
@@ -2390,128 +2392,128 @@ impl<'a> MarginRegistryContract<'a> {
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (registry_obj, pool_obj) = self.pool_id_arg(tx, pool_key)?;
         Ok(tx.move_call(
             &self.margin_registry_target("base_margin_pool_id"),
             vec![registry_obj, pool_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn quote_margin_pool_id(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (registry_obj, pool_obj) = self.pool_id_arg(tx, pool_key)?;
         Ok(tx.move_call(
             &self.margin_registry_target("quote_margin_pool_id"),
             vec![registry_obj, pool_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn min_withdraw_risk_ratio(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (registry_obj, pool_obj) = self.pool_id_arg(tx, pool_key)?;
         Ok(tx.move_call(
             &self.margin_registry_target("min_withdraw_risk_ratio"),
             vec![registry_obj, pool_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn min_borrow_risk_ratio(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (registry_obj, pool_obj) = self.pool_id_arg(tx, pool_key)?;
         Ok(tx.move_call(
             &self.margin_registry_target("min_borrow_risk_ratio"),
             vec![registry_obj, pool_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn liquidation_risk_ratio(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (registry_obj, pool_obj) = self.pool_id_arg(tx, pool_key)?;
         Ok(tx.move_call(
             &self.margin_registry_target("liquidation_risk_ratio"),
             vec![registry_obj, pool_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn target_liquidation_risk_ratio(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (registry_obj, pool_obj) = self.pool_id_arg(tx, pool_key)?;
         Ok(tx.move_call(
             &self.margin_registry_target("target_liquidation_risk_ratio"),
             vec![registry_obj, pool_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn user_liquidation_reward(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (registry_obj, pool_obj) = self.pool_id_arg(tx, pool_key)?;
         Ok(tx.move_call(
             &self.margin_registry_target("user_liquidation_reward"),
             vec![registry_obj, pool_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn pool_liquidation_reward(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (registry_obj, pool_obj) = self.pool_id_arg(tx, pool_key)?;
         Ok(tx.move_call(
             &self.margin_registry_target("pool_liquidation_reward"),
             vec![registry_obj, pool_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn allowed_maintainers(
         &self,
         tx: &mut Transaction,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         Ok(tx.move_call(
             &self.margin_registry_target("allowed_maintainers"),
             vec![registry_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn allowed_pause_caps(
         &self,
         tx: &mut Transaction,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         Ok(tx.move_call(
             &self.margin_registry_target("allowed_pause_caps"),
             vec![registry_obj],
             vec![],
-        ))
+        )?)
     }
 }
 
@@ -2526,21 +2528,21 @@ impl<'a> MarginPoolContract<'a> {
     pub fn mint_supplier_cap(
         &self,
         tx: &mut Transaction,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let clock_obj = tx.object("0x6");
         Ok(tx.move_call(
             &self.margin_pool_target("mint_supplier_cap"),
             vec![registry_obj, clock_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn mint_supply_referral(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
@@ -2549,7 +2551,7 @@ impl<'a> MarginPoolContract<'a> {
             &self.margin_pool_target("mint_supply_referral"),
             vec![pool_obj, registry_obj, clock_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn supply(
@@ -2559,7 +2561,7 @@ impl<'a> MarginPoolContract<'a> {
         supplier_cap_id: &str,
         supply_coin_object_id: &str,
         referral_id: Option<&str>,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
@@ -2578,7 +2580,7 @@ impl<'a> MarginPoolContract<'a> {
                 clock_obj,
             ],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn withdraw_referral_fees(
@@ -2586,7 +2588,7 @@ impl<'a> MarginPoolContract<'a> {
         tx: &mut Transaction,
         coin_key: &str,
         referral_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
@@ -2595,21 +2597,21 @@ impl<'a> MarginPoolContract<'a> {
             &self.margin_pool_target("withdraw_referral_fees"),
             vec![pool_obj, registry_obj, referral_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_id(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
         Ok(tx.move_call(
             &self.margin_pool_target("id"),
             vec![pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn deepbook_pool_allowed(
@@ -2617,7 +2619,7 @@ impl<'a> MarginPoolContract<'a> {
         tx: &mut Transaction,
         coin_key: &str,
         deepbook_pool_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
         let deepbook_pool_obj = tx.object(deepbook_pool_id.to_string());
@@ -2625,147 +2627,147 @@ impl<'a> MarginPoolContract<'a> {
             &self.margin_pool_target("deepbook_pool_allowed"),
             vec![pool_obj, deepbook_pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn total_supply(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
         Ok(tx.move_call(
             &self.margin_pool_target("total_supply"),
             vec![pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn supply_shares(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
         Ok(tx.move_call(
             &self.margin_pool_target("supply_shares"),
             vec![pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn total_borrow(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
         Ok(tx.move_call(
             &self.margin_pool_target("total_borrow"),
             vec![pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn borrow_shares(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
         Ok(tx.move_call(
             &self.margin_pool_target("borrow_shares"),
             vec![pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn last_update_timestamp(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
         Ok(tx.move_call(
             &self.margin_pool_target("last_update_timestamp"),
             vec![pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn supply_cap(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
         Ok(tx.move_call(
             &self.margin_pool_target("supply_cap"),
             vec![pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn max_utilization_rate(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
         Ok(tx.move_call(
             &self.margin_pool_target("max_utilization_rate"),
             vec![pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn protocol_spread(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
         Ok(tx.move_call(
             &self.margin_pool_target("protocol_spread"),
             vec![pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn min_borrow(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
         Ok(tx.move_call(
             &self.margin_pool_target("min_borrow"),
             vec![pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn interest_rate(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
         Ok(tx.move_call(
             &self.margin_pool_target("interest_rate"),
             vec![pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn user_supply_shares(
@@ -2773,7 +2775,7 @@ impl<'a> MarginPoolContract<'a> {
         tx: &mut Transaction,
         coin_key: &str,
         supplier_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
         let supplier_cap_obj = tx.object(supplier_cap_id.to_string());
@@ -2781,7 +2783,7 @@ impl<'a> MarginPoolContract<'a> {
             &self.margin_pool_target("user_supply_shares"),
             vec![pool_obj, supplier_cap_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn user_supply_amount(
@@ -2789,7 +2791,7 @@ impl<'a> MarginPoolContract<'a> {
         tx: &mut Transaction,
         coin_key: &str,
         supplier_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
         let supplier_cap_obj = tx.object(supplier_cap_id.to_string());
@@ -2798,7 +2800,7 @@ impl<'a> MarginPoolContract<'a> {
             &self.margin_pool_target("user_supply_amount"),
             vec![pool_obj, supplier_cap_obj, clock_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 }
 
@@ -2849,7 +2851,7 @@ impl<'a> PoolProxyContract<'a> {
         is_bid: bool,
         pay_with_deep: bool,
         expiration: u64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, pool, base, quote) = self.manager_pool_types(margin_manager_key)?;
         let input_price =
             ((price * FLOAT_SCALAR * quote.scalar as f64) / base.scalar as f64).round() as u64;
@@ -2883,7 +2885,7 @@ impl<'a> PoolProxyContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn place_market_order(
@@ -2895,7 +2897,7 @@ impl<'a> PoolProxyContract<'a> {
         quantity: f64,
         is_bid: bool,
         pay_with_deep: bool,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, pool, base, quote) = self.manager_pool_types(margin_manager_key)?;
         let input_quantity = (quantity * base.scalar as f64).round() as u64;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
@@ -2921,7 +2923,7 @@ impl<'a> PoolProxyContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn place_reduce_only_limit_order(
@@ -2936,7 +2938,7 @@ impl<'a> PoolProxyContract<'a> {
         is_bid: bool,
         pay_with_deep: bool,
         expiration: u64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, pool, base, quote) = self.manager_pool_types(margin_manager_key)?;
         let input_price =
             ((price * FLOAT_SCALAR * quote.scalar as f64) / base.scalar as f64).round() as u64;
@@ -2982,7 +2984,7 @@ impl<'a> PoolProxyContract<'a> {
                 quote.type_tag.clone(),
                 debt_coin.type_tag.clone(),
             ],
-        ))
+        )?)
     }
 
     pub fn place_reduce_only_market_order(
@@ -2994,7 +2996,7 @@ impl<'a> PoolProxyContract<'a> {
         quantity: f64,
         is_bid: bool,
         pay_with_deep: bool,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, pool, base, quote) = self.manager_pool_types(margin_manager_key)?;
         let input_quantity = (quantity * base.scalar as f64).round() as u64;
         let debt_margin_pool = if is_bid {
@@ -3032,7 +3034,7 @@ impl<'a> PoolProxyContract<'a> {
                 quote.type_tag.clone(),
                 debt_coin.type_tag.clone(),
             ],
-        ))
+        )?)
     }
 
     pub fn modify_order(
@@ -3041,7 +3043,7 @@ impl<'a> PoolProxyContract<'a> {
         margin_manager_key: &str,
         order_id: u128,
         new_quantity: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, pool, base, quote) = self.manager_pool_types(margin_manager_key)?;
         let input_quantity = (new_quantity * base.scalar as f64).round() as u64;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
@@ -3061,7 +3063,7 @@ impl<'a> PoolProxyContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn cancel_order(
@@ -3069,7 +3071,7 @@ impl<'a> PoolProxyContract<'a> {
         tx: &mut Transaction,
         margin_manager_key: &str,
         order_id: u128,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, pool, base, quote) = self.manager_pool_types(margin_manager_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let manager_obj = tx.object(manager.address.clone());
@@ -3080,7 +3082,7 @@ impl<'a> PoolProxyContract<'a> {
             &self.pool_proxy_target("cancel_order"),
             vec![registry_obj, manager_obj, pool_obj, order_id_arg, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn cancel_orders(
@@ -3088,7 +3090,7 @@ impl<'a> PoolProxyContract<'a> {
         tx: &mut Transaction,
         margin_manager_key: &str,
         order_ids: &[u128],
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, pool, base, quote) = self.manager_pool_types(margin_manager_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let manager_obj = tx.object(manager.address.clone());
@@ -3105,14 +3107,14 @@ impl<'a> PoolProxyContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn cancel_all_orders(
         &self,
         tx: &mut Transaction,
         margin_manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, pool, base, quote) = self.manager_pool_types(margin_manager_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let manager_obj = tx.object(manager.address.clone());
@@ -3122,14 +3124,14 @@ impl<'a> PoolProxyContract<'a> {
             &self.pool_proxy_target("cancel_all_orders"),
             vec![registry_obj, manager_obj, pool_obj, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn withdraw_settled_amounts(
         &self,
         tx: &mut Transaction,
         margin_manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, pool, base, quote) = self.manager_pool_types(margin_manager_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let manager_obj = tx.object(manager.address.clone());
@@ -3138,7 +3140,7 @@ impl<'a> PoolProxyContract<'a> {
             &self.pool_proxy_target("withdraw_settled_amounts"),
             vec![registry_obj, manager_obj, pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn stake(
@@ -3146,7 +3148,7 @@ impl<'a> PoolProxyContract<'a> {
         tx: &mut Transaction,
         margin_manager_key: &str,
         stake_amount: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, pool, base, quote) = self.manager_pool_types(margin_manager_key)?;
         let deep = self.config.get_coin("DEEP")?;
         let stake_input = (stake_amount * deep.scalar as f64).round() as u64;
@@ -3158,14 +3160,14 @@ impl<'a> PoolProxyContract<'a> {
             &self.pool_proxy_target("stake"),
             vec![registry_obj, manager_obj, pool_obj, stake_arg],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn unstake(
         &self,
         tx: &mut Transaction,
         margin_manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, pool, base, quote) = self.manager_pool_types(margin_manager_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let manager_obj = tx.object(manager.address.clone());
@@ -3174,7 +3176,7 @@ impl<'a> PoolProxyContract<'a> {
             &self.pool_proxy_target("unstake"),
             vec![registry_obj, manager_obj, pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn submit_proposal(
@@ -3184,7 +3186,7 @@ impl<'a> PoolProxyContract<'a> {
         taker_fee: f64,
         maker_fee: f64,
         stake_required: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, pool, base, quote) = self.manager_pool_types(margin_manager_key)?;
         let stake_input = (stake_required * FLOAT_SCALAR).round() as u64;
         let taker_fee_input = (taker_fee * FLOAT_SCALAR).round() as u64;
@@ -3206,7 +3208,7 @@ impl<'a> PoolProxyContract<'a> {
                 stake_arg,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn vote(
@@ -3214,7 +3216,7 @@ impl<'a> PoolProxyContract<'a> {
         tx: &mut Transaction,
         margin_manager_key: &str,
         proposal_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, pool, base, quote) = self.manager_pool_types(margin_manager_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let manager_obj = tx.object(manager.address.clone());
@@ -3224,14 +3226,14 @@ impl<'a> PoolProxyContract<'a> {
             &self.pool_proxy_target("vote"),
             vec![registry_obj, manager_obj, pool_obj, proposal_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn claim_rebate(
         &self,
         tx: &mut Transaction,
         margin_manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, pool, base, quote) = self.manager_pool_types(margin_manager_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let manager_obj = tx.object(manager.address.clone());
@@ -3240,7 +3242,7 @@ impl<'a> PoolProxyContract<'a> {
             &self.pool_proxy_target("claim_rebate"),
             vec![registry_obj, manager_obj, pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn withdraw_margin_settled_amounts(
@@ -3248,7 +3250,7 @@ impl<'a> PoolProxyContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         margin_manager_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -3259,7 +3261,7 @@ impl<'a> PoolProxyContract<'a> {
             &self.pool_proxy_target("withdraw_settled_amounts_permissionless"),
             vec![registry_obj, manager_obj, pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 }
 
@@ -3279,13 +3281,13 @@ impl<'a> MarginLiquidationsContract<'a> {
         &self,
         tx: &mut Transaction,
         liquidation_admin_cap: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let cap_obj = tx.object(liquidation_admin_cap.to_string());
         Ok(tx.move_call(
             &self.liquidation_target("create_liquidation_vault"),
             vec![cap_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn deposit(
@@ -3295,7 +3297,7 @@ impl<'a> MarginLiquidationsContract<'a> {
         liquidation_admin_cap: &str,
         coin_key: &str,
         coin_object_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let coin = self.config.get_coin(coin_key)?;
         let vault_obj = tx.object(vault_id.to_string());
         let cap_obj = tx.object(liquidation_admin_cap.to_string());
@@ -3304,7 +3306,7 @@ impl<'a> MarginLiquidationsContract<'a> {
             &self.liquidation_target("deposit"),
             vec![vault_obj, cap_obj, coin_obj],
             vec![coin.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn withdraw(
@@ -3314,7 +3316,7 @@ impl<'a> MarginLiquidationsContract<'a> {
         liquidation_admin_cap: &str,
         coin_key: &str,
         amount: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let coin = self.config.get_coin(coin_key)?;
         let input_amount = (amount * coin.scalar as f64).round() as u64;
         let vault_obj = tx.object(vault_id.to_string());
@@ -3324,7 +3326,7 @@ impl<'a> MarginLiquidationsContract<'a> {
             &self.liquidation_target("withdraw"),
             vec![vault_obj, cap_obj, amount_arg],
             vec![coin.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn liquidate_base(
@@ -3334,7 +3336,7 @@ impl<'a> MarginLiquidationsContract<'a> {
         manager_address: &str,
         pool_key: &str,
         repay_amount: Option<f64>,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base_coin = self.config.get_coin(&pool.base_coin)?;
         let quote_coin = self.config.get_coin(&pool.quote_coin)?;
@@ -3379,7 +3381,7 @@ impl<'a> MarginLiquidationsContract<'a> {
                 clock_obj,
             ],
             vec![base_coin.type_tag.clone(), quote_coin.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn liquidate_quote(
@@ -3389,7 +3391,7 @@ impl<'a> MarginLiquidationsContract<'a> {
         manager_address: &str,
         pool_key: &str,
         repay_amount: Option<f64>,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base_coin = self.config.get_coin(&pool.base_coin)?;
         let quote_coin = self.config.get_coin(&pool.quote_coin)?;
@@ -3434,7 +3436,7 @@ impl<'a> MarginLiquidationsContract<'a> {
                 clock_obj,
             ],
             vec![base_coin.type_tag.clone(), quote_coin.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn balance(
@@ -3442,14 +3444,14 @@ impl<'a> MarginLiquidationsContract<'a> {
         tx: &mut Transaction,
         vault_id: &str,
         coin_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let coin = self.config.get_coin(coin_key)?;
         let vault_obj = tx.object(vault_id.to_string());
         Ok(tx.move_call(
             &self.liquidation_target("balance"),
             vec![vault_obj],
             vec![coin.type_tag.clone()],
-        ))
+        )?)
     }
 }
 
@@ -3487,7 +3489,7 @@ impl<'a> FlashLoanContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         borrow_amount: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let input_quantity = (borrow_amount * base.scalar as f64).round() as u64;
         let pool_obj = tx.object(pool.address.clone());
@@ -3496,7 +3498,7 @@ impl<'a> FlashLoanContract<'a> {
             &self.flash_target("borrow_flashloan_base"),
             vec![pool_obj, quantity_arg],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn borrow_flashloan_base(
@@ -3504,7 +3506,7 @@ impl<'a> FlashLoanContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         borrow_amount: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         self.borrow_base_asset(tx, pool_key, borrow_amount)
     }
 
@@ -3514,7 +3516,7 @@ impl<'a> FlashLoanContract<'a> {
         pool_key: &str,
         base_coin_object_id: &str,
         flashloan_object_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let pool_obj = tx.object(pool.address.clone());
         let base_coin_obj = tx.object(base_coin_object_id.to_string());
@@ -3523,7 +3525,7 @@ impl<'a> FlashLoanContract<'a> {
             &self.flash_target("return_flashloan_base"),
             vec![pool_obj, base_coin_obj, flashloan_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn return_flashloan_base(
@@ -3532,7 +3534,7 @@ impl<'a> FlashLoanContract<'a> {
         pool_key: &str,
         base_coin_object_id: &str,
         flashloan_object_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         self.return_base_asset(tx, pool_key, base_coin_object_id, flashloan_object_id)
     }
 
@@ -3541,7 +3543,7 @@ impl<'a> FlashLoanContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         borrow_amount: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let input_quantity = (borrow_amount * quote.scalar as f64).round() as u64;
         let pool_obj = tx.object(pool.address.clone());
@@ -3550,7 +3552,7 @@ impl<'a> FlashLoanContract<'a> {
             &self.flash_target("borrow_flashloan_quote"),
             vec![pool_obj, quantity_arg],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn borrow_flashloan_quote(
@@ -3558,7 +3560,7 @@ impl<'a> FlashLoanContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         borrow_amount: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         self.borrow_quote_asset(tx, pool_key, borrow_amount)
     }
 
@@ -3568,7 +3570,7 @@ impl<'a> FlashLoanContract<'a> {
         pool_key: &str,
         quote_coin_object_id: &str,
         flashloan_object_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (pool, base, quote) = self.pool_types(pool_key)?;
         let pool_obj = tx.object(pool.address.clone());
         let quote_coin_obj = tx.object(quote_coin_object_id.to_string());
@@ -3577,7 +3579,7 @@ impl<'a> FlashLoanContract<'a> {
             &self.flash_target("return_flashloan_quote"),
             vec![pool_obj, quote_coin_obj, flashloan_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn return_flashloan_quote(
@@ -3586,7 +3588,7 @@ impl<'a> FlashLoanContract<'a> {
         pool_key: &str,
         quote_coin_object_id: &str,
         flashloan_object_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         self.return_quote_asset(tx, pool_key, quote_coin_object_id, flashloan_object_id)
     }
 }
@@ -3617,7 +3619,7 @@ impl<'a> MarginMaintainerContract<'a> {
         base_slope: f64,
         optimal_utilization: f64,
         excess_slope: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let base_rate_arg = tx.pure_bytes(&encode_u64((base_rate * FLOAT_SCALAR).round() as u64));
         let base_slope_arg = tx.pure_bytes(&encode_u64((base_slope * FLOAT_SCALAR).round() as u64));
         let optimal_utilization_arg = tx.pure_bytes(&encode_u64(
@@ -3634,7 +3636,7 @@ impl<'a> MarginMaintainerContract<'a> {
                 excess_slope_arg,
             ],
             vec![],
-        ))
+        )?)
     }
 
     pub fn new_margin_pool_config(
@@ -3645,7 +3647,7 @@ impl<'a> MarginMaintainerContract<'a> {
         max_utilization_rate: f64,
         referral_spread: f64,
         min_borrow: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let coin = self.config.get_coin(coin_key)?;
         let supply_cap_arg =
             tx.pure_bytes(&encode_u64((supply_cap * coin.scalar as f64).round() as u64));
@@ -3665,7 +3667,7 @@ impl<'a> MarginMaintainerContract<'a> {
                 min_borrow_arg,
             ],
             vec![],
-        ))
+        )?)
     }
 
     pub fn new_margin_pool_config_with_rate_limit(
@@ -3679,7 +3681,7 @@ impl<'a> MarginMaintainerContract<'a> {
         rate_limit_capacity: f64,
         rate_limit_refill_rate_per_ms: f64,
         rate_limit_enabled: bool,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let coin = self.config.get_coin(coin_key)?;
         let supply_cap_arg =
             tx.pure_bytes(&encode_u64((supply_cap * coin.scalar as f64).round() as u64));
@@ -3709,29 +3711,29 @@ impl<'a> MarginMaintainerContract<'a> {
                 rate_limit_enabled_arg,
             ],
             vec![],
-        ))
+        )?)
     }
 
     pub fn new_protocol_config(
         &self,
         tx: &mut Transaction,
-        margin_pool_config_object: serde_json::Value,
-        interest_config_object: serde_json::Value,
-    ) -> Result<serde_json::Value, ContractError> {
+        margin_pool_config_object: Argument,
+        interest_config_object: Argument,
+    ) -> Result<Argument, ContractError> {
         Ok(tx.move_call(
             &self.protocol_config_target("new_protocol_config"),
             vec![margin_pool_config_object, interest_config_object],
             vec![],
-        ))
+        )?)
     }
 
     pub fn create_margin_pool(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-        pool_config_object: serde_json::Value,
+        pool_config_object: Argument,
         margin_maintainer_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let coin = self.config.get_coin(coin_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let cap_obj = tx.object(margin_maintainer_cap_id.to_string());
@@ -3740,7 +3742,7 @@ impl<'a> MarginMaintainerContract<'a> {
             &self.margin_pool_target("create_margin_pool"),
             vec![registry_obj, pool_config_object, cap_obj, clock_obj],
             vec![coin.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn enable_deepbook_pool_for_loan(
@@ -3749,7 +3751,7 @@ impl<'a> MarginMaintainerContract<'a> {
         deepbook_pool_key: &str,
         coin_key: &str,
         margin_pool_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let deepbook_pool = self.config.get_pool(deepbook_pool_key)?;
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
@@ -3767,7 +3769,7 @@ impl<'a> MarginMaintainerContract<'a> {
                 clock_obj,
             ],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn disable_deepbook_pool_for_loan(
@@ -3776,7 +3778,7 @@ impl<'a> MarginMaintainerContract<'a> {
         deepbook_pool_key: &str,
         coin_key: &str,
         margin_pool_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let deepbook_pool = self.config.get_pool(deepbook_pool_key)?;
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
@@ -3794,7 +3796,7 @@ impl<'a> MarginMaintainerContract<'a> {
                 clock_obj,
             ],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn update_interest_params(
@@ -3802,8 +3804,8 @@ impl<'a> MarginMaintainerContract<'a> {
         tx: &mut Transaction,
         coin_key: &str,
         margin_pool_cap_id: &str,
-        interest_config_object: serde_json::Value,
-    ) -> Result<serde_json::Value, ContractError> {
+        interest_config_object: Argument,
+    ) -> Result<Argument, ContractError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
@@ -3819,7 +3821,7 @@ impl<'a> MarginMaintainerContract<'a> {
                 clock_obj,
             ],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn update_margin_pool_config(
@@ -3827,8 +3829,8 @@ impl<'a> MarginMaintainerContract<'a> {
         tx: &mut Transaction,
         coin_key: &str,
         margin_pool_cap_id: &str,
-        margin_pool_config_object: serde_json::Value,
-    ) -> Result<serde_json::Value, ContractError> {
+        margin_pool_config_object: Argument,
+    ) -> Result<Argument, ContractError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let pool_obj = tx.object(margin_pool.address.clone());
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
@@ -3844,7 +3846,7 @@ impl<'a> MarginMaintainerContract<'a> {
                 clock_obj,
             ],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 }
 
@@ -3879,7 +3881,7 @@ impl<'a> MarginManagerContract<'a> {
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -3891,14 +3893,14 @@ impl<'a> MarginManagerContract<'a> {
             &self.margin_manager_target("new"),
             vec![pool_obj, registry_obj, margin_registry_obj, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn new_with_initializer(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -3910,7 +3912,7 @@ impl<'a> MarginManagerContract<'a> {
             &self.margin_manager_target("new_with_initializer"),
             vec![pool_obj, registry_obj, margin_registry_obj, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn share(
@@ -3919,7 +3921,7 @@ impl<'a> MarginManagerContract<'a> {
         manager_key: &str,
         manager_object_id: &str,
         initializer_object_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (_manager, _pool, base, quote) = self.manager_types(manager_key)?;
         let manager_obj = tx.object(manager_object_id.to_string());
         let initializer_obj = tx.object(initializer_object_id.to_string());
@@ -3927,126 +3929,126 @@ impl<'a> MarginManagerContract<'a> {
             &self.margin_manager_target("share"),
             vec![manager_obj, initializer_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn owner(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, _pool, base, quote) = self.manager_types(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         Ok(tx.move_call(
             &self.margin_manager_target("owner"),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn deepbook_pool(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, _pool, base, quote) = self.manager_types(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         Ok(tx.move_call(
             &self.margin_manager_target("deepbook_pool"),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn margin_pool_id(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, _pool, base, quote) = self.manager_types(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         Ok(tx.move_call(
             &self.margin_manager_target("margin_pool_id"),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn borrowed_shares(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, _pool, base, quote) = self.manager_types(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         Ok(tx.move_call(
             &self.margin_manager_target("borrowed_shares"),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn borrowed_base_shares(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, _pool, base, quote) = self.manager_types(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         Ok(tx.move_call(
             &self.margin_manager_target("borrowed_base_shares"),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn borrowed_quote_shares(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, _pool, base, quote) = self.manager_types(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         Ok(tx.move_call(
             &self.margin_manager_target("borrowed_quote_shares"),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn has_base_debt(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, _pool, base, quote) = self.manager_types(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         Ok(tx.move_call(
             &self.margin_manager_target("has_base_debt"),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn balance_manager(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, _pool, base, quote) = self.manager_types(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         Ok(tx.move_call(
             &self.margin_manager_target("balance_manager"),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn calculate_assets(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, pool, base, quote) = self.manager_types(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         let pool_obj = tx.object(pool.address.clone());
@@ -4054,7 +4056,7 @@ impl<'a> MarginManagerContract<'a> {
             &self.margin_manager_target("calculate_assets"),
             vec![manager_obj, pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn calculate_debts(
@@ -4062,7 +4064,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         manager_key: &str,
         debt_coin_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, _pool, base, quote) = self.manager_types(manager_key)?;
         let debt_coin = self.config.get_coin(debt_coin_key)?;
         let margin_pool = self.config.get_margin_pool(debt_coin_key)?;
@@ -4077,56 +4079,56 @@ impl<'a> MarginManagerContract<'a> {
                 quote.type_tag.clone(),
                 debt_coin.type_tag.clone(),
             ],
-        ))
+        )?)
     }
 
     pub fn base_balance(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, _pool, base, quote) = self.manager_types(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         Ok(tx.move_call(
             &self.margin_manager_target("base_balance"),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn quote_balance(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, _pool, base, quote) = self.manager_types(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         Ok(tx.move_call(
             &self.margin_manager_target("quote_balance"),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn deep_balance(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, _pool, base, quote) = self.manager_types(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         Ok(tx.move_call(
             &self.margin_manager_target("deep_balance"),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn manager_state(
         &self,
         tx: &mut Transaction,
         manager_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, pool, base, quote) = self.manager_types(manager_key)?;
         let base_margin_pool = self.config.get_margin_pool(&pool.base_coin)?;
         let quote_margin_pool = self.config.get_margin_pool(&pool.quote_coin)?;
@@ -4160,7 +4162,7 @@ impl<'a> MarginManagerContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn borrow_base(
@@ -4168,7 +4170,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         manager_key: &str,
         amount: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, pool, base, quote) = self.manager_types(manager_key)?;
         let base_margin_pool = self.config.get_margin_pool(&pool.base_coin)?;
         let input_amount = (amount * base.scalar as f64).round() as u64;
@@ -4202,7 +4204,7 @@ impl<'a> MarginManagerContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn borrow_quote(
@@ -4210,7 +4212,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         manager_key: &str,
         amount: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, pool, base, quote) = self.manager_types(manager_key)?;
         let quote_margin_pool = self.config.get_margin_pool(&pool.quote_coin)?;
         let input_amount = (amount * quote.scalar as f64).round() as u64;
@@ -4244,7 +4246,7 @@ impl<'a> MarginManagerContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn repay_base(
@@ -4252,7 +4254,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         manager_key: &str,
         amount: Option<f64>,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, pool, base, quote) = self.manager_types(manager_key)?;
         let base_margin_pool = self.config.get_margin_pool(&pool.base_coin)?;
         let repay_amount_scaled = amount.map(|v| (v * base.scalar as f64).round() as u64);
@@ -4271,7 +4273,7 @@ impl<'a> MarginManagerContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn repay_quote(
@@ -4279,7 +4281,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         manager_key: &str,
         amount: Option<f64>,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, pool, base, quote) = self.manager_types(manager_key)?;
         let quote_margin_pool = self.config.get_margin_pool(&pool.quote_coin)?;
         let repay_amount_scaled = amount.map(|v| (v * quote.scalar as f64).round() as u64);
@@ -4298,7 +4300,7 @@ impl<'a> MarginManagerContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn liquidate(
@@ -4308,7 +4310,7 @@ impl<'a> MarginManagerContract<'a> {
         pool_key: &str,
         debt_is_base: bool,
         repay_coin_object_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -4349,7 +4351,7 @@ impl<'a> MarginManagerContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn set_margin_manager_referral(
@@ -4357,7 +4359,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         manager_key: &str,
         referral_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, _pool, base, quote) = self.manager_types(manager_key)?;
         let manager_obj = tx.object(manager.address.clone());
         let referral_obj = tx.object(referral_id.to_string());
@@ -4365,7 +4367,7 @@ impl<'a> MarginManagerContract<'a> {
             &self.margin_manager_target("set_margin_manager_referral"),
             vec![manager_obj, referral_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn unset_margin_manager_referral(
@@ -4373,7 +4375,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         manager_key: &str,
         pool_key: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let (manager, _manager_pool, base, quote) = self.manager_types(manager_key)?;
         let pool = self.config.get_pool(pool_key)?;
         let manager_obj = tx.object(manager.address.clone());
@@ -4382,7 +4384,7 @@ impl<'a> MarginManagerContract<'a> {
             &self.margin_manager_target("unset_margin_manager_referral"),
             vec![manager_obj, pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 }
 
@@ -4416,7 +4418,7 @@ impl<'a> DeepBookAdminContract<'a> {
         whitelisted: bool,
         stable_pool: bool,
         admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let base_coin = self.config.get_coin(base_coin_key)?;
         let quote_coin = self.config.get_coin(quote_coin_key)?;
         let adjusted_tick_size = ((tick_size * FLOAT_SCALAR * quote_coin.scalar as f64)
@@ -4443,7 +4445,7 @@ impl<'a> DeepBookAdminContract<'a> {
                 cap_obj,
             ],
             vec![base_coin.type_tag.clone(), quote_coin.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn unregister_pool_admin(
@@ -4451,7 +4453,7 @@ impl<'a> DeepBookAdminContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -4462,7 +4464,7 @@ impl<'a> DeepBookAdminContract<'a> {
             &self.pool_target("unregister_pool_admin"),
             vec![pool_obj, registry_obj, cap_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn update_allowed_versions(
@@ -4470,7 +4472,7 @@ impl<'a> DeepBookAdminContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -4481,7 +4483,7 @@ impl<'a> DeepBookAdminContract<'a> {
             &self.pool_target("update_allowed_versions"),
             vec![pool_obj, registry_obj, cap_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn enable_version(
@@ -4489,7 +4491,7 @@ impl<'a> DeepBookAdminContract<'a> {
         tx: &mut Transaction,
         version: u64,
         admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let registry_obj = tx.object(self.config.package_ids.registry_id.clone());
         let version_arg = tx.pure_bytes(&encode_u64(version));
         let cap_obj = tx.object(admin_cap_id.to_string());
@@ -4497,7 +4499,7 @@ impl<'a> DeepBookAdminContract<'a> {
             &self.registry_target("enable_version"),
             vec![registry_obj, version_arg, cap_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn disable_version(
@@ -4505,7 +4507,7 @@ impl<'a> DeepBookAdminContract<'a> {
         tx: &mut Transaction,
         version: u64,
         admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let registry_obj = tx.object(self.config.package_ids.registry_id.clone());
         let version_arg = tx.pure_bytes(&encode_u64(version));
         let cap_obj = tx.object(admin_cap_id.to_string());
@@ -4513,7 +4515,7 @@ impl<'a> DeepBookAdminContract<'a> {
             &self.registry_target("disable_version"),
             vec![registry_obj, version_arg, cap_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn set_treasury_address(
@@ -4521,7 +4523,7 @@ impl<'a> DeepBookAdminContract<'a> {
         tx: &mut Transaction,
         treasury_address: &str,
         admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let registry_obj = tx.object(self.config.package_ids.registry_id.clone());
         let treasury_obj = tx.object(treasury_address.to_string());
         let cap_obj = tx.object(admin_cap_id.to_string());
@@ -4529,7 +4531,7 @@ impl<'a> DeepBookAdminContract<'a> {
             &self.registry_target("set_treasury_address"),
             vec![registry_obj, treasury_obj, cap_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn add_stable_coin(
@@ -4537,7 +4539,7 @@ impl<'a> DeepBookAdminContract<'a> {
         tx: &mut Transaction,
         stable_coin_key: &str,
         admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let coin = self.config.get_coin(stable_coin_key)?;
         let registry_obj = tx.object(self.config.package_ids.registry_id.clone());
         let cap_obj = tx.object(admin_cap_id.to_string());
@@ -4545,7 +4547,7 @@ impl<'a> DeepBookAdminContract<'a> {
             &self.registry_target("add_stablecoin"),
             vec![registry_obj, cap_obj],
             vec![coin.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn add_stablecoin(
@@ -4553,7 +4555,7 @@ impl<'a> DeepBookAdminContract<'a> {
         tx: &mut Transaction,
         stable_coin_key: &str,
         admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         self.add_stable_coin(tx, stable_coin_key, admin_cap_id)
     }
 
@@ -4562,7 +4564,7 @@ impl<'a> DeepBookAdminContract<'a> {
         tx: &mut Transaction,
         stable_coin_key: &str,
         admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let coin = self.config.get_coin(stable_coin_key)?;
         let registry_obj = tx.object(self.config.package_ids.registry_id.clone());
         let cap_obj = tx.object(admin_cap_id.to_string());
@@ -4570,7 +4572,7 @@ impl<'a> DeepBookAdminContract<'a> {
             &self.registry_target("remove_stablecoin"),
             vec![registry_obj, cap_obj],
             vec![coin.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn remove_stablecoin(
@@ -4578,7 +4580,7 @@ impl<'a> DeepBookAdminContract<'a> {
         tx: &mut Transaction,
         stable_coin_key: &str,
         admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         self.remove_stable_coin(tx, stable_coin_key, admin_cap_id)
     }
 
@@ -4588,7 +4590,7 @@ impl<'a> DeepBookAdminContract<'a> {
         pool_key: &str,
         new_tick_size: f64,
         admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -4603,7 +4605,7 @@ impl<'a> DeepBookAdminContract<'a> {
             &self.pool_target("adjust_tick_size_admin"),
             vec![pool_obj, tick_arg, cap_obj, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn adjust_tick_size_admin(
@@ -4612,7 +4614,7 @@ impl<'a> DeepBookAdminContract<'a> {
         pool_key: &str,
         new_tick_size: f64,
         admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         self.adjust_tick_size(tx, pool_key, new_tick_size, admin_cap_id)
     }
 
@@ -4623,7 +4625,7 @@ impl<'a> DeepBookAdminContract<'a> {
         new_lot_size: f64,
         new_min_size: f64,
         admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -4638,7 +4640,7 @@ impl<'a> DeepBookAdminContract<'a> {
             &self.pool_target("adjust_min_lot_size_admin"),
             vec![pool_obj, lot_arg, min_arg, cap_obj, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn adjust_min_lot_size_admin(
@@ -4648,7 +4650,7 @@ impl<'a> DeepBookAdminContract<'a> {
         new_lot_size: f64,
         new_min_size: f64,
         admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         self.adjust_min_lot_size(tx, pool_key, new_lot_size, new_min_size, admin_cap_id)
     }
 
@@ -4656,14 +4658,14 @@ impl<'a> DeepBookAdminContract<'a> {
         &self,
         tx: &mut Transaction,
         admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let registry_obj = tx.object(self.config.package_ids.registry_id.clone());
         let admin_cap_obj = tx.object(admin_cap_id.to_string());
         Ok(tx.move_call(
             &self.registry_target("init_balance_manager_map"),
             vec![registry_obj, admin_cap_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn set_ewma_params(
@@ -4674,7 +4676,7 @@ impl<'a> DeepBookAdminContract<'a> {
         alpha: f64,
         z_score_threshold: f64,
         additional_taker_fee: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -4699,7 +4701,7 @@ impl<'a> DeepBookAdminContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn enable_ewma_state(
@@ -4708,7 +4710,7 @@ impl<'a> DeepBookAdminContract<'a> {
         pool_key: &str,
         admin_cap_id: &str,
         enabled: bool,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -4720,7 +4722,7 @@ impl<'a> DeepBookAdminContract<'a> {
             &self.pool_target("enable_ewma_state"),
             vec![pool_obj, admin_cap_obj, enabled_arg, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn authorize_app(
@@ -4728,14 +4730,14 @@ impl<'a> DeepBookAdminContract<'a> {
         tx: &mut Transaction,
         admin_cap_id: &str,
         app_type_tag: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let registry_obj = tx.object(self.config.package_ids.registry_id.clone());
         let admin_cap_obj = tx.object(admin_cap_id.to_string());
         Ok(tx.move_call(
             &self.registry_target("authorize_app"),
             vec![registry_obj, admin_cap_obj],
             vec![app_type_tag.to_string()],
-        ))
+        )?)
     }
 
     pub fn deauthorize_app(
@@ -4743,14 +4745,14 @@ impl<'a> DeepBookAdminContract<'a> {
         tx: &mut Transaction,
         admin_cap_id: &str,
         app_type_tag: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let registry_obj = tx.object(self.config.package_ids.registry_id.clone());
         let admin_cap_obj = tx.object(admin_cap_id.to_string());
         Ok(tx.move_call(
             &self.registry_target("deauthorize_app"),
             vec![registry_obj, admin_cap_obj],
             vec![app_type_tag.to_string()],
-        ))
+        )?)
     }
 }
 
@@ -4770,7 +4772,7 @@ impl<'a> MarginAdminContract<'a> {
         &self,
         tx: &mut Transaction,
         margin_admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let cap_obj = tx.object(margin_admin_cap_id.to_string());
         let clock_obj = tx.object("0x6");
@@ -4778,7 +4780,7 @@ impl<'a> MarginAdminContract<'a> {
             &self.margin_registry_target("mint_maintainer_cap"),
             vec![registry_obj, cap_obj, clock_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn revoke_maintainer_cap(
@@ -4786,7 +4788,7 @@ impl<'a> MarginAdminContract<'a> {
         tx: &mut Transaction,
         margin_admin_cap_id: &str,
         maintainer_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let admin_cap_obj = tx.object(margin_admin_cap_id.to_string());
         let maintainer_cap_obj = tx.object(maintainer_cap_id.to_string());
@@ -4795,7 +4797,7 @@ impl<'a> MarginAdminContract<'a> {
             &self.margin_registry_target("revoke_maintainer_cap"),
             vec![registry_obj, admin_cap_obj, maintainer_cap_obj, clock_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn register_deepbook_pool(
@@ -4803,8 +4805,8 @@ impl<'a> MarginAdminContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         margin_admin_cap_id: &str,
-        pool_config_object: serde_json::Value,
-    ) -> Result<serde_json::Value, ContractError> {
+        pool_config_object: Argument,
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -4822,7 +4824,7 @@ impl<'a> MarginAdminContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn enable_deepbook_pool(
@@ -4830,7 +4832,7 @@ impl<'a> MarginAdminContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         margin_admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -4842,7 +4844,7 @@ impl<'a> MarginAdminContract<'a> {
             &self.margin_registry_target("enable_deepbook_pool"),
             vec![registry_obj, admin_cap_obj, pool_obj, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn disable_deepbook_pool(
@@ -4850,7 +4852,7 @@ impl<'a> MarginAdminContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         margin_admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -4862,7 +4864,7 @@ impl<'a> MarginAdminContract<'a> {
             &self.margin_registry_target("disable_deepbook_pool"),
             vec![registry_obj, admin_cap_obj, pool_obj, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn update_risk_params(
@@ -4870,8 +4872,8 @@ impl<'a> MarginAdminContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         margin_admin_cap_id: &str,
-        pool_config_object: serde_json::Value,
-    ) -> Result<serde_json::Value, ContractError> {
+        pool_config_object: Argument,
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -4889,15 +4891,15 @@ impl<'a> MarginAdminContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn add_config(
         &self,
         tx: &mut Transaction,
         margin_admin_cap_id: &str,
-        config_object: serde_json::Value,
-    ) -> Result<serde_json::Value, ContractError> {
+        config_object: Argument,
+    ) -> Result<Argument, ContractError> {
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let admin_cap_obj = tx.object(margin_admin_cap_id.to_string());
         Ok(tx.move_call(
@@ -4907,14 +4909,14 @@ impl<'a> MarginAdminContract<'a> {
                 "{}::oracle::PythConfig",
                 self.config.package_ids.margin_package_id
             )],
-        ))
+        )?)
     }
 
     pub fn remove_config(
         &self,
         tx: &mut Transaction,
         margin_admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let admin_cap_obj = tx.object(margin_admin_cap_id.to_string());
         Ok(tx.move_call(
@@ -4924,7 +4926,7 @@ impl<'a> MarginAdminContract<'a> {
                 "{}::oracle::PythConfig",
                 self.config.package_ids.margin_package_id
             )],
-        ))
+        )?)
     }
 
     pub fn new_pool_config(
@@ -4937,7 +4939,7 @@ impl<'a> MarginAdminContract<'a> {
         target_liquidation_risk_ratio: f64,
         user_liquidation_reward: f64,
         pool_liquidation_reward: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -4972,7 +4974,7 @@ impl<'a> MarginAdminContract<'a> {
                 pool_reward_arg,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn new_pool_config_with_leverage(
@@ -4980,7 +4982,7 @@ impl<'a> MarginAdminContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         leverage: f64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -4990,7 +4992,7 @@ impl<'a> MarginAdminContract<'a> {
             &self.margin_registry_target("new_pool_config_with_leverage"),
             vec![registry_obj, leverage_arg],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn new_coin_type_data_from_currency(
@@ -5001,7 +5003,7 @@ impl<'a> MarginAdminContract<'a> {
         price_feed_id_bytes: &[u8],
         max_conf_bps: u64,
         max_ewma_difference_bps: u64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let coin = self.config.get_coin(coin_key)?;
         let currency_obj = tx.object(currency_id.to_string());
         let feed_id_arg = tx.pure_bytes(price_feed_id_bytes);
@@ -5014,15 +5016,15 @@ impl<'a> MarginAdminContract<'a> {
             ),
             vec![currency_obj, feed_id_arg, max_conf_arg, max_ewma_arg],
             vec![coin.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn new_pyth_config(
         &self,
         tx: &mut Transaction,
-        coin_type_data_vector: serde_json::Value,
+        coin_type_data_vector: Argument,
         max_age_seconds: u64,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let max_age_arg = tx.pure_bytes(&encode_u64(max_age_seconds));
         Ok(tx.move_call(
             &format!(
@@ -5031,7 +5033,7 @@ impl<'a> MarginAdminContract<'a> {
             ),
             vec![coin_type_data_vector, max_age_arg],
             vec![],
-        ))
+        )?)
     }
 
     pub fn enable_version(
@@ -5039,7 +5041,7 @@ impl<'a> MarginAdminContract<'a> {
         tx: &mut Transaction,
         version: u64,
         margin_admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let version_arg = tx.pure_bytes(&encode_u64(version));
         let admin_cap_obj = tx.object(margin_admin_cap_id.to_string());
@@ -5047,7 +5049,7 @@ impl<'a> MarginAdminContract<'a> {
             &self.margin_registry_target("enable_version"),
             vec![registry_obj, version_arg, admin_cap_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn disable_version(
@@ -5055,7 +5057,7 @@ impl<'a> MarginAdminContract<'a> {
         tx: &mut Transaction,
         version: u64,
         margin_admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let version_arg = tx.pure_bytes(&encode_u64(version));
         let admin_cap_obj = tx.object(margin_admin_cap_id.to_string());
@@ -5063,14 +5065,14 @@ impl<'a> MarginAdminContract<'a> {
             &self.margin_registry_target("disable_version"),
             vec![registry_obj, version_arg, admin_cap_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn mint_pause_cap(
         &self,
         tx: &mut Transaction,
         margin_admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let admin_cap_obj = tx.object(margin_admin_cap_id.to_string());
         let clock_obj = tx.object("0x6");
@@ -5078,7 +5080,7 @@ impl<'a> MarginAdminContract<'a> {
             &self.margin_registry_target("mint_pause_cap"),
             vec![registry_obj, admin_cap_obj, clock_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn revoke_pause_cap(
@@ -5086,7 +5088,7 @@ impl<'a> MarginAdminContract<'a> {
         tx: &mut Transaction,
         margin_admin_cap_id: &str,
         pause_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let admin_cap_obj = tx.object(margin_admin_cap_id.to_string());
         let clock_obj = tx.object("0x6");
@@ -5095,7 +5097,7 @@ impl<'a> MarginAdminContract<'a> {
             &self.margin_registry_target("revoke_pause_cap"),
             vec![registry_obj, admin_cap_obj, clock_obj, pause_cap_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn disable_version_pause_cap(
@@ -5103,7 +5105,7 @@ impl<'a> MarginAdminContract<'a> {
         tx: &mut Transaction,
         version: u64,
         pause_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let version_arg = tx.pure_bytes(&encode_u64(version));
         let pause_cap_obj = tx.object(pause_cap_id.to_string());
@@ -5111,7 +5113,7 @@ impl<'a> MarginAdminContract<'a> {
             &self.margin_registry_target("disable_version_pause_cap"),
             vec![registry_obj, version_arg, pause_cap_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn admin_withdraw_default_referral_fees(
@@ -5119,7 +5121,7 @@ impl<'a> MarginAdminContract<'a> {
         tx: &mut Transaction,
         coin_key: &str,
         margin_admin_cap_id: &str,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let coin = self.config.get_coin(coin_key)?;
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let margin_pool_obj = tx.object(margin_pool.address.clone());
@@ -5132,7 +5134,7 @@ impl<'a> MarginAdminContract<'a> {
             ),
             vec![margin_pool_obj, registry_obj, admin_cap_obj],
             vec![coin.type_tag.clone()],
-        ))
+        )?)
     }
 }
 
@@ -5148,7 +5150,7 @@ impl<'a> GovernanceContract<'a> {
         pool_key: &str,
         balance_manager_key: &str,
         proposal_id: u128,
-    ) -> Result<serde_json::Value, ContractError> {
+    ) -> Result<Argument, ContractError> {
         let pool = self.config.get_pool(pool_key)?;
         let manager = self.config.get_balance_manager(balance_manager_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -5167,6 +5169,6 @@ impl<'a> GovernanceContract<'a> {
             ),
             vec![pool_obj, manager_obj, proof, proposal_arg],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 }
@@ -1,5 +1,7 @@
 use crate::config::DeepBookConfig;
 use crate::config::FLOAT_SCALAR;
+use crate::encode::{encode_bool, encode_u64};
+use sui::transactions::{Argument, Transaction, TransactionError};
 
 #[derive(Debug, thiserror::Error)]
 pub enum DeepBookAdminError {
@@ -9,6 +11,8 @@ pub enum DeepBookAdminError {
     CoinNotFound(String),
     #[error("config error: {0}")]
     Config(String),
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
 }
 
 pub struct DeepBookAdminContract<'a> {
@@ -18,6 +22,7 @@ pub struct DeepBookAdminContract<'a> {
 impl<'a> DeepBookAdminContract<'a> {
     pub fn create_pool_admin(
         &self,
+        tx: &mut Transaction,
         base_coin_key: &str,
         quote_coin_key: &str,
         tick_size: f64,
@@ -25,7 +30,7 @@ impl<'a> DeepBookAdminContract<'a> {
         min_size: f64,
         whitelisted: bool,
         stable_pool: bool,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), DeepBookAdminError> {
+    ) -> Result<Argument, DeepBookAdminError> {
         let base = self
             .config
             .get_coin(base_coin_key)
@@ -44,24 +49,26 @@ impl<'a> DeepBookAdminContract<'a> {
             "{}::pool::create_pool_admin",
             self.config.package_ids.deepbook_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(self.config.package_ids.registry_id),
-                serde_json::json!(adjusted_tick_size),
-                serde_json::json!(adjusted_lot_size),
-                serde_json::json!(adjusted_min_size),
-                serde_json::json!(whitelisted),
-                serde_json::json!(stable_pool),
-            ],
+        let arguments = vec![
+            tx.object(self.config.package_ids.registry_id.clone()),
+            tx.pure_bytes(&encode_u64(adjusted_tick_size)),
+            tx.pure_bytes(&encode_u64(adjusted_lot_size)),
+            tx.pure_bytes(&encode_u64(adjusted_min_size)),
+            tx.pure_bytes(&encode_bool(whitelisted)),
+            tx.pure_bytes(&encode_bool(stable_pool)),
+        ];
+        Ok(tx.move_call(
+            &target,
+            arguments,
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn unregister_pool_admin(
         &self,
+        tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), DeepBookAdminError> {
+    ) -> Result<Argument, DeepBookAdminError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -79,20 +86,22 @@ impl<'a> DeepBookAdminContract<'a> {
             "{}::pool::unregister_pool_admin",
             self.config.package_ids.deepbook_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(pool.address),
-                serde_json::json!(self.config.package_ids.registry_id),
-            ],
+        let arguments = vec![
+            tx.object(pool.address.clone()),
+            tx.object(self.config.package_ids.registry_id.clone()),
+        ];
+        Ok(tx.move_call(
+            &target,
+            arguments,
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn update_allowed_versions(
         &self,
+        tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), DeepBookAdminError> {
+    ) -> Result<Argument, DeepBookAdminError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -110,50 +119,47 @@ impl<'a> DeepBookAdminContract<'a> {
             "{}::pool::update_allowed_versions",
             self.config.package_ids.deepbook_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(pool.address),
-                serde_json::json!(self.config.package_ids.registry_id),
-            ],
+        let arguments = vec![
+            tx.object(pool.address.clone()),
+            tx.object(self.config.package_ids.registry_id.clone()),
+        ];
+        Ok(tx.move_call(
+            &target,
+            arguments,
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn enable_version(
         &self,
+        tx: &mut Transaction,
         version: u64,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), DeepBookAdminError> {
+    ) -> Result<Argument, DeepBookAdminError> {
         let target = format!(
             "{}::registry::enable_version",
             self.config.package_ids.deepbook_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(self.config.package_ids.registry_id),
-                serde_json::json!(version),
-            ],
-            vec![],
-        ))
+        let arguments = vec![
+            tx.object(self.config.package_ids.registry_id.clone()),
+            tx.pure_bytes(&encode_u64(version)),
+        ];
+        Ok(tx.move_call(&target, arguments, vec![])?)
     }
 
     pub fn disable_version(
         &self,
+        tx: &mut Transaction,
         version: u64,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), DeepBookAdminError> {
+    ) -> Result<Argument, DeepBookAdminError> {
         let target = format!(
             "{}::registry::disable_version",
             self.config.package_ids.deepbook_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(self.config.package_ids.registry_id),
-                serde_json::json!(version),
-            ],
-            vec![],
-        ))
+        let arguments = vec![
+            tx.object(self.config.package_ids.registry_id.clone()),
+            tx.pure_bytes(&encode_u64(version)),
+        ];
+        Ok(tx.move_call(&target, arguments, vec![])?)
     }
 }
 
@@ -172,7 +178,9 @@ mod tests {
     fn test_create_pool_admin() {
         let config = DeepBookConfig::default();
         let admin = DeepBookAdminContract { config: &config };
-        let result = admin.create_pool_admin("DEEP", "SUI", 0.0001, 0.01, 0.1, false, false);
+        let mut tx = Transaction::new();
+        let result =
+            admin.create_pool_admin(&mut tx, "DEEP", "SUI", 0.0001, 0.01, 0.1, false, false);
         assert!(result.is_ok());
     }
 
@@ -180,7 +188,8 @@ mod tests {
     fn test_unregister_pool_admin() {
         let config = DeepBookConfig::default();
         let admin = DeepBookAdminContract { config: &config };
-        let result = admin.unregister_pool_admin("DEEP_SUI");
+        let mut tx = Transaction::new();
+        let result = admin.unregister_pool_admin(&mut tx, "DEEP_SUI");
         assert!(result.is_ok());
     }
 }
@@ -1,5 +1,6 @@
 use crate::config::DeepBookConfig;
 use crate::encode::encode_u64;
+use sui::transactions::{Argument, Transaction, TransactionError};
 
 #[derive(Debug, thiserror::Error)]
 pub enum FlashLoansError {
@@ -7,6 +8,8 @@ pub enum FlashLoansError {
     PoolNotFound(String),
     #[error("coin not found: {0}")]
     CoinNotFound(String),
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
 }
 
 pub struct FlashLoansContract<'a> {
@@ -16,9 +19,10 @@ pub struct FlashLoansContract<'a> {
 impl<'a> FlashLoansContract<'a> {
     pub fn borrow_base_asset(
         &self,
+        tx: &mut Transaction,
         pool_key: &str,
         borrow_amount: f64,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), FlashLoansError> {
+    ) -> Result<Argument, FlashLoansError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -38,21 +42,23 @@ impl<'a> FlashLoansContract<'a> {
             "{}::pool::borrow_flashloan_base",
             self.config.package_ids.deepbook_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(pool.address),
-                serde_json::json!(input_quantity),
-            ],
+        let arguments = vec![
+            tx.object(pool.address.clone()),
+            tx.pure_bytes(&encode_u64(input_quantity)),
+        ];
+        Ok(tx.move_call(
+            &target,
+            arguments,
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn return_base_asset(
         &self,
+        tx: &mut Transaction,
         pool_key: &str,
         borrow_amount: f64,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), FlashLoansError> {
+    ) -> Result<Argument, FlashLoansError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -72,21 +78,23 @@ impl<'a> FlashLoansContract<'a> {
             "{}::pool::return_flashloan_base",
             self.config.package_ids.deepbook_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(pool.address),
-                serde_json::json!(input_quantity),
-            ],
+        let arguments = vec![
+            tx.object(pool.address.clone()),
+            tx.pure_bytes(&encode_u64(input_quantity)),
+        ];
+        Ok(tx.move_call(
+            &target,
+            arguments,
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn borrow_quote_asset(
         &self,
+        tx: &mut Transaction,
         pool_key: &str,
         borrow_amount: f64,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), FlashLoansError> {
+    ) -> Result<Argument, FlashLoansError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -106,21 +114,23 @@ impl<'a> FlashLoansContract<'a> {
             "{}::pool::borrow_flashloan_quote",
             self.config.package_ids.deepbook_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(pool.address),
-                serde_json::json!(input_quantity),
-            ],
+        let arguments = vec![
+            tx.object(pool.address.clone()),
+            tx.pure_bytes(&encode_u64(input_quantity)),
+        ];
+        Ok(tx.move_call(
+            &target,
+            arguments,
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn return_quote_asset(
         &self,
+        tx: &mut Transaction,
         pool_key: &str,
         borrow_amount: f64,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), FlashLoansError> {
+    ) -> Result<Argument, FlashLoansError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -140,14 +150,15 @@ impl<'a> FlashLoansContract<'a> {
             "{}::pool::return_flashloan_quote",
             self.config.package_ids.deepbook_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(pool.address),
-                serde_json::json!(input_quantity),
-            ],
+        let arguments = vec![
+            tx.object(pool.address.clone()),
+            tx.pure_bytes(&encode_u64(input_quantity)),
+        ];
+        Ok(tx.move_call(
+            &target,
+            arguments,
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 }
 
@@ -166,7 +177,8 @@ mod tests {
     fn test_borrow_base_asset() {
         let config = DeepBookConfig::default();
         let flash_loans = FlashLoansContract { config: &config };
-        let result = flash_loans.borrow_base_asset("DEEP_SUI", 100.0);
+        let mut tx = Transaction::new();
+        let result = flash_loans.borrow_base_asset(&mut tx, "DEEP_SUI", 100.0);
         assert!(result.is_ok());
     }
 
@@ -174,7 +186,8 @@ mod tests {
     fn test_borrow_quote_asset() {
         let config = DeepBookConfig::default();
         let flash_loans = FlashLoansContract { config: &config };
-        let result = flash_loans.borrow_quote_asset("DEEP_SUI", 100.0);
+        let mut tx = Transaction::new();
+        let result = flash_loans.borrow_quote_asset(&mut tx, "DEEP_SUI", 100.0);
         assert!(result.is_ok());
     }
 }
@@ -1,6 +1,6 @@
 use crate::config::DeepBookConfig;
 use crate::encode::{encode_u64, encode_u8};
-use sui::transactions::Transaction;
+use sui::transactions::{Argument, Transaction, TransactionError};
 
 #[derive(Debug, thiserror::Error)]
 pub enum GovernanceError {
@@ -12,6 +12,8 @@ pub enum GovernanceError {
     BalanceManagerNotFound(String),
     #[error("coin not found: {0}")]
     CoinNotFound(String),
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
 }
 
 pub struct GovernanceContract<'a> {
@@ -25,7 +27,7 @@ impl<'a> GovernanceContract<'a> {
         pool_key: &str,
         balance_manager_key: &str,
         stake_amount: f64,
-    ) -> Result<serde_json::Value, GovernanceError> {
+    ) -> Result<Argument, GovernanceError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -56,7 +58,7 @@ impl<'a> GovernanceContract<'a> {
             ),
             vec![pool_obj, manager_obj, proof, amount_arg],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn unstake(
@@ -64,7 +66,7 @@ impl<'a> GovernanceContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         balance_manager_key: &str,
-    ) -> Result<serde_json::Value, GovernanceError> {
+    ) -> Result<Argument, GovernanceError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -92,7 +94,7 @@ impl<'a> GovernanceContract<'a> {
             ),
             vec![pool_obj, manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn submit_proposal(
@@ -103,7 +105,7 @@ impl<'a> GovernanceContract<'a> {
         taker_fee: f64,
         maker_fee: f64,
         stake_required: f64,
-    ) -> Result<serde_json::Value, GovernanceError> {
+    ) -> Result<Argument, GovernanceError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -144,7 +146,7 @@ impl<'a> GovernanceContract<'a> {
                 stake_arg,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn vote(
@@ -153,7 +155,7 @@ impl<'a> GovernanceContract<'a> {
         pool_key: &str,
         balance_manager_key: &str,
         proposal_id: &str,
-    ) -> Result<serde_json::Value, GovernanceError> {
+    ) -> Result<Argument, GovernanceError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -182,7 +184,7 @@ impl<'a> GovernanceContract<'a> {
             ),
             vec![pool_obj, manager_obj, proposal_id_arg],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 }
 
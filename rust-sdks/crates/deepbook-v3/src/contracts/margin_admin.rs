@@ -1,5 +1,6 @@
 use crate::config::DeepBookConfig;
 use crate::config::FLOAT_SCALAR;
+use sui::transactions::{Argument, Transaction, TransactionError};
 
 #[derive(Debug, thiserror::Error)]
 pub enum MarginAdminError {
@@ -9,6 +10,8 @@ pub enum MarginAdminError {
     CoinNotFound(String),
     #[error("config error: {0}")]
     Config(String),
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
 }
 
 pub struct MarginAdminContract<'a> {
@@ -16,27 +19,23 @@ pub struct MarginAdminContract<'a> {
 }
 
 impl<'a> MarginAdminContract<'a> {
-    pub fn mint_maintainer_cap(
-        &self,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), MarginAdminError> {
+    pub fn mint_maintainer_cap(&self, tx: &mut Transaction) -> Result<Argument, MarginAdminError> {
         let target = format!(
             "{}::margin_registry::mint_maintainer_cap",
             self.config.package_ids.margin_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(self.config.package_ids.margin_registry_id),
-                serde_json::json!("0x6"), // clock
-            ],
-            vec![],
-        ))
+        let arguments = vec![
+            tx.object(self.config.package_ids.margin_registry_id.clone()),
+            tx.object("0x6"),
+        ];
+        Ok(tx.move_call(&target, arguments, vec![])?)
     }
 
     pub fn register_deepbook_pool(
         &self,
+        tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), MarginAdminError> {
+    ) -> Result<Argument, MarginAdminError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -54,22 +53,24 @@ impl<'a> MarginAdminContract<'a> {
             "{}::margin_registry::register_deepbook_pool",
             self.config.package_ids.margin_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(self.config.package_ids.margin_registry_id),
-                serde_json::json!(pool.address),
-                serde_json::json!("0x6"), // config placeholder
-                serde_json::json!("0x6"), // clock placeholder
-            ],
+        let arguments = vec![
+            tx.object(self.config.package_ids.margin_registry_id.clone()),
+            tx.object(pool.address.clone()),
+            tx.object("0x6"),
+            tx.object("0x6"),
+        ];
+        Ok(tx.move_call(
+            &target,
+            arguments,
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn enable_deepbook_pool(
         &self,
+        tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), MarginAdminError> {
+    ) -> Result<Argument, MarginAdminError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -87,21 +88,23 @@ impl<'a> MarginAdminContract<'a> {
             "{}::margin_registry::enable_deepbook_pool",
             self.config.package_ids.margin_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(self.config.package_ids.margin_registry_id),
-                serde_json::json!(pool.address),
-                serde_json::json!("0x6"), // clock
-            ],
+        let arguments = vec![
+            tx.object(self.config.package_ids.margin_registry_id.clone()),
+            tx.object(pool.address.clone()),
+            tx.object("0x6"),
+        ];
+        Ok(tx.move_call(
+            &target,
+            arguments,
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn disable_deepbook_pool(
         &self,
+        tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), MarginAdminError> {
+    ) -> Result<Argument, MarginAdminError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -119,25 +122,27 @@ impl<'a> MarginAdminContract<'a> {
             "{}::margin_registry::disable_deepbook_pool",
             self.config.package_ids.margin_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(self.config.package_ids.margin_registry_id),
-                serde_json::json!(pool.address),
-                serde_json::json!("0x6"), // clock
-            ],
+        let arguments = vec![
+            tx.object(self.config.package_ids.margin_registry_id.clone()),
+            tx.object(pool.address.clone()),
+            tx.object("0x6"),
+        ];
+        Ok(tx.move_call(
+            &target,
+            arguments,
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn update_risk_params(
         &self,
+        tx: &mut Transaction,
         pool_key: &str,
         min_withdraw_risk_ratio: f64,
         min_borrow_risk_ratio: f64,
         liquidation_risk_ratio: f64,
         target_liquidation_risk_ratio: f64,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), MarginAdminError> {
+    ) -> Result<Argument, MarginAdminError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -155,16 +160,17 @@ impl<'a> MarginAdminContract<'a> {
             "{}::margin_registry::update_risk_params",
             self.config.package_ids.margin_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(self.config.package_ids.margin_registry_id),
-                serde_json::json!(pool.address),
-                serde_json::json!("0x6"), // config placeholder
-                serde_json::json!("0x6"), // clock placeholder
-            ],
+        let arguments = vec![
+            tx.object(self.config.package_ids.margin_registry_id.clone()),
+            tx.object(pool.address.clone()),
+            tx.object("0x6"),
+            tx.object("0x6"),
+        ];
+        Ok(tx.move_call(
+            &target,
+            arguments,
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 }
 
@@ -183,7 +189,8 @@ mod tests {
     fn test_register_deepbook_pool() {
         let config = DeepBookConfig::default();
         let admin = MarginAdminContract { config: &config };
-        let result = admin.register_deepbook_pool("DEEP_SUI");
+        let mut tx = Transaction::new();
+        let result = admin.register_deepbook_pool(&mut tx, "DEEP_SUI");
         assert!(result.is_ok());
     }
 
@@ -191,7 +198,8 @@ mod tests {
     fn test_enable_deepbook_pool() {
         let config = DeepBookConfig::default();
         let admin = MarginAdminContract { config: &config };
-        let result = admin.enable_deepbook_pool("DEEP_SUI");
+        let mut tx = Transaction::new();
+        let result = admin.enable_deepbook_pool(&mut tx, "DEEP_SUI");
         assert!(result.is_ok());
     }
 }
@@ -1,6 +1,6 @@
 use crate::config::{ConfigError, DeepBookConfig};
 use crate::encode::{encode_option_u64, encode_u64};
-use sui::transactions::Transaction;
+use sui::transactions::{Argument, Transaction, TransactionError};
 
 #[derive(Debug, thiserror::Error)]
 pub enum MarginLiquidationsError {
@@ -12,6 +12,8 @@ pub enum MarginLiquidationsError {
     PoolNotFound(String),
     #[error("margin pool not found: {0}")]
     MarginPoolNotFound(String),
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
 }
 
 impl From<ConfigError> for MarginLiquidationsError {
@@ -29,7 +31,7 @@ impl<'a> MarginLiquidationsContract<'a> {
         &self,
         tx: &mut Transaction,
         admin_cap: &str,
-    ) -> Result<serde_json::Value, MarginLiquidationsError> {
+    ) -> Result<Argument, MarginLiquidationsError> {
         let admin_cap_obj = tx.object(admin_cap.to_string());
 
         Ok(tx.move_call(
@@ -39,7 +41,7 @@ impl<'a> MarginLiquidationsContract<'a> {
             ),
             vec![admin_cap_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn deposit(
@@ -49,7 +51,7 @@ impl<'a> MarginLiquidationsContract<'a> {
         admin_cap: &str,
         coin_key: &str,
         amount: f64,
-    ) -> Result<serde_json::Value, MarginLiquidationsError> {
+    ) -> Result<Argument, MarginLiquidationsError> {
         let coin = self.config.get_coin(coin_key)?;
         let vault_obj = tx.object(vault_id.to_string());
         let admin_cap_obj = tx.object(admin_cap.to_string());
@@ -62,7 +64,7 @@ impl<'a> MarginLiquidationsContract<'a> {
             ),
             vec![vault_obj, admin_cap_obj, coin_obj],
             vec![coin.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn withdraw(
@@ -72,7 +74,7 @@ impl<'a> MarginLiquidationsContract<'a> {
         admin_cap: &str,
         coin_key: &str,
         amount: f64,
-    ) -> Result<serde_json::Value, MarginLiquidationsError> {
+    ) -> Result<Argument, MarginLiquidationsError> {
         let coin = self.config.get_coin(coin_key)?;
         let vault_obj = tx.object(vault_id.to_string());
         let admin_cap_obj = tx.object(admin_cap.to_string());
@@ -85,7 +87,7 @@ impl<'a> MarginLiquidationsContract<'a> {
             ),
             vec![vault_obj, admin_cap_obj, amount_arg],
             vec![coin.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn liquidate_base(
@@ -95,7 +97,7 @@ impl<'a> MarginLiquidationsContract<'a> {
         manager_address: &str,
         pool_key: &str,
         repay_amount: Option<f64>,
-    ) -> Result<serde_json::Value, MarginLiquidationsError> {
+    ) -> Result<Argument, MarginLiquidationsError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -142,7 +144,7 @@ impl<'a> MarginLiquidationsContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn liquidate_quote(
@@ -152,7 +154,7 @@ impl<'a> MarginLiquidationsContract<'a> {
         manager_address: &str,
         pool_key: &str,
         repay_amount: Option<f64>,
-    ) -> Result<serde_json::Value, MarginLiquidationsError> {
+    ) -> Result<Argument, MarginLiquidationsError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -199,7 +201,7 @@ impl<'a> MarginLiquidationsContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn balance(
@@ -207,7 +209,7 @@ impl<'a> MarginLiquidationsContract<'a> {
         tx: &mut Transaction,
         vault_id: &str,
         coin_key: &str,
-    ) -> Result<serde_json::Value, MarginLiquidationsError> {
+    ) -> Result<Argument, MarginLiquidationsError> {
         let coin = self.config.get_coin(coin_key)?;
         let vault_obj = tx.object(vault_id.to_string());
 
@@ -218,7 +220,7 @@ impl<'a> MarginLiquidationsContract<'a> {
             ),
             vec![vault_obj],
             vec![coin.type_tag.clone()],
-        ))
+        )?)
     }
 }
 
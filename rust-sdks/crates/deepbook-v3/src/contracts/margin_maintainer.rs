@@ -1,6 +1,6 @@
 use crate::config::{ConfigError, DeepBookConfig};
 use crate::encode::{encode_bool, encode_u64};
-use sui::transactions::Transaction;
+use sui::transactions::{Argument, Transaction, TransactionError};
 
 #[derive(Debug, thiserror::Error)]
 pub enum MarginMaintainerError {
@@ -14,6 +14,8 @@ pub enum MarginMaintainerError {
     PoolNotFound(String),
     #[error("margin maintainer cap not set")]
     MaintainerCapNotSet,
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
 }
 
 impl From<ConfigError> for MarginMaintainerError {
@@ -47,7 +49,7 @@ impl<'a> MarginMaintainerContract<'a> {
         coin_key: &str,
         margin_pool_config: &MarginPoolConfigParams,
         interest_config: &InterestConfigParams,
-    ) -> Result<serde_json::Value, MarginMaintainerError> {
+    ) -> Result<Argument, MarginMaintainerError> {
         let margin_pool_config_obj = if margin_pool_config.rate_limit_capacity.is_some()
             && margin_pool_config.rate_limit_refill_rate_per_ms.is_some()
             && margin_pool_config.rate_limit_enabled.is_some()
@@ -65,7 +67,7 @@ impl<'a> MarginMaintainerContract<'a> {
             ),
             vec![margin_pool_config_obj, interest_config_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn new_margin_pool_config(
@@ -73,7 +75,7 @@ impl<'a> MarginMaintainerContract<'a> {
         tx: &mut Transaction,
         coin_key: &str,
         margin_pool_config: &MarginPoolConfigParams,
-    ) -> Result<serde_json::Value, MarginMaintainerError> {
+    ) -> Result<Argument, MarginMaintainerError> {
         let coin = self.config.get_coin(coin_key)?;
         let supply_cap_arg = tx.pure_bytes(&encode_u64(
             (margin_pool_config.supply_cap * coin.scalar as f64).round() as u64,
@@ -100,7 +102,7 @@ impl<'a> MarginMaintainerContract<'a> {
                 min_borrow_arg,
             ],
             vec![],
-        ))
+        )?)
     }
 
     pub fn new_margin_pool_config_with_rate_limit(
@@ -108,7 +110,7 @@ impl<'a> MarginMaintainerContract<'a> {
         tx: &mut Transaction,
         coin_key: &str,
         margin_pool_config: &MarginPoolConfigParams,
-    ) -> Result<serde_json::Value, MarginMaintainerError> {
+    ) -> Result<Argument, MarginMaintainerError> {
         let coin = self.config.get_coin(coin_key)?;
         let supply_cap_arg = tx.pure_bytes(&encode_u64(
             (margin_pool_config.supply_cap * coin.scalar as f64).round() as u64,
@@ -157,14 +159,14 @@ impl<'a> MarginMaintainerContract<'a> {
                 rate_limit_enabled_arg,
             ],
             vec![],
-        ))
+        )?)
     }
 
     pub fn new_interest_config(
         &self,
         tx: &mut Transaction,
         interest_config: &InterestConfigParams,
-    ) -> Result<serde_json::Value, MarginMaintainerError> {
+    ) -> Result<Argument, MarginMaintainerError> {
         let base_rate_arg = tx.pure_bytes(&encode_u64(
             (interest_config.base_rate * crate::config::FLOAT_SCALAR).round() as u64,
         ));
@@ -190,7 +192,7 @@ impl<'a> MarginMaintainerContract<'a> {
                 excess_slope_arg,
             ],
             vec![],
-        ))
+        )?)
     }
 
     pub fn enable_deepbook_pool_for_loan(
@@ -199,7 +201,7 @@ impl<'a> MarginMaintainerContract<'a> {
         deepbook_pool_key: &str,
         coin_key: &str,
         margin_pool_cap: &str,
-    ) -> Result<serde_json::Value, MarginMaintainerError> {
+    ) -> Result<Argument, MarginMaintainerError> {
         let deepbook_pool = self.config.get_pool(deepbook_pool_key)?;
         let margin_pool = self.config.get_margin_pool(coin_key)?;
 
@@ -222,7 +224,7 @@ impl<'a> MarginMaintainerContract<'a> {
                 clock_obj,
             ],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn disable_deepbook_pool_for_loan(
@@ -231,7 +233,7 @@ impl<'a> MarginMaintainerContract<'a> {
         deepbook_pool_key: &str,
         coin_key: &str,
         margin_pool_cap: &str,
-    ) -> Result<serde_json::Value, MarginMaintainerError> {
+    ) -> Result<Argument, MarginMaintainerError> {
         let deepbook_pool = self.config.get_pool(deepbook_pool_key)?;
         let margin_pool = self.config.get_margin_pool(coin_key)?;
 
@@ -254,7 +256,7 @@ impl<'a> MarginMaintainerContract<'a> {
                 clock_obj,
             ],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn update_interest_params(
@@ -263,7 +265,7 @@ impl<'a> MarginMaintainerContract<'a> {
         coin_key: &str,
         margin_pool_cap: &str,
         interest_config: &InterestConfigParams,
-    ) -> Result<serde_json::Value, MarginMaintainerError> {
+    ) -> Result<Argument, MarginMaintainerError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let interest_config_obj = self.new_interest_config(tx, interest_config)?;
         let margin_pool_obj = tx.object(margin_pool.address.clone());
@@ -284,7 +286,7 @@ impl<'a> MarginMaintainerContract<'a> {
                 clock_obj,
             ],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn update_margin_pool_config(
@@ -293,7 +295,7 @@ impl<'a> MarginMaintainerContract<'a> {
         coin_key: &str,
         margin_pool_cap: &str,
         margin_pool_config: &MarginPoolConfigParams,
-    ) -> Result<serde_json::Value, MarginMaintainerError> {
+    ) -> Result<Argument, MarginMaintainerError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         if margin_pool_config.rate_limit_capacity.is_some()
             && margin_pool_config.rate_limit_refill_rate_per_ms.is_some()
@@ -319,7 +321,7 @@ impl<'a> MarginMaintainerContract<'a> {
                     clock_obj,
                 ],
                 vec![margin_pool.type_tag.clone()],
-            ))
+            )?)
         } else {
             let margin_pool_config_obj =
                 self.new_margin_pool_config(tx, coin_key, margin_pool_config)?;
@@ -341,7 +343,7 @@ impl<'a> MarginMaintainerContract<'a> {
                     clock_obj,
                 ],
                 vec![margin_pool.type_tag.clone()],
-            ))
+            )?)
         }
     }
 }
@@ -1,6 +1,6 @@
 use crate::config::{ConfigError, DeepBookConfig};
 use crate::encode::{encode_bool, encode_u64, encode_u8};
-use sui::transactions::Transaction;
+use sui::transactions::{Argument, Transaction, TransactionError};
 
 #[derive(Debug, thiserror::Error)]
 pub enum MarginManagerError {
@@ -14,6 +14,8 @@ pub enum MarginManagerError {
     MarginManagerNotFound(String),
     #[error("margin pool not found: {0}")]
     MarginPoolNotFound(String),
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
 }
 
 impl From<ConfigError> for MarginManagerError {
@@ -31,7 +33,7 @@ impl<'a> MarginManagerContract<'a> {
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -48,14 +50,14 @@ impl<'a> MarginManagerContract<'a> {
             ),
             vec![pool_obj, registry_obj, margin_registry_obj, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn new_margin_manager_with_initializer(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -72,14 +74,14 @@ impl<'a> MarginManagerContract<'a> {
             ),
             vec![pool_obj, registry_obj, margin_registry_obj, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn share_margin_manager(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -91,16 +93,16 @@ impl<'a> MarginManagerContract<'a> {
             ),
             vec![],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn deposit_during_initialization(
         &self,
         tx: &mut Transaction,
-        manager: serde_json::Value,
+        manager: Argument,
         pool_key: &str,
         coin_type: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -139,7 +141,7 @@ impl<'a> MarginManagerContract<'a> {
                 quote.type_tag.clone(),
                 deposit_coin.type_tag.clone(),
             ],
-        ))
+        )?)
     }
 
     pub fn deposit_base(
@@ -147,7 +149,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         margin_manager_key: &str,
         amount: f64,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -188,7 +190,7 @@ impl<'a> MarginManagerContract<'a> {
                 quote.type_tag.clone(),
                 deep.type_tag.clone(),
             ],
-        ))
+        )?)
     }
 
     pub fn withdraw_base(
@@ -196,7 +198,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         margin_manager_key: &str,
         amount: f64,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -244,7 +246,7 @@ impl<'a> MarginManagerContract<'a> {
                 quote.type_tag.clone(),
                 base.type_tag.clone(),
             ],
-        ))
+        )?)
     }
 
     pub fn withdraw_quote(
@@ -252,7 +254,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         margin_manager_key: &str,
         amount: f64,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -300,7 +302,7 @@ impl<'a> MarginManagerContract<'a> {
                 quote.type_tag.clone(),
                 quote.type_tag.clone(),
             ],
-        ))
+        )?)
     }
 
     pub fn withdraw_deep(
@@ -308,7 +310,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         margin_manager_key: &str,
         amount: f64,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -357,7 +359,7 @@ impl<'a> MarginManagerContract<'a> {
                 quote.type_tag.clone(),
                 deep.type_tag.clone(),
             ],
-        ))
+        )?)
     }
 
     pub fn borrow_base(
@@ -365,7 +367,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         margin_manager_key: &str,
         amount: f64,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -406,7 +408,7 @@ impl<'a> MarginManagerContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn borrow_quote(
@@ -414,7 +416,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         margin_manager_key: &str,
         amount: f64,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -455,7 +457,7 @@ impl<'a> MarginManagerContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn repay_base(
@@ -463,7 +465,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         margin_manager_key: &str,
         amount: Option<f64>,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -490,7 +492,7 @@ impl<'a> MarginManagerContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn repay_quote(
@@ -498,7 +500,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         margin_manager_key: &str,
         amount: Option<f64>,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -525,7 +527,7 @@ impl<'a> MarginManagerContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn liquidate(
@@ -535,7 +537,7 @@ impl<'a> MarginManagerContract<'a> {
         pool_key: &str,
         debt_is_base: bool,
         repay_amount: f64,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -584,7 +586,7 @@ impl<'a> MarginManagerContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn set_margin_manager_referral(
@@ -592,7 +594,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         margin_manager_key: &str,
         referral: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -608,7 +610,7 @@ impl<'a> MarginManagerContract<'a> {
             ),
             vec![manager_obj, referral_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn unset_margin_manager_referral(
@@ -616,7 +618,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         margin_manager_key: &str,
         pool_key: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -632,7 +634,7 @@ impl<'a> MarginManagerContract<'a> {
             ),
             vec![manager_obj, pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn owner(
@@ -640,7 +642,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         margin_manager_id: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -653,7 +655,7 @@ impl<'a> MarginManagerContract<'a> {
             ),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn deepbook_pool(
@@ -661,7 +663,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         margin_manager_id: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -674,7 +676,7 @@ impl<'a> MarginManagerContract<'a> {
             ),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn margin_pool_id(
@@ -682,7 +684,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         margin_manager_id: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -695,7 +697,7 @@ impl<'a> MarginManagerContract<'a> {
             ),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn borrowed_shares(
@@ -703,7 +705,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         margin_manager_id: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -716,7 +718,7 @@ impl<'a> MarginManagerContract<'a> {
             ),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn borrowed_base_shares(
@@ -724,7 +726,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         margin_manager_id: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -737,7 +739,7 @@ impl<'a> MarginManagerContract<'a> {
             ),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn borrowed_quote_shares(
@@ -745,7 +747,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         margin_manager_id: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -758,7 +760,7 @@ impl<'a> MarginManagerContract<'a> {
             ),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn has_base_debt(
@@ -766,7 +768,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         margin_manager_id: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -779,7 +781,7 @@ impl<'a> MarginManagerContract<'a> {
             ),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn balance_manager(
@@ -787,7 +789,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         margin_manager_id: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -800,7 +802,7 @@ impl<'a> MarginManagerContract<'a> {
             ),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn calculate_assets(
@@ -808,7 +810,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         margin_manager_id: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -822,7 +824,7 @@ impl<'a> MarginManagerContract<'a> {
             ),
             vec![manager_obj, pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn calculate_debts(
@@ -831,7 +833,7 @@ impl<'a> MarginManagerContract<'a> {
         pool_key: &str,
         coin_key: &str,
         margin_manager_id: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -852,7 +854,7 @@ impl<'a> MarginManagerContract<'a> {
                 quote.type_tag.clone(),
                 debt_coin.type_tag.clone(),
             ],
-        ))
+        )?)
     }
 
     pub fn manager_state(
@@ -860,7 +862,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         margin_manager_id: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -901,7 +903,7 @@ impl<'a> MarginManagerContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn base_balance(
@@ -909,7 +911,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         margin_manager_id: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -922,7 +924,7 @@ impl<'a> MarginManagerContract<'a> {
             ),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn quote_balance(
@@ -930,7 +932,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         margin_manager_id: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -943,7 +945,7 @@ impl<'a> MarginManagerContract<'a> {
             ),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn deep_balance(
@@ -951,7 +953,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         margin_manager_id: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -964,7 +966,7 @@ impl<'a> MarginManagerContract<'a> {
             ),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_margin_account_order_details(
@@ -972,7 +974,7 @@ impl<'a> MarginManagerContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         margin_manager_id: &str,
-    ) -> Result<serde_json::Value, MarginManagerError> {
+    ) -> Result<Argument, MarginManagerError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -985,7 +987,7 @@ impl<'a> MarginManagerContract<'a> {
             ),
             vec![manager_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        );
+        )?;
 
         let pool_obj = tx.object(pool.address.clone());
 
@@ -996,7 +998,7 @@ impl<'a> MarginManagerContract<'a> {
             ),
             vec![pool_obj, balance_manager],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 }
 
@@ -1,6 +1,6 @@
 use crate::config::{ConfigError, DeepBookConfig};
 use crate::encode::{encode_option_u64, encode_u64};
-use sui::transactions::Transaction;
+use sui::transactions::{Argument, Transaction, TransactionError};
 
 #[derive(Debug, thiserror::Error)]
 pub enum MarginPoolError {
@@ -10,6 +10,8 @@ pub enum MarginPoolError {
     CoinNotFound(String),
     #[error("margin pool not found: {0}")]
     MarginPoolNotFound(String),
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
 }
 
 impl From<ConfigError> for MarginPoolError {
@@ -26,7 +28,7 @@ impl<'a> MarginPoolContract<'a> {
     pub fn mint_supplier_cap(
         &self,
         tx: &mut Transaction,
-    ) -> Result<serde_json::Value, MarginPoolError> {
+    ) -> Result<Argument, MarginPoolError> {
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let clock_obj = tx.object("0x6");
 
@@ -37,7 +39,7 @@ impl<'a> MarginPoolContract<'a> {
             ),
             vec![registry_obj, clock_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn supply(
@@ -45,7 +47,7 @@ impl<'a> MarginPoolContract<'a> {
         tx: &mut Transaction,
         coin_key: &str,
         amount: f64,
-    ) -> Result<serde_json::Value, MarginPoolError> {
+    ) -> Result<Argument, MarginPoolError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let coin = self.config.get_coin(coin_key)?;
         let deposit_input = (amount * coin.scalar as f64).round() as u64;
@@ -62,7 +64,7 @@ impl<'a> MarginPoolContract<'a> {
             ),
             vec![margin_pool_obj, registry_obj, amount_arg, clock_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn withdraw(
@@ -70,7 +72,7 @@ impl<'a> MarginPoolContract<'a> {
         tx: &mut Transaction,
         coin_key: &str,
         amount: Option<f64>,
-    ) -> Result<serde_json::Value, MarginPoolError> {
+    ) -> Result<Argument, MarginPoolError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
         let coin = self.config.get_coin(coin_key)?;
 
@@ -87,14 +89,14 @@ impl<'a> MarginPoolContract<'a> {
             ),
             vec![margin_pool_obj, registry_obj, amount_arg, clock_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn mint_supply_referral(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, MarginPoolError> {
+    ) -> Result<Argument, MarginPoolError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
 
         let margin_pool_obj = tx.object(margin_pool.address.clone());
@@ -108,7 +110,7 @@ impl<'a> MarginPoolContract<'a> {
             ),
             vec![margin_pool_obj, registry_obj, clock_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn withdraw_referral_fees(
@@ -116,7 +118,7 @@ impl<'a> MarginPoolContract<'a> {
         tx: &mut Transaction,
         coin_key: &str,
         referral_id: &str,
-    ) -> Result<serde_json::Value, MarginPoolError> {
+    ) -> Result<Argument, MarginPoolError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
 
         let margin_pool_obj = tx.object(margin_pool.address.clone());
@@ -130,14 +132,14 @@ impl<'a> MarginPoolContract<'a> {
             ),
             vec![margin_pool_obj, registry_obj, referral_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn id(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, MarginPoolError> {
+    ) -> Result<Argument, MarginPoolError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
 
         let margin_pool_obj = tx.object(margin_pool.address.clone());
@@ -149,7 +151,7 @@ impl<'a> MarginPoolContract<'a> {
             ),
             vec![margin_pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn deepbook_pool_allowed(
@@ -157,7 +159,7 @@ impl<'a> MarginPoolContract<'a> {
         tx: &mut Transaction,
         coin_key: &str,
         deepbook_pool_id: &str,
-    ) -> Result<serde_json::Value, MarginPoolError> {
+    ) -> Result<Argument, MarginPoolError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
 
         let margin_pool_obj = tx.object(margin_pool.address.clone());
@@ -170,14 +172,14 @@ impl<'a> MarginPoolContract<'a> {
             ),
             vec![margin_pool_obj, pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn total_supply(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, MarginPoolError> {
+    ) -> Result<Argument, MarginPoolError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
 
         let margin_pool_obj = tx.object(margin_pool.address.clone());
@@ -189,14 +191,14 @@ impl<'a> MarginPoolContract<'a> {
             ),
             vec![margin_pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn supply_shares(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, MarginPoolError> {
+    ) -> Result<Argument, MarginPoolError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
 
         let margin_pool_obj = tx.object(margin_pool.address.clone());
@@ -208,14 +210,14 @@ impl<'a> MarginPoolContract<'a> {
             ),
             vec![margin_pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn total_borrow(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, MarginPoolError> {
+    ) -> Result<Argument, MarginPoolError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
 
         let margin_pool_obj = tx.object(margin_pool.address.clone());
@@ -227,14 +229,14 @@ impl<'a> MarginPoolContract<'a> {
             ),
             vec![margin_pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn borrow_shares(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, MarginPoolError> {
+    ) -> Result<Argument, MarginPoolError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
 
         let margin_pool_obj = tx.object(margin_pool.address.clone());
@@ -246,14 +248,14 @@ impl<'a> MarginPoolContract<'a> {
             ),
             vec![margin_pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn last_update_timestamp(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, MarginPoolError> {
+    ) -> Result<Argument, MarginPoolError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
 
         let margin_pool_obj = tx.object(margin_pool.address.clone());
@@ -265,14 +267,14 @@ impl<'a> MarginPoolContract<'a> {
             ),
             vec![margin_pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn supply_cap(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, MarginPoolError> {
+    ) -> Result<Argument, MarginPoolError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
 
         let margin_pool_obj = tx.object(margin_pool.address.clone());
@@ -284,14 +286,14 @@ impl<'a> MarginPoolContract<'a> {
             ),
             vec![margin_pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn max_utilization_rate(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, MarginPoolError> {
+    ) -> Result<Argument, MarginPoolError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
 
         let margin_pool_obj = tx.object(margin_pool.address.clone());
@@ -303,14 +305,14 @@ impl<'a> MarginPoolContract<'a> {
             ),
             vec![margin_pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn protocol_spread(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, MarginPoolError> {
+    ) -> Result<Argument, MarginPoolError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
 
         let margin_pool_obj = tx.object(margin_pool.address.clone());
@@ -322,14 +324,14 @@ impl<'a> MarginPoolContract<'a> {
             ),
             vec![margin_pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn min_borrow(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, MarginPoolError> {
+    ) -> Result<Argument, MarginPoolError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
 
         let margin_pool_obj = tx.object(margin_pool.address.clone());
@@ -341,14 +343,14 @@ impl<'a> MarginPoolContract<'a> {
             ),
             vec![margin_pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn interest_rate(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, MarginPoolError> {
+    ) -> Result<Argument, MarginPoolError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
 
         let margin_pool_obj = tx.object(margin_pool.address.clone());
@@ -360,7 +362,7 @@ impl<'a> MarginPoolContract<'a> {
             ),
             vec![margin_pool_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn user_supply_shares(
@@ -368,7 +370,7 @@ impl<'a> MarginPoolContract<'a> {
         tx: &mut Transaction,
         coin_key: &str,
         supplier_cap_id: &str,
-    ) -> Result<serde_json::Value, MarginPoolError> {
+    ) -> Result<Argument, MarginPoolError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
 
         let margin_pool_obj = tx.object(margin_pool.address.clone());
@@ -381,7 +383,7 @@ impl<'a> MarginPoolContract<'a> {
             ),
             vec![margin_pool_obj, cap_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn user_supply_amount(
@@ -389,7 +391,7 @@ impl<'a> MarginPoolContract<'a> {
         tx: &mut Transaction,
         coin_key: &str,
         supplier_cap_id: &str,
-    ) -> Result<serde_json::Value, MarginPoolError> {
+    ) -> Result<Argument, MarginPoolError> {
         let margin_pool = self.config.get_margin_pool(coin_key)?;
 
         let margin_pool_obj = tx.object(margin_pool.address.clone());
@@ -403,7 +405,7 @@ impl<'a> MarginPoolContract<'a> {
             ),
             vec![margin_pool_obj, cap_obj, clock_obj],
             vec![margin_pool.type_tag.clone()],
-        ))
+        )?)
     }
 }
 
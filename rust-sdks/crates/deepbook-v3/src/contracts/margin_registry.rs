@@ -1,6 +1,5 @@
 use crate::config::{ConfigError, DeepBookConfig};
-use crate::encode::encode_address;
-use sui::transactions::Transaction;
+use sui::transactions::{Argument, PureValue, Transaction, TransactionError};
 
 #[derive(Debug, thiserror::Error)]
 pub enum MarginRegistryError {
@@ -10,6 +9,8 @@ pub enum MarginRegistryError {
     CoinNotFound(String),
     #[error("pool not found: {0}")]
     PoolNotFound(String),
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
 }
 
 impl From<ConfigError> for MarginRegistryError {
@@ -31,7 +32,7 @@ impl<'a> MarginRegistryContract<'a> {
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, MarginRegistryError> {
+    ) -> Result<Argument, MarginRegistryError> {
         let pool = self.config.get_pool(pool_key)?;
         let base_coin = self.config.get_coin(&pool.base_coin)?;
         let quote_coin = self.config.get_coin(&pool.quote_coin)?;
@@ -46,14 +47,14 @@ impl<'a> MarginRegistryContract<'a> {
             ),
             vec![registry_obj, pool_obj],
             vec![base_coin.type_tag.clone(), quote_coin.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_margin_pool_id(
         &self,
         tx: &mut Transaction,
         coin_key: &str,
-    ) -> Result<serde_json::Value, MarginRegistryError> {
+    ) -> Result<Argument, MarginRegistryError> {
         let coin = self.config.get_coin(coin_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
 
@@ -64,14 +65,14 @@ impl<'a> MarginRegistryContract<'a> {
             ),
             vec![registry_obj],
             vec![coin.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn get_deepbook_pool_margin_pool_ids(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, MarginRegistryError> {
+    ) -> Result<Argument, MarginRegistryError> {
         let pool = self.config.get_pool(pool_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let pool_obj = tx.object(pool.address.clone());
@@ -83,16 +84,16 @@ impl<'a> MarginRegistryContract<'a> {
             ),
             vec![registry_obj, pool_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn get_margin_manager_ids(
         &self,
         tx: &mut Transaction,
         owner: &str,
-    ) -> Result<serde_json::Value, MarginRegistryError> {
+    ) -> Result<Argument, MarginRegistryError> {
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
-        let owner_arg = tx.pure_bytes(&encode_address(owner));
+        let owner_arg = tx.pure_typed(&PureValue::Address(owner.to_string()))?;
 
         Ok(tx.move_call(
             &format!(
@@ -101,14 +102,14 @@ impl<'a> MarginRegistryContract<'a> {
             ),
             vec![registry_obj, owner_arg],
             vec![],
-        ))
+        )?)
     }
 
     pub fn base_margin_pool_id(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, MarginRegistryError> {
+    ) -> Result<Argument, MarginRegistryError> {
         let pool = self.config.get_pool(pool_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let pool_obj = tx.object(pool.address.clone());
@@ -120,14 +121,14 @@ impl<'a> MarginRegistryContract<'a> {
             ),
             vec![registry_obj, pool_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn quote_margin_pool_id(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, MarginRegistryError> {
+    ) -> Result<Argument, MarginRegistryError> {
         let pool = self.config.get_pool(pool_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let pool_obj = tx.object(pool.address.clone());
@@ -139,14 +140,14 @@ impl<'a> MarginRegistryContract<'a> {
             ),
             vec![registry_obj, pool_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn min_withdraw_risk_ratio(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, MarginRegistryError> {
+    ) -> Result<Argument, MarginRegistryError> {
         let pool = self.config.get_pool(pool_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let pool_obj = tx.object(pool.address.clone());
@@ -158,14 +159,14 @@ impl<'a> MarginRegistryContract<'a> {
             ),
             vec![registry_obj, pool_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn min_borrow_risk_ratio(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, MarginRegistryError> {
+    ) -> Result<Argument, MarginRegistryError> {
         let pool = self.config.get_pool(pool_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let pool_obj = tx.object(pool.address.clone());
@@ -177,14 +178,14 @@ impl<'a> MarginRegistryContract<'a> {
             ),
             vec![registry_obj, pool_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn liquidation_risk_ratio(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, MarginRegistryError> {
+    ) -> Result<Argument, MarginRegistryError> {
         let pool = self.config.get_pool(pool_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let pool_obj = tx.object(pool.address.clone());
@@ -196,14 +197,14 @@ impl<'a> MarginRegistryContract<'a> {
             ),
             vec![registry_obj, pool_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn target_liquidation_risk_ratio(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, MarginRegistryError> {
+    ) -> Result<Argument, MarginRegistryError> {
         let pool = self.config.get_pool(pool_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let pool_obj = tx.object(pool.address.clone());
@@ -215,14 +216,14 @@ impl<'a> MarginRegistryContract<'a> {
             ),
             vec![registry_obj, pool_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn user_liquidation_reward(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, MarginRegistryError> {
+    ) -> Result<Argument, MarginRegistryError> {
         let pool = self.config.get_pool(pool_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let pool_obj = tx.object(pool.address.clone());
@@ -234,14 +235,14 @@ impl<'a> MarginRegistryContract<'a> {
             ),
             vec![registry_obj, pool_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn pool_liquidation_reward(
         &self,
         tx: &mut Transaction,
         pool_key: &str,
-    ) -> Result<serde_json::Value, MarginRegistryError> {
+    ) -> Result<Argument, MarginRegistryError> {
         let pool = self.config.get_pool(pool_key)?;
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
         let pool_obj = tx.object(pool.address.clone());
@@ -253,13 +254,13 @@ impl<'a> MarginRegistryContract<'a> {
             ),
             vec![registry_obj, pool_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn allowed_maintainers(
         &self,
         tx: &mut Transaction,
-    ) -> Result<serde_json::Value, MarginRegistryError> {
+    ) -> Result<Argument, MarginRegistryError> {
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
 
         Ok(tx.move_call(
@@ -269,13 +270,13 @@ impl<'a> MarginRegistryContract<'a> {
             ),
             vec![registry_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn allowed_pause_caps(
         &self,
         tx: &mut Transaction,
-    ) -> Result<serde_json::Value, MarginRegistryError> {
+    ) -> Result<Argument, MarginRegistryError> {
         let registry_obj = tx.object(self.config.package_ids.margin_registry_id.clone());
 
         Ok(tx.move_call(
@@ -285,7 +286,7 @@ impl<'a> MarginRegistryContract<'a> {
             ),
             vec![registry_obj],
             vec![],
-        ))
+        )?)
     }
 }
 
@@ -1,6 +1,6 @@
 use crate::config::{ConfigError, DeepBookConfig, FLOAT_SCALAR, MAX_TIMESTAMP};
 use crate::encode::{encode_bool, encode_u64, encode_u8};
-use sui::transactions::Transaction;
+use sui::transactions::{Argument, Transaction, TransactionError};
 
 #[derive(Debug, thiserror::Error)]
 pub enum PoolProxyError {
@@ -14,6 +14,8 @@ pub enum PoolProxyError {
     CoinNotFound(String),
     #[error("margin pool not found: {0}")]
     MarginPoolNotFound(String),
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
 }
 
 impl From<ConfigError> for PoolProxyError {
@@ -39,7 +41,7 @@ impl<'a> PoolProxyContract<'a> {
         is_bid: bool,
         pay_with_deep: bool,
         expiration: u64,
-    ) -> Result<serde_json::Value, PoolProxyError> {
+    ) -> Result<Argument, PoolProxyError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -81,7 +83,7 @@ impl<'a> PoolProxyContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn place_market_order(
@@ -93,7 +95,7 @@ impl<'a> PoolProxyContract<'a> {
         quantity: f64,
         is_bid: bool,
         pay_with_deep: bool,
-    ) -> Result<serde_json::Value, PoolProxyError> {
+    ) -> Result<Argument, PoolProxyError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -127,7 +129,7 @@ impl<'a> PoolProxyContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn place_reduce_only_limit_order(
@@ -142,7 +144,7 @@ impl<'a> PoolProxyContract<'a> {
         is_bid: bool,
         pay_with_deep: bool,
         expiration: u64,
-    ) -> Result<serde_json::Value, PoolProxyError> {
+    ) -> Result<Argument, PoolProxyError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -201,7 +203,7 @@ impl<'a> PoolProxyContract<'a> {
                 quote.type_tag.clone(),
                 debt_type.clone(),
             ],
-        ))
+        )?)
     }
 
     pub fn place_reduce_only_market_order(
@@ -213,7 +215,7 @@ impl<'a> PoolProxyContract<'a> {
         quantity: f64,
         is_bid: bool,
         pay_with_deep: bool,
-    ) -> Result<serde_json::Value, PoolProxyError> {
+    ) -> Result<Argument, PoolProxyError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -264,7 +266,7 @@ impl<'a> PoolProxyContract<'a> {
                 quote.type_tag.clone(),
                 debt_type.clone(),
             ],
-        ))
+        )?)
     }
 
     pub fn modify_order(
@@ -273,7 +275,7 @@ impl<'a> PoolProxyContract<'a> {
         margin_manager_key: &str,
         order_id: u128,
         new_quantity: f64,
-    ) -> Result<serde_json::Value, PoolProxyError> {
+    ) -> Result<Argument, PoolProxyError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -301,7 +303,7 @@ impl<'a> PoolProxyContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn cancel_order(
@@ -309,7 +311,7 @@ impl<'a> PoolProxyContract<'a> {
         tx: &mut Transaction,
         margin_manager_key: &str,
         order_id: u128,
-    ) -> Result<serde_json::Value, PoolProxyError> {
+    ) -> Result<Argument, PoolProxyError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -328,7 +330,7 @@ impl<'a> PoolProxyContract<'a> {
             ),
             vec![registry_obj, manager_obj, pool_obj, order_id_arg, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn cancel_orders(
@@ -336,7 +338,7 @@ impl<'a> PoolProxyContract<'a> {
         tx: &mut Transaction,
         margin_manager_key: &str,
         order_ids: &[u128],
-    ) -> Result<serde_json::Value, PoolProxyError> {
+    ) -> Result<Argument, PoolProxyError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -361,14 +363,14 @@ impl<'a> PoolProxyContract<'a> {
                 clock_obj,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn cancel_all_orders(
         &self,
         tx: &mut Transaction,
         margin_manager_key: &str,
-    ) -> Result<serde_json::Value, PoolProxyError> {
+    ) -> Result<Argument, PoolProxyError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -386,14 +388,14 @@ impl<'a> PoolProxyContract<'a> {
             ),
             vec![registry_obj, manager_obj, pool_obj, clock_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn withdraw_settled_amounts(
         &self,
         tx: &mut Transaction,
         margin_manager_key: &str,
-    ) -> Result<serde_json::Value, PoolProxyError> {
+    ) -> Result<Argument, PoolProxyError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -410,7 +412,7 @@ impl<'a> PoolProxyContract<'a> {
             ),
             vec![registry_obj, manager_obj, pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn stake(
@@ -418,7 +420,7 @@ impl<'a> PoolProxyContract<'a> {
         tx: &mut Transaction,
         margin_manager_key: &str,
         stake_amount: f64,
-    ) -> Result<serde_json::Value, PoolProxyError> {
+    ) -> Result<Argument, PoolProxyError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -438,14 +440,14 @@ impl<'a> PoolProxyContract<'a> {
             ),
             vec![registry_obj, manager_obj, pool_obj, stake_arg],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn unstake(
         &self,
         tx: &mut Transaction,
         margin_manager_key: &str,
-    ) -> Result<serde_json::Value, PoolProxyError> {
+    ) -> Result<Argument, PoolProxyError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -462,7 +464,7 @@ impl<'a> PoolProxyContract<'a> {
             ),
             vec![registry_obj, manager_obj, pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn submit_proposal(
@@ -472,7 +474,7 @@ impl<'a> PoolProxyContract<'a> {
         taker_fee: f64,
         maker_fee: f64,
         stake_required: f64,
-    ) -> Result<serde_json::Value, PoolProxyError> {
+    ) -> Result<Argument, PoolProxyError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -502,7 +504,7 @@ impl<'a> PoolProxyContract<'a> {
                 stake_arg,
             ],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn vote(
@@ -510,7 +512,7 @@ impl<'a> PoolProxyContract<'a> {
         tx: &mut Transaction,
         margin_manager_key: &str,
         proposal_id: &str,
-    ) -> Result<serde_json::Value, PoolProxyError> {
+    ) -> Result<Argument, PoolProxyError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -528,14 +530,14 @@ impl<'a> PoolProxyContract<'a> {
             ),
             vec![registry_obj, manager_obj, pool_obj, proposal_id_arg],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn claim_rebate(
         &self,
         tx: &mut Transaction,
         margin_manager_key: &str,
-    ) -> Result<serde_json::Value, PoolProxyError> {
+    ) -> Result<Argument, PoolProxyError> {
         let manager = self.config.get_margin_manager(margin_manager_key)?;
         let pool = self.config.get_pool(&manager.pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
@@ -552,7 +554,7 @@ impl<'a> PoolProxyContract<'a> {
             ),
             vec![registry_obj, manager_obj, pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn withdraw_margin_settled_amounts(
@@ -560,7 +562,7 @@ impl<'a> PoolProxyContract<'a> {
         tx: &mut Transaction,
         pool_key: &str,
         margin_manager_id: &str,
-    ) -> Result<serde_json::Value, PoolProxyError> {
+    ) -> Result<Argument, PoolProxyError> {
         let pool = self.config.get_pool(pool_key)?;
         let base = self.config.get_coin(&pool.base_coin)?;
         let quote = self.config.get_coin(&pool.quote_coin)?;
@@ -576,7 +578,7 @@ impl<'a> PoolProxyContract<'a> {
             ),
             vec![registry_obj, manager_obj, pool_obj],
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 }
 
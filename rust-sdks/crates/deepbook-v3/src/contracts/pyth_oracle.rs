@@ -1,6 +1,6 @@
 use crate::encode::encode_u64;
 use serde::{Deserialize, Serialize};
-use sui::transactions::Transaction;
+use sui::transactions::{Argument, Transaction, TransactionError};
 
 const PYTH_PACKAGE_ID: &str = "0xabf837e98c26087cba0883c0a7a28326b1fa3c5e1e2c5abdb486f9e8f594c837";
 
@@ -82,6 +82,8 @@ pub enum PythError {
     InvalidVAA,
     #[error("governance error: {0}")]
     Governance(String),
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
 }
 
 pub struct PythOracleContract;
@@ -91,7 +93,7 @@ impl PythOracleContract {
         tx: &mut Transaction,
         state_id: &str,
         price_feed_id: &[u8],
-    ) -> Result<serde_json::Value, PythError> {
+    ) -> Result<Argument, PythError> {
         let state_obj = tx.object(state_id.to_string());
         let price_feed_arg = tx.pure_bytes(price_feed_id);
         let clock_obj = tx.object("0x6");
@@ -100,14 +102,14 @@ impl PythOracleContract {
             &format!("{}::pyth::get_price", PYTH_PACKAGE_ID),
             vec![state_obj, price_feed_arg, clock_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn get_price_unsafe(
         tx: &mut Transaction,
         state_id: &str,
         price_feed_id: &[u8],
-    ) -> Result<serde_json::Value, PythError> {
+    ) -> Result<Argument, PythError> {
         let state_obj = tx.object(state_id.to_string());
         let price_feed_arg = tx.pure_bytes(price_feed_id);
 
@@ -115,7 +117,7 @@ impl PythOracleContract {
             &format!("{}::pyth::get_price_unsafe", PYTH_PACKAGE_ID),
             vec![state_obj, price_feed_arg],
             vec![],
-        ))
+        )?)
     }
 
     pub fn get_price_no_older_than(
@@ -123,7 +125,7 @@ impl PythOracleContract {
         state_id: &str,
         price_feed_id: &[u8],
         max_age_secs: u64,
-    ) -> Result<serde_json::Value, PythError> {
+    ) -> Result<Argument, PythError> {
         let state_obj = tx.object(state_id.to_string());
         let price_feed_arg = tx.pure_bytes(price_feed_id);
         let max_age_arg = tx.pure_bytes(&encode_u64(max_age_secs));
@@ -133,14 +135,14 @@ impl PythOracleContract {
             &format!("{}::pyth::get_price_no_older_than", PYTH_PACKAGE_ID),
             vec![state_obj, price_feed_arg, max_age_arg, clock_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn price_feed_exists(
         tx: &mut Transaction,
         state_id: &str,
         price_feed_id: &[u8],
-    ) -> Result<serde_json::Value, PythError> {
+    ) -> Result<Argument, PythError> {
         let state_obj = tx.object(state_id.to_string());
         let price_feed_arg = tx.pure_bytes(price_feed_id);
 
@@ -148,7 +150,7 @@ impl PythOracleContract {
             &format!("{}::pyth::price_feed_exists", PYTH_PACKAGE_ID),
             vec![state_obj, price_feed_arg],
             vec![],
-        ))
+        )?)
     }
 
     pub fn update_single_price_feed(
@@ -156,7 +158,7 @@ impl PythOracleContract {
         state_id: &str,
         vaa: &[u8],
         price_feed_id: &[u8],
-    ) -> Result<serde_json::Value, PythError> {
+    ) -> Result<Argument, PythError> {
         let state_obj = tx.object(state_id.to_string());
         let price_feed_arg = tx.pure_bytes(price_feed_id);
         let vaa_arg = tx.pure_bytes(vaa);
@@ -166,14 +168,14 @@ impl PythOracleContract {
             &format!("{}::pyth::update_single_price_feed", PYTH_PACKAGE_ID),
             vec![state_obj, vaa_arg, price_feed_arg, clock_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn create_authenticated_price_info(
         tx: &mut Transaction,
         state_id: &str,
         price_attestation: &[u8],
-    ) -> Result<serde_json::Value, PythError> {
+    ) -> Result<Argument, PythError> {
         let state_obj = tx.object(state_id.to_string());
         let attestation_arg = tx.pure_bytes(price_attestation);
         let clock_obj = tx.object("0x6");
@@ -182,27 +184,27 @@ impl PythOracleContract {
             &format!("{}::pyth::create_authenticated_price_info", PYTH_PACKAGE_ID),
             vec![state_obj, attestation_arg, clock_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn get_stale_price_threshold_secs(
         tx: &mut Transaction,
         state_id: &str,
-    ) -> Result<serde_json::Value, PythError> {
+    ) -> Result<Argument, PythError> {
         let state_obj = tx.object(state_id.to_string());
 
         Ok(tx.move_call(
             &format!("{}::pyth::get_stale_price_threshold_secs", PYTH_PACKAGE_ID),
             vec![state_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn get_total_update_fee(
         tx: &mut Transaction,
         state_id: &str,
         price_feed_ids: Vec<Vec<u8>>,
-    ) -> Result<serde_json::Value, PythError> {
+    ) -> Result<Argument, PythError> {
         let state_obj = tx.object(state_id.to_string());
         let count_arg = tx.pure_bytes(&encode_u64(price_feed_ids.len() as u64));
 
@@ -210,7 +212,7 @@ impl PythOracleContract {
             &format!("{}::pyth::get_total_update_fee", PYTH_PACKAGE_ID),
             vec![state_obj, count_arg],
             vec![],
-        ))
+        )?)
     }
 
     pub fn set_governance_data_source(
@@ -218,7 +220,7 @@ impl PythOracleContract {
         state_id: &str,
         upgrade_cap: &str,
         governance_data_source: &[u8],
-    ) -> Result<serde_json::Value, PythError> {
+    ) -> Result<Argument, PythError> {
         let state_obj = tx.object(state_id.to_string());
         let upgrade_cap_obj = tx.object(upgrade_cap.to_string());
         let gov_source_arg = tx.pure_bytes(governance_data_source);
@@ -230,14 +232,14 @@ impl PythOracleContract {
             ),
             vec![state_obj, upgrade_cap_obj, gov_source_arg],
             vec![],
-        ))
+        )?)
     }
 
     pub fn verify_vaa(
         tx: &mut Transaction,
         state_id: &str,
         vaa: &[u8],
-    ) -> Result<serde_json::Value, PythError> {
+    ) -> Result<Argument, PythError> {
         let state_obj = tx.object(state_id.to_string());
         let vaa_arg = tx.pure_bytes(vaa);
 
@@ -245,7 +247,7 @@ impl PythOracleContract {
             &format!("{}::governance::verify_vaa", PYTH_PACKAGE_ID),
             vec![state_obj, vaa_arg],
             vec![],
-        ))
+        )?)
     }
 
     pub fn execute_governance_instruction(
@@ -253,7 +255,7 @@ impl PythOracleContract {
         state_id: &str,
         vaa_receipt: &str,
         governance_instruction: &[u8],
-    ) -> Result<serde_json::Value, PythError> {
+    ) -> Result<Argument, PythError> {
         let state_obj = tx.object(state_id.to_string());
         let vaa_receipt_obj = tx.object(vaa_receipt.to_string());
         let instruction_arg = tx.pure_bytes(governance_instruction);
@@ -265,7 +267,7 @@ impl PythOracleContract {
             ),
             vec![state_obj, vaa_receipt_obj, instruction_arg],
             vec![],
-        ))
+        )?)
     }
 
     pub fn parse_price_from_bytecode(bytecode: &[u8]) -> Result<Price, PythError> {
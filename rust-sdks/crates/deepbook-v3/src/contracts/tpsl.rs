@@ -1,6 +1,7 @@
 use crate::config::DeepBookConfig;
 use crate::config::{FLOAT_SCALAR, MAX_TIMESTAMP};
 use crate::encode::{encode_bool, encode_u64, encode_u8};
+use sui::transactions::{Argument, Transaction, TransactionError};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OrderType {
@@ -29,6 +30,8 @@ pub enum TpslError {
     MarginPoolNotFound(String),
     #[error("config error: {0}")]
     Config(String),
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
 }
 
 pub struct TpslContract<'a> {
@@ -38,10 +41,11 @@ pub struct TpslContract<'a> {
 impl<'a> TpslContract<'a> {
     pub fn new_condition(
         &self,
+        tx: &mut Transaction,
         pool_key: &str,
         trigger_below_price: bool,
         trigger_price: f64,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), TpslError> {
+    ) -> Result<Argument, TpslError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -63,18 +67,16 @@ impl<'a> TpslContract<'a> {
             "{}::tpsl::new_condition",
             self.config.package_ids.margin_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(trigger_below_price),
-                serde_json::json!(input_price),
-            ],
-            vec![],
-        ))
+        let arguments = vec![
+            tx.pure_bytes(&encode_bool(trigger_below_price)),
+            tx.pure_bytes(&encode_u64(input_price)),
+        ];
+        Ok(tx.move_call(&target, arguments, vec![])?)
     }
 
     pub fn new_pending_limit_order(
         &self,
+        tx: &mut Transaction,
         pool_key: &str,
         client_order_id: u64,
         order_type: OrderType,
@@ -84,7 +86,7 @@ impl<'a> TpslContract<'a> {
         is_bid: bool,
         pay_with_deep: bool,
         expire_timestamp: Option<u64>,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), TpslError> {
+    ) -> Result<Argument, TpslError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -107,31 +109,29 @@ impl<'a> TpslContract<'a> {
             "{}::tpsl::new_pending_limit_order",
             self.config.package_ids.margin_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(client_order_id),
-                serde_json::json!(order_type as u8),
-                serde_json::json!(self_matching_option as u8),
-                serde_json::json!(input_price),
-                serde_json::json!(input_quantity),
-                serde_json::json!(is_bid),
-                serde_json::json!(pay_with_deep),
-                serde_json::json!(expiration),
-            ],
-            vec![],
-        ))
+        let arguments = vec![
+            tx.pure_bytes(&encode_u64(client_order_id)),
+            tx.pure_bytes(&encode_u8(order_type as u8)),
+            tx.pure_bytes(&encode_u8(self_matching_option as u8)),
+            tx.pure_bytes(&encode_u64(input_price)),
+            tx.pure_bytes(&encode_u64(input_quantity)),
+            tx.pure_bytes(&encode_bool(is_bid)),
+            tx.pure_bytes(&encode_bool(pay_with_deep)),
+            tx.pure_bytes(&encode_u64(expiration)),
+        ];
+        Ok(tx.move_call(&target, arguments, vec![])?)
     }
 
     pub fn new_pending_market_order(
         &self,
+        tx: &mut Transaction,
         pool_key: &str,
         client_order_id: u64,
         self_matching_option: SelfMatchingOptions,
         quantity: f64,
         is_bid: bool,
         pay_with_deep: bool,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), TpslError> {
+    ) -> Result<Argument, TpslError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -147,27 +147,29 @@ impl<'a> TpslContract<'a> {
             "{}::tpsl::new_pending_market_order",
             self.config.package_ids.margin_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(client_order_id),
-                serde_json::json!(self_matching_option as u8),
-                serde_json::json!(input_quantity),
-                serde_json::json!(is_bid),
-                serde_json::json!(pay_with_deep),
-            ],
-            vec![],
-        ))
+        let arguments = vec![
+            tx.pure_bytes(&encode_u64(client_order_id)),
+            tx.pure_bytes(&encode_u8(self_matching_option as u8)),
+            tx.pure_bytes(&encode_u64(input_quantity)),
+            tx.pure_bytes(&encode_bool(is_bid)),
+            tx.pure_bytes(&encode_bool(pay_with_deep)),
+        ];
+        Ok(tx.move_call(&target, arguments, vec![])?)
     }
 
     pub fn add_conditional_order(
         &self,
+        tx: &mut Transaction,
         margin_manager_key: &str,
         pool_key: &str,
         conditional_order_id: u64,
         trigger_below_price: bool,
         trigger_price: f64,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), TpslError> {
+    ) -> Result<Argument, TpslError> {
+        let manager = self
+            .config
+            .get_margin_manager(margin_manager_key)
+            .map_err(|e| TpslError::MarginManagerNotFound(e.to_string()))?;
         let pool = self
             .config
             .get_pool(pool_key)
@@ -181,27 +183,33 @@ impl<'a> TpslContract<'a> {
             .get_coin(&pool.quote_coin)
             .map_err(|e| TpslError::CoinNotFound(e.to_string()))?;
 
+        let input_price = ((trigger_price * FLOAT_SCALAR * quote.scalar as f64)
+            / base.scalar as f64)
+            .round() as u64;
+
         let target = format!(
             "{}::margin_manager::add_conditional_order",
             self.config.package_ids.margin_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(margin_manager_key),
-                serde_json::json!(conditional_order_id),
-                serde_json::json!(trigger_below_price),
-                serde_json::json!(trigger_price),
-            ],
+        let arguments = vec![
+            tx.object(manager.address.clone()),
+            tx.pure_bytes(&encode_u64(conditional_order_id)),
+            tx.pure_bytes(&encode_bool(trigger_below_price)),
+            tx.pure_bytes(&encode_u64(input_price)),
+        ];
+        Ok(tx.move_call(
+            &target,
+            arguments,
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn cancel_conditional_order(
         &self,
+        tx: &mut Transaction,
         margin_manager_key: &str,
         conditional_order_id: u64,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), TpslError> {
+    ) -> Result<Argument, TpslError> {
         let manager = self
             .config
             .get_margin_manager(margin_manager_key)
@@ -223,21 +231,23 @@ impl<'a> TpslContract<'a> {
             "{}::margin_manager::cancel_conditional_order",
             self.config.package_ids.margin_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(manager.address),
-                serde_json::json!(conditional_order_id),
-                serde_json::json!("0x6"), // clock
-            ],
+        let arguments = vec![
+            tx.object(manager.address.clone()),
+            tx.pure_bytes(&encode_u64(conditional_order_id)),
+            tx.object("0x6"),
+        ];
+        Ok(tx.move_call(
+            &target,
+            arguments,
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn cancel_all_conditional_orders(
         &self,
+        tx: &mut Transaction,
         margin_manager_key: &str,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), TpslError> {
+    ) -> Result<Argument, TpslError> {
         let manager = self
             .config
             .get_margin_manager(margin_manager_key)
@@ -259,22 +269,24 @@ impl<'a> TpslContract<'a> {
             "{}::margin_manager::cancel_all_conditional_orders",
             self.config.package_ids.margin_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(manager.address),
-                serde_json::json!("0x6"), // clock
-            ],
+        let arguments = vec![
+            tx.object(manager.address.clone()),
+            tx.object("0x6"),
+        ];
+        Ok(tx.move_call(
+            &target,
+            arguments,
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn execute_conditional_orders(
         &self,
+        tx: &mut Transaction,
         manager_address: &str,
         pool_key: &str,
         max_orders_to_execute: u64,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), TpslError> {
+    ) -> Result<Argument, TpslError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -292,22 +304,24 @@ impl<'a> TpslContract<'a> {
             "{}::margin_manager::execute_conditional_orders",
             self.config.package_ids.margin_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(manager_address),
-                serde_json::json!(pool.address),
-                serde_json::json!(max_orders_to_execute),
-            ],
+        let arguments = vec![
+            tx.object(manager_address),
+            tx.object(pool.address.clone()),
+            tx.pure_bytes(&encode_u64(max_orders_to_execute)),
+        ];
+        Ok(tx.move_call(
+            &target,
+            arguments,
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn conditional_order_ids(
         &self,
+        tx: &mut Transaction,
         pool_key: &str,
         margin_manager_id: &str,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), TpslError> {
+    ) -> Result<Argument, TpslError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -325,19 +339,21 @@ impl<'a> TpslContract<'a> {
             "{}::margin_manager::conditional_order_ids",
             self.config.package_ids.margin_package_id
         );
-        Ok((
-            target,
-            vec![serde_json::json!(margin_manager_id)],
+        let arguments = vec![tx.object(margin_manager_id)];
+        Ok(tx.move_call(
+            &target,
+            arguments,
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn conditional_order(
         &self,
+        tx: &mut Transaction,
         pool_key: &str,
         margin_manager_id: &str,
         conditional_order_id: u64,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), TpslError> {
+    ) -> Result<Argument, TpslError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -355,21 +371,23 @@ impl<'a> TpslContract<'a> {
             "{}::margin_manager::conditional_order",
             self.config.package_ids.margin_package_id
         );
-        Ok((
-            target,
-            vec![
-                serde_json::json!(margin_manager_id),
-                serde_json::json!(conditional_order_id),
-            ],
+        let arguments = vec![
+            tx.object(margin_manager_id),
+            tx.pure_bytes(&encode_u64(conditional_order_id)),
+        ];
+        Ok(tx.move_call(
+            &target,
+            arguments,
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn lowest_trigger_above_price(
         &self,
+        tx: &mut Transaction,
         pool_key: &str,
         margin_manager_id: &str,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), TpslError> {
+    ) -> Result<Argument, TpslError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -387,18 +405,20 @@ impl<'a> TpslContract<'a> {
             "{}::margin_manager::lowest_trigger_above_price",
             self.config.package_ids.margin_package_id
         );
-        Ok((
-            target,
-            vec![serde_json::json!(margin_manager_id)],
+        let arguments = vec![tx.object(margin_manager_id)];
+        Ok(tx.move_call(
+            &target,
+            arguments,
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 
     pub fn highest_trigger_below_price(
         &self,
+        tx: &mut Transaction,
         pool_key: &str,
         margin_manager_id: &str,
-    ) -> Result<(String, Vec<serde_json::Value>, Vec<String>), TpslError> {
+    ) -> Result<Argument, TpslError> {
         let pool = self
             .config
             .get_pool(pool_key)
@@ -416,11 +436,12 @@ impl<'a> TpslContract<'a> {
             "{}::margin_manager::highest_trigger_below_price",
             self.config.package_ids.margin_package_id
         );
-        Ok((
-            target,
-            vec![serde_json::json!(margin_manager_id)],
+        let arguments = vec![tx.object(margin_manager_id)];
+        Ok(tx.move_call(
+            &target,
+            arguments,
             vec![base.type_tag.clone(), quote.type_tag.clone()],
-        ))
+        )?)
     }
 }
 
@@ -446,7 +467,8 @@ mod tests {
     fn test_new_condition() {
         let config = create_test_config();
         let tpsl = TpslContract { config: &config };
-        let result = tpsl.new_condition("DEEP_SUI", true, 1.0);
+        let mut tx = Transaction::new();
+        let result = tpsl.new_condition(&mut tx, "DEEP_SUI", true, 1.0);
         assert!(result.is_ok());
     }
 
@@ -454,7 +476,9 @@ mod tests {
     fn test_new_pending_limit_order() {
         let config = create_test_config();
         let tpsl = TpslContract { config: &config };
+        let mut tx = Transaction::new();
         let result = tpsl.new_pending_limit_order(
+            &mut tx,
             "DEEP_SUI",
             1,
             OrderType::NoRestriction,
@@ -472,7 +496,9 @@ mod tests {
     fn test_new_pending_market_order() {
         let config = create_test_config();
         let tpsl = TpslContract { config: &config };
+        let mut tx = Transaction::new();
         let result = tpsl.new_pending_market_order(
+            &mut tx,
             "DEEP_SUI",
             1,
             SelfMatchingOptions::SelfMatchingAllowed,
@@ -1,6 +1,6 @@
 use crate::encode::encode_u64;
 use serde::{Deserialize, Serialize};
-use sui::transactions::Transaction;
+use sui::transactions::{Argument, Transaction, TransactionError};
 
 const WORMHOLE_PACKAGE_ID: &str =
     "0xf47329f4344f3bf0f8e436e2f7b485466cff300f12a166563995d3888c296a94";
@@ -80,6 +80,8 @@ pub enum WormholeError {
     VAAExpired,
     #[error("insufficient signatures")]
     InsufficientSignatures,
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
 }
 
 pub struct WormholeContract;
@@ -88,43 +90,43 @@ impl WormholeContract {
     pub fn get_state(
         tx: &mut Transaction,
         state_id: &str,
-    ) -> Result<serde_json::Value, WormholeError> {
+    ) -> Result<Argument, WormholeError> {
         let state_obj = tx.object(state_id.to_string());
         Ok(tx.move_call(
             &format!("{}::state::borrow_state", WORMHOLE_PACKAGE_ID),
             vec![state_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn get_governance_chain(
         tx: &mut Transaction,
         state_id: &str,
-    ) -> Result<serde_json::Value, WormholeError> {
+    ) -> Result<Argument, WormholeError> {
         let state_obj = tx.object(state_id.to_string());
         Ok(tx.move_call(
             &format!("{}::state::get_governance_chain", WORMHOLE_PACKAGE_ID),
             vec![state_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn get_guardian_set_index(
         tx: &mut Transaction,
         state_id: &str,
-    ) -> Result<serde_json::Value, WormholeError> {
+    ) -> Result<Argument, WormholeError> {
         let state_obj = tx.object(state_id.to_string());
         Ok(tx.move_call(
             &format!("{}::state::get_guardian_set_index", WORMHOLE_PACKAGE_ID),
             vec![state_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn get_guardian_set_seconds_to_live(
         tx: &mut Transaction,
         state_id: &str,
-    ) -> Result<serde_json::Value, WormholeError> {
+    ) -> Result<Argument, WormholeError> {
         let state_obj = tx.object(state_id.to_string());
         Ok(tx.move_call(
             &format!(
@@ -133,13 +135,13 @@ impl WormholeContract {
             ),
             vec![state_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn get_fee_collector(
         tx: &mut Transaction,
         state_id: &str,
-    ) -> Result<serde_json::Value, WormholeError> {
+    ) -> Result<Argument, WormholeError> {
         let state_obj = tx.object(state_id.to_string());
         Ok(tx.move_call(
             &format!(
@@ -148,19 +150,19 @@ impl WormholeContract {
             ),
             vec![state_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn get_fee_amount(
         tx: &mut Transaction,
         state_id: &str,
-    ) -> Result<serde_json::Value, WormholeError> {
+    ) -> Result<Argument, WormholeError> {
         let state_obj = tx.object(state_id.to_string());
         Ok(tx.move_call(
             &format!("{}::fee_collector::get_fee_amount", WORMHOLE_PACKAGE_ID),
             vec![state_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn set_fee_amount(
@@ -168,7 +170,7 @@ impl WormholeContract {
         state_id: &str,
         upgrade_cap: &str,
         new_fee: u64,
-    ) -> Result<serde_json::Value, WormholeError> {
+    ) -> Result<Argument, WormholeError> {
         let state_obj = tx.object(state_id.to_string());
         let upgrade_cap_obj = tx.object(upgrade_cap.to_string());
         let fee_arg = tx.pure_bytes(&encode_u64(new_fee));
@@ -177,7 +179,7 @@ impl WormholeContract {
             &format!("{}::fee_collector::set_fee_amount", WORMHOLE_PACKAGE_ID),
             vec![state_obj, upgrade_cap_obj, fee_arg],
             vec![],
-        ))
+        )?)
     }
 
     pub fn consume_fee(
@@ -185,7 +187,7 @@ impl WormholeContract {
         state_id: &str,
         upgrade_cap: &str,
         amount: u64,
-    ) -> Result<serde_json::Value, WormholeError> {
+    ) -> Result<Argument, WormholeError> {
         let state_obj = tx.object(state_id.to_string());
         let upgrade_cap_obj = tx.object(upgrade_cap.to_string());
         let amount_arg = tx.pure_bytes(&encode_u64(amount));
@@ -194,14 +196,14 @@ impl WormholeContract {
             &format!("{}::fee_collector::consume_fee", WORMHOLE_PACKAGE_ID),
             vec![state_obj, upgrade_cap_obj, amount_arg],
             vec![],
-        ))
+        )?)
     }
 
     pub fn is_vaa_consumed(
         tx: &mut Transaction,
         state_id: &str,
         vaa_hash: &[u8],
-    ) -> Result<serde_json::Value, WormholeError> {
+    ) -> Result<Argument, WormholeError> {
         let state_obj = tx.object(state_id.to_string());
         let hash_arg = tx.pure_bytes(vaa_hash);
 
@@ -209,7 +211,7 @@ impl WormholeContract {
             &format!("{}::consumed_vaas::is_vaa_consumed", WORMHOLE_PACKAGE_ID),
             vec![state_obj, hash_arg],
             vec![],
-        ))
+        )?)
     }
 
     pub fn consume_vaa(
@@ -217,7 +219,7 @@ impl WormholeContract {
         state_id: &str,
         upgrade_cap: &str,
         vaa_hash: &[u8],
-    ) -> Result<serde_json::Value, WormholeError> {
+    ) -> Result<Argument, WormholeError> {
         let state_obj = tx.object(state_id.to_string());
         let upgrade_cap_obj = tx.object(upgrade_cap.to_string());
         let hash_arg = tx.pure_bytes(vaa_hash);
@@ -226,26 +228,26 @@ impl WormholeContract {
             &format!("{}::consumed_vaas::consume_vaa", WORMHOLE_PACKAGE_ID),
             vec![state_obj, upgrade_cap_obj, hash_arg],
             vec![],
-        ))
+        )?)
     }
 
     pub fn get_upgrade_cap(
         tx: &mut Transaction,
         state_id: &str,
-    ) -> Result<serde_json::Value, WormholeError> {
+    ) -> Result<Argument, WormholeError> {
         let state_obj = tx.object(state_id.to_string());
         Ok(tx.move_call(
             &format!("{}::state::get_upgrade_cap", WORMHOLE_PACKAGE_ID),
             vec![state_obj],
             vec![],
-        ))
+        )?)
     }
 
     pub fn verify_and_execute_vaa(
         tx: &mut Transaction,
         state_id: &str,
         vaa: &[u8],
-    ) -> Result<serde_json::Value, WormholeError> {
+    ) -> Result<Argument, WormholeError> {
         let state_obj = tx.object(state_id.to_string());
         let vaa_arg = tx.pure_bytes(vaa);
 
@@ -253,7 +255,7 @@ impl WormholeContract {
             &format!("{}::state::verify_and_execute_vaa", WORMHOLE_PACKAGE_ID),
             vec![state_obj, vaa_arg],
             vec![],
-        ))
+        )?)
     }
 }
 
@@ -1,27 +1,62 @@
-use base64::Engine as _;
+use std::sync::Arc;
+
 use httpmock::Method::POST;
 use httpmock::MockServer;
-use serde_json::json;
+use serde_json::{json, Value};
 
 use deepbook_v3::client::DeepBookClient;
 use deepbook_v3::config::DeepBookConfig;
 use deepbook_v3::types::{BalanceManager, MarginManager};
 use sui::jsonrpc;
+use sui::transactions::resolve::BoxFuture;
+use sui::transactions::{CallArg, PluginError, SharedObjectRef, Transaction, TransactionPlugin};
+
+/// Resolves every object input as a shared object, in place of the
+/// on-chain lookups made by the default resolver.
+struct SharedObjects;
+
+impl TransactionPlugin for SharedObjects {
+    fn name(&self) -> String {
+        "SharedObjects".to_string()
+    }
+
+    fn resolve_inputs<'a>(
+        &'a self,
+        tx: &'a mut Transaction,
+    ) -> BoxFuture<'a, Result<(), PluginError>> {
+        for input in &mut tx.data.inputs {
+            if let CallArg::UnresolvedObject(object) = input {
+                *input = CallArg::shared_object_ref(SharedObjectRef {
+                    object_id: object.object_id.clone(),
+                    mutable: true,
+                    initial_shared_version: 1,
+                });
+            }
+        }
+        Box::pin(async { Ok(()) })
+    }
+}
 
-fn b64_u64(v: u64) -> String {
-    base64::engine::general_purpose::STANDARD.encode(v.to_le_bytes())
+fn fixture_client(server: &MockServer, cfg: DeepBookConfig) -> DeepBookClient {
+    DeepBookClient::new(jsonrpc::Client::new(server.url("/"), "testnet"), cfg)
+        .with_input_resolver(Arc::new(SharedObjects))
 }
 
-fn b64_vec_u64(values: &[u64]) -> String {
+/// A `[bcs, type]` entry of `returnValues` in dev inspect results.
+fn return_value(bcs: impl Into<Vec<u8>>, ty: &str) -> Value {
+    json!([bcs.into(), ty])
+}
+
+fn bcs_vec_u64(values: &[u64]) -> Vec<u8> {
     let mut out = Vec::with_capacity(1 + values.len() * 8);
     out.push(values.len() as u8);
     for v in values {
         out.extend_from_slice(&v.to_le_bytes());
     }
-    base64::engine::general_purpose::STANDARD.encode(out)
+    out
 }
 
-fn b64_vecset_addresses(addrs: &[&str]) -> String {
+fn bcs_vecset_addresses(addrs: &[&str]) -> Vec<u8> {
     let mut out = Vec::with_capacity(1 + addrs.len() * 32);
     out.push(addrs.len() as u8);
     for addr in addrs {
@@ -38,7 +73,7 @@ fn b64_vecset_addresses(addrs: &[&str]) -> String {
         assert_eq!(bytes.len(), 32, "address must fit 32 bytes");
         out.extend_from_slice(&bytes);
     }
-    base64::engine::general_purpose::STANDARD.encode(out)
+    out
 }
 
 #[tokio::test]
@@ -48,18 +83,18 @@ async fn deepbook_client_quantity_and_whitelist_methods() {
     let _mock = server.mock(|when, then| {
         when.method(POST)
             .path("/")
-            .body_contains("\"method\":\"sui_dryRunTransactionBlock\"");
+            .body_contains("\"method\":\"sui_devInspectTransactionBlock\"");
         then.status(200).header("content-type", "application/json").json_body(json!({
             "jsonrpc": "2.0",
             "id": 1,
             "result": {
-                "commandResults": [
+                "results": [
                     {"returnValues": [
-                        {"bcs": b64_u64(100)},
-                        {"bcs": b64_u64(200)},
-                        {"bcs": b64_u64(300)}
+                        return_value(100u64.to_le_bytes(), "u64"),
+                        return_value(200u64.to_le_bytes(), "u64"),
+                        return_value(300u64.to_le_bytes(), "u64")
                     ]},
-                    {"returnValues": [{"bcs": base64::engine::general_purpose::STANDARD.encode([1u8])}]}
+                    {"returnValues": [return_value([1u8], "bool")]}
                 ]
             }
         }));
@@ -84,8 +119,7 @@ async fn deepbook_client_quantity_and_whitelist_methods() {
         sui.price_info_object_id = Some("0x99".to_string());
     }
 
-    let rpc = jsonrpc::Client::new(server.url("/"), "testnet");
-    let client = DeepBookClient::new(rpc, cfg);
+    let client = fixture_client(&server, cfg);
 
     let q = client
         .get_quote_quantity_out("DEEP_SUI", 1.0)
@@ -520,7 +554,7 @@ async fn deepbook_client_quantity_and_whitelist_methods() {
         .expect("get_margin_manager_deep_balance");
     assert!(!deep_balance.is_empty());
 
-    // commandResults[1][0] exists in mock payload, enough for baseline parser test.
+    // results[1][0] exists in mock payload, enough for baseline parser test.
     let raw_margin = client
         .get_margin_account_order_details("mm1")
         .await
@@ -545,10 +579,10 @@ async fn deepbook_client_tpsl_and_margin_registry_methods() {
         then.status(200).json_body(json!({
             "jsonrpc": "2.0",
             "id": 1,
-            "result": { "commandResults": [ { "returnValues": [ { "bcs": b64_vec_u64(&[11, 22]) } ] } ] }
+            "result": { "results": [ { "returnValues": [ return_value(bcs_vec_u64(&[11, 22]), "vector<u64>") ] } ] }
         }));
     });
-    let client = DeepBookClient::new(jsonrpc::Client::new(server.url("/"), "testnet"), cfg.clone());
+    let client = fixture_client(&server, cfg.clone());
     let order_ids = client
         .get_conditional_order_ids("mm1")
         .await
@@ -567,10 +601,10 @@ async fn deepbook_client_tpsl_and_margin_registry_methods() {
         then.status(200).json_body(json!({
             "jsonrpc": "2.0",
             "id": 1,
-            "result": { "commandResults": [ { "returnValues": [ { "bcs": base64::engine::general_purpose::STANDARD.encode([1u8]) } ] } ] }
+            "result": { "results": [ { "returnValues": [ return_value([1u8], "bool") ] } ] }
         }));
     });
-    let client = DeepBookClient::new(jsonrpc::Client::new(server.url("/"), "testnet"), cfg.clone());
+    let client = fixture_client(&server, cfg.clone());
     let enabled = client
         .is_pool_enabled_for_margin("DEEP_SUI")
         .await
@@ -583,10 +617,10 @@ async fn deepbook_client_tpsl_and_margin_registry_methods() {
         then.status(200).json_body(json!({
             "jsonrpc": "2.0",
             "id": 1,
-            "result": { "commandResults": [ { "returnValues": [ { "bcs": b64_u64(1000) } ] } ] }
+            "result": { "results": [ { "returnValues": [ return_value(1000u64.to_le_bytes(), "u64") ] } ] }
         }));
     });
-    let client = DeepBookClient::new(jsonrpc::Client::new(server.url("/"), "testnet"), cfg.clone());
+    let client = fixture_client(&server, cfg.clone());
     let min_withdraw = client
         .get_min_withdraw_risk_ratio("DEEP_SUI")
         .await
@@ -599,10 +633,10 @@ async fn deepbook_client_tpsl_and_margin_registry_methods() {
         then.status(200).json_body(json!({
             "jsonrpc": "2.0",
             "id": 1,
-            "result": { "commandResults": [ { "returnValues": [ { "bcs": base64::engine::general_purpose::STANDARD.encode([1u8; 32]) } ] } ] }
+            "result": { "results": [ { "returnValues": [ return_value([1u8; 32], "0x2::object::ID") ] } ] }
         }));
     });
-    let client = DeepBookClient::new(jsonrpc::Client::new(server.url("/"), "testnet"), cfg.clone());
+    let client = fixture_client(&server, cfg.clone());
     let base_margin_pool_id = client
         .get_base_margin_pool_id("DEEP_SUI")
         .await
@@ -615,13 +649,99 @@ async fn deepbook_client_tpsl_and_margin_registry_methods() {
         then.status(200).json_body(json!({
             "jsonrpc": "2.0",
             "id": 1,
-            "result": { "commandResults": [ { "returnValues": [ { "bcs": b64_vecset_addresses(&["0x1", "0x2"]) } ] } ] }
+            "result": { "results": [ { "returnValues": [ return_value(bcs_vecset_addresses(&["0x1", "0x2"]), "0x2::vec_set::VecSet<address>") ] } ] }
         }));
     });
-    let client = DeepBookClient::new(jsonrpc::Client::new(server.url("/"), "testnet"), cfg);
+    let client = fixture_client(&server, cfg);
     let maintainers = client
         .get_allowed_maintainers()
         .await
         .expect("get_allowed_maintainers");
     assert_eq!(maintainers.len(), 2);
 }
+
+#[tokio::test]
+async fn deepbook_client_simulate_resolves_inputs_and_dev_inspects() {
+    let cfg = DeepBookConfig::default();
+    let pool = cfg.get_pool("DEEP_SUI").expect("pool").address.clone();
+    let mut expected = Transaction::new();
+    let pool_arg = expected.shared_object_ref(SharedObjectRef {
+        object_id: pool.clone(),
+        mutable: false,
+        initial_shared_version: 389_750_322,
+    });
+    expected.move_call(
+        &format!("{}::pool::whitelisted", cfg.package_ids.deepbook_package_id),
+        vec![pool_arg],
+        vec![
+            cfg.get_coin("DEEP").expect("DEEP").type_tag.clone(),
+            cfg.get_coin("SUI").expect("SUI").type_tag.clone(),
+        ],
+    )
+    .expect("move call");
+    let expected_kind = expected.build_kind_base64().expect("kind");
+
+    let server = MockServer::start();
+    let functions = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"sui_getNormalizedMoveFunction\"")
+            .body_contains("\"whitelisted\"");
+        then.status(200).json_body(json!([{"jsonrpc": "2.0", "id": 1, "result": {
+            "visibility": "Public",
+            "isEntry": false,
+            "typeParameters": [{"abilities": []}, {"abilities": []}],
+            "parameters": [{"Reference": {"Struct": {
+                "address": cfg.package_ids.deepbook_package_id,
+                "module": "pool",
+                "name": "Pool",
+                "typeArguments": [{"TypeParameter": 0}, {"TypeParameter": 1}]
+            }}}],
+            "return": ["Bool"]
+        }}]));
+    });
+    let objects = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"sui_multiGetObjects\"");
+        then.status(200).json_body(json!({"jsonrpc": "2.0", "id": 2, "result": [{"data": {
+            "objectId": pool,
+            "version": "500000000",
+            "digest": "11111111111111111111111111111111",
+            "owner": {"Shared": {"initial_shared_version": 389_750_322}}
+        }}]}));
+    });
+    let inspect = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"sui_devInspectTransactionBlock\"")
+            .body_contains(&expected_kind);
+        then.status(200).json_body(json!({"jsonrpc": "2.0", "id": 3, "result": {
+            "effects": {},
+            "events": [],
+            "results": [{"returnValues": [return_value([1u8], "bool")]}]
+        }}));
+    });
+
+    let deepbook = DeepBookClient::new(jsonrpc::Client::new(server.url("/"), "testnet"), cfg);
+    assert!(deepbook.whitelisted("DEEP_SUI").await.expect("whitelisted"));
+    assert!(deepbook.whitelisted("DEEP_SUI").await.expect("whitelisted"));
+    // Move signatures are cached across queries; objects are fetched each time.
+    functions.assert_hits(1);
+    objects.assert_hits(2);
+    inspect.assert_hits(2);
+
+    let server = MockServer::start();
+    let _mock = server.mock(|when, then| {
+        when.method(POST).path("/");
+        then.status(200).json_body(json!({"jsonrpc": "2.0", "id": 1, "result": {
+            "effects": {},
+            "error": "MoveAbort(..., 1) in command 0"
+        }}));
+    });
+    let err = fixture_client(&server, DeepBookConfig::default())
+        .whitelisted("DEEP_SUI")
+        .await
+        .unwrap_err();
+    assert!(matches!(err, deepbook_v3::client::ClientError::DevInspect(_)));
+}
@@ -7,8 +7,9 @@ use deepbook_v3::contracts::{
     MarginManagerContract, MarginPoolContract, MarginRegistryContract, MarginTPSLContract,
     PoolProxyContract,
 };
+use deepbook_v3::margin_registry::{self, MarginRegistryError};
 use deepbook_v3::types::{BalanceManager, MarginManager};
-use sui::transactions::Transaction;
+use sui::transactions::{CommandKind, Transaction};
 
 fn new_test_config() -> DeepBookConfig {
     let mut cfg = DeepBookConfig::default();
@@ -34,12 +35,10 @@ fn new_test_config() -> DeepBookConfig {
 }
 
 fn command_function(tx: &Transaction, idx: usize) -> String {
-    let cmd = &tx.data.commands[idx];
-    cmd.get("MoveCall")
-        .and_then(|v| v.get("function"))
-        .and_then(|v| v.as_str())
-        .unwrap_or_default()
-        .to_string()
+    match &tx.data.commands[idx].kind {
+        CommandKind::MoveCall(call) => call.function.clone(),
+        _ => String::new(),
+    }
 }
 
 #[test]
@@ -1394,3 +1393,23 @@ fn governance_vote_target() {
     let last = tx.data.commands.len() - 1;
     assert_eq!(command_function(&tx, last), "vote");
 }
+
+#[test]
+fn margin_registry_rejects_malformed_owner() {
+    let cfg = new_test_config();
+    let margin_registry = margin_registry::MarginRegistryContract { config: &cfg };
+
+    let mut tx = Transaction::new();
+    margin_registry
+        .get_margin_manager_ids(&mut tx, "0x1")
+        .expect("call should succeed");
+    let sui::transactions::CallArg::Pure(owner) = &tx.data.inputs[1] else {
+        panic!("expected pure owner");
+    };
+    assert_eq!(owner.bytes, "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAE=");
+
+    let err = margin_registry
+        .get_margin_manager_ids(&mut Transaction::new(), "not-an-address")
+        .unwrap_err();
+    assert!(matches!(err, MarginRegistryError::Transaction(_)));
+}
@@ -8,3 +8,13 @@ fn encoding_helpers() {
     let v = encode::encode_vec_u128(&[1, 2]);
     assert!(!v.is_empty());
 }
+
+#[test]
+fn vector_and_option_encodings_match_bcs() {
+    assert_eq!(encode::encode_vec_u8(&[7, 8]), vec![2, 7, 8]);
+    let v = encode::encode_vec_u128(&[1]);
+    assert_eq!(v.len(), 17);
+    assert_eq!(v[0], 1);
+    assert_eq!(encode::encode_option_u64(None), vec![0]);
+    assert_eq!(encode::encode_option_u64(Some(2))[..2], [1, 2]);
+}
@@ -1,41 +1,70 @@
-use base64::Engine as _;
+use std::sync::Arc;
+
 use httpmock::Method::POST;
 use httpmock::MockServer;
-use serde_json::json;
+use serde_json::{json, Value};
 
 use deepbook_v3::client::DeepBookClient;
 use deepbook_v3::config::DeepBookConfig;
 use deepbook_v3::types::MarginManager;
 use sui::jsonrpc;
+use sui::transactions::resolve::BoxFuture;
+use sui::transactions::{CallArg, PluginError, SharedObjectRef, Transaction, TransactionPlugin};
+
+/// Resolves every object input as a shared object, in place of the
+/// on-chain lookups made by the default resolver.
+struct SharedObjects;
+
+impl TransactionPlugin for SharedObjects {
+    fn name(&self) -> String {
+        "SharedObjects".to_string()
+    }
+
+    fn resolve_inputs<'a>(
+        &'a self,
+        tx: &'a mut Transaction,
+    ) -> BoxFuture<'a, Result<(), PluginError>> {
+        for input in &mut tx.data.inputs {
+            if let CallArg::UnresolvedObject(object) = input {
+                *input = CallArg::shared_object_ref(SharedObjectRef {
+                    object_id: object.object_id.clone(),
+                    mutable: true,
+                    initial_shared_version: 1,
+                });
+            }
+        }
+        Box::pin(async { Ok(()) })
+    }
+}
 
-fn b64_u64(v: u64) -> String {
-    base64::engine::general_purpose::STANDARD.encode(v.to_le_bytes())
+fn u64_value(v: u64) -> Value {
+    json!([v.to_le_bytes(), "u64"])
 }
 
-fn b64_u8(v: u8) -> String {
-    base64::engine::general_purpose::STANDARD.encode([v])
+fn u8_value(v: u8) -> Value {
+    json!([[v], "u8"])
 }
 
-fn b64_addr(fill: u8) -> String {
-    base64::engine::general_purpose::STANDARD.encode(vec![fill; 32])
+fn addr_value(fill: u8) -> Value {
+    json!([vec![fill; 32], "0x2::object::ID"])
 }
 
 fn state_return_values(seed: u64) -> serde_json::Value {
     json!([
-        {"bcs": b64_addr((seed % 255) as u8 + 1)},
-        {"bcs": b64_addr(((seed + 1) % 255) as u8 + 1)},
-        {"bcs": b64_u64(seed + 2)},
-        {"bcs": b64_u64(seed + 3)},
-        {"bcs": b64_u64(seed + 4)},
-        {"bcs": b64_u64(seed + 5)},
-        {"bcs": b64_u64(seed + 6)},
-        {"bcs": b64_u64(seed + 7)},
-        {"bcs": b64_u8(9)},
-        {"bcs": b64_u64(seed + 10)},
-        {"bcs": b64_u8(10)},
-        {"bcs": b64_u64(seed + 12)},
-        {"bcs": b64_u64(seed + 13)},
-        {"bcs": b64_u64(seed + 14)}
+        addr_value((seed % 255) as u8 + 1),
+        addr_value(((seed + 1) % 255) as u8 + 1),
+        u64_value(seed + 2),
+        u64_value(seed + 3),
+        u64_value(seed + 4),
+        u64_value(seed + 5),
+        u64_value(seed + 6),
+        u64_value(seed + 7),
+        u8_value(9),
+        u64_value(seed + 10),
+        u8_value(10),
+        u64_value(seed + 12),
+        u64_value(seed + 13),
+        u64_value(seed + 14)
     ])
 }
 
@@ -45,12 +74,12 @@ async fn deepbook_margin_manager_state_methods() {
     let _mock = server.mock(|when, then| {
         when.method(POST)
             .path("/")
-            .body_contains("\"method\":\"sui_dryRunTransactionBlock\"");
+            .body_contains("\"method\":\"sui_devInspectTransactionBlock\"");
         then.status(200).header("content-type", "application/json").json_body(json!({
             "jsonrpc": "2.0",
             "id": 1,
             "result": {
-                "commandResults": [
+                "results": [
                     {"returnValues": state_return_values(100)},
                     {"returnValues": state_return_values(200)}
                 ]
@@ -80,8 +109,8 @@ async fn deepbook_margin_manager_state_methods() {
         sui.price_info_object_id = Some("0xa".to_string());
     }
 
-    let rpc = jsonrpc::Client::new(server.url("/"), "testnet");
-    let client = DeepBookClient::new(rpc, cfg);
+    let client = DeepBookClient::new(jsonrpc::Client::new(server.url("/"), "testnet"), cfg)
+        .with_input_resolver(Arc::new(SharedObjects));
 
     let state = client
         .get_margin_manager_state("mm1", 6)
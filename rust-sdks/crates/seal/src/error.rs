@@ -15,6 +15,8 @@ pub enum SealError {
     #[error("invalid package: {0}")]
     InvalidPackage(String),
     #[error(transparent)]
+    Signer(#[from] sui::cryptography::SignerError),
+    #[error(transparent)]
     Http(#[from] reqwest::Error),
     #[error(transparent)]
     Json(#[from] serde_json::Error),
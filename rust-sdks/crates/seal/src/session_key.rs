@@ -10,6 +10,8 @@ pub struct ExportedSessionKey {
     pub creation_time_ms: u64,
     pub ttl_min: u32,
     pub session_private_key_b64: String,
+    #[serde(default)]
+    pub personal_message_signature: Option<String>,
 }
 
 pub struct SessionKey {
@@ -19,6 +21,7 @@ pub struct SessionKey {
     creation_time_ms: u64,
     ttl_min: u32,
     keypair: sui::keypairs::ed25519::Keypair,
+    personal_message_signature: Option<String>,
 }
 
 impl core::fmt::Debug for SessionKey {
@@ -29,15 +32,15 @@ impl core::fmt::Debug for SessionKey {
             .field("mvr_name", &self.mvr_name)
             .field("creation_time_ms", &self.creation_time_ms)
             .field("ttl_min", &self.ttl_min)
+            .field("personal_message_signature", &self.personal_message_signature)
             .finish()
     }
 }
 
 impl Clone for SessionKey {
     fn clone(&self) -> Self {
-        let sui_sk = self.keypair.to_sui_private_key();
-        let keypair = sui::keypairs::ed25519::Keypair::from_sui_private_key(&sui_sk)
-            .expect("valid sui private key");
+        let keypair = sui::keypairs::ed25519::Keypair::from_secret_key(&self.keypair.secret_key_bytes())
+            .expect("valid secret key");
         Self {
             address: self.address.clone(),
             package_id: self.package_id.clone(),
@@ -45,6 +48,7 @@ impl Clone for SessionKey {
             creation_time_ms: self.creation_time_ms,
             ttl_min: self.ttl_min,
             keypair,
+            personal_message_signature: self.personal_message_signature.clone(),
         }
     }
 }
@@ -61,9 +65,25 @@ impl SessionKey {
             creation_time_ms: current_ms(),
             ttl_min,
             keypair: sui::keypairs::ed25519::Keypair::generate(),
+            personal_message_signature: None,
         })
     }
 
+    /// Creates a session key for the signer's address and signs its personal
+    /// message with it. Any account type works, including multisig, zkLogin
+    /// and passkey accounts.
+    pub async fn create_with_signer(
+        package_id: String,
+        mvr_name: Option<String>,
+        ttl_min: u32,
+        signer: &dyn sui::cryptography::AsyncSigner,
+    ) -> Result<Self, SealError> {
+        let mut session = Self::create(signer.address(), package_id, mvr_name, ttl_min)?;
+        let signature = signer.sign_personal_message(&session.get_personal_message()).await?;
+        session.personal_message_signature = Some(signature);
+        Ok(session)
+    }
+
     pub fn is_expired(&self) -> bool {
         self.creation_time_ms + self.ttl_min as u64 * 60_000 < current_ms()
     }
@@ -91,6 +111,18 @@ impl SessionKey {
         .into_bytes()
     }
 
+    /// Serialized signature of [`SessionKey::get_personal_message`] by the
+    /// session's address, once it has been signed.
+    pub fn get_personal_message_signature(&self) -> Option<&str> {
+        self.personal_message_signature.as_deref()
+    }
+
+    /// Sets a signature of the personal message obtained outside this SDK,
+    /// e.g. from a wallet.
+    pub fn set_personal_message_signature(&mut self, signature: String) {
+        self.personal_message_signature = Some(signature);
+    }
+
     pub fn create_request_signature(&self, tx_bytes: &[u8], enc_key_pk: &[u8], enc_vk: &[u8]) -> Result<String, SealError> {
         if self.is_expired() {
             return Err(SealError::ExpiredSessionKey);
@@ -104,13 +136,7 @@ impl SessionKey {
     }
 
     pub fn export(&self) -> ExportedSessionKey {
-        let mut sk = [0u8; 32];
-        let bytes = base64::engine::general_purpose::STANDARD
-            .decode(self.keypair.to_sui_private_key())
-            .unwrap_or_default();
-        if bytes.len() >= 33 {
-            sk.copy_from_slice(&bytes[1..33]);
-        }
+        let sk = self.keypair.secret_key_bytes();
         ExportedSessionKey {
             address: self.address.clone(),
             package_id: self.package_id.clone(),
@@ -118,6 +144,7 @@ impl SessionKey {
             creation_time_ms: self.creation_time_ms,
             ttl_min: self.ttl_min,
             session_private_key_b64: base64::engine::general_purpose::STANDARD.encode(sk),
+            personal_message_signature: self.personal_message_signature.clone(),
         }
     }
 
@@ -134,6 +161,7 @@ impl SessionKey {
             creation_time_ms: data.creation_time_ms,
             ttl_min: data.ttl_min,
             keypair,
+            personal_message_signature: data.personal_message_signature,
         })
     }
 }
@@ -0,0 +1,44 @@
+use seal::SessionKey;
+use sui::crypto::SignatureScheme;
+use sui::cryptography::{Keypair, Signer};
+
+#[tokio::test]
+async fn session_key_signed_by_any_signer() {
+    for scheme in [
+        SignatureScheme::Ed25519,
+        SignatureScheme::Secp256k1,
+        SignatureScheme::Secp256r1,
+    ] {
+        let signer = Keypair::generate(scheme);
+        let session = SessionKey::create_with_signer("0x2".to_string(), None, 10, &signer)
+            .await
+            .unwrap();
+        assert_eq!(session.get_address(), signer.address());
+        assert_eq!(
+            session.get_personal_message_signature(),
+            Some(
+                signer
+                    .sign_personal_message(&session.get_personal_message())
+                    .unwrap()
+                    .as_str()
+            )
+        );
+
+        let imported = SessionKey::import(session.export()).unwrap();
+        assert_eq!(
+            imported.get_personal_message_signature(),
+            session.get_personal_message_signature()
+        );
+    }
+}
+
+#[test]
+fn session_key_accepts_external_signature() {
+    let mut session = SessionKey::create("0xabc".to_string(), "0x2".to_string(), None, 10).unwrap();
+    assert_eq!(session.get_personal_message_signature(), None);
+    session.set_personal_message_signature("AA==".to_string());
+    assert_eq!(
+        session.clone().get_personal_message_signature(),
+        Some("AA==")
+    );
+}
@@ -1,4 +1,4 @@
-use sha2::{Digest, Sha256};
+use fastcrypto::hash::{Blake2b256, HashFunction};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum SignatureScheme {
@@ -34,13 +34,21 @@ pub fn to_sui_public_key(flag: u8, public_key_bytes: &[u8]) -> String {
     base64::engine::general_purpose::STANDARD.encode(sui_bytes)
 }
 
+pub const MULTISIG_SCHEME_FLAG: u8 = 0x03;
+pub const ZKLOGIN_SCHEME_FLAG: u8 = 0x05;
+pub const PASSKEY_SCHEME_FLAG: u8 = 0x06;
+
+pub fn blake2b256(data: &[u8]) -> [u8; 32] {
+    Blake2b256::digest(data).digest
+}
+
+/// Sui address of a public key: Blake2b-256 over `flag || public_key_bytes`.
 pub fn to_sui_address(flag: u8, public_key_bytes: &[u8]) -> String {
     let mut sui_bytes = Vec::with_capacity(public_key_bytes.len() + 1);
     sui_bytes.push(flag);
     sui_bytes.extend_from_slice(public_key_bytes);
 
-    let digest = Sha256::digest(sui_bytes);
-    format!("0x{}", hex::encode(&digest[..32]))
+    format!("0x{}", hex::encode(blake2b256(&sui_bytes)))
 }
 
 pub fn message_with_intent(intent: [u8; 3], message: &[u8]) -> Vec<u8> {
@@ -50,9 +58,10 @@ pub fn message_with_intent(intent: [u8; 3], message: &[u8]) -> Vec<u8> {
     result
 }
 
+/// Blake2b-256 over the intent-prefixed message, the digest Sui signs. Same as
+/// [`crate::transactions::hash::hash_with_intent`].
 pub fn hash_with_intent(intent: [u8; 3], message: &[u8]) -> [u8; 32] {
-    let payload = message_with_intent(intent, message);
-    Sha256::digest(payload).into()
+    crate::transactions::hash::hash_with_intent(intent, message)
 }
 
 pub mod fastcrypto_support {
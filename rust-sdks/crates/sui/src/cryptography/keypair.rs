@@ -1,12 +1,75 @@
+use zeroize::Zeroizing;
+
 use crate::crypto::SignatureScheme;
+use crate::cryptography::PublicKey;
+use crate::cryptography::private_key::{self, PrivateKeyError};
+use crate::cryptography::signer::{Signer, SignerError};
+use crate::keypairs::{ed25519, secp256k1, secp256r1};
+
+#[derive(Debug, thiserror::Error)]
+pub enum KeypairError {
+    #[error(transparent)]
+    Ed25519(#[from] ed25519::KeypairError),
+    #[error(transparent)]
+    Secp256k1(#[from] secp256k1::KeypairError),
+    #[error(transparent)]
+    Secp256r1(#[from] secp256r1::KeypairError),
+    #[error(transparent)]
+    PrivateKey(#[from] PrivateKeyError),
+}
 
+/// A keypair of any of the plain signature schemes.
 pub enum Keypair {
-    Ed25519(Vec<u8>),
-    Secp256k1(Vec<u8>),
-    Secp256r1(Vec<u8>),
+    Ed25519(Box<ed25519::Keypair>),
+    Secp256k1(secp256k1::Keypair),
+    Secp256r1(secp256r1::Keypair),
 }
 
 impl Keypair {
+    pub fn generate(scheme: SignatureScheme) -> Self {
+        match scheme {
+            SignatureScheme::Ed25519 => Keypair::Ed25519(Box::new(ed25519::Keypair::generate())),
+            SignatureScheme::Secp256k1 => Keypair::Secp256k1(secp256k1::Keypair::generate()),
+            SignatureScheme::Secp256r1 => Keypair::Secp256r1(secp256r1::Keypair::generate()),
+        }
+    }
+
+    pub fn from_secret_key(
+        scheme: SignatureScheme,
+        secret_key: &[u8],
+    ) -> Result<Self, KeypairError> {
+        Ok(match scheme {
+            SignatureScheme::Ed25519 => ed25519::Keypair::from_secret_key(secret_key)?.into(),
+            SignatureScheme::Secp256k1 => secp256k1::Keypair::from_secret_key(secret_key)?.into(),
+            SignatureScheme::Secp256r1 => secp256r1::Keypair::from_secret_key(secret_key)?.into(),
+        })
+    }
+
+    /// Reads a private key in any format [`private_key::decode_private_key`]
+    /// recognizes, taking the scheme from the key itself.
+    pub fn from_private_key(value: &str) -> Result<Self, KeypairError> {
+        let parsed = private_key::decode_private_key(value)?;
+        Self::from_secret_key(parsed.scheme, &parsed.secret_key)
+    }
+
+    /// Derives the `scheme` key for `mnemonic` at `path`, or at the scheme's
+    /// default Sui path.
+    pub fn derive_keypair(
+        scheme: SignatureScheme,
+        mnemonic: &str,
+        path: Option<&str>,
+    ) -> Result<Self, KeypairError> {
+        Ok(match scheme {
+            SignatureScheme::Ed25519 => ed25519::Keypair::derive_keypair(mnemonic, path)?.into(),
+            SignatureScheme::Secp256k1 => {
+                secp256k1::Keypair::derive_keypair(mnemonic, path)?.into()
+            }
+            SignatureScheme::Secp256r1 => {
+                secp256r1::Keypair::derive_keypair(mnemonic, path)?.into()
+            }
+        })
+    }
+
     pub fn scheme(&self) -> SignatureScheme {
         match self {
             Keypair::Ed25519(_) => SignatureScheme::Ed25519,
@@ -17,13 +80,153 @@ impl Keypair {
 
     pub fn public_key_bytes(&self) -> Vec<u8> {
         match self {
-            Keypair::Ed25519(bytes) => bytes.clone(),
-            Keypair::Secp256k1(bytes) => bytes.clone(),
-            Keypair::Secp256r1(bytes) => bytes.clone(),
+            Keypair::Ed25519(keypair) => keypair.public_key_bytes(),
+            Keypair::Secp256k1(keypair) => keypair.public_key_bytes(),
+            Keypair::Secp256r1(keypair) => keypair.public_key_bytes(),
+        }
+    }
+
+    pub fn secret_key_bytes(&self) -> Zeroizing<Vec<u8>> {
+        match self {
+            Keypair::Ed25519(keypair) => keypair.secret_key_bytes(),
+            Keypair::Secp256k1(keypair) => keypair.secret_key_bytes(),
+            Keypair::Secp256r1(keypair) => keypair.secret_key_bytes(),
+        }
+    }
+
+    /// The `suiprivkey1..` encoding the Sui CLI and wallets import.
+    pub fn to_sui_private_key(&self) -> String {
+        private_key::encode_sui_private_key(self.scheme(), &self.secret_key_bytes())
+            .expect("secret key is 32 bytes")
+    }
+
+    /// PKCS#8 `PRIVATE KEY` PEM, embedding the public key for ECDSA keys.
+    pub fn to_pkcs8_pem(&self) -> Result<String, KeypairError> {
+        let public_key = self.public_key_bytes();
+        let public_key = (self.scheme() != SignatureScheme::Ed25519).then_some(&public_key[..]);
+        Ok(private_key::encode_pkcs8_pem(
+            self.scheme(),
+            &self.secret_key_bytes(),
+            public_key,
+        )?)
+    }
+
+    /// SEC1 `EC PRIVATE KEY` PEM; ed25519 keys have no SEC1 form.
+    pub fn to_sec1_pem(&self) -> Result<String, KeypairError> {
+        Ok(private_key::encode_sec1_pem(
+            self.scheme(),
+            &self.secret_key_bytes(),
+            Some(&self.public_key_bytes()),
+        )?)
+    }
+
+    /// Raw signature over `message`; use [`Signer`] for Sui signatures.
+    pub fn sign(&self, message: &[u8]) -> Vec<u8> {
+        match self {
+            Keypair::Ed25519(keypair) => keypair.sign(message),
+            Keypair::Secp256k1(keypair) => keypair.sign(message),
+            Keypair::Secp256r1(keypair) => keypair.sign(message),
         }
     }
 
-    pub fn sign(&self, _message: &[u8]) -> Vec<u8> {
-        Vec::new()
+    fn signer(&self) -> &dyn Signer {
+        match self {
+            Keypair::Ed25519(keypair) => keypair.as_ref(),
+            Keypair::Secp256k1(keypair) => keypair,
+            Keypair::Secp256r1(keypair) => keypair,
+        }
+    }
+}
+
+impl Signer for Keypair {
+    fn public_key(&self) -> PublicKey {
+        self.signer().public_key()
+    }
+
+    fn address(&self) -> String {
+        self.signer().address()
+    }
+
+    fn sign_digest(&self, digest: &[u8; 32]) -> Result<Vec<u8>, SignerError> {
+        self.signer().sign_digest(digest)
+    }
+}
+
+impl From<ed25519::Keypair> for Keypair {
+    fn from(keypair: ed25519::Keypair) -> Self {
+        Keypair::Ed25519(Box::new(keypair))
+    }
+}
+
+impl From<secp256k1::Keypair> for Keypair {
+    fn from(keypair: secp256k1::Keypair) -> Self {
+        Keypair::Secp256k1(keypair)
+    }
+}
+
+impl From<secp256r1::Keypair> for Keypair {
+    fn from(keypair: secp256r1::Keypair) -> Self {
+        Keypair::Secp256r1(keypair)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::verify;
+
+    #[test]
+    fn test_keypair_signs_with_its_scheme() {
+        for scheme in [
+            SignatureScheme::Ed25519,
+            SignatureScheme::Secp256k1,
+            SignatureScheme::Secp256r1,
+        ] {
+            let keypair = Keypair::generate(scheme);
+            assert_eq!(keypair.scheme(), scheme);
+            let signature = keypair.sign(b"message");
+            assert!(!signature.is_empty());
+            assert!(
+                verify::verify_signature(
+                    scheme,
+                    &keypair.public_key_bytes(),
+                    b"message",
+                    &signature
+                )
+                .unwrap()
+            );
+            assert_eq!(
+                keypair.public_key().to_sui_address(),
+                keypair.address()
+            );
+        }
+    }
+
+    #[test]
+    fn test_keypair_round_trips_through_private_key_formats() {
+        for scheme in [
+            SignatureScheme::Ed25519,
+            SignatureScheme::Secp256k1,
+            SignatureScheme::Secp256r1,
+        ] {
+            let keypair = Keypair::generate(scheme);
+            let mut encoded = vec![
+                keypair.to_sui_private_key(),
+                keypair.to_pkcs8_pem().unwrap(),
+            ];
+            if scheme != SignatureScheme::Ed25519 {
+                encoded.push(keypair.to_sec1_pem().unwrap());
+            }
+            for value in encoded {
+                let restored = Keypair::from_private_key(&value).unwrap();
+                assert_eq!(restored.scheme(), scheme);
+                assert_eq!(restored.address(), keypair.address());
+            }
+        }
+        assert!(
+            Keypair::generate(SignatureScheme::Ed25519)
+                .to_sec1_pem()
+                .is_err()
+        );
     }
 }
@@ -0,0 +1,197 @@
+//! BIP-39 mnemonics and the key derivation Sui wallets use for them:
+//! SLIP-0010 along hardened `m/44'/784'/..` paths for ed25519, and BIP-32
+//! along `m/54'/784'/..` and `m/74'/784'/..` for secp256k1 and secp256r1.
+
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+
+pub const DEFAULT_ED25519_DERIVATION_PATH: &str = "m/44'/784'/0'/0'/0'";
+pub const DEFAULT_SECP256K1_DERIVATION_PATH: &str = "m/54'/784'/0'/0/0";
+pub const DEFAULT_SECP256R1_DERIVATION_PATH: &str = "m/74'/784'/0'/0/0";
+
+const SUI_COIN_TYPE: u32 = 784;
+const HARDENED_OFFSET: u32 = 0x8000_0000;
+const ED25519_CURVE: &[u8] = b"ed25519 seed";
+
+#[derive(Debug, thiserror::Error)]
+pub enum MnemonicError {
+    #[error("invalid mnemonic: {0}")]
+    Mnemonic(#[from] bip39::Error),
+    #[error("invalid derivation path: {0}")]
+    InvalidPath(String),
+    #[error("key derivation failed: {0}")]
+    Derive(#[from] bip32::Error),
+}
+
+/// Lowercases the words and joins them with single spaces.
+pub fn normalize_mnemonic(phrase: &str) -> String {
+    phrase
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A new English mnemonic of 12, 15, 18, 21 or 24 words.
+pub fn generate_mnemonic(word_count: usize) -> Result<String, MnemonicError> {
+    Ok(bip39::Mnemonic::generate(word_count)?.to_string())
+}
+
+pub fn is_valid_mnemonic(phrase: &str) -> bool {
+    bip39::Mnemonic::parse_in(bip39::Language::English, normalize_mnemonic(phrase)).is_ok()
+}
+
+/// Checks the phrase's checksum and stretches it into the 64-byte seed keys
+/// are derived from.
+pub fn mnemonic_to_seed(phrase: &str, passphrase: &str) -> Result<[u8; 64], MnemonicError> {
+    let mnemonic = bip39::Mnemonic::parse_in(bip39::Language::English, normalize_mnemonic(phrase))?;
+    Ok(mnemonic.to_seed(passphrase))
+}
+
+/// `(index, hardened)` for every level of `m/a/b'/..`.
+fn parse_path(path: &str) -> Result<Vec<(u32, bool)>, MnemonicError> {
+    let invalid = || MnemonicError::InvalidPath(path.to_string());
+    let mut levels = path.split('/');
+    if levels.next() != Some("m") {
+        return Err(invalid());
+    }
+    levels
+        .map(|level| {
+            let (index, hardened) = match level.strip_suffix('\'') {
+                Some(index) => (index, true),
+                None => (level, false),
+            };
+            if index.is_empty() || !index.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            match index.parse::<u32>() {
+                Ok(index) if index < HARDENED_OFFSET => Ok((index, hardened)),
+                _ => Err(invalid()),
+            }
+        })
+        .collect()
+}
+
+/// Whether `path` is a five level Sui path under `purpose`, hardened at the
+/// levels marked in `hardened`.
+fn is_sui_path(path: &str, purpose: u32, hardened: [bool; 5]) -> bool {
+    let Ok(levels) = parse_path(path) else {
+        return false;
+    };
+    levels.len() == 5
+        && levels[0].0 == purpose
+        && levels[1].0 == SUI_COIN_TYPE
+        && levels.iter().zip(hardened).all(|(level, h)| level.1 == h)
+}
+
+/// `m/44'/784'/{account}'/{change}'/{address}'`, the ed25519 path.
+pub fn is_valid_hardened_path(path: &str) -> bool {
+    is_sui_path(path, 44, [true; 5])
+}
+
+/// `m/{purpose}'/784'/{account}'/{change}/{address}`, the secp256k1 (purpose
+/// 54) and secp256r1 (purpose 74) path.
+pub fn is_valid_bip32_path(path: &str, purpose: u32) -> bool {
+    is_sui_path(path, purpose, [true, true, true, false, false])
+}
+
+/// SLIP-0010 ed25519 secret key at a fully hardened `path`.
+pub fn derive_ed25519_secret_key(seed: &[u8], path: &str) -> Result<[u8; 32], MnemonicError> {
+    let levels = parse_path(path)?;
+    if levels.iter().any(|(_, hardened)| !hardened) {
+        return Err(MnemonicError::InvalidPath(format!(
+            "{path}: ed25519 only derives hardened keys"
+        )));
+    }
+
+    let hmac = |key: &[u8], data: &[&[u8]]| {
+        let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("hmac takes any key size");
+        for part in data {
+            mac.update(part);
+        }
+        let out = mac.finalize().into_bytes();
+        let (key, chain_code) = out.split_at(32);
+        (
+            <[u8; 32]>::try_from(key).expect("32 bytes"),
+            <[u8; 32]>::try_from(chain_code).expect("32 bytes"),
+        )
+    };
+    let (mut key, mut chain_code) = hmac(ED25519_CURVE, &[seed]);
+    for (index, _) in levels {
+        (key, chain_code) = hmac(
+            &chain_code,
+            &[&[0], &key, &(index | HARDENED_OFFSET).to_be_bytes()],
+        );
+    }
+    Ok(key)
+}
+
+/// BIP-32 secret key at `path`. Sui derives secp256r1 keys over the
+/// secp256k1 tree as well, keeping the resulting 32 bytes as the r1 scalar.
+pub fn derive_bip32_secret_key(seed: &[u8], path: &str) -> Result<[u8; 32], MnemonicError> {
+    parse_path(path)?;
+    let path: bip32::DerivationPath = path
+        .parse()
+        .map_err(|_| MnemonicError::InvalidPath(path.to_string()))?;
+    let key = bip32::XPrv::derive_from_path(seed, &path)?;
+    Ok(key.to_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sui_paths() {
+        assert!(is_valid_hardened_path(DEFAULT_ED25519_DERIVATION_PATH));
+        assert!(is_valid_hardened_path("m/44'/784'/3'/1'/7'"));
+        assert!(!is_valid_hardened_path("m/44'/784'/0'/0/0"));
+        assert!(!is_valid_hardened_path("m/44'/60'/0'/0'/0'"));
+        assert!(!is_valid_hardened_path("m/44'/784'/0'/0'"));
+
+        assert!(is_valid_bip32_path(DEFAULT_SECP256K1_DERIVATION_PATH, 54));
+        assert!(is_valid_bip32_path(DEFAULT_SECP256R1_DERIVATION_PATH, 74));
+        assert!(!is_valid_bip32_path(DEFAULT_SECP256R1_DERIVATION_PATH, 54));
+        assert!(!is_valid_bip32_path("m/54'/784'/0'/0'/0'", 54));
+        assert!(!is_valid_bip32_path("m/54'/784'/0'/0/x", 54));
+        assert!(!is_valid_bip32_path("54'/784'/0'/0/0", 54));
+    }
+
+    #[test]
+    fn test_slip10_ed25519_vector() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        assert_eq!(
+            hex::encode(derive_ed25519_secret_key(&seed, "m").unwrap()),
+            "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"
+        );
+        assert_eq!(
+            hex::encode(derive_ed25519_secret_key(&seed, "m/0'").unwrap()),
+            "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3"
+        );
+        assert!(matches!(
+            derive_ed25519_secret_key(&seed, "m/0"),
+            Err(MnemonicError::InvalidPath(_))
+        ));
+    }
+
+    #[test]
+    fn test_mnemonic_checksum_and_normalization() {
+        let phrase = generate_mnemonic(12).unwrap();
+        assert_eq!(phrase.split(' ').count(), 12);
+        assert!(is_valid_mnemonic(&phrase));
+        assert!(is_valid_mnemonic(&format!("  {}  ", phrase.to_uppercase())));
+        assert_eq!(
+            mnemonic_to_seed(&phrase, "").unwrap(),
+            mnemonic_to_seed(&phrase.replace(' ', "   "), "").unwrap()
+        );
+
+        let abandon = "abandon ".repeat(11);
+        assert!(is_valid_mnemonic(&format!("{abandon}about")));
+        assert!(!is_valid_mnemonic(&format!("{abandon}abandon")));
+        assert!(matches!(
+            mnemonic_to_seed(&format!("{abandon}abandon"), ""),
+            Err(MnemonicError::Mnemonic(_))
+        ));
+        assert!(generate_mnemonic(13).is_err());
+    }
+}
@@ -1,11 +1,19 @@
 pub mod keypair;
+pub mod mnemonics;
+pub mod private_key;
+pub mod public_key;
 pub mod signature;
+pub mod signer;
 
 pub use crate::keypairs::ed25519;
 pub use crate::keypairs::secp256k1;
 pub use crate::keypairs::secp256r1;
-pub use keypair::Keypair;
+pub use keypair::{Keypair, KeypairError};
+pub use mnemonics::MnemonicError;
+pub use private_key::{ParsedPrivateKey, PrivateKeyError};
+pub use public_key::PublicKey;
 pub use signature::Signature;
+pub use signer::{AsyncSigner, Signer, SignerError};
 
 pub type Ed25519Keypair = crate::keypairs::ed25519::Keypair;
 pub type Secp256k1Keypair = crate::keypairs::secp256k1::Keypair;
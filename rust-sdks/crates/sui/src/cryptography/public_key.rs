@@ -0,0 +1,75 @@
+use crate::crypto::{self, SignatureScheme};
+use crate::keypairs::passkey::PasskeyPublicKey;
+use crate::multisig::MultiSigPublicKey;
+use crate::zklogin::ZkLoginPublicIdentifier;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PublicKey {
+    Ed25519(Vec<u8>),
+    Secp256k1(Vec<u8>),
+    Secp256r1(Vec<u8>),
+    MultiSig(MultiSigPublicKey),
+    ZkLogin(ZkLoginPublicIdentifier),
+    Passkey(PasskeyPublicKey),
+}
+
+impl PublicKey {
+    pub fn flag(&self) -> u8 {
+        match self {
+            PublicKey::Ed25519(_) => SignatureScheme::Ed25519.flag(),
+            PublicKey::Secp256k1(_) => SignatureScheme::Secp256k1.flag(),
+            PublicKey::Secp256r1(_) => SignatureScheme::Secp256r1.flag(),
+            PublicKey::MultiSig(_) => crypto::MULTISIG_SCHEME_FLAG,
+            PublicKey::ZkLogin(_) => crypto::ZKLOGIN_SCHEME_FLAG,
+            PublicKey::Passkey(_) => crypto::PASSKEY_SCHEME_FLAG,
+        }
+    }
+
+    pub fn from_bytes(scheme: SignatureScheme, bytes: Vec<u8>) -> Self {
+        match scheme {
+            SignatureScheme::Ed25519 => PublicKey::Ed25519(bytes),
+            SignatureScheme::Secp256k1 => PublicKey::Secp256k1(bytes),
+            SignatureScheme::Secp256r1 => PublicKey::Secp256r1(bytes),
+        }
+    }
+
+    pub fn to_sui_address(&self) -> String {
+        match self {
+            PublicKey::Ed25519(bytes)
+            | PublicKey::Secp256k1(bytes)
+            | PublicKey::Secp256r1(bytes) => crypto::to_sui_address(self.flag(), bytes),
+            PublicKey::MultiSig(multisig) => multisig.to_sui_address(),
+            PublicKey::ZkLogin(identifier) => identifier.to_sui_address(),
+            PublicKey::Passkey(passkey) => passkey.to_sui_address(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_public_key_flags() {
+        assert_eq!(
+            PublicKey::from_bytes(SignatureScheme::Secp256k1, vec![]).flag(),
+            0x01
+        );
+        let passkey = PasskeyPublicKey::new(&[2u8; 33]).unwrap();
+        assert_eq!(PublicKey::Passkey(passkey).flag(), 0x06);
+        let zklogin = ZkLoginPublicIdentifier::new("accounts.google.com", [0u8; 32]);
+        assert_eq!(zklogin.iss, "https://accounts.google.com");
+        assert_eq!(PublicKey::ZkLogin(zklogin).flag(), 0x05);
+    }
+
+    #[test]
+    fn test_public_key_address_matches_keypair() {
+        let keypair = crate::keypairs::ed25519::Keypair::from_secret_key(&[1u8; 32]).unwrap();
+        let public_key =
+            PublicKey::from_bytes(SignatureScheme::Ed25519, keypair.public_key_bytes());
+        assert_eq!(
+            public_key.to_sui_address(),
+            keypair.to_sui_address()
+        );
+    }
+}
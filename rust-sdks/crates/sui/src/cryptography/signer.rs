@@ -0,0 +1,258 @@
+//! One signing interface over every Sui account type.
+//!
+//! All schemes sign the Blake2b-256 digest of the intent message and return a
+//! serialized Sui signature: `flag || signature || public key` for plain keys,
+//! `flag || bcs(authenticator)` for multisig, zkLogin and passkey accounts.
+
+use crate::crypto::{self, SignatureScheme};
+use crate::cryptography::PublicKey;
+use crate::keypairs::passkey::PasskeyError;
+use crate::keypairs::{ed25519, secp256k1, secp256r1};
+use crate::multisig::MultiSigError;
+use crate::transactions::resolve::BoxFuture;
+use crate::zklogin::ZkLoginError;
+
+pub const TRANSACTION_INTENT: [u8; 3] = [0, 0, 0];
+pub const PERSONAL_MESSAGE_INTENT: [u8; 3] = [3, 0, 0];
+
+#[derive(Debug, thiserror::Error)]
+pub enum SignerError {
+    #[error("signing failed: {0}")]
+    Failed(String),
+    #[error(transparent)]
+    MultiSig(#[from] MultiSigError),
+    #[error(transparent)]
+    ZkLogin(#[from] ZkLoginError),
+    #[error(transparent)]
+    Passkey(#[from] PasskeyError),
+    #[error("bcs encode failed: {0}")]
+    Bcs(#[from] bcs::Error),
+}
+
+/// Digest signed for a transaction: Blake2b-256 over the `TransactionData`
+/// intent message.
+pub fn transaction_digest(tx_bytes: &[u8]) -> [u8; 32] {
+    crypto::blake2b256(&crypto::message_with_intent(TRANSACTION_INTENT, tx_bytes))
+}
+
+/// Digest signed for a personal message, which is BCS-encoded as `vector<u8>`
+/// before the intent is prepended.
+pub fn personal_message_digest(message: &[u8]) -> Result<[u8; 32], bcs::Error> {
+    let message = bcs::to_bytes(message)?;
+    Ok(crypto::blake2b256(&crypto::message_with_intent(
+        PERSONAL_MESSAGE_INTENT,
+        &message,
+    )))
+}
+
+/// `flag || signature || public key`, the serialized form of a plain key
+/// signature.
+pub fn serialize_signature(
+    scheme: SignatureScheme,
+    signature: &[u8],
+    public_key: &[u8],
+) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(1 + signature.len() + public_key.len());
+    bytes.push(scheme.flag());
+    bytes.extend_from_slice(signature);
+    bytes.extend_from_slice(public_key);
+    bytes
+}
+
+fn encode(bytes: Vec<u8>) -> String {
+    use base64::Engine as _;
+    base64::engine::general_purpose::STANDARD.encode(bytes)
+}
+
+pub trait Signer: Send + Sync {
+    fn public_key(&self) -> PublicKey;
+
+    fn address(&self) -> String;
+
+    /// Signs an intent digest, returning the serialized Sui signature.
+    fn sign_digest(&self, digest: &[u8; 32]) -> Result<Vec<u8>, SignerError>;
+
+    /// Base64 serialized signature over BCS `TransactionData`.
+    fn sign_transaction(&self, tx_bytes: &[u8]) -> Result<String, SignerError> {
+        Ok(encode(self.sign_digest(&transaction_digest(tx_bytes))?))
+    }
+
+    fn sign_personal_message(&self, message: &[u8]) -> Result<String, SignerError> {
+        Ok(encode(
+            self.sign_digest(&personal_message_digest(message)?)?,
+        ))
+    }
+}
+
+/// A signer that has to wait on something else, such as a remote KMS or a
+/// hardware authenticator. Every [`Signer`] is also an `AsyncSigner`.
+pub trait AsyncSigner: Send + Sync {
+    fn public_key(&self) -> PublicKey;
+
+    fn address(&self) -> String;
+
+    fn sign_digest<'a>(&'a self, digest: [u8; 32]) -> BoxFuture<'a, Result<Vec<u8>, SignerError>>;
+
+    fn sign_transaction<'a>(
+        &'a self,
+        tx_bytes: &'a [u8],
+    ) -> BoxFuture<'a, Result<String, SignerError>> {
+        Box::pin(async move {
+            Ok(encode(
+                AsyncSigner::sign_digest(self, transaction_digest(tx_bytes)).await?,
+            ))
+        })
+    }
+
+    fn sign_personal_message<'a>(
+        &'a self,
+        message: &'a [u8],
+    ) -> BoxFuture<'a, Result<String, SignerError>> {
+        Box::pin(async move {
+            let digest = personal_message_digest(message)?;
+            Ok(encode(AsyncSigner::sign_digest(self, digest).await?))
+        })
+    }
+}
+
+impl<T: Signer + ?Sized> AsyncSigner for T {
+    fn public_key(&self) -> PublicKey {
+        Signer::public_key(self)
+    }
+
+    fn address(&self) -> String {
+        Signer::address(self)
+    }
+
+    fn sign_digest<'a>(&'a self, digest: [u8; 32]) -> BoxFuture<'a, Result<Vec<u8>, SignerError>> {
+        Box::pin(async move { Signer::sign_digest(self, &digest) })
+    }
+}
+
+impl Signer for ed25519::Keypair {
+    fn public_key(&self) -> PublicKey {
+        PublicKey::Ed25519(self.public_key_bytes())
+    }
+
+    fn address(&self) -> String {
+        self.to_sui_address()
+    }
+
+    fn sign_digest(&self, digest: &[u8; 32]) -> Result<Vec<u8>, SignerError> {
+        Ok(serialize_signature(
+            SignatureScheme::Ed25519,
+            &self.sign(digest),
+            &self.public_key_bytes(),
+        ))
+    }
+}
+
+impl Signer for secp256k1::Keypair {
+    fn public_key(&self) -> PublicKey {
+        PublicKey::Secp256k1(self.public_key_bytes())
+    }
+
+    fn address(&self) -> String {
+        self.to_sui_address()
+    }
+
+    /// The digest is hashed again with SHA-256 before ECDSA signing.
+    fn sign_digest(&self, digest: &[u8; 32]) -> Result<Vec<u8>, SignerError> {
+        Ok(serialize_signature(
+            SignatureScheme::Secp256k1,
+            &self.sign(digest),
+            &self.public_key_bytes(),
+        ))
+    }
+}
+
+impl Signer for secp256r1::Keypair {
+    fn public_key(&self) -> PublicKey {
+        PublicKey::Secp256r1(self.public_key_bytes())
+    }
+
+    fn address(&self) -> String {
+        self.to_sui_address()
+    }
+
+    /// The digest is hashed again with SHA-256 before ECDSA signing.
+    fn sign_digest(&self, digest: &[u8; 32]) -> Result<Vec<u8>, SignerError> {
+        Ok(serialize_signature(
+            SignatureScheme::Secp256r1,
+            &self.sign(digest),
+            &self.public_key_bytes(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        SignatureScheme, Signer, crypto, ed25519, personal_message_digest, secp256k1, secp256r1,
+    };
+    use crate::verify;
+
+    fn split(serialized: &str) -> (u8, Vec<u8>, Vec<u8>) {
+        use base64::Engine as _;
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(serialized)
+            .unwrap();
+        let (signature, public_key) = bytes[1..].split_at(64);
+        (bytes[0], signature.to_vec(), public_key.to_vec())
+    }
+
+    #[test]
+    fn test_keypairs_sign_intent_digest() {
+        let signers: Vec<(SignatureScheme, Box<dyn Signer>)> = vec![
+            (
+                SignatureScheme::Ed25519,
+                Box::new(ed25519::Keypair::generate()),
+            ),
+            (
+                SignatureScheme::Secp256k1,
+                Box::new(secp256k1::Keypair::generate()),
+            ),
+            (
+                SignatureScheme::Secp256r1,
+                Box::new(secp256r1::Keypair::generate()),
+            ),
+        ];
+        for (scheme, signer) in signers {
+            let (flag, signature, public_key) = split(&signer.sign_transaction(b"tx").unwrap());
+            assert_eq!(flag, scheme.flag());
+            assert_eq!(
+                signer.public_key().to_sui_address(),
+                signer.address()
+            );
+            assert!(verify::verify_transaction(scheme, &public_key, b"tx", &signature).unwrap());
+
+            let (_, signature, _) = split(&signer.sign_personal_message(b"hello").unwrap());
+            assert!(
+                verify::verify_personal_message(scheme, &public_key, b"hello", &signature).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_personal_message_digest_wraps_message_in_bcs() {
+        let mut expected = vec![3, 0, 0, 5];
+        expected.extend_from_slice(b"hello");
+        assert_eq!(
+            personal_message_digest(b"hello").unwrap(),
+            crypto::blake2b256(&expected)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_signers_are_async_signers() {
+        use super::AsyncSigner;
+
+        let keypair = ed25519::Keypair::from_secret_key(&[7u8; 32]).unwrap();
+        let signer: &dyn AsyncSigner = &keypair;
+        assert_eq!(
+            signer.sign_transaction(b"tx").await.unwrap(),
+            Signer::sign_transaction(&keypair, b"tx").unwrap()
+        );
+        assert_eq!(AsyncSigner::address(&keypair), keypair.to_sui_address());
+    }
+}
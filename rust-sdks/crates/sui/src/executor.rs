@@ -1,117 +1,862 @@
-// Transaction Executor for Rust SDK
-use std::collections::HashMap;
+//! Executors that sign and submit transactions for a single sender.
+//!
+//! [`SerialTransactionExecutor`] runs one transaction at a time and learns
+//! object versions from each transaction's effects, so consecutive
+//! transactions from the same address reuse its gas coin and owned objects
+//! without fetching them again. [`ParallelTransactionExecutor`] pays for each
+//! transaction with its own coin from a pool, so transactions that touch
+//! different owned objects run at the same time.
+
+use std::collections::HashSet;
 use std::sync::{Arc, Mutex};
 
-/// TransactionResult
+use serde_json::{Value, json};
+
+use crate::cryptography::Signer;
+use crate::intents::{IntentError, resolve_coin_with_balance};
+use crate::jsonrpc;
+use crate::transactions::gas::{CoinPage, GasConfig, GasError, GasProvider, SUI_COIN_TYPE};
+use crate::transactions::object_cache::ObjectCache;
+use crate::transactions::resolve::{BoxFuture, CachingObjectProvider};
+use crate::transactions::{
+    Argument, CallArg, CoinProvider, MoveCallTarget, NormalizedMoveFunction, ObjectKind, ObjectOwner,
+    ObjectProvider, ObjectRef, PluginError, PureValue, ResolveError, ResolvedObject, Transaction,
+    TransactionError, normalize_sui_address,
+};
+use crate::types::GasCost;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExecutorError {
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
+    #[error(transparent)]
+    Plugin(#[from] PluginError),
+    #[error(transparent)]
+    Resolve(#[from] ResolveError),
+    #[error(transparent)]
+    Gas(#[from] GasError),
+    #[error(transparent)]
+    Intent(#[from] IntentError),
+    #[error(transparent)]
+    JsonRpc(#[from] jsonrpc::JsonRpcError),
+    #[error("invalid execution response: {0}")]
+    InvalidResponse(String),
+    #[error("gas coin refill {digest} failed: {error}")]
+    RefillFailed { digest: String, error: String },
+}
+
+/// The outcome of an executed transaction. A transaction that was included
+/// but aborted is `success: false` with the abort in `error`.
 #[derive(Debug, Clone)]
 pub struct TransactionResult {
+    pub digest: String,
     pub success: bool,
     pub error: Option<String>,
+    /// The full `sui_executeTransactionBlock` response.
+    pub response: Value,
 }
 
 impl TransactionResult {
-    pub fn new_success() -> Self {
-        Self { success: true, error: None }
+    pub fn effects(&self) -> Option<&Value> {
+        self.response.get("effects")
     }
 }
 
-/// Transaction
-#[derive(Debug, Clone)]
-pub struct Transaction {
-    pub commands: Vec<Command>,
-    pub sender: Option<String>,
-    pub gas_price: Option<u64>,
+/// Object refs, Move signatures and the gas price learned from previous
+/// transactions. Objects missing from the cache are fetched from the node.
+pub struct ExecutorCache {
+    provider: CachingObjectProvider<jsonrpc::Client>,
+    objects: Mutex<ObjectCache<ResolvedObject>>,
+    gas_price: Mutex<Option<u64>>,
 }
 
-/// Command
-#[derive(Debug, Clone)]
-pub struct Command {
-    pub command_type: String,
-    pub data: Option<serde_json::Value>,
+impl ExecutorCache {
+    pub fn new(client: jsonrpc::Client) -> Self {
+        Self {
+            provider: CachingObjectProvider::new(client),
+            objects: Mutex::new(ObjectCache::new()),
+            gas_price: Mutex::new(None),
+        }
+    }
+
+    fn client(&self) -> &jsonrpc::Client {
+        self.provider.inner()
+    }
+
+    pub fn get_object(&self, object_id: &str) -> Option<ResolvedObject> {
+        self.objects
+            .lock()
+            .expect("object cache poisoned")
+            .get(&normalize_sui_address(object_id))
+            .cloned()
+    }
+
+    fn set_object(&self, object: ResolvedObject) {
+        self.objects
+            .lock()
+            .expect("object cache poisoned")
+            .set(object.object_id.clone(), object);
+    }
+
+    fn remove_object(&self, object_id: &str) {
+        self.objects
+            .lock()
+            .expect("object cache poisoned")
+            .remove(&normalize_sui_address(object_id));
+    }
+
+    /// Forgets the given objects so they are fetched again on next use.
+    pub fn invalidate(&self, object_ids: &[String]) {
+        for object_id in object_ids {
+            self.remove_object(object_id);
+        }
+    }
+
+    /// Forgets every object and the gas price. Move signatures are kept since
+    /// published packages never change.
+    pub fn clear(&self) {
+        self.objects.lock().expect("object cache poisoned").clear();
+        *self.gas_price.lock().expect("gas price cache poisoned") = None;
+    }
+
+    /// Records the new versions of every object the transaction touched and
+    /// drops the objects it deleted or wrapped.
+    pub fn apply_effects(&self, effects: &Value) -> Result<(), ResolveError> {
+        for key in ["created", "mutated", "unwrapped"] {
+            for entry in effects_entries(effects, key) {
+                self.set_object(ResolvedObject::from_effects_ref(entry)?);
+            }
+        }
+        if let Some(gas) = effects.get("gasObject") {
+            self.set_object(ResolvedObject::from_effects_ref(gas)?);
+        }
+        for key in ["deleted", "wrapped", "unwrappedThenDeleted"] {
+            for entry in effects_entries(effects, key) {
+                if let Some(object_id) = entry.get("objectId").and_then(Value::as_str) {
+                    self.remove_object(object_id);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn effects_entries<'a>(effects: &'a Value, key: &str) -> &'a [Value] {
+    effects
+        .get(key)
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+impl ObjectProvider for ExecutorCache {
+    fn get_objects(
+        &self,
+        object_ids: Vec<String>,
+    ) -> BoxFuture<'_, Result<Vec<ResolvedObject>, ResolveError>> {
+        Box::pin(async move {
+            let missing: Vec<String> = object_ids
+                .iter()
+                .filter(|id| self.get_object(id).is_none())
+                .cloned()
+                .collect();
+            if !missing.is_empty() {
+                for object in self.provider.get_objects(missing).await? {
+                    self.set_object(object);
+                }
+            }
+            object_ids
+                .iter()
+                .map(|id| {
+                    self.get_object(id)
+                        .ok_or_else(|| ResolveError::ObjectNotFound(id.clone()))
+                })
+                .collect()
+        })
+    }
+
+    fn get_move_functions(
+        &self,
+        targets: Vec<MoveCallTarget>,
+    ) -> BoxFuture<'_, Result<Vec<NormalizedMoveFunction>, ResolveError>> {
+        self.provider.get_move_functions(targets)
+    }
+}
+
+impl CoinProvider for ExecutorCache {
+    fn coins(
+        &self,
+        owner: String,
+        coin_type: String,
+        cursor: Option<String>,
+    ) -> BoxFuture<'_, Result<CoinPage, GasError>> {
+        self.client().coins(owner, coin_type, cursor)
+    }
 }
 
-/// SerialTransactionExecutor
+impl GasProvider for ExecutorCache {
+    fn reference_gas_price(&self) -> BoxFuture<'_, Result<u64, GasError>> {
+        Box::pin(async move {
+            if let Some(price) = *self.gas_price.lock().expect("gas price cache poisoned") {
+                return Ok(price);
+            }
+            let price = self.client().reference_gas_price().await?;
+            *self.gas_price.lock().expect("gas price cache poisoned") = Some(price);
+            Ok(price)
+        })
+    }
+
+    fn dry_run(&self, tx_bytes: Vec<u8>) -> BoxFuture<'_, Result<GasCost, GasError>> {
+        self.client().dry_run(tx_bytes)
+    }
+}
+
+/// Executes transactions from one sender strictly one after another.
+///
+/// After each transaction the executor keeps the gas coin and the versions of
+/// the objects in its effects, so the next transaction is built without
+/// re-fetching them. A failed transaction clears the cache, since the failure
+/// may have been caused by stale versions.
 pub struct SerialTransactionExecutor {
-    executor: Arc<Mutex<TransactionExecutor>>,
+    client: jsonrpc::Client,
+    signer: Arc<dyn Signer>,
+    gas_config: GasConfig,
+    cache: ExecutorCache,
+    gas_coin: Mutex<Option<ObjectRef>>,
+    queue: tokio::sync::Mutex<()>,
 }
 
 impl SerialTransactionExecutor {
-    pub fn new() -> Self {
+    pub fn new(client: jsonrpc::Client, signer: Arc<dyn Signer>) -> Self {
         Self {
-            executor: Arc::new(Mutex::new(TransactionExecutor::new())),
+            cache: ExecutorCache::new(client.clone()),
+            client,
+            signer,
+            gas_config: GasConfig::default(),
+            gas_coin: Mutex::new(None),
+            queue: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    pub fn with_gas_config(mut self, gas_config: GasConfig) -> Self {
+        self.gas_config = gas_config;
+        self
+    }
+
+    pub fn address(&self) -> String {
+        self.signer.address()
+    }
+
+    pub fn cache(&self) -> &ExecutorCache {
+        &self.cache
+    }
+
+    /// The coin paying for the next transaction, once one has been used.
+    pub fn gas_coin(&self) -> Option<ObjectRef> {
+        self.gas_coin.lock().expect("gas coin poisoned").clone()
+    }
+
+    /// Forgets cached objects and the gas coin, e.g. after the address was
+    /// used outside this executor.
+    pub fn reset_cache(&self) {
+        self.cache.clear();
+        *self.gas_coin.lock().expect("gas coin poisoned") = None;
+    }
+
+    /// Builds `tx` as this executor's sender, signs and submits it, and waits
+    /// for the effects. Transactions are queued if another one is in flight.
+    pub async fn execute(&self, tx: Transaction) -> Result<TransactionResult, ExecutorError> {
+        let _turn = self.queue.lock().await;
+        let result = self.execute_now(tx).await;
+        match &result {
+            Ok(executed) if executed.success => {}
+            _ => self.reset_cache(),
         }
+        result
     }
 
-    pub async fn execute(&self, tx: Transaction) -> TransactionResult {
-        let executor = self.executor.lock().unwrap();
-        executor.execute(tx)
+    async fn execute_now(&self, mut tx: Transaction) -> Result<TransactionResult, ExecutorError> {
+        tx.set_sender_if_not_set(self.signer.address());
+        if tx.data.gas_data.payment.is_none()
+            && let Some(coin) = self.gas_coin()
+        {
+            tx.set_gas_payment(vec![coin]);
+        }
+        tx.prepare().await?;
+        tx.resolve(&self.cache).await?;
+        tx.resolve_gas(&self.cache, &self.gas_config).await?;
+
+        let result = submit(&self.client, self.signer.as_ref(), &self.cache, &tx).await?;
+        if result.success {
+            *self.gas_coin.lock().expect("gas coin poisoned") = Some(gas_object(&result)?);
+        }
+        Ok(result)
     }
 }
 
-/// ParallelTransactionExecutor
-pub struct ParallelTransactionExecutor {
-    executor: Arc<Mutex<TransactionExecutor>>,
-    workers: usize,
+/// Signs and submits a built transaction, applying its effects to `cache`
+/// when it succeeds.
+async fn submit(
+    client: &jsonrpc::Client,
+    signer: &dyn Signer,
+    cache: &ExecutorCache,
+    tx: &Transaction,
+) -> Result<TransactionResult, ExecutorError> {
+    let signed = tx.sign(signer)?;
+    let response = signed
+        .execute(
+            client,
+            Some(json!({"showEffects": true})),
+            Some("WaitForLocalExecution"),
+        )
+        .await?;
+    let effects = response
+        .get("effects")
+        .ok_or_else(|| ExecutorError::InvalidResponse("missing effects".to_string()))?;
+    let digest = match response.get("digest").and_then(Value::as_str) {
+        Some(digest) => digest.to_string(),
+        None => signed.digest()?,
+    };
+    let success = effects.pointer("/status/status").and_then(Value::as_str) == Some("success");
+    let error = effects
+        .pointer("/status/error")
+        .and_then(Value::as_str)
+        .map(str::to_string);
+    if success {
+        cache.apply_effects(effects)?;
+    }
+    Ok(TransactionResult {
+        digest,
+        success,
+        error,
+        response,
+    })
 }
 
-impl ParallelTransactionExecutor {
-    pub fn new(workers: usize) -> Self {
+/// The gas coin's ref after the transaction, which changes even when it fails.
+fn gas_object(result: &TransactionResult) -> Result<ObjectRef, ExecutorError> {
+    let gas = result
+        .effects()
+        .and_then(|effects| effects.get("gasObject"))
+        .ok_or_else(|| ExecutorError::InvalidResponse("missing gasObject".to_string()))?;
+    Ok(ResolvedObject::from_effects_ref(gas)?.object_ref())
+}
+
+/// Net MIST charged to the gas coin.
+fn gas_used(result: &TransactionResult) -> u64 {
+    let cost = |key: &str| {
+        result
+            .effects()
+            .and_then(|effects| effects.get("gasUsed"))
+            .and_then(|gas| gas.get(key))
+            .and_then(|value| match value {
+                Value::String(s) => s.parse().ok(),
+                other => other.as_u64(),
+            })
+            .unwrap_or(0u64)
+    };
+    (cost("computationCost") + cost("storageCost")).saturating_sub(cost("storageRebate"))
+}
+
+fn uses_gas_coin(tx: &Transaction) -> bool {
+    tx.data.commands.iter().any(|command| {
+        command
+            .kind
+            .arguments()
+            .into_iter()
+            .any(|argument| *argument == Argument::GasCoin)
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct ParallelExecutorConfig {
+    /// Upper bound on transactions in flight.
+    pub workers: usize,
+    /// Gas coins split off per refill of the pool.
+    pub coin_batch_size: u16,
+    /// Balance of every newly split gas coin, in MIST.
+    pub initial_coin_balance: u64,
+    /// Coins below this balance leave the pool and are merged back into the
+    /// sender's other coins by the next refill.
+    pub minimum_coin_balance: u64,
+    /// Budget for transactions that do not set one, so they skip the dry run.
+    pub default_gas_budget: u64,
+}
+
+impl Default for ParallelExecutorConfig {
+    fn default() -> Self {
         Self {
-            executor: Arc::new(Mutex::new(TransactionExecutor::new())),
-            workers,
+            workers: 10,
+            coin_batch_size: 20,
+            initial_coin_balance: 200_000_000,
+            minimum_coin_balance: 50_000_000,
+            default_gas_budget: 50_000_000,
         }
     }
 }
 
-/// TransactionExecutor
-pub struct TransactionExecutor {
-    queue: Vec<Transaction>,
+#[derive(Debug, Clone)]
+struct PoolCoin {
+    object_ref: ObjectRef,
+    balance: u64,
+}
+
+#[derive(Default)]
+struct GasPool {
+    available: Vec<PoolCoin>,
+    /// Every coin owned by the pool, whether available or paying for a
+    /// transaction in flight.
+    owned: HashSet<String>,
+}
+
+#[derive(Default)]
+struct Scheduler {
+    next_ticket: u64,
+    /// Transactions admitted but not finished, with the owned objects they use.
+    pending: Vec<(u64, Vec<String>)>,
 }
 
-impl TransactionExecutor {
-    pub fn new() -> Self {
-        Self { queue: Vec::new() }
+/// Releases a transaction's objects for the ones queued behind it.
+struct Ticket<'a> {
+    executor: &'a ParallelTransactionExecutor,
+    ticket: u64,
+    objects: Vec<String>,
+}
+
+impl Drop for Ticket<'_> {
+    fn drop(&mut self) {
+        self.executor
+            .scheduler
+            .lock()
+            .expect("scheduler poisoned")
+            .pending
+            .retain(|(ticket, _)| *ticket != self.ticket);
+        self.executor.scheduled.notify_waiters();
     }
+}
+
+/// The sender's coins minus the ones the pool or an admitted transaction holds.
+struct UnusedCoins<'a> {
+    cache: &'a ExecutorCache,
+    excluded: HashSet<String>,
+}
 
-    pub fn execute(&self, tx: Transaction) -> TransactionResult {
-        TransactionResult::new_success()
+impl CoinProvider for UnusedCoins<'_> {
+    fn coins(
+        &self,
+        owner: String,
+        coin_type: String,
+        cursor: Option<String>,
+    ) -> BoxFuture<'_, Result<CoinPage, GasError>> {
+        Box::pin(async move {
+            let (coins, next) = self.cache.coins(owner, coin_type, cursor).await?;
+            let unused = coins
+                .into_iter()
+                .filter(|coin| {
+                    !self
+                        .excluded
+                        .contains(&normalize_sui_address(&coin.object_ref.object_id))
+                })
+                .collect();
+            Ok((unused, next))
+        })
     }
 }
 
-/// ObjectCache
-#[derive(Clone)]
-pub struct ObjectCache {
-    cache: Arc<Mutex<HashMap<String, Object>>>,
+/// Executes transactions from one sender concurrently.
+///
+/// Each transaction is paid for by its own coin from a pool the executor
+/// splits off the sender's SUI. Transactions using the same owned object run
+/// in submission order; all others run at once, up to `workers` at a time.
+pub struct ParallelTransactionExecutor {
+    client: jsonrpc::Client,
+    signer: Arc<dyn Signer>,
+    config: ParallelExecutorConfig,
+    cache: ExecutorCache,
+    pool: Mutex<GasPool>,
+    refill: tokio::sync::Mutex<()>,
+    scheduler: Mutex<Scheduler>,
+    scheduled: tokio::sync::Notify,
+    workers: tokio::sync::Semaphore,
 }
 
-impl ObjectCache {
-    pub fn new() -> Self {
+impl ParallelTransactionExecutor {
+    pub fn new(
+        client: jsonrpc::Client,
+        signer: Arc<dyn Signer>,
+        config: ParallelExecutorConfig,
+    ) -> Self {
         Self {
-            cache: Arc::new(Mutex::new(HashMap::new())),
+            cache: ExecutorCache::new(client.clone()),
+            client,
+            signer,
+            workers: tokio::sync::Semaphore::new(config.workers.max(1)),
+            config,
+            pool: Mutex::new(GasPool::default()),
+            refill: tokio::sync::Mutex::new(()),
+            scheduler: Mutex::new(Scheduler::default()),
+            scheduled: tokio::sync::Notify::new(),
+        }
+    }
+
+    pub fn address(&self) -> String {
+        self.signer.address()
+    }
+
+    pub fn cache(&self) -> &ExecutorCache {
+        &self.cache
+    }
+
+    /// Number of gas coins waiting for a transaction.
+    pub fn available_coins(&self) -> usize {
+        self.pool.lock().expect("gas pool poisoned").available.len()
+    }
+
+    /// Builds, signs and submits `tx` once no earlier transaction using one of
+    /// its owned objects is still running. `CoinWithBalance` intents are
+    /// resolved first, from coins no other transaction holds.
+    pub async fn execute(&self, mut tx: Transaction) -> Result<TransactionResult, ExecutorError> {
+        tx.set_sender_if_not_set(self.signer.address());
+        let ticket = self.admit(&mut tx).await?;
+        self.wait_turn(&ticket).await;
+        let objects = ticket.objects.clone();
+        let _worker = self
+            .workers
+            .acquire()
+            .await
+            .expect("worker semaphore is never closed");
+
+        let coin = self.acquire_coin().await?;
+        let result = self.execute_with_coin(&mut tx, &coin).await;
+        match &result {
+            Ok(executed) => {
+                if uses_gas_coin(&tx) {
+                    // Splitting or transferring the gas coin leaves its balance
+                    // and owner unknown; the next refill merges it back if the
+                    // sender still owns it.
+                    self.forget_coin(&coin);
+                } else {
+                    self.release_coin(coin, executed);
+                }
+                if !executed.success {
+                    self.cache.invalidate(&objects);
+                }
+            }
+            Err(_) => {
+                // The coin may or may not have been used; the next refill
+                // sweeps it back in with its current version.
+                self.forget_coin(&coin);
+                self.cache.invalidate(&objects);
+            }
+        }
+        result
+    }
+
+    async fn execute_with_coin(
+        &self,
+        tx: &mut Transaction,
+        coin: &PoolCoin,
+    ) -> Result<TransactionResult, ExecutorError> {
+        tx.set_gas_payment(vec![coin.object_ref.clone()]);
+        if tx.data.gas_data.budget.is_none() {
+            tx.set_gas_budget(self.config.default_gas_budget);
+        }
+        tx.prepare().await?;
+        tx.resolve(&self.cache).await?;
+        tx.resolve_gas(&self.cache, &GasConfig::default()).await?;
+        submit(&self.client, self.signer.as_ref(), &self.cache, tx).await
+    }
+
+    /// Owned objects the transaction takes, which no other transaction may use
+    /// at the same time. Objects known to be shared or immutable are skipped.
+    fn owned_objects(&self, tx: &Transaction) -> Vec<String> {
+        let mut objects: Vec<String> = tx
+            .data
+            .inputs
+            .iter()
+            .filter(|input| !matches!(input, CallArg::Object(ObjectKind::SharedObject(_))))
+            .filter_map(CallArg::object_id)
+            .map(normalize_sui_address)
+            .filter(|id| match self.cache.get_object(id) {
+                Some(object) => {
+                    object.initial_shared_version().is_none()
+                        && object.owner != ObjectOwner::Immutable
+                }
+                None => true,
+            })
+            .collect();
+        objects.sort();
+        objects.dedup();
+        objects
+    }
+
+    /// Resolves the `CoinWithBalance` intents of `tx` and registers the owned
+    /// objects it then takes, coins selected for the intents included.
+    async fn admit(&self, tx: &mut Transaction) -> Result<Ticket<'_>, ExecutorError> {
+        // Transactions are admitted one at a time so intents skip the coins
+        // of earlier ones, and never while a refill runs, since it merges every
+        // coin not admitted when it starts.
+        let _refill = self.refill.lock().await;
+        let coins = UnusedCoins {
+            cache: &self.cache,
+            excluded: self.reserved_objects(),
+        };
+        resolve_coin_with_balance(tx, &coins).await?;
+        let objects = self.owned_objects(tx);
+
+        let mut scheduler = self.scheduler.lock().expect("scheduler poisoned");
+        let ticket = scheduler.next_ticket;
+        scheduler.next_ticket += 1;
+        scheduler.pending.push((ticket, objects.clone()));
+        Ok(Ticket {
+            executor: self,
+            ticket,
+            objects,
+        })
+    }
+
+    /// Waits until no earlier transaction using one of the ticket's objects
+    /// is still running.
+    async fn wait_turn(&self, ticket: &Ticket<'_>) {
+        loop {
+            let notified = self.scheduled.notified();
+            let blocked = self
+                .scheduler
+                .lock()
+                .expect("scheduler poisoned")
+                .pending
+                .iter()
+                .any(|(other, used)| {
+                    *other < ticket.ticket && used.iter().any(|id| ticket.objects.contains(id))
+                });
+            if !blocked {
+                return;
+            }
+            notified.await;
         }
     }
 
-    pub fn get(&self, id: &str) -> Option<Object> {
-        let cache = self.cache.lock().unwrap();
-        cache.get(id).cloned()
+    /// Coins held by the pool and objects taken by admitted transactions.
+    fn reserved_objects(&self) -> HashSet<String> {
+        let pool = self.pool.lock().expect("gas pool poisoned");
+        let scheduler = self.scheduler.lock().expect("scheduler poisoned");
+        pool.owned
+            .iter()
+            .cloned()
+            .chain(scheduler.pending.iter().flat_map(|(_, used)| used.clone()))
+            .collect()
     }
 
-    pub fn set(&self, id: String, obj: Object) {
-        let mut cache = self.cache.lock().unwrap();
-        cache.insert(id, obj);
+    async fn acquire_coin(&self) -> Result<PoolCoin, ExecutorError> {
+        loop {
+            if let Some(coin) = self.pool.lock().expect("gas pool poisoned").available.pop() {
+                return Ok(coin);
+            }
+            let _refill = self.refill.lock().await;
+            if self.available_coins() == 0 {
+                self.refill_pool().await?;
+            }
+        }
     }
 
-    pub fn delete(&self, id: &str) {
-        let mut cache = self.cache.lock().unwrap();
-        cache.remove(id);
+    fn release_coin(&self, coin: PoolCoin, result: &TransactionResult) {
+        let mut pool = self.pool.lock().expect("gas pool poisoned");
+        let balance = coin.balance.saturating_sub(gas_used(result));
+        match gas_object(result) {
+            Ok(object_ref) if balance >= self.config.minimum_coin_balance => {
+                pool.available.push(PoolCoin {
+                    object_ref,
+                    balance,
+                });
+            }
+            _ => {
+                pool.owned.remove(&coin.object_ref.object_id);
+            }
+        }
+    }
+
+    fn forget_coin(&self, coin: &PoolCoin) {
+        self.pool
+            .lock()
+            .expect("gas pool poisoned")
+            .owned
+            .remove(&coin.object_ref.object_id);
+    }
+
+    /// Merges every SUI coin outside the pool into one and splits a new batch
+    /// of gas coins off it.
+    async fn refill_pool(&self) -> Result<(), ExecutorError> {
+        let owner = normalize_sui_address(&self.signer.address());
+        let excluded = self.reserved_objects();
+
+        let max_gas_objects = GasConfig::default().max_gas_objects;
+        let mut payment = Vec::new();
+        let mut available = 0u64;
+        let mut cursor = None;
+        loop {
+            let (coins, next) = self
+                .cache
+                .coins(owner.clone(), SUI_COIN_TYPE.to_string(), cursor)
+                .await?;
+            for coin in coins {
+                let object_id = normalize_sui_address(&coin.object_ref.object_id);
+                if !excluded.contains(&object_id) && payment.len() < max_gas_objects {
+                    available = available.saturating_add(coin.balance);
+                    payment.push(coin.object_ref);
+                }
+            }
+            match next {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+        let batch = self.config.coin_batch_size.max(1);
+        let required = self
+            .config
+            .initial_coin_balance
+            .saturating_mul(u64::from(batch));
+        if available < required {
+            return Err(GasError::InsufficientGas {
+                owner,
+                required,
+                available,
+            }
+            .into());
+        }
+
+        let mut tx = Transaction::new();
+        tx.set_sender(owner.clone());
+        tx.set_gas_payment(payment);
+        let amount = tx.pure_bytes(&self.config.initial_coin_balance.to_le_bytes());
+        let split = tx.split_coins(Transaction::gas(), vec![amount; usize::from(batch)]);
+        let coins = (0..batch).filter_map(|index| split.nested(index)).collect();
+        let recipient = tx.pure_typed(&PureValue::Address(owner.clone()))?;
+        tx.transfer_objects(coins, recipient);
+        tx.resolve_gas(&self.cache, &GasConfig::default()).await?;
+
+        let result = submit(&self.client, self.signer.as_ref(), &self.cache, &tx).await?;
+        if !result.success {
+            return Err(ExecutorError::RefillFailed {
+                digest: result.digest,
+                error: result.error.unwrap_or_default(),
+            });
+        }
+        let created = result
+            .effects()
+            .map(|effects| effects_entries(effects, "created"))
+            .unwrap_or_default();
+        let mut pool = self.pool.lock().expect("gas pool poisoned");
+        for entry in created {
+            let object = ResolvedObject::from_effects_ref(entry)?;
+            if matches!(&object.owner, ObjectOwner::AddressOwner(address) if normalize_sui_address(address) == owner)
+            {
+                pool.owned.insert(object.object_id.clone());
+                pool.available.push(PoolCoin {
+                    object_ref: object.object_ref(),
+                    balance: self.config.initial_coin_balance,
+                });
+            }
+        }
+        Ok(())
     }
 }
 
-/// Object
-#[derive(Debug, Clone)]
-pub struct Object {
-    pub id: String,
-    pub object_type: String,
-    pub data: Vec<u8>,
-    pub digest: String,
-}
\ No newline at end of file
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::keypairs::ed25519::Keypair;
+    use crate::transactions::SharedObjectRef;
+
+    fn executor() -> ParallelTransactionExecutor {
+        ParallelTransactionExecutor::new(
+            jsonrpc::Client::new("http://127.0.0.1:1", "localnet"),
+            Arc::new(Keypair::generate()),
+            ParallelExecutorConfig::default(),
+        )
+    }
+
+    #[test]
+    fn test_owned_objects_skip_shared_inputs() {
+        let executor = executor();
+        let mut tx = Transaction::new();
+        tx.object("0x10");
+        tx.shared_object_ref(SharedObjectRef {
+            object_id: "0x6".to_string(),
+            initial_shared_version: 1,
+            mutable: false,
+        });
+        tx.object("0x10");
+        tx.object("0x11");
+        executor.cache.set_object(ResolvedObject {
+            object_id: normalize_sui_address("0x11"),
+            version: 1,
+            digest: "11111111111111111111111111111111".to_string(),
+            owner: ObjectOwner::Immutable,
+        });
+        assert_eq!(
+            executor.owned_objects(&tx),
+            vec![normalize_sui_address("0x10")]
+        );
+    }
+
+    async fn schedule<'a>(
+        executor: &'a ParallelTransactionExecutor,
+        objects: &[&str],
+    ) -> Ticket<'a> {
+        let mut tx = Transaction::new();
+        for object_id in objects {
+            tx.object(*object_id);
+        }
+        let ticket = executor.admit(&mut tx).await.expect("admit");
+        executor.wait_turn(&ticket).await;
+        ticket
+    }
+
+    #[tokio::test]
+    async fn test_conflicting_transactions_wait_in_order() {
+        let executor = executor();
+
+        let first = schedule(&executor, &["0xa"]).await;
+        let unrelated =
+            tokio::time::timeout(Duration::from_millis(50), schedule(&executor, &["0xb"])).await;
+        assert!(unrelated.is_ok());
+        drop(unrelated);
+
+        let blocked = schedule(&executor, &["0xa", "0xb"]);
+        tokio::pin!(blocked);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), &mut blocked)
+                .await
+                .is_err()
+        );
+        drop(first);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), &mut blocked)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_schedule_waits_for_refill() {
+        let executor = executor();
+        let refill = executor.refill.lock().await;
+
+        let waiting = schedule(&executor, &["0xa"]);
+        tokio::pin!(waiting);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), &mut waiting)
+                .await
+                .is_err()
+        );
+        assert!(executor.scheduler.lock().unwrap().pending.is_empty());
+        drop(refill);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), &mut waiting)
+                .await
+                .is_ok()
+        );
+    }
+}
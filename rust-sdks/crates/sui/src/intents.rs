@@ -1,9 +1,40 @@
 // Intent system for Rust SDK
-pub mod intents;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde_json::{Value, json};
+
+use crate::transactions::bcs_types::TypeTag;
+use crate::transactions::gas::{CoinProvider, GasError};
+use crate::transactions::resolve::BoxFuture;
+use crate::transactions::{
+    Argument, CallArg, Command, CommandKind, ObjectRef, Transaction, TransactionCommands,
+    TransactionError, normalize_sui_address,
+};
 
 pub const COIN_WITH_BALANCE: &str = "CoinWithBalance";
 pub const SUI_TYPE: &str = "0x2::sui::SUI";
 
+/// Intent data type marking a SUI balance split from the gas coin.
+const GAS_COIN_TYPE: &str = "gas";
+
+#[derive(Debug, thiserror::Error)]
+pub enum IntentError {
+    #[error(transparent)]
+    Coins(#[from] GasError),
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
+    #[error("invalid intent: {0}")]
+    InvalidIntent(String),
+    #[error("insufficient {coin_type} balance: {owner} needs {required}, found {available}")]
+    InsufficientBalance {
+        owner: String,
+        coin_type: String,
+        required: u64,
+        available: u64,
+    },
+}
+
 /// CoinWithBalance intent
 #[derive(Debug, Clone)]
 pub struct CoinWithBalance {
@@ -13,6 +44,23 @@ pub struct CoinWithBalance {
     pub use_gas_coin: bool,
 }
 
+impl CoinWithBalance {
+    /// The `$Intent` command standing in for the coin until it is resolved.
+    pub fn to_command(&self) -> Command {
+        let coin_type = normalize_coin_type(&self.coin_type);
+        let coin_type = if self.use_gas_coin && coin_type == normalize_coin_type(SUI_TYPE) {
+            GAS_COIN_TYPE.to_string()
+        } else {
+            coin_type
+        };
+        TransactionCommands::intent(
+            self.name.clone(),
+            json!({}),
+            json!({"type": coin_type, "balance": self.balance.to_string()}),
+        )
+    }
+}
+
 /// CoinWithBalanceBuilder for building CoinWithBalance intent
 pub struct CoinWithBalanceBuilder {
     balance: u64,
@@ -34,8 +82,8 @@ impl CoinWithBalanceBuilder {
         self
     }
 
-    pub fn use_gas_coin(mut self, use: bool) -> Self {
-        self.use_gas_coin = use;
+    pub fn use_gas_coin(mut self, use_gas_coin: bool) -> Self {
+        self.use_gas_coin = use_gas_coin;
         self
     }
 
@@ -49,38 +97,423 @@ impl CoinWithBalanceBuilder {
     }
 }
 
+impl From<CoinWithBalanceBuilder> for CoinWithBalance {
+    fn from(builder: CoinWithBalanceBuilder) -> Self {
+        builder.build()
+    }
+}
+
 /// Builder function for CoinWithBalance
 pub fn coin_with_balance(balance: u64) -> CoinWithBalanceBuilder {
     CoinWithBalanceBuilder::new(balance)
 }
 
+impl Transaction {
+    /// A coin of exactly the requested balance, produced from the sender's
+    /// coins (or the gas coin for SUI) once the intent is resolved.
+    pub fn coin_with_balance(&mut self, intent: impl Into<CoinWithBalance>) -> Argument {
+        self.add_command(intent.into().to_command())
+    }
+}
+
+fn normalize_coin_type(coin_type: &str) -> String {
+    coin_type
+        .parse::<TypeTag>()
+        .map(|tag| tag.to_string())
+        .unwrap_or_else(|_| coin_type.to_string())
+}
+
 /// IntentResolver trait
-pub trait IntentResolver {
-    fn resolve(&self, intent: &CoinWithBalance) -> Result<(), Box<dyn std::error::Error>>;
+///
+/// Expands every `$Intent` command named [`IntentResolver::name`] into plain
+/// commands.
+pub trait IntentResolver: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn resolve<'a>(&'a self, tx: &'a mut Transaction) -> BoxFuture<'a, Result<(), IntentError>>;
 }
 
 /// CoinWithBalanceResolver
 pub struct CoinWithBalanceResolver {
-    sender: String,
+    provider: Arc<dyn CoinProvider>,
 }
 
 impl CoinWithBalanceResolver {
-    pub fn new(sender: String) -> Self {
-        Self { sender }
+    pub fn new(provider: Arc<dyn CoinProvider>) -> Self {
+        Self { provider }
     }
 }
 
 impl IntentResolver for CoinWithBalanceResolver {
-    fn resolve(&self, intent: &CoinWithBalance) -> Result<(), Box<dyn std::error::Error>> {
-        // Resolution logic here
-        Ok(())
+    fn name(&self) -> &str {
+        COIN_WITH_BALANCE
+    }
+
+    fn resolve<'a>(&'a self, tx: &'a mut Transaction) -> BoxFuture<'a, Result<(), IntentError>> {
+        Box::pin(resolve_coin_with_balance(tx, self.provider.as_ref()))
+    }
+}
+
+fn coin_with_balance_data(command: &Command) -> Result<Option<(String, u64)>, IntentError> {
+    let CommandKind::Intent(intent) = &command.kind else {
+        return Ok(None);
+    };
+    if intent.name != COIN_WITH_BALANCE {
+        return Ok(None);
+    }
+    let coin_type = intent
+        .data
+        .get("type")
+        .and_then(Value::as_str)
+        .ok_or_else(|| IntentError::InvalidIntent(format!("{COIN_WITH_BALANCE} without type")))?;
+    let balance = match intent.data.get("balance") {
+        Some(Value::String(s)) => s.parse().ok(),
+        Some(Value::Number(n)) => n.as_u64(),
+        _ => None,
+    }
+    .ok_or_else(|| IntentError::InvalidIntent(format!("{COIN_WITH_BALANCE} without balance")))?;
+    Ok(Some((coin_type.to_string(), balance)))
+}
+
+/// Rewrites every `CoinWithBalance` intent into `MergeCoins` / `SplitCoins`
+/// commands over the sender's coins and remaps the references to its result.
+pub(crate) async fn resolve_coin_with_balance(
+    tx: &mut Transaction,
+    provider: &dyn CoinProvider,
+) -> Result<(), IntentError> {
+    let mut required: Vec<(String, u64)> = Vec::new();
+    let mut has_intents = false;
+    for command in &tx.data.commands {
+        let Some((coin_type, balance)) = coin_with_balance_data(command)? else {
+            continue;
+        };
+        has_intents = true;
+        if coin_type == GAS_COIN_TYPE || balance == 0 {
+            continue;
+        }
+        match required.iter_mut().find(|(t, _)| *t == coin_type) {
+            Some((_, total)) => *total = total.saturating_add(balance),
+            None => required.push((coin_type, balance)),
+        }
+    }
+    if !has_intents {
+        return Ok(());
+    }
+
+    let mut coins: HashMap<String, Vec<ObjectRef>> = HashMap::new();
+    if !required.is_empty() {
+        let owner = tx
+            .data
+            .sender
+            .as_deref()
+            .map(normalize_sui_address)
+            .ok_or(TransactionError::MissingSender)?;
+        // Coins the transaction already takes, including the ones paying gas.
+        let payment = tx.data.gas_data.payment.iter().flatten();
+        let used: Vec<String> = tx
+            .data
+            .inputs
+            .iter()
+            .filter_map(CallArg::object_id)
+            .chain(payment.map(|coin| coin.object_id.as_str()))
+            .map(normalize_sui_address)
+            .collect();
+        for (coin_type, amount) in required {
+            let selected = select_coins(provider, &owner, &coin_type, amount, &used).await?;
+            coins.insert(coin_type, selected);
+        }
+    }
+
+    let commands = std::mem::take(&mut tx.data.commands);
+    let mut results: HashMap<u16, Argument> = HashMap::new();
+    let mut merged: HashMap<String, Argument> = HashMap::new();
+    for (index, mut command) in commands.into_iter().enumerate() {
+        let result = match coin_with_balance_data(&command)? {
+            Some((coin_type, 0)) => {
+                let coin_type = if coin_type == GAS_COIN_TYPE {
+                    normalize_coin_type(SUI_TYPE)
+                } else {
+                    coin_type
+                };
+                tx.move_call("0x2::coin::zero", vec![], vec![coin_type])?
+            }
+            Some((coin_type, balance)) => {
+                let source = if coin_type == GAS_COIN_TYPE {
+                    Argument::GasCoin
+                } else if let Some(source) = merged.get(&coin_type) {
+                    *source
+                } else {
+                    let refs = coins.remove(&coin_type).unwrap_or_default();
+                    let mut args = refs.into_iter().map(|coin| tx.object_ref(coin));
+                    let first = args.next().ok_or_else(|| {
+                        IntentError::InvalidIntent(format!("no coins selected for {coin_type}"))
+                    })?;
+                    let rest: Vec<Argument> = args.collect();
+                    if !rest.is_empty() {
+                        tx.merge_coins(first, rest);
+                    }
+                    merged.insert(coin_type, first);
+                    first
+                };
+                let amount = tx.pure_bytes(&balance.to_le_bytes());
+                let split = tx.data.commands.len() as u16;
+                tx.split_coins(source, vec![amount]);
+                Argument::NestedResult(split, 0)
+            }
+            None => {
+                for arg in command.kind.arguments_mut() {
+                    *arg = remap_result(*arg, &results);
+                }
+                tx.add_command(command)
+            }
+        };
+        results.insert(index as u16, result);
+    }
+    Ok(())
+}
+
+/// Points a reference to an original command at the command(s) replacing it.
+fn remap_result(arg: Argument, results: &HashMap<u16, Argument>) -> Argument {
+    match arg {
+        Argument::Result(index) => results.get(&index).copied().unwrap_or(arg),
+        Argument::NestedResult(index, nested) => match results.get(&index) {
+            Some(Argument::Result(new_index)) => Argument::NestedResult(*new_index, nested),
+            Some(replacement) if nested == 0 => *replacement,
+            _ => arg,
+        },
+        other => other,
     }
 }
 
+async fn select_coins(
+    provider: &dyn CoinProvider,
+    owner: &str,
+    coin_type: &str,
+    amount: u64,
+    used: &[String],
+) -> Result<Vec<ObjectRef>, IntentError> {
+    let mut selected = Vec::new();
+    let mut total: u64 = 0;
+    let mut cursor = None;
+    while total < amount {
+        let (coins, next_cursor) = provider
+            .coins(owner.to_string(), coin_type.to_string(), cursor)
+            .await?;
+        for coin in coins {
+            if used.contains(&normalize_sui_address(&coin.object_ref.object_id)) {
+                continue;
+            }
+            total = total.saturating_add(coin.balance);
+            selected.push(coin.object_ref);
+            if total >= amount {
+                break;
+            }
+        }
+        match next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+
+    if total < amount {
+        return Err(IntentError::InsufficientBalance {
+            owner: owner.to_string(),
+            coin_type: coin_type.to_string(),
+            required: amount,
+            available: total,
+        });
+    }
+    Ok(selected)
+}
+
 /// IntentScope enum
 #[repr(u8)]
 pub enum IntentScope {
     TransactionData = 0,
     PersonalMessage = 1,
     Transaction = 3,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transactions::gas::{CoinPage, OwnedCoin};
+    use crate::transactions::ObjectKind;
+
+    const USDC: &str =
+        "0xa1ec7fc00a6f40db9693ad1415d0c193ad3906494428cf252621037bd7117e29::usdc::USDC";
+
+    struct MockCoins {
+        coin_type: String,
+        coins: Vec<OwnedCoin>,
+    }
+
+    impl CoinProvider for MockCoins {
+        fn coins(
+            &self,
+            _owner: String,
+            coin_type: String,
+            _cursor: Option<String>,
+        ) -> BoxFuture<'_, Result<CoinPage, GasError>> {
+            Box::pin(async move {
+                assert_eq!(coin_type, normalize_coin_type(&self.coin_type));
+                Ok((self.coins.clone(), None))
+            })
+        }
+    }
+
+    fn coin(id: &str, balance: u64) -> OwnedCoin {
+        OwnedCoin {
+            object_ref: ObjectRef {
+                object_id: normalize_sui_address(id),
+                digest: "11111111111111111111111111111111".to_string(),
+                version: 1,
+            },
+            balance,
+        }
+    }
+
+    fn resolver(coins: Vec<OwnedCoin>) -> CoinWithBalanceResolver {
+        resolver_for(USDC, coins)
+    }
+
+    fn resolver_for(coin_type: &str, coins: Vec<OwnedCoin>) -> CoinWithBalanceResolver {
+        CoinWithBalanceResolver::new(Arc::new(MockCoins {
+            coin_type: coin_type.to_string(),
+            coins,
+        }))
+    }
+
+    #[test]
+    fn test_coin_with_balance_builder() {
+        let intent = coin_with_balance(100)
+            .coin_type("0x2::sui::SUI".to_string())
+            .use_gas_coin(false)
+            .build();
+        assert_eq!(intent.name, COIN_WITH_BALANCE);
+        assert_eq!(intent.balance, 100);
+        assert!(!intent.use_gas_coin);
+
+        let CommandKind::Intent(command) = coin_with_balance(5).build().to_command().kind else {
+            panic!("expected intent");
+        };
+        assert_eq!(command.data, json!({"type": "gas", "balance": "5"}));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_coin_with_balance() {
+        let mut tx = Transaction::new();
+        tx.set_sender("0x1");
+        let usdc = tx.coin_with_balance(coin_with_balance(150).coin_type(USDC.to_string()));
+        let sui = tx.coin_with_balance(coin_with_balance(7));
+        let more = tx.coin_with_balance(coin_with_balance(50).coin_type(USDC.to_string()));
+        let recipient = tx.pure_bytes(&[0; 32]);
+        tx.transfer_objects(vec![usdc, sui, more], recipient);
+
+        resolver(vec![coin("0xa", 100), coin("0xb", 100), coin("0xc", 100)])
+            .resolve(&mut tx)
+            .await
+            .unwrap();
+
+        let kinds: Vec<&CommandKind> = tx.data.commands.iter().map(|c| &c.kind).collect();
+        assert_eq!(kinds.len(), 5);
+        let CommandKind::MergeCoins(merge) = kinds[0] else {
+            panic!("expected MergeCoins");
+        };
+        assert_eq!(merge.destination, Argument::Input(1));
+        assert_eq!(merge.sources, vec![Argument::Input(2)]);
+        let CommandKind::SplitCoins(split) = kinds[1] else {
+            panic!("expected SplitCoins");
+        };
+        assert_eq!(split.coin, Argument::Input(1));
+        let Argument::Input(amount) = split.amounts[0] else {
+            panic!("expected pure amount");
+        };
+        assert_eq!(
+            tx.data.inputs[amount as usize],
+            CallArg::pure(150u64.to_le_bytes().to_vec())
+        );
+        let CommandKind::SplitCoins(gas) = kinds[2] else {
+            panic!("expected SplitCoins");
+        };
+        assert_eq!(gas.coin, Argument::GasCoin);
+        let CommandKind::SplitCoins(second) = kinds[3] else {
+            panic!("expected SplitCoins");
+        };
+        assert_eq!(second.coin, Argument::Input(1));
+        let CommandKind::TransferObjects(transfer) = kinds[4] else {
+            panic!("expected TransferObjects");
+        };
+        assert_eq!(
+            transfer.objects,
+            vec![
+                Argument::NestedResult(1, 0),
+                Argument::NestedResult(2, 0),
+                Argument::NestedResult(3, 0)
+            ]
+        );
+        assert_eq!(transfer.address, Argument::Input(0));
+        assert!(matches!(
+            &tx.data.inputs[1],
+            CallArg::Object(ObjectKind::ImmOrOwnedObject(obj)) if obj.object_id == normalize_sui_address("0xa")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_skips_gas_payment() {
+        let mut tx = Transaction::new();
+        tx.set_sender("0x1");
+        tx.set_gas_payment(vec![coin("0xa", 100).object_ref]);
+        tx.coin_with_balance(coin_with_balance(50).use_gas_coin(false));
+
+        resolver_for(SUI_TYPE, vec![coin("0xa", 100), coin("0xb", 100)])
+            .resolve(&mut tx)
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            &tx.data.inputs[0],
+            CallArg::Object(ObjectKind::ImmOrOwnedObject(obj)) if obj.object_id == normalize_sui_address("0xb")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_zero_balance_and_results() {
+        let mut tx = Transaction::new();
+        let zero = tx.coin_with_balance(coin_with_balance(0).coin_type(USDC.to_string()));
+        let value = tx.move_call("0x2::coin::value", vec![zero], vec![USDC.to_string()]).unwrap();
+        tx.move_call("0x2::test::use_value", vec![value], vec![]).unwrap();
+
+        resolver(vec![]).resolve(&mut tx).await.unwrap();
+
+        let CommandKind::MoveCall(call) = &tx.data.commands[0].kind else {
+            panic!("expected MoveCall");
+        };
+        assert_eq!(call.function, "zero");
+        assert_eq!(call.type_arguments, vec![normalize_coin_type(USDC)]);
+        let CommandKind::MoveCall(call) = &tx.data.commands[2].kind else {
+            panic!("expected MoveCall");
+        };
+        assert_eq!(call.arguments, vec![Argument::Result(1)]);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_insufficient_balance() {
+        let mut tx = Transaction::new();
+        tx.set_sender("0x1");
+        tx.coin_with_balance(coin_with_balance(500).coin_type(USDC.to_string()));
+
+        let err = resolver(vec![coin("0xa", 100)])
+            .resolve(&mut tx)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            IntentError::InsufficientBalance {
+                required: 500,
+                available: 100,
+                ..
+            }
+        ));
+    }
+}
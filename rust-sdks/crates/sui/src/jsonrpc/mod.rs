@@ -138,6 +138,23 @@ impl Client {
         .await
     }
 
+    /// Runs BCS `TransactionKind` bytes (see `Transaction::build_kind`)
+    /// without gas or signatures and returns every command's results.
+    pub async fn dev_inspect_transaction_block(
+        &self,
+        sender: &str,
+        tx_kind_base64: &str,
+    ) -> Result<Value, JsonRpcError> {
+        self.call(
+            "sui_devInspectTransactionBlock",
+            vec![
+                Value::String(utils::normalize_sui_address(sender)),
+                Value::String(tx_kind_base64.to_string()),
+            ],
+        )
+        .await
+    }
+
     pub async fn batch_call(
         &self,
         calls: Vec<(&str, Vec<Value>)>,
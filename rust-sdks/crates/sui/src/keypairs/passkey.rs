@@ -1,8 +1,23 @@
-use crate::crypto;
+//! Passkey accounts: WebAuthn assertions over the intent digest, wrapped as
+//! `0x06 || bcs(PasskeyAuthenticator)` signatures.
 
-pub const PASSKEY_SCHEME_FLAG: u8 = 0x06;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use fastcrypto::secp256r1::{Secp256r1PublicKey, Secp256r1Signature};
+use fastcrypto::traits::{ToFromBytes, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::crypto::{self, SignatureScheme};
+use crate::cryptography::{PublicKey, Signer, SignerError};
+
+pub use crate::crypto::PASSKEY_SCHEME_FLAG;
 pub const PASSKEY_PUBLIC_KEY_SIZE: usize = 33;
 pub const PASSKEY_UNCOMPRESSED_PUBLIC_KEY_SIZE: usize = 65;
+pub const PASSKEY_SIGNATURE_SIZE: usize = 64;
+/// `rpIdHash || flags || signCount`, before any extensions.
+pub const AUTHENTICATOR_DATA_MIN_SIZE: usize = 37;
+pub const WEBAUTHN_GET_TYPE: &str = "webauthn.get";
 
 pub const SECP256R1_SPKI_HEADER: [u8; 26] = [
     0x30, 0x59, 0x30, 0x13, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x08,
@@ -19,6 +34,26 @@ pub enum PasskeyError {
     InvalidSpkiHeader,
     #[error("invalid uncompressed key marker")]
     InvalidPointMarker,
+    #[error("invalid passkey signature length: expected 64, got {0}")]
+    InvalidSignatureLength(usize),
+    #[error("authenticator failed: {0}")]
+    Authenticator(String),
+    #[error("invalid passkey public key")]
+    InvalidPublicKey,
+    #[error("invalid DER signature")]
+    InvalidDerSignature,
+    #[error("invalid signature flag: expected 0x06, got 0x{0:02x}")]
+    InvalidSignatureFlag(u8),
+    #[error("invalid user signature: expected a secp256r1 signature of 98 bytes")]
+    InvalidUserSignature,
+    #[error("authenticator data is {0} bytes, expected at least 37")]
+    InvalidAuthenticatorData(usize),
+    #[error("invalid client data: {0}")]
+    InvalidClientData(String),
+    #[error("bcs failed: {0}")]
+    Bcs(#[from] bcs::Error),
+    #[error("base64 decode failed: {0}")]
+    Base64(#[from] base64::DecodeError),
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -36,6 +71,18 @@ impl PasskeyPublicKey {
         })
     }
 
+    /// Compresses the uncompressed `0x04 || x || y` point WebAuthn reports.
+    pub fn from_uncompressed(bytes: &[u8]) -> Result<Self, PasskeyError> {
+        let point =
+            p256::PublicKey::from_sec1_bytes(bytes).map_err(|_| PasskeyError::InvalidPublicKey)?;
+        Self::new(p256::EncodedPoint::from(point).compress().as_bytes())
+    }
+
+    /// Reads the SPKI DER of `AuthenticatorAttestationResponse.getPublicKey()`.
+    pub fn from_der_spki(der_bytes: &[u8]) -> Result<Self, PasskeyError> {
+        Self::from_uncompressed(&parse_der_spki(der_bytes)?)
+    }
+
     pub fn to_raw_bytes(&self) -> &[u8] {
         &self.bytes
     }
@@ -45,6 +92,295 @@ impl PasskeyPublicKey {
     }
 }
 
+/// What a WebAuthn `navigator.credentials.get` call returns for a challenge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasskeyAssertion {
+    pub authenticator_data: Vec<u8>,
+    pub client_data_json: String,
+    /// secp256r1 signature, ASN.1 DER as browsers return it or compact
+    /// `r || s`.
+    pub signature: Vec<u8>,
+}
+
+/// The device or browser holding the passkey's private key.
+pub trait PasskeyProvider: Send + Sync {
+    fn get(&self, challenge: &[u8]) -> Result<PasskeyAssertion, PasskeyError>;
+}
+
+/// The `CollectedClientData` a WebAuthn client signs over, as JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectedClientData {
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Unpadded base64url challenge.
+    pub challenge: String,
+    pub origin: String,
+    #[serde(default)]
+    pub cross_origin: bool,
+}
+
+impl CollectedClientData {
+    pub fn parse(client_data_json: &str) -> Result<Self, PasskeyError> {
+        serde_json::from_str(client_data_json)
+            .map_err(|err| PasskeyError::InvalidClientData(err.to_string()))
+    }
+
+    pub fn challenge_bytes(&self) -> Result<Vec<u8>, PasskeyError> {
+        use base64::Engine as _;
+        Ok(base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(&self.challenge)?)
+    }
+}
+
+/// Compact `r || s` form of a DER or compact secp256r1 signature, with `s`
+/// normalized to the lower half of the order as Sui requires.
+pub fn normalize_signature(signature: &[u8]) -> Result<[u8; 64], PasskeyError> {
+    let signature = if signature.len() == PASSKEY_SIGNATURE_SIZE {
+        p256::ecdsa::Signature::from_slice(signature)
+    } else {
+        p256::ecdsa::Signature::from_der(signature)
+    }
+    .map_err(|_| PasskeyError::InvalidDerSignature)?;
+    let signature = signature.normalize_s().unwrap_or(signature);
+    Ok(signature.to_bytes().into())
+}
+
+/// What the authenticator signs: `authenticatorData || SHA-256(clientDataJSON)`.
+fn signed_message(authenticator_data: &[u8], client_data_json: &str) -> Vec<u8> {
+    [
+        authenticator_data,
+        Sha256::digest(client_data_json.as_bytes()).as_slice(),
+    ]
+    .concat()
+}
+
+/// A passkey signature: the WebAuthn assertion and a secp256r1 serialized
+/// signature, `0x02 || r || s || compressed public key`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PasskeyAuthenticator {
+    pub authenticator_data: Vec<u8>,
+    pub client_data_json: String,
+    pub user_signature: Vec<u8>,
+}
+
+impl PasskeyAuthenticator {
+    pub fn new(
+        assertion: PasskeyAssertion,
+        public_key: &PasskeyPublicKey,
+    ) -> Result<Self, PasskeyError> {
+        if assertion.authenticator_data.len() < AUTHENTICATOR_DATA_MIN_SIZE {
+            return Err(PasskeyError::InvalidAuthenticatorData(
+                assertion.authenticator_data.len(),
+            ));
+        }
+        let signature = normalize_signature(&assertion.signature)?;
+        let mut user_signature = vec![SignatureScheme::Secp256r1.flag()];
+        user_signature.extend_from_slice(&signature);
+        user_signature.extend_from_slice(&public_key.bytes);
+        Ok(Self {
+            authenticator_data: assertion.authenticator_data,
+            client_data_json: assertion.client_data_json,
+            user_signature,
+        })
+    }
+
+    /// `0x06 || bcs(self)`.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, PasskeyError> {
+        Ok([vec![PASSKEY_SCHEME_FLAG], bcs::to_bytes(self)?].concat())
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PasskeyError> {
+        match bytes.first() {
+            Some(&PASSKEY_SCHEME_FLAG) => {}
+            Some(&flag) => return Err(PasskeyError::InvalidSignatureFlag(flag)),
+            None => return Err(PasskeyError::InvalidUserSignature),
+        }
+        let authenticator: Self = bcs::from_bytes(&bytes[1..])?;
+        if authenticator.user_signature.len()
+            != 1 + PASSKEY_SIGNATURE_SIZE + PASSKEY_PUBLIC_KEY_SIZE
+            || authenticator.user_signature[0] != SignatureScheme::Secp256r1.flag()
+        {
+            return Err(PasskeyError::InvalidUserSignature);
+        }
+        Ok(authenticator)
+    }
+
+    /// Reads a base64 serialized passkey signature.
+    pub fn from_base64(signature: &str) -> Result<Self, PasskeyError> {
+        use base64::Engine as _;
+        Self::from_bytes(&base64::engine::general_purpose::STANDARD.decode(signature)?)
+    }
+
+    pub fn signature(&self) -> &[u8] {
+        &self.user_signature[1..1 + PASSKEY_SIGNATURE_SIZE]
+    }
+
+    pub fn public_key(&self) -> Result<PasskeyPublicKey, PasskeyError> {
+        PasskeyPublicKey::new(&self.user_signature[1 + PASSKEY_SIGNATURE_SIZE..])
+    }
+
+    pub fn client_data(&self) -> Result<CollectedClientData, PasskeyError> {
+        CollectedClientData::parse(&self.client_data_json)
+    }
+
+    /// Checks that the assertion is a `webauthn.get` over `digest` and that its
+    /// signature is valid for the embedded public key.
+    pub fn verify(&self, digest: &[u8; 32]) -> Result<bool, PasskeyError> {
+        let client_data = self.client_data()?;
+        if client_data.kind != WEBAUTHN_GET_TYPE {
+            return Err(PasskeyError::InvalidClientData(format!(
+                "type {}",
+                client_data.kind
+            )));
+        }
+        if self.authenticator_data.len() < AUTHENTICATOR_DATA_MIN_SIZE {
+            return Err(PasskeyError::InvalidAuthenticatorData(
+                self.authenticator_data.len(),
+            ));
+        }
+        if client_data.challenge_bytes()? != digest {
+            return Ok(false);
+        }
+        let public_key = Secp256r1PublicKey::from_bytes(&self.public_key()?.bytes)
+            .map_err(|_| PasskeyError::InvalidPublicKey)?;
+        let signature = Secp256r1Signature::from_bytes(self.signature())
+            .map_err(|_| PasskeyError::InvalidSignatureLength(self.signature().len()))?;
+        Ok(public_key
+            .verify(
+                &signed_message(&self.authenticator_data, &self.client_data_json),
+                &signature,
+            )
+            .is_ok())
+    }
+}
+
+/// Signs through a [`PasskeyProvider`], using the intent digest as the
+/// WebAuthn challenge.
+pub struct PasskeySigner {
+    public_key: PasskeyPublicKey,
+    provider: Box<dyn PasskeyProvider>,
+}
+
+impl PasskeySigner {
+    pub fn new(public_key: PasskeyPublicKey, provider: Box<dyn PasskeyProvider>) -> Self {
+        Self {
+            public_key,
+            provider,
+        }
+    }
+}
+
+impl Signer for PasskeySigner {
+    fn public_key(&self) -> PublicKey {
+        PublicKey::Passkey(self.public_key.clone())
+    }
+
+    fn address(&self) -> String {
+        self.public_key.to_sui_address()
+    }
+
+    /// `0x06 || bcs(PasskeyAuthenticator)`, whose user signature is a
+    /// secp256r1 serialized signature.
+    fn sign_digest(&self, digest: &[u8; 32]) -> Result<Vec<u8>, SignerError> {
+        let assertion = self.provider.get(digest)?;
+        Ok(PasskeyAuthenticator::new(assertion, &self.public_key)?.to_bytes()?)
+    }
+}
+
+/// A passkey held in memory that answers `get` the way a browser does, for
+/// tests and tooling without a real authenticator.
+pub struct SoftwareAuthenticator {
+    signing_key: p256::ecdsa::SigningKey,
+    rp_id: String,
+    origin: String,
+    sign_count: AtomicU32,
+}
+
+impl SoftwareAuthenticator {
+    pub fn new(rp_id: impl Into<String>, origin: impl Into<String>) -> Self {
+        Self::with_signing_key(
+            p256::ecdsa::SigningKey::random(&mut rand::thread_rng()),
+            rp_id.into(),
+            origin.into(),
+        )
+    }
+
+    pub fn from_secret_key(
+        secret_key: &[u8],
+        rp_id: impl Into<String>,
+        origin: impl Into<String>,
+    ) -> Result<Self, PasskeyError> {
+        let signing_key = p256::ecdsa::SigningKey::from_slice(secret_key)
+            .map_err(|err| PasskeyError::Authenticator(err.to_string()))?;
+        Ok(Self::with_signing_key(
+            signing_key,
+            rp_id.into(),
+            origin.into(),
+        ))
+    }
+
+    fn with_signing_key(
+        signing_key: p256::ecdsa::SigningKey,
+        rp_id: String,
+        origin: String,
+    ) -> Self {
+        Self {
+            signing_key,
+            rp_id,
+            origin,
+            sign_count: AtomicU32::new(0),
+        }
+    }
+
+    pub fn public_key(&self) -> PasskeyPublicKey {
+        PasskeyPublicKey::new(
+            self.signing_key
+                .verifying_key()
+                .to_encoded_point(true)
+                .as_bytes(),
+        )
+        .expect("compressed point is 33 bytes")
+    }
+
+    /// The credential public key as SPKI DER, like `getPublicKey()`.
+    pub fn public_key_spki(&self) -> Vec<u8> {
+        let point = self.signing_key.verifying_key().to_encoded_point(false);
+        [&SECP256R1_SPKI_HEADER[..], point.as_bytes()].concat()
+    }
+}
+
+impl PasskeyProvider for SoftwareAuthenticator {
+    /// User present and verified, with a DER signature and the next
+    /// signature counter.
+    fn get(&self, challenge: &[u8]) -> Result<PasskeyAssertion, PasskeyError> {
+        use base64::Engine as _;
+        use p256::ecdsa::signature::Signer as _;
+
+        let client_data = CollectedClientData {
+            kind: WEBAUTHN_GET_TYPE.to_string(),
+            challenge: base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(challenge),
+            origin: self.origin.clone(),
+            cross_origin: false,
+        };
+        let client_data_json = serde_json::to_string(&client_data)
+            .map_err(|err| PasskeyError::Authenticator(err.to_string()))?;
+
+        let sign_count = self.sign_count.fetch_add(1, Ordering::SeqCst) + 1;
+        let mut authenticator_data = Sha256::digest(self.rp_id.as_bytes()).to_vec();
+        authenticator_data.push(0x05);
+        authenticator_data.extend_from_slice(&sign_count.to_be_bytes());
+
+        let signature: p256::ecdsa::Signature = self
+            .signing_key
+            .sign(&signed_message(&authenticator_data, &client_data_json));
+        Ok(PasskeyAssertion {
+            authenticator_data,
+            client_data_json,
+            signature: signature.to_der().as_bytes().to_vec(),
+        })
+    }
+}
+
 pub fn parse_der_spki(der_bytes: &[u8]) -> Result<Vec<u8>, PasskeyError> {
     if der_bytes.len() != SECP256R1_SPKI_HEADER.len() + PASSKEY_UNCOMPRESSED_PUBLIC_KEY_SIZE {
         return Err(PasskeyError::InvalidDerLength);
@@ -68,4 +404,127 @@ mod tests {
         assert_eq!(pk.to_raw_bytes().len(), PASSKEY_PUBLIC_KEY_SIZE);
         assert!(pk.to_sui_address().starts_with("0x"));
     }
+
+    struct FixedProvider;
+
+    impl PasskeyProvider for FixedProvider {
+        fn get(&self, challenge: &[u8]) -> Result<PasskeyAssertion, PasskeyError> {
+            Ok(PasskeyAssertion {
+                authenticator_data: vec![1; 37],
+                client_data_json: format!("{{\"challenge\":\"{}\"}}", hex::encode(challenge)),
+                signature: vec![2; 64],
+            })
+        }
+    }
+
+    #[test]
+    fn test_passkey_signer_serializes_authenticator() {
+        let public_key = PasskeyPublicKey::new(&[3u8; PASSKEY_PUBLIC_KEY_SIZE]).unwrap();
+        let signer = PasskeySigner::new(public_key.clone(), Box::new(FixedProvider));
+        assert_eq!(signer.address(), public_key.to_sui_address());
+
+        let bytes = signer.sign_digest(&[9u8; 32]).unwrap();
+        assert_eq!(bytes[0], PASSKEY_SCHEME_FLAG);
+        let authenticator: PasskeyAuthenticator = bcs::from_bytes(&bytes[1..]).unwrap();
+        assert_eq!(authenticator.authenticator_data, vec![1; 37]);
+        assert!(
+            authenticator
+                .client_data_json
+                .contains(&hex::encode([9u8; 32]))
+        );
+        assert_eq!(authenticator.user_signature[0], 0x02);
+        assert_eq!(&authenticator.user_signature[65..], &[3u8; 33]);
+    }
+
+    fn authenticator() -> SoftwareAuthenticator {
+        SoftwareAuthenticator::from_secret_key(&[5u8; 32], "sui.io", "https://sui.io").unwrap()
+    }
+
+    #[test]
+    fn test_software_authenticator_matches_browser_output() {
+        let authenticator = authenticator();
+        let assertion = authenticator.get(&[9u8; 32]).unwrap();
+        assert_eq!(
+            assertion.client_data_json,
+            format!(
+                r#"{{"type":"webauthn.get","challenge":"{}","origin":"https://sui.io","crossOrigin":false}}"#,
+                "CQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQk"
+            )
+        );
+        assert_eq!(
+            &assertion.authenticator_data[..32],
+            Sha256::digest(b"sui.io").as_slice()
+        );
+        assert_eq!(&assertion.authenticator_data[32..], &[0x05, 0, 0, 0, 1]);
+        assert_eq!(assertion.signature[0], 0x30);
+        assert_eq!(
+            authenticator.get(&[9u8; 32]).unwrap().authenticator_data[36],
+            2
+        );
+
+        assert_eq!(
+            PasskeyPublicKey::from_der_spki(&authenticator.public_key_spki()).unwrap(),
+            authenticator.public_key()
+        );
+    }
+
+    #[test]
+    fn test_passkey_signature_verifies() {
+        let provider = authenticator();
+        let public_key = provider.public_key();
+        let signer = PasskeySigner::new(public_key.clone(), Box::new(provider));
+        let digest = [4u8; 32];
+
+        let bytes = signer.sign_digest(&digest).unwrap();
+        let authenticator = PasskeyAuthenticator::from_bytes(&bytes).unwrap();
+        assert_eq!(authenticator.public_key().unwrap(), public_key);
+        assert_eq!(
+            authenticator
+                .client_data()
+                .unwrap()
+                .challenge_bytes()
+                .unwrap(),
+            digest
+        );
+        assert!(authenticator.verify(&digest).unwrap());
+        assert!(!authenticator.verify(&[5u8; 32]).unwrap());
+
+        let mut tampered = authenticator.clone();
+        tampered.authenticator_data[33] ^= 1;
+        assert!(!tampered.verify(&digest).unwrap());
+
+        let mut wrong_type = authenticator.clone();
+        wrong_type.client_data_json = wrong_type
+            .client_data_json
+            .replace(WEBAUTHN_GET_TYPE, "webauthn.create");
+        assert!(matches!(
+            wrong_type.verify(&digest),
+            Err(PasskeyError::InvalidClientData(_))
+        ));
+        assert!(matches!(
+            PasskeyAuthenticator::from_bytes(&[0x05, 0]),
+            Err(PasskeyError::InvalidSignatureFlag(0x05))
+        ));
+    }
+
+    #[test]
+    fn test_normalize_signature_lowers_s() {
+        use p256::elliptic_curve::scalar::IsHigh;
+
+        let signature = p256::ecdsa::Signature::from_slice(&[7u8; 64]).unwrap();
+        let (r, s) = signature.split_scalars();
+        let high = p256::ecdsa::Signature::from_scalars(r, -*s).unwrap();
+        assert!(bool::from(high.s().is_high()));
+
+        let expected: [u8; 64] = signature.to_bytes().into();
+        assert_eq!(
+            normalize_signature(high.to_der().as_bytes()).unwrap(),
+            expected
+        );
+        assert_eq!(normalize_signature(&high.to_bytes()).unwrap(), expected);
+        assert!(matches!(
+            normalize_signature(&[0x30, 0x02, 0x01]),
+            Err(PasskeyError::InvalidDerSignature)
+        ));
+    }
 }
@@ -2,8 +2,11 @@ use fastcrypto::secp256k1::{
     Secp256k1KeyPair, Secp256k1PrivateKey, Secp256k1Signature, SECP256K1_PRIVATE_KEY_LENGTH,
 };
 use fastcrypto::traits::{KeyPair, Signer, ToFromBytes, VerifyingKey};
+use zeroize::Zeroizing;
 
-use crate::crypto;
+use crate::crypto::{self, SignatureScheme};
+use crate::cryptography::mnemonics::{self, MnemonicError};
+use crate::cryptography::private_key::{self, PrivateKeyError};
 
 const SECP256K1_SCHEME_FLAG: u8 = 0x01;
 
@@ -19,6 +22,10 @@ pub enum KeypairError {
     InvalidPrivateKey,
     #[error("invalid Sui private key scheme flag: expected 0x01, got 0x{0:02x}")]
     InvalidScheme(u8),
+    #[error(transparent)]
+    Mnemonic(#[from] MnemonicError),
+    #[error(transparent)]
+    PrivateKey(#[from] PrivateKeyError),
 }
 
 pub struct Keypair {
@@ -45,16 +52,32 @@ impl Keypair {
         })
     }
 
+    /// Reads a `suiprivkey1..` key, or the base64 `flag || secret key` form
+    /// of older keystores.
     pub fn from_sui_private_key(encoded: &str) -> Result<Self, KeypairError> {
-        use base64::Engine as _;
-        let bytes = base64::engine::general_purpose::STANDARD.decode(encoded)?;
-        if bytes.is_empty() {
-            return Err(KeypairError::InvalidPrivateKey);
+        let parsed = if encoded.trim().starts_with(private_key::SUI_PRIVATE_KEY_PREFIX) {
+            private_key::decode_sui_private_key(encoded)?
+        } else {
+            private_key::decode_base64_private_key(encoded)?
+        };
+        if parsed.scheme != SignatureScheme::Secp256k1 {
+            return Err(KeypairError::InvalidScheme(parsed.scheme.flag()));
         }
-        if bytes[0] != SECP256K1_SCHEME_FLAG {
-            return Err(KeypairError::InvalidScheme(bytes[0]));
+        Self::from_secret_key(&parsed.secret_key)
+    }
+
+    /// Derives the key a Sui wallet holds for `mnemonic` at `path`, by default
+    /// [`mnemonics::DEFAULT_SECP256K1_DERIVATION_PATH`].
+    pub fn derive_keypair(mnemonic: &str, path: Option<&str>) -> Result<Self, KeypairError> {
+        Self::derive_keypair_from_seed(&mnemonics::mnemonic_to_seed(mnemonic, "")?, path)
+    }
+
+    pub fn derive_keypair_from_seed(seed: &[u8], path: Option<&str>) -> Result<Self, KeypairError> {
+        let path = path.unwrap_or(mnemonics::DEFAULT_SECP256K1_DERIVATION_PATH);
+        if !mnemonics::is_valid_bip32_path(path, 54) {
+            return Err(MnemonicError::InvalidPath(path.to_string()).into());
         }
-        Self::from_secret_key(&bytes[1..])
+        Self::from_secret_key(&mnemonics::derive_bip32_secret_key(seed, path)?)
     }
 
     pub fn sign(&self, message: &[u8]) -> Vec<u8> {
@@ -74,11 +97,13 @@ impl Keypair {
         crypto::to_sui_address(SECP256K1_SCHEME_FLAG, self.inner.public().as_ref())
     }
 
+    pub fn secret_key_bytes(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(self.inner.as_ref().to_vec())
+    }
+
+    /// The `suiprivkey1..` encoding the Sui CLI and wallets import.
     pub fn to_sui_private_key(&self) -> String {
-        let mut bytes = Vec::with_capacity(1 + SECP256K1_PRIVATE_KEY_LENGTH);
-        bytes.push(SECP256K1_SCHEME_FLAG);
-        bytes.extend_from_slice(self.inner.as_ref());
-        use base64::Engine as _;
-        base64::engine::general_purpose::STANDARD.encode(bytes)
+        private_key::encode_sui_private_key(SignatureScheme::Secp256k1, self.inner.as_ref())
+            .expect("secret key is 32 bytes")
     }
 }
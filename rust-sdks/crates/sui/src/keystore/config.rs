@@ -0,0 +1,240 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use serde::de::Error as _;
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{FileKeystore, KeystoreError, SUI_CLIENT_CONFIG, default_config_dir};
+use crate::cryptography::Keypair;
+use crate::jsonrpc;
+
+/// Where the CLI keeps its keys.
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeystoreLocation {
+    File(PathBuf),
+    InMem(serde_yaml::Value),
+}
+
+// Written as the `File: path` map the CLI's YAML library uses for enums,
+// rather than the `!File path` tag of serde_yaml 0.9.
+impl Serialize for KeystoreLocation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(1))?;
+        match self {
+            KeystoreLocation::File(path) => map.serialize_entry("File", path)?,
+            KeystoreLocation::InMem(keys) => map.serialize_entry("InMem", keys)?,
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for KeystoreLocation {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let map = BTreeMap::<String, serde_yaml::Value>::deserialize(deserializer)?;
+        let mut entries = map.into_iter();
+        match (entries.next(), entries.next()) {
+            (Some((kind, value)), None) if kind == "File" => serde_yaml::from_value(value)
+                .map(KeystoreLocation::File)
+                .map_err(D::Error::custom),
+            (Some((kind, value)), None) if kind == "InMem" => Ok(KeystoreLocation::InMem(value)),
+            _ => Err(D::Error::custom("expected `File` or `InMem` keystore")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BasicAuth {
+    pub username: String,
+    pub password: String,
+}
+
+/// A network the CLI can switch to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SuiEnv {
+    pub alias: String,
+    pub rpc: String,
+    #[serde(default)]
+    pub ws: Option<String>,
+    #[serde(default)]
+    pub basic_auth: Option<BasicAuth>,
+    /// Fields of newer CLI versions, kept so saving does not drop them.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_yaml::Value>,
+}
+
+/// `client.yaml`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClientConfig {
+    pub keystore: KeystoreLocation,
+    #[serde(default)]
+    pub envs: Vec<SuiEnv>,
+    #[serde(default)]
+    pub active_env: Option<String>,
+    #[serde(default)]
+    pub active_address: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_yaml::Value>,
+}
+
+impl ClientConfig {
+    pub fn from_yaml(yaml: &str) -> Result<Self, KeystoreError> {
+        Ok(serde_yaml::from_str(yaml)?)
+    }
+
+    pub fn to_yaml(&self) -> Result<String, KeystoreError> {
+        Ok(serde_yaml::to_string(self)?)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, KeystoreError> {
+        Self::from_yaml(&super::read_file(path.as_ref())?)
+    }
+
+    /// `client.yaml` in [`default_config_dir`].
+    pub fn load_default() -> Result<Self, KeystoreError> {
+        Self::load(default_config_dir()?.join(SUI_CLIENT_CONFIG))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), KeystoreError> {
+        super::write_file(path.as_ref(), &self.to_yaml()?)
+    }
+
+    pub fn env(&self, alias: &str) -> Option<&SuiEnv> {
+        self.envs.iter().find(|env| env.alias == alias)
+    }
+
+    pub fn active_env(&self) -> Result<&SuiEnv, KeystoreError> {
+        let alias = self
+            .active_env
+            .as_deref()
+            .ok_or(KeystoreError::Missing("active_env"))?;
+        self.env(alias)
+            .ok_or_else(|| KeystoreError::UnknownEnv(alias.to_string()))
+    }
+
+    /// RPC URL of the active environment.
+    pub fn rpc_url(&self) -> Result<&str, KeystoreError> {
+        Ok(&self.active_env()?.rpc)
+    }
+
+    /// Makes `alias` the active environment, adding it with `rpc` if it is not
+    /// configured yet.
+    pub fn set_active_env(&mut self, alias: &str, rpc: Option<&str>) -> Result<(), KeystoreError> {
+        if self.env(alias).is_none() {
+            let rpc = rpc.ok_or_else(|| KeystoreError::UnknownEnv(alias.to_string()))?;
+            self.envs.push(SuiEnv {
+                alias: alias.to_string(),
+                rpc: rpc.to_string(),
+                ws: None,
+                basic_auth: None,
+                extra: BTreeMap::new(),
+            });
+        }
+        self.active_env = Some(alias.to_string());
+        Ok(())
+    }
+
+    /// A JSON-RPC client for the active environment.
+    pub fn jsonrpc_client(&self) -> Result<jsonrpc::Client, KeystoreError> {
+        let env = self.active_env()?;
+        let client = jsonrpc::Client::new(&env.rpc, &env.alias);
+        Ok(match &env.basic_auth {
+            Some(auth) => {
+                use base64::Engine as _;
+                let credentials = base64::engine::general_purpose::STANDARD
+                    .encode(format!("{}:{}", auth.username, auth.password));
+                client.with_header("Authorization", &format!("Basic {credentials}"))?
+            }
+            None => client,
+        })
+    }
+
+    /// The file keystore the config points at.
+    pub fn keystore(&self) -> Result<FileKeystore, KeystoreError> {
+        match &self.keystore {
+            KeystoreLocation::File(path) => FileKeystore::load(path),
+            KeystoreLocation::InMem(_) => Err(KeystoreError::NotAFileKeystore),
+        }
+    }
+
+    /// The signer of the active address.
+    pub fn active_signer(&self) -> Result<Arc<Keypair>, KeystoreError> {
+        let address = self
+            .active_address
+            .as_deref()
+            .ok_or(KeystoreError::Missing("active_address"))?;
+        self.keystore()?.signer(address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLIENT_YAML: &str = r#"---
+keystore:
+  File: /home/dev/.sui/sui_config/sui.keystore
+external_keys: ~
+envs:
+  - alias: devnet
+    rpc: "https://fullnode.devnet.sui.io:443"
+    ws: ~
+    basic_auth: ~
+  - alias: local
+    rpc: "http://127.0.0.1:9000"
+    ws: ~
+    basic_auth:
+      username: dev
+      password: secret
+    chain_id: 4c78adac
+active_env: devnet
+active_address: "0x1"
+"#;
+
+    #[test]
+    fn test_reads_cli_client_config() {
+        let mut config = ClientConfig::from_yaml(CLIENT_YAML).unwrap();
+        assert_eq!(
+            config.keystore,
+            KeystoreLocation::File("/home/dev/.sui/sui_config/sui.keystore".into())
+        );
+        assert_eq!(
+            config.rpc_url().unwrap(),
+            "https://fullnode.devnet.sui.io:443"
+        );
+        assert_eq!(
+            config
+                .env("local")
+                .unwrap()
+                .basic_auth
+                .as_ref()
+                .unwrap()
+                .username,
+            "dev"
+        );
+
+        config.set_active_env("local", None).unwrap();
+        config.jsonrpc_client().unwrap();
+        assert!(matches!(
+            config.set_active_env("mainnet", None),
+            Err(KeystoreError::UnknownEnv(_))
+        ));
+        config
+            .set_active_env("mainnet", Some("https://fullnode.mainnet.sui.io:443"))
+            .unwrap();
+
+        let yaml = config.to_yaml().unwrap();
+        assert!(yaml.contains("keystore:\n  File: /home/dev/.sui/sui_config/sui.keystore\n"));
+        let restored = ClientConfig::from_yaml(&yaml).unwrap();
+        assert_eq!(restored, config);
+        assert!(restored.extra.contains_key("external_keys"));
+        assert!(
+            restored
+                .env("local")
+                .unwrap()
+                .extra
+                .contains_key("chain_id")
+        );
+    }
+}
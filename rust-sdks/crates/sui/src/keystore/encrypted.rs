@@ -0,0 +1,465 @@
+//! A keystore that only keeps secret keys on disk encrypted: each key is
+//! sealed with AES-256-GCM under a key stretched from a password with scrypt
+//! or Argon2id.
+
+use std::path::{Path, PathBuf};
+
+use aes_gcm::Aes256Gcm;
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use zeroize::Zeroizing;
+
+use super::{KeystoreError, next_free_alias, read_file, write_file};
+use crate::crypto::SignatureScheme;
+use crate::cryptography::{Keypair, Signer};
+use crate::transactions::normalize_sui_address;
+
+/// The only encrypted keystore version written and read.
+pub const ENCRYPTED_KEYSTORE_VERSION: u64 = 1;
+const SALT_SIZE: usize = 16;
+const NONCE_SIZE: usize = 12;
+/// Associated data of the password check, which seals an empty message.
+const VERIFIER_AAD: &[u8] = b"sui encrypted keystore";
+
+/// How the password is stretched into the AES key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "name", rename_all = "lowercase")]
+pub enum KdfParams {
+    Scrypt {
+        log_n: u8,
+        r: u32,
+        p: u32,
+    },
+    Argon2id {
+        m_cost: u32,
+        t_cost: u32,
+        p_cost: u32,
+    },
+}
+
+impl KdfParams {
+    /// scrypt with N = 2^17, r = 8, p = 1.
+    pub fn scrypt() -> Self {
+        KdfParams::Scrypt {
+            log_n: 17,
+            r: 8,
+            p: 1,
+        }
+    }
+
+    /// Argon2id with 19 MiB of memory, 2 passes and 1 lane.
+    pub fn argon2id() -> Self {
+        KdfParams::Argon2id {
+            m_cost: 19 * 1024,
+            t_cost: 2,
+            p_cost: 1,
+        }
+    }
+
+    fn derive_key(
+        &self,
+        password: &[u8],
+        salt: &[u8],
+    ) -> Result<Zeroizing<[u8; 32]>, KeystoreError> {
+        let kdf = |err: &dyn std::fmt::Display| KeystoreError::Kdf(err.to_string());
+        let mut key = Zeroizing::new([0u8; 32]);
+        match *self {
+            KdfParams::Scrypt { log_n, r, p } => {
+                let params =
+                    scrypt::Params::new(log_n, r, p, key.len()).map_err(|err| kdf(&err))?;
+                scrypt::scrypt(password, salt, &params, key.as_mut()).map_err(|err| kdf(&err))?;
+            }
+            KdfParams::Argon2id {
+                m_cost,
+                t_cost,
+                p_cost,
+            } => {
+                let params = argon2::Params::new(m_cost, t_cost, p_cost, Some(key.len()))
+                    .map_err(|err| kdf(&err))?;
+                argon2::Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params)
+                    .hash_password_into(password, salt, key.as_mut())
+                    .map_err(|err| kdf(&err))?;
+            }
+        }
+        Ok(key)
+    }
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self::scrypt()
+    }
+}
+
+fn cipher(key: &[u8; 32]) -> Aes256Gcm {
+    Aes256Gcm::new(key.as_slice().into())
+}
+
+fn encode(bytes: &[u8]) -> String {
+    use base64::Engine as _;
+    base64::engine::general_purpose::STANDARD.encode(bytes)
+}
+
+fn decode(value: &str) -> Result<Vec<u8>, KeystoreError> {
+    use base64::Engine as _;
+    Ok(base64::engine::general_purpose::STANDARD.decode(value)?)
+}
+
+/// An AES-GCM ciphertext and the nonce it was sealed with, both base64.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Sealed {
+    nonce: String,
+    ciphertext: String,
+}
+
+impl Sealed {
+    fn seal(cipher: &Aes256Gcm, msg: &[u8], aad: &[u8]) -> Self {
+        let nonce: [u8; NONCE_SIZE] = rand::random();
+        let ciphertext = cipher
+            .encrypt(&nonce.into(), Payload { msg, aad })
+            .expect("key fits in an AES-GCM message");
+        Self {
+            nonce: encode(&nonce),
+            ciphertext: encode(&ciphertext),
+        }
+    }
+
+    /// The plaintext, or `None` if the password or associated data is wrong.
+    fn open(
+        &self,
+        cipher: &Aes256Gcm,
+        aad: &[u8],
+    ) -> Result<Option<Zeroizing<Vec<u8>>>, KeystoreError> {
+        let nonce: [u8; NONCE_SIZE] = decode(&self.nonce)?
+            .try_into()
+            .map_err(|_| KeystoreError::Corrupt("nonce".to_string()))?;
+        let msg = decode(&self.ciphertext)?;
+        Ok(cipher
+            .decrypt(&nonce.into(), Payload { msg: &msg, aad })
+            .ok()
+            .map(Zeroizing::new))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EncryptedKey {
+    address: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    alias: Option<String>,
+    scheme: SignatureScheme,
+    /// Base64 public key, without the scheme flag.
+    public_key: String,
+    /// The secret key, with the address as associated data.
+    #[serde(flatten)]
+    secret_key: Sealed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EncryptedKeystoreFile {
+    version: u64,
+    kdf: KdfParams,
+    /// Base64 KDF salt.
+    salt: String,
+    verifier: Sealed,
+    keys: Vec<EncryptedKey>,
+}
+
+/// What [`EncryptedKeystore::list`] reveals about a key without decrypting it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeystoreEntry {
+    pub address: String,
+    pub alias: Option<String>,
+    pub scheme: SignatureScheme,
+    pub public_key: Vec<u8>,
+}
+
+/// An unlocked encrypted keystore. Keys stay encrypted in memory and are
+/// only decrypted for [`EncryptedKeystore::signer`]; the derived AES key is
+/// wiped when the keystore is dropped.
+pub struct EncryptedKeystore {
+    path: PathBuf,
+    file: EncryptedKeystoreFile,
+    key: Zeroizing<[u8; 32]>,
+}
+
+impl std::fmt::Debug for EncryptedKeystore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EncryptedKeystore")
+            .field("path", &self.path)
+            .field("kdf", &self.file.kdf)
+            .field(
+                "addresses",
+                &self
+                    .file
+                    .keys
+                    .iter()
+                    .map(|key| &key.address)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl EncryptedKeystore {
+    /// Creates an empty keystore at `path` protected by `password`. Fails if
+    /// `path` already exists rather than overwriting another keystore.
+    pub fn create(
+        path: impl Into<PathBuf>,
+        password: &str,
+        kdf: KdfParams,
+    ) -> Result<Self, KeystoreError> {
+        let path = path.into();
+        if path.exists() {
+            return Err(KeystoreError::AlreadyExists(path));
+        }
+        let (file, key) = Self::protect(password, kdf)?;
+        let keystore = Self { path, file, key };
+        keystore.save()?;
+        Ok(keystore)
+    }
+
+    /// Opens the keystore at `path`, failing with
+    /// [`KeystoreError::WrongPassword`] if `password` does not match.
+    pub fn unlock(path: impl Into<PathBuf>, password: &str) -> Result<Self, KeystoreError> {
+        let path = path.into();
+        let value: Value = serde_json::from_str(&read_file(&path)?)?;
+        match value.get("version").and_then(Value::as_u64) {
+            Some(ENCRYPTED_KEYSTORE_VERSION) => {}
+            version => return Err(KeystoreError::UnsupportedVersion(version.unwrap_or(0))),
+        }
+        let file: EncryptedKeystoreFile = serde_json::from_value(value)?;
+        let key = file
+            .kdf
+            .derive_key(password.as_bytes(), &decode(&file.salt)?)?;
+        if file.verifier.open(&cipher(&key), VERIFIER_AAD)?.is_none() {
+            return Err(KeystoreError::WrongPassword);
+        }
+        Ok(Self { path, file, key })
+    }
+
+    fn protect(
+        password: &str,
+        kdf: KdfParams,
+    ) -> Result<(EncryptedKeystoreFile, Zeroizing<[u8; 32]>), KeystoreError> {
+        let salt: [u8; SALT_SIZE] = rand::random();
+        let key = kdf.derive_key(password.as_bytes(), &salt)?;
+        let file = EncryptedKeystoreFile {
+            version: ENCRYPTED_KEYSTORE_VERSION,
+            kdf,
+            salt: encode(&salt),
+            verifier: Sealed::seal(&cipher(&key), &[], VERIFIER_AAD),
+            keys: Vec::new(),
+        };
+        Ok((file, key))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn kdf(&self) -> KdfParams {
+        self.file.kdf
+    }
+
+    /// Every key's address, alias and public key.
+    pub fn list(&self) -> Result<Vec<KeystoreEntry>, KeystoreError> {
+        self.file
+            .keys
+            .iter()
+            .map(|key| {
+                Ok(KeystoreEntry {
+                    address: key.address.clone(),
+                    alias: key.alias.clone(),
+                    scheme: key.scheme,
+                    public_key: decode(&key.public_key)?,
+                })
+            })
+            .collect()
+    }
+
+    fn position(&self, address_or_alias: &str) -> Result<usize, KeystoreError> {
+        let address = normalize_sui_address(address_or_alias);
+        self.file
+            .keys
+            .iter()
+            .position(|key| key.alias.as_deref() == Some(address_or_alias))
+            .or_else(|| self.file.keys.iter().position(|key| key.address == address))
+            .ok_or_else(|| KeystoreError::UnknownKey(address_or_alias.to_string()))
+    }
+
+    /// Adds `keypair` under `alias`, or under the next free `key-N` alias,
+    /// replacing any key already stored for its address, and returns its
+    /// address. Call [`EncryptedKeystore::save`] to persist it.
+    pub fn add(
+        &mut self,
+        keypair: &Keypair,
+        alias: Option<String>,
+    ) -> Result<String, KeystoreError> {
+        let address = keypair.address();
+        let existing = self.file.keys.iter().position(|key| key.address == address);
+        let alias = match alias {
+            Some(alias) => {
+                if self
+                    .file
+                    .keys
+                    .iter()
+                    .any(|key| key.alias.as_deref() == Some(&alias) && key.address != address)
+                {
+                    return Err(KeystoreError::AliasExists(alias));
+                }
+                alias
+            }
+            None => match existing.and_then(|index| self.file.keys[index].alias.clone()) {
+                Some(alias) => alias,
+                None => {
+                    next_free_alias(self.file.keys.iter().filter_map(|key| key.alias.as_deref()))
+                }
+            },
+        };
+        let key = EncryptedKey {
+            address: address.clone(),
+            alias: Some(alias),
+            scheme: keypair.scheme(),
+            public_key: encode(&keypair.public_key_bytes()),
+            secret_key: Sealed::seal(
+                &cipher(&self.key),
+                &keypair.secret_key_bytes(),
+                address.as_bytes(),
+            ),
+        };
+        match existing {
+            Some(index) => self.file.keys[index] = key,
+            None => self.file.keys.push(key),
+        }
+        Ok(address)
+    }
+
+    /// Removes the key of an address or alias and returns its address.
+    pub fn remove(&mut self, address_or_alias: &str) -> Result<String, KeystoreError> {
+        let index = self.position(address_or_alias)?;
+        Ok(self.file.keys.remove(index).address)
+    }
+
+    fn decrypt(&self, key: &EncryptedKey) -> Result<Keypair, KeystoreError> {
+        let secret_key = key
+            .secret_key
+            .open(&cipher(&self.key), key.address.as_bytes())?
+            .ok_or_else(|| KeystoreError::Corrupt(key.address.clone()))?;
+        let keypair = Keypair::from_secret_key(key.scheme, &secret_key)?;
+        if keypair.address() != key.address {
+            return Err(KeystoreError::Corrupt(key.address.clone()));
+        }
+        Ok(keypair)
+    }
+
+    /// Decrypts the key of an address or alias. The decrypted bytes are wiped
+    /// once the keypair is built.
+    pub fn signer(&self, address_or_alias: &str) -> Result<Keypair, KeystoreError> {
+        self.decrypt(&self.file.keys[self.position(address_or_alias)?])
+    }
+
+    /// Re-encrypts every key under `new_password`, with fresh salt and
+    /// nonces, and `kdf` if given, then saves the keystore.
+    pub fn change_password(
+        &mut self,
+        new_password: &str,
+        kdf: Option<KdfParams>,
+    ) -> Result<(), KeystoreError> {
+        let keypairs = self
+            .file
+            .keys
+            .iter()
+            .map(|key| Ok((self.decrypt(key)?, key.alias.clone())))
+            .collect::<Result<Vec<_>, KeystoreError>>()?;
+        let (file, key) = Self::protect(new_password, kdf.unwrap_or(self.file.kdf))?;
+        self.file = file;
+        self.key = key;
+        for (keypair, alias) in keypairs {
+            self.add(&keypair, alias)?;
+        }
+        self.save()
+    }
+
+    /// Writes the keystore through a temporary file, so a failed write never
+    /// leaves a truncated keystore behind.
+    pub fn save(&self) -> Result<(), KeystoreError> {
+        let temp = self.path.with_extension("tmp");
+        write_file(&temp, &serde_json::to_string_pretty(&self.file)?)?;
+        std::fs::rename(&temp, &self.path).map_err(|source| KeystoreError::Io {
+            path: self.path.clone(),
+            source,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn light_kdf() -> KdfParams {
+        KdfParams::Scrypt {
+            log_n: 4,
+            r: 8,
+            p: 1,
+        }
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "sui-encrypted-keystore-{name}-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir.join("sui.keystore.json")
+    }
+
+    #[test]
+    fn test_kdf_params_serialize_with_name() {
+        assert_eq!(
+            serde_json::to_value(KdfParams::scrypt()).unwrap(),
+            serde_json::json!({"name": "scrypt", "log_n": 17, "r": 8, "p": 1})
+        );
+        let argon2: KdfParams =
+            serde_json::from_str(r#"{"name":"argon2id","m_cost":64,"t_cost":1,"p_cost":1}"#)
+                .unwrap();
+        assert!(matches!(argon2, KdfParams::Argon2id { m_cost: 64, .. }));
+        assert!(matches!(
+            KdfParams::Argon2id {
+                m_cost: 1,
+                t_cost: 1,
+                p_cost: 1
+            }
+            .derive_key(b"pw", b"salt"),
+            Err(KeystoreError::Kdf(_))
+        ));
+    }
+
+    #[test]
+    fn test_entries_are_bound_to_their_address() {
+        let path = temp_path("bound");
+        let mut keystore = EncryptedKeystore::create(&path, "pw", light_kdf()).unwrap();
+        let first = keystore
+            .add(
+                &Keypair::from_secret_key(SignatureScheme::Ed25519, &[1; 32]).unwrap(),
+                None,
+            )
+            .unwrap();
+        let second = keystore
+            .add(
+                &Keypair::from_secret_key(SignatureScheme::Secp256k1, &[2; 32]).unwrap(),
+                None,
+            )
+            .unwrap();
+        assert_eq!(keystore.list().unwrap()[1].alias.as_deref(), Some("key-1"));
+
+        let swapped = keystore.file.keys[1].secret_key.clone();
+        keystore.file.keys[0].secret_key = swapped;
+        assert!(matches!(
+            keystore.signer(&first),
+            Err(KeystoreError::Corrupt(address)) if address == first
+        ));
+        assert_eq!(keystore.signer("key-1").unwrap().address(), second);
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,335 @@
+//! The Sui CLI's local key storage: `sui.keystore`, its `sui.aliases`
+//! companion, and the environments in `client.yaml`.
+
+mod config;
+mod encrypted;
+
+pub use config::{BasicAuth, ClientConfig, KeystoreLocation, SuiEnv};
+pub use encrypted::{ENCRYPTED_KEYSTORE_VERSION, EncryptedKeystore, KdfParams, KeystoreEntry};
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cryptography::private_key::{self, PrivateKeyError};
+use crate::cryptography::{Keypair, KeypairError, Signer};
+use crate::jsonrpc;
+use crate::transactions::normalize_sui_address;
+
+pub const SUI_CONFIG_DIR_ENV: &str = "SUI_CONFIG_DIR";
+pub const SUI_CLIENT_CONFIG: &str = "client.yaml";
+pub const SUI_KEYSTORE_FILENAME: &str = "sui.keystore";
+const ALIASES_FILE_EXTENSION: &str = "aliases";
+
+#[derive(Debug, thiserror::Error)]
+pub enum KeystoreError {
+    #[error("{path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("invalid keystore json: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("invalid client config: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+    #[error(transparent)]
+    Keypair(#[from] KeypairError),
+    #[error(transparent)]
+    PrivateKey(#[from] PrivateKeyError),
+    #[error(transparent)]
+    JsonRpc(#[from] jsonrpc::JsonRpcError),
+    #[error("no key for {0} in the keystore")]
+    UnknownKey(String),
+    #[error("alias {0} is already in use")]
+    AliasExists(String),
+    #[error("environment {0} is not configured")]
+    UnknownEnv(String),
+    #[error("client config has no {0}")]
+    Missing(&'static str),
+    #[error("keystore is not stored in a file")]
+    NotAFileKeystore,
+    #[error("cannot locate the sui config directory: set {SUI_CONFIG_DIR_ENV} or HOME")]
+    NoConfigDir,
+    #[error("{} already exists", .0.display())]
+    AlreadyExists(PathBuf),
+    #[error("wrong keystore password")]
+    WrongPassword,
+    #[error("unsupported encrypted keystore version {0}")]
+    UnsupportedVersion(u64),
+    #[error("invalid kdf parameters: {0}")]
+    Kdf(String),
+    #[error("corrupt keystore entry {0}")]
+    Corrupt(String),
+    #[error("invalid base64: {0}")]
+    Base64(#[from] base64::DecodeError),
+}
+
+pub(crate) fn read_file(path: &Path) -> Result<String, KeystoreError> {
+    std::fs::read_to_string(path).map_err(|source| KeystoreError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+pub(crate) fn write_file(path: &Path, contents: &str) -> Result<(), KeystoreError> {
+    let io = |source| KeystoreError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir).map_err(io)?;
+    }
+    std::fs::write(path, contents).map_err(io)
+}
+
+/// `$SUI_CONFIG_DIR`, or `~/.sui/sui_config` as the CLI uses by default.
+pub fn default_config_dir() -> Result<PathBuf, KeystoreError> {
+    if let Some(dir) = std::env::var_os(SUI_CONFIG_DIR_ENV) {
+        return Ok(PathBuf::from(dir));
+    }
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(".sui").join("sui_config"))
+        .ok_or(KeystoreError::NoConfigDir)
+}
+
+/// The first `key-N` alias not in `taken`, as the CLI names unaliased keys.
+fn next_free_alias<'a>(taken: impl Iterator<Item = &'a str> + Clone) -> String {
+    (0..)
+        .map(|n| format!("key-{n}"))
+        .find(|name| taken.clone().all(|alias| alias != name))
+        .expect("unbounded range")
+}
+
+/// An entry of `sui.aliases`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Alias {
+    pub alias: String,
+    /// Base64 `flag || public key`.
+    #[serde(rename = "public_key_base64")]
+    pub public_base64_key: String,
+}
+
+/// Keys of `sui.keystore`, a JSON array of base64 `flag || secret key`
+/// strings, by address, together with their aliases.
+pub struct FileKeystore {
+    path: PathBuf,
+    keys: BTreeMap<String, Arc<Keypair>>,
+    aliases: BTreeMap<String, Alias>,
+}
+
+impl std::fmt::Debug for FileKeystore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileKeystore")
+            .field("path", &self.path)
+            .field("addresses", &self.keys.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+fn public_base64_key(keypair: &Keypair) -> String {
+    use base64::Engine as _;
+    let bytes = [&[keypair.scheme().flag()][..], &keypair.public_key_bytes()].concat();
+    base64::engine::general_purpose::STANDARD.encode(bytes)
+}
+
+impl FileKeystore {
+    /// An empty keystore that [`FileKeystore::save`] writes to `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            keys: BTreeMap::new(),
+            aliases: BTreeMap::new(),
+        }
+    }
+
+    /// Reads `path` and the `.aliases` file next to it. A missing keystore
+    /// reads as empty, as the CLI creates it on first use.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, KeystoreError> {
+        let mut keystore = Self::new(path);
+        if !keystore.path.exists() {
+            return Ok(keystore);
+        }
+        let encoded: Vec<String> = serde_json::from_str(&read_file(&keystore.path)?)?;
+        for encoded in encoded {
+            let parsed = private_key::decode_base64_private_key(&encoded)?;
+            let keypair = Keypair::from_secret_key(parsed.scheme, &parsed.secret_key)?;
+            keystore.keys.insert(keypair.address(), Arc::new(keypair));
+        }
+
+        let aliases_path = keystore.aliases_path();
+        if aliases_path.exists() {
+            let aliases: Vec<Alias> = serde_json::from_str(&read_file(&aliases_path)?)?;
+            let addresses: BTreeMap<_, _> = keystore
+                .keys
+                .iter()
+                .map(|(address, keypair)| (public_base64_key(keypair), address.clone()))
+                .collect();
+            for alias in aliases {
+                if let Some(address) = addresses.get(&alias.public_base64_key) {
+                    keystore.aliases.insert(address.clone(), alias);
+                }
+            }
+        }
+        Ok(keystore)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn aliases_path(&self) -> PathBuf {
+        self.path.with_extension(ALIASES_FILE_EXTENSION)
+    }
+
+    pub fn addresses(&self) -> Vec<String> {
+        self.keys.keys().cloned().collect()
+    }
+
+    pub fn alias(&self, address: &str) -> Option<&str> {
+        self.aliases
+            .get(&normalize_sui_address(address))
+            .map(|alias| alias.alias.as_str())
+    }
+
+    /// The address of `address_or_alias`.
+    pub fn resolve(&self, address_or_alias: &str) -> Result<String, KeystoreError> {
+        if let Some((address, _)) = self
+            .aliases
+            .iter()
+            .find(|(_, alias)| alias.alias == address_or_alias)
+        {
+            return Ok(address.clone());
+        }
+        let address = normalize_sui_address(address_or_alias);
+        if self.keys.contains_key(&address) {
+            Ok(address)
+        } else {
+            Err(KeystoreError::UnknownKey(address_or_alias.to_string()))
+        }
+    }
+
+    /// The signer of an address or alias.
+    pub fn signer(&self, address_or_alias: &str) -> Result<Arc<Keypair>, KeystoreError> {
+        Ok(self.keys[&self.resolve(address_or_alias)?].clone())
+    }
+
+    /// Every key by address.
+    pub fn signers(&self) -> impl Iterator<Item = (&str, Arc<Keypair>)> {
+        self.keys
+            .iter()
+            .map(|(address, keypair)| (address.as_str(), keypair.clone()))
+    }
+
+    /// Adds a key under `alias`, or under the next free `key-N` alias, and
+    /// returns its address.
+    pub fn add(
+        &mut self,
+        keypair: Keypair,
+        alias: Option<String>,
+    ) -> Result<String, KeystoreError> {
+        let address = keypair.address();
+        let alias = match alias {
+            Some(alias) => {
+                if self
+                    .aliases
+                    .iter()
+                    .any(|(other, existing)| existing.alias == alias && *other != address)
+                {
+                    return Err(KeystoreError::AliasExists(alias));
+                }
+                alias
+            }
+            None => match self.aliases.get(&address) {
+                Some(existing) => existing.alias.clone(),
+                None => next_free_alias(self.aliases.values().map(|alias| alias.alias.as_str())),
+            },
+        };
+        self.aliases.insert(
+            address.clone(),
+            Alias {
+                alias,
+                public_base64_key: public_base64_key(&keypair),
+            },
+        );
+        self.keys.insert(address.clone(), Arc::new(keypair));
+        Ok(address)
+    }
+
+    pub fn remove(&mut self, address_or_alias: &str) -> Result<Arc<Keypair>, KeystoreError> {
+        let address = self.resolve(address_or_alias)?;
+        self.aliases.remove(&address);
+        Ok(self
+            .keys
+            .remove(&address)
+            .expect("resolved address has a key"))
+    }
+
+    /// Writes the keystore and its aliases in the CLI's formats.
+    pub fn save(&self) -> Result<(), KeystoreError> {
+        let keys = self
+            .keys
+            .values()
+            .map(|keypair| {
+                private_key::encode_base64_private_key(
+                    keypair.scheme(),
+                    &keypair.secret_key_bytes(),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        write_file(&self.path, &serde_json::to_string_pretty(&keys)?)?;
+        let aliases: Vec<_> = self.aliases.values().collect();
+        write_file(
+            &self.aliases_path(),
+            &serde_json::to_string_pretty(&aliases)?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::SignatureScheme;
+
+    fn keypair(scheme: SignatureScheme, byte: u8) -> Keypair {
+        Keypair::from_secret_key(scheme, &[byte; 32]).unwrap()
+    }
+
+    #[test]
+    fn test_add_assigns_unique_aliases() {
+        let mut keystore = FileKeystore::new("sui.keystore");
+        let first = keystore
+            .add(keypair(SignatureScheme::Ed25519, 1), None)
+            .unwrap();
+        let second = keystore
+            .add(
+                keypair(SignatureScheme::Secp256k1, 1),
+                Some("ops".to_string()),
+            )
+            .unwrap();
+        keystore
+            .add(keypair(SignatureScheme::Secp256r1, 1), None)
+            .unwrap();
+
+        assert_eq!(keystore.alias(&first), Some("key-0"));
+        assert_eq!(keystore.resolve("ops").unwrap(), second);
+        assert_eq!(keystore.signer("ops").unwrap().address(), second);
+        assert!(matches!(
+            keystore.add(
+                keypair(SignatureScheme::Ed25519, 2),
+                Some("ops".to_string())
+            ),
+            Err(KeystoreError::AliasExists(_))
+        ));
+        assert_eq!(keystore.aliases_path(), PathBuf::from("sui.aliases"));
+
+        keystore.remove("key-0").unwrap();
+        assert!(matches!(
+            keystore.signer(&first),
+            Err(KeystoreError::UnknownKey(_))
+        ));
+        assert_eq!(keystore.addresses().len(), 2);
+    }
+}
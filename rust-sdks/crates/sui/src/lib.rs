@@ -6,12 +6,15 @@ pub mod client;
 pub mod cryptography;
 pub mod dynamic_fields;
 pub mod events;
+pub mod executor;
 pub mod faucet;
 pub mod graphql;
 pub mod grpc;
+pub mod intents;
 pub mod jsonrpc;
 pub mod jsonrpc_ws;
 pub mod keypairs;
+pub mod keystore;
 pub mod multisig;
 pub mod pagination;
 pub mod query_builder;
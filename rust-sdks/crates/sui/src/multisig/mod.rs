@@ -1,88 +1,662 @@
-use std::collections::HashSet;
+//! Multisig accounts in the on-chain format: `MultiSigPublicKey` and
+//! `0x03 || bcs(MultiSig)` signatures, whose members may be plain keys,
+//! zkLogin accounts or passkeys.
 
 use serde::{Deserialize, Serialize};
 
-use crate::crypto::SignatureScheme;
-
+use crate::crypto::{self, SignatureScheme};
+use crate::cryptography::{PublicKey, Signer, SignerError, signer};
+use crate::keypairs::passkey::{PasskeyAuthenticator, PasskeyError, PasskeyPublicKey};
 use crate::verify;
+use crate::zklogin::{self, ZkLoginError, ZkLoginPublicIdentifier};
+
+pub const MAX_SIGNER_IN_MULTISIG: usize = 10;
 
 #[derive(Debug, thiserror::Error)]
 pub enum MultiSigError {
     #[error("base64 decode failed: {0}")]
     Base64(#[from] base64::DecodeError),
-    #[error("json error: {0}")]
-    Json(#[from] serde_json::Error),
+    #[error("bcs failed: {0}")]
+    Bcs(#[from] bcs::Error),
     #[error("invalid multisig: {0}")]
     Invalid(String),
     #[error("verify error: {0}")]
     Verify(#[from] verify::VerifyError),
+    #[error(transparent)]
+    Passkey(#[from] PasskeyError),
+    #[error(transparent)]
+    ZkLogin(#[from] ZkLoginError),
+    #[error("zkLogin member signatures can only be verified on chain")]
+    ZkLoginVerification,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct WeightedPublicKey {
-    pub scheme: SignatureScheme,
-    #[serde(rename = "publicKey")]
-    pub public_key: String,
-    pub weight: u16,
+/// `[u8; N]` as a BCS fixed array; serde only derives arrays up to 32 bytes.
+mod fixed_bytes {
+    use serde::de::{self, SeqAccess, Visitor};
+    use serde::ser::SerializeTuple;
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        bytes: &[u8; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for byte in bytes {
+            tuple.serialize_element(byte)?;
+        }
+        tuple.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[u8; N], D::Error> {
+        struct BytesVisitor<const N: usize>;
+
+        impl<'de, const N: usize> Visitor<'de> for BytesVisitor<N> {
+            type Value = [u8; N];
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "{N} bytes")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<[u8; N], A::Error> {
+                let mut bytes = [0u8; N];
+                for (index, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+                }
+                Ok(bytes)
+            }
+        }
+
+        deserializer.deserialize_tuple(N, BytesVisitor::<N>)
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MultiSigPublicKey {
-    #[serde(rename = "publicKeys")]
-    pub public_keys: Vec<WeightedPublicKey>,
-    pub threshold: u16,
+/// A member key as BCS lays it out: the variant index, then the fixed size
+/// key, or the identifier bytes for zkLogin.
+#[derive(Serialize, Deserialize)]
+enum BcsPublicKey {
+    Ed25519(#[serde(with = "fixed_bytes")] [u8; 32]),
+    Secp256k1(#[serde(with = "fixed_bytes")] [u8; 33]),
+    Secp256r1(#[serde(with = "fixed_bytes")] [u8; 33]),
+    ZkLogin(Vec<u8>),
+    Passkey(#[serde(with = "fixed_bytes")] [u8; 33]),
+}
+
+#[derive(Serialize, Deserialize)]
+struct BcsMultiSigPublicKey {
+    pk_map: Vec<(BcsPublicKey, u8)>,
+    threshold: u16,
+}
+
+/// One member's signature inside a [`MultiSig`]: the bare signature for
+/// plain keys, the whole serialized signature for zkLogin and passkeys.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CompressedSignature {
+    Ed25519(#[serde(with = "fixed_bytes")] [u8; 64]),
+    Secp256k1(#[serde(with = "fixed_bytes")] [u8; 64]),
+    Secp256r1(#[serde(with = "fixed_bytes")] [u8; 64]),
+    ZkLogin(Vec<u8>),
+    Passkey(Vec<u8>),
+}
+
+#[derive(Serialize, Deserialize)]
+struct BcsMultiSig {
+    sigs: Vec<CompressedSignature>,
+    bitmap: u16,
+    multisig_pk: BcsMultiSigPublicKey,
+}
+
+fn invalid(message: impl Into<String>) -> MultiSigError {
+    MultiSigError::Invalid(message.into())
+}
+
+fn fixed<const N: usize>(bytes: &[u8]) -> Result<[u8; N], MultiSigError> {
+    bytes
+        .try_into()
+        .map_err(|_| invalid(format!("expected {N} bytes, got {}", bytes.len())))
+}
+
+fn to_bcs_public_key(public_key: &PublicKey) -> Result<BcsPublicKey, MultiSigError> {
+    Ok(match public_key {
+        PublicKey::Ed25519(bytes) => BcsPublicKey::Ed25519(fixed(bytes)?),
+        PublicKey::Secp256k1(bytes) => BcsPublicKey::Secp256k1(fixed(bytes)?),
+        PublicKey::Secp256r1(bytes) => BcsPublicKey::Secp256r1(fixed(bytes)?),
+        PublicKey::ZkLogin(identifier) => BcsPublicKey::ZkLogin(identifier.to_bytes()),
+        PublicKey::Passkey(passkey) => BcsPublicKey::Passkey(fixed(passkey.to_raw_bytes())?),
+        PublicKey::MultiSig(_) => return Err(invalid("a multisig cannot be a member")),
+    })
+}
+
+fn from_bcs_public_key(public_key: BcsPublicKey) -> Result<PublicKey, MultiSigError> {
+    Ok(match public_key {
+        BcsPublicKey::Ed25519(bytes) => PublicKey::Ed25519(bytes.to_vec()),
+        BcsPublicKey::Secp256k1(bytes) => PublicKey::Secp256k1(bytes.to_vec()),
+        BcsPublicKey::Secp256r1(bytes) => PublicKey::Secp256r1(bytes.to_vec()),
+        BcsPublicKey::ZkLogin(bytes) => {
+            PublicKey::ZkLogin(ZkLoginPublicIdentifier::from_bytes(&bytes)?)
+        }
+        BcsPublicKey::Passkey(bytes) => PublicKey::Passkey(PasskeyPublicKey::new(&bytes)?),
+    })
+}
+
+/// `flag || public key` as it is hashed into addresses.
+fn sui_public_key_bytes(public_key: &PublicKey) -> Vec<u8> {
+    let bytes = match public_key {
+        PublicKey::Ed25519(bytes) | PublicKey::Secp256k1(bytes) | PublicKey::Secp256r1(bytes) => {
+            bytes.clone()
+        }
+        PublicKey::ZkLogin(identifier) => identifier.to_bytes(),
+        PublicKey::Passkey(passkey) => passkey.to_raw_bytes().to_vec(),
+        PublicKey::MultiSig(_) => unreachable!("members are checked on construction"),
+    };
+    [vec![public_key.flag()], bytes].concat()
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MultiSigEntry {
-    #[serde(rename = "publicKey")]
-    pub public_key: String,
-    pub signature: String,
+/// Splits a member's serialized signature into the key it is from and its
+/// compressed form.
+fn parse_partial_signature(
+    bytes: &[u8],
+) -> Result<(PublicKey, CompressedSignature), MultiSigError> {
+    let (&flag, rest) = bytes
+        .split_first()
+        .ok_or_else(|| invalid("empty signature"))?;
+    match flag {
+        crypto::ZKLOGIN_SCHEME_FLAG => {
+            let parsed = zklogin::parse_zklogin_signature_bytes(bytes)?;
+            Ok((
+                PublicKey::ZkLogin(parsed.inputs.public_identifier()?),
+                CompressedSignature::ZkLogin(bytes.to_vec()),
+            ))
+        }
+        crypto::PASSKEY_SCHEME_FLAG => {
+            let authenticator = PasskeyAuthenticator::from_bytes(bytes)?;
+            Ok((
+                PublicKey::Passkey(authenticator.public_key()?),
+                CompressedSignature::Passkey(bytes.to_vec()),
+            ))
+        }
+        crypto::MULTISIG_SCHEME_FLAG => Err(invalid("multisig signatures cannot be nested")),
+        flag => {
+            let scheme = SignatureScheme::from_flag(flag)
+                .ok_or_else(|| invalid(format!("unknown signature flag 0x{flag:02x}")))?;
+            if rest.len() < 64 {
+                return Err(invalid(format!("short {scheme:?} signature")));
+            }
+            let (signature, public_key) = rest.split_at(64);
+            let signature = fixed(signature)?;
+            let compressed = match scheme {
+                SignatureScheme::Ed25519 => CompressedSignature::Ed25519(signature),
+                SignatureScheme::Secp256k1 => CompressedSignature::Secp256k1(signature),
+                SignatureScheme::Secp256r1 => CompressedSignature::Secp256r1(signature),
+            };
+            Ok((
+                PublicKey::from_bytes(scheme, public_key.to_vec()),
+                compressed,
+            ))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeightedPublicKey {
+    pub public_key: PublicKey,
+    pub weight: u8,
+}
+
+impl WeightedPublicKey {
+    pub fn new(public_key: PublicKey, weight: u8) -> Self {
+        Self { public_key, weight }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MultiSigSerialized {
-    pub signatures: Vec<MultiSigEntry>,
-    pub bitmap: Vec<usize>,
-    pub threshold: u16,
+/// Members and threshold of a multisig account, checked to be one the
+/// network accepts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiSigPublicKey {
+    public_keys: Vec<WeightedPublicKey>,
+    threshold: u16,
 }
 
 impl MultiSigPublicKey {
-    pub fn verify(&self, message: &[u8], serialized: &str) -> Result<bool, MultiSigError> {
-        let payload: MultiSigSerialized = serde_json::from_str(serialized)?;
-        if payload.threshold != self.threshold {
-            return Err(MultiSigError::Invalid("threshold mismatch".to_string()));
+    pub fn new(public_keys: Vec<WeightedPublicKey>, threshold: u16) -> Result<Self, MultiSigError> {
+        if public_keys.is_empty() || public_keys.len() > MAX_SIGNER_IN_MULTISIG {
+            return Err(invalid(format!(
+                "{} members, expected 1 to {MAX_SIGNER_IN_MULTISIG}",
+                public_keys.len()
+            )));
         }
+        if threshold == 0 {
+            return Err(invalid("threshold must be positive"));
+        }
+        let mut total: u32 = 0;
+        for (index, member) in public_keys.iter().enumerate() {
+            if member.weight == 0 {
+                return Err(invalid(format!("member {index} has zero weight")));
+            }
+            to_bcs_public_key(&member.public_key)?;
+            if public_keys[..index]
+                .iter()
+                .any(|other| other.public_key == member.public_key)
+            {
+                return Err(invalid(format!("member {index} is listed twice")));
+            }
+            total += member.weight as u32;
+        }
+        if total < threshold as u32 {
+            return Err(invalid(format!(
+                "members weigh {total}, below the threshold of {threshold}"
+            )));
+        }
+        Ok(Self {
+            public_keys,
+            threshold,
+        })
+    }
 
-        let mut valid_weight: u32 = 0;
-        let mut used = HashSet::new();
-        for entry in &payload.signatures {
-            if !used.insert(entry.public_key.clone()) {
-                continue;
+    pub fn public_keys(&self) -> &[WeightedPublicKey] {
+        &self.public_keys
+    }
+
+    pub fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    fn to_bcs(&self) -> BcsMultiSigPublicKey {
+        BcsMultiSigPublicKey {
+            pk_map: self
+                .public_keys
+                .iter()
+                .map(|member| {
+                    let key = to_bcs_public_key(&member.public_key)
+                        .expect("members are checked on construction");
+                    (key, member.weight)
+                })
+                .collect(),
+            threshold: self.threshold,
+        }
+    }
+
+    fn from_bcs(public_key: BcsMultiSigPublicKey) -> Result<Self, MultiSigError> {
+        let public_keys = public_key
+            .pk_map
+            .into_iter()
+            .map(|(key, weight)| Ok(WeightedPublicKey::new(from_bcs_public_key(key)?, weight)))
+            .collect::<Result<_, MultiSigError>>()?;
+        Self::new(public_keys, public_key.threshold)
+    }
+
+    /// BCS `MultiSigPublicKey`.
+    pub fn to_raw_bytes(&self) -> Vec<u8> {
+        bcs::to_bytes(&self.to_bcs()).expect("multisig public key serializes")
+    }
+
+    pub fn from_raw_bytes(bytes: &[u8]) -> Result<Self, MultiSigError> {
+        Self::from_bcs(bcs::from_bytes(bytes)?)
+    }
+
+    /// Blake2b-256 over `0x03 || threshold (u16 LE) || (flag || public key || weight)*`.
+    pub fn to_sui_address(&self) -> String {
+        let mut bytes = vec![crypto::MULTISIG_SCHEME_FLAG];
+        bytes.extend_from_slice(&self.threshold.to_le_bytes());
+        for member in &self.public_keys {
+            bytes.extend_from_slice(&sui_public_key_bytes(&member.public_key));
+            bytes.push(member.weight);
+        }
+        format!("0x{}", hex::encode(crypto::blake2b256(&bytes)))
+    }
+
+    fn member_index(&self, public_key: &PublicKey) -> Option<usize> {
+        self.public_keys
+            .iter()
+            .position(|member| member.public_key == *public_key)
+    }
+
+    /// Combines members' serialized signatures, in any order, into one
+    /// multisig signature.
+    pub fn combine_signatures(&self, signatures: &[Vec<u8>]) -> Result<MultiSig, MultiSigError> {
+        let mut members = Vec::with_capacity(signatures.len());
+        for signature in signatures {
+            let (public_key, compressed) = parse_partial_signature(signature)?;
+            let index = self.member_index(&public_key).ok_or_else(|| {
+                invalid(format!(
+                    "{} is not a member",
+                    public_key.to_sui_address()
+                ))
+            })?;
+            if members.iter().any(|(other, _)| *other == index) {
+                return Err(invalid(format!("member {index} signed more than once")));
             }
-            if let Some(weighted) = self.public_keys.iter().find(|pk| pk.public_key == entry.public_key) {
-                use base64::Engine as _;
-                let pk_bytes = base64::engine::general_purpose::STANDARD.decode(&weighted.public_key)?;
-                let sig_bytes = base64::engine::general_purpose::STANDARD.decode(&entry.signature)?;
-                let ok = verify::verify_signature(weighted.scheme, &pk_bytes, message, &sig_bytes)?;
-                if ok {
-                    valid_weight += weighted.weight as u32;
+            members.push((index, compressed));
+        }
+        members.sort_by_key(|(index, _)| *index);
+        Ok(MultiSig {
+            bitmap: members
+                .iter()
+                .fold(0, |bitmap, (index, _)| bitmap | 1 << index),
+            signatures: members
+                .into_iter()
+                .map(|(_, signature)| signature)
+                .collect(),
+            public_key: self.clone(),
+        })
+    }
+
+    /// [`MultiSigPublicKey::combine_signatures`] over base64 signatures, as
+    /// wallets return them.
+    pub fn combine_partial_signatures(
+        &self,
+        signatures: &[impl AsRef<str>],
+    ) -> Result<MultiSig, MultiSigError> {
+        use base64::Engine as _;
+        let signatures = signatures
+            .iter()
+            .map(|signature| base64::engine::general_purpose::STANDARD.decode(signature.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.combine_signatures(&signatures)
+    }
+
+    /// Checks a serialized multisig signature over an intent digest. It must
+    /// be for this public key and carry valid member signatures of at least
+    /// the threshold's weight.
+    pub fn verify(&self, digest: &[u8; 32], signature: &[u8]) -> Result<bool, MultiSigError> {
+        let multisig = MultiSig::from_bytes(signature)?;
+        if multisig.public_key != *self {
+            return Ok(false);
+        }
+        multisig.verify(digest)
+    }
+
+    pub fn verify_transaction(
+        &self,
+        tx_bytes: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, MultiSigError> {
+        self.verify(&signer::transaction_digest(tx_bytes), signature)
+    }
+
+    pub fn verify_personal_message(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, MultiSigError> {
+        self.verify(&signer::personal_message_digest(message)?, signature)
+    }
+}
+
+/// A multisig signature: member signatures in member order, the bitmap of
+/// which members signed, and the multisig public key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiSig {
+    signatures: Vec<CompressedSignature>,
+    bitmap: u16,
+    public_key: MultiSigPublicKey,
+}
+
+impl MultiSig {
+    pub fn signatures(&self) -> &[CompressedSignature] {
+        &self.signatures
+    }
+
+    pub fn bitmap(&self) -> u16 {
+        self.bitmap
+    }
+
+    pub fn public_key(&self) -> &MultiSigPublicKey {
+        &self.public_key
+    }
+
+    /// Indices of the members that signed, ascending.
+    pub fn signer_indices(&self) -> Vec<usize> {
+        (0..MAX_SIGNER_IN_MULTISIG)
+            .filter(|index| self.bitmap & (1 << index) != 0)
+            .collect()
+    }
+
+    /// `0x03 || bcs(MultiSig)`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let multisig = bcs::to_bytes(&BcsMultiSig {
+            sigs: self.signatures.clone(),
+            bitmap: self.bitmap,
+            multisig_pk: self.public_key.to_bcs(),
+        })
+        .expect("multisig serializes");
+        [vec![crypto::MULTISIG_SCHEME_FLAG], multisig].concat()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, MultiSigError> {
+        match bytes.first() {
+            Some(&crypto::MULTISIG_SCHEME_FLAG) => {}
+            Some(&flag) => return Err(invalid(format!("signature flag 0x{flag:02x}"))),
+            None => return Err(invalid("empty signature")),
+        }
+        let multisig: BcsMultiSig = bcs::from_bytes(&bytes[1..])?;
+        let multisig = Self {
+            signatures: multisig.sigs,
+            bitmap: multisig.bitmap,
+            public_key: MultiSigPublicKey::from_bcs(multisig.multisig_pk)?,
+        };
+        if multisig.bitmap >> multisig.public_key.public_keys.len() != 0 {
+            return Err(invalid("bitmap names a member that does not exist"));
+        }
+        if multisig.signer_indices().len() != multisig.signatures.len() {
+            return Err(invalid("bitmap does not match the signature count"));
+        }
+        Ok(multisig)
+    }
+
+    pub fn to_base64(&self) -> String {
+        use base64::Engine as _;
+        base64::engine::general_purpose::STANDARD.encode(self.to_bytes())
+    }
+
+    pub fn from_base64(signature: &str) -> Result<Self, MultiSigError> {
+        use base64::Engine as _;
+        Self::from_bytes(&base64::engine::general_purpose::STANDARD.decode(signature)?)
+    }
+
+    /// Checks every member signature over `digest` and that their weights
+    /// reach the threshold. A single invalid signature fails the multisig, as
+    /// it does on chain.
+    pub fn verify(&self, digest: &[u8; 32]) -> Result<bool, MultiSigError> {
+        let mut weight: u32 = 0;
+        for (index, signature) in self.signer_indices().into_iter().zip(&self.signatures) {
+            let member = &self.public_key.public_keys[index];
+            let valid = match (&member.public_key, signature) {
+                (PublicKey::Ed25519(key), CompressedSignature::Ed25519(signature)) => {
+                    verify::verify_signature(SignatureScheme::Ed25519, key, digest, signature)?
                 }
+                (PublicKey::Secp256k1(key), CompressedSignature::Secp256k1(signature)) => {
+                    verify::verify_signature(SignatureScheme::Secp256k1, key, digest, signature)?
+                }
+                (PublicKey::Secp256r1(key), CompressedSignature::Secp256r1(signature)) => {
+                    verify::verify_signature(SignatureScheme::Secp256r1, key, digest, signature)?
+                }
+                (PublicKey::Passkey(key), CompressedSignature::Passkey(signature)) => {
+                    let authenticator = PasskeyAuthenticator::from_bytes(signature)?;
+                    authenticator.public_key()? == *key && authenticator.verify(digest)?
+                }
+                (PublicKey::ZkLogin(_), CompressedSignature::ZkLogin(_)) => {
+                    return Err(MultiSigError::ZkLoginVerification);
+                }
+                _ => false,
+            };
+            if !valid {
+                return Ok(false);
             }
+            weight += member.weight as u32;
         }
-
-        Ok(valid_weight >= self.threshold as u32)
+        Ok(weight >= self.public_key.threshold as u32)
     }
+}
+
+/// Base64 `0x03 || bcs(MultiSig)`.
+pub fn serialize_multisig(multisig: &MultiSig) -> String {
+    multisig.to_base64()
+}
 
-    pub fn to_raw_bytes(&self) -> Result<Vec<u8>, MultiSigError> {
-        Ok(serde_json::to_vec(self)?)
+pub fn parse_multisig(signature: &str) -> Result<MultiSig, MultiSigError> {
+    MultiSig::from_base64(signature)
+}
+
+/// Signs with enough members of a multisig to meet its threshold.
+pub struct MultiSigSigner {
+    public_key: MultiSigPublicKey,
+    address: String,
+    signers: Vec<Box<dyn Signer>>,
+}
+
+impl MultiSigSigner {
+    pub fn new(
+        public_key: MultiSigPublicKey,
+        signers: Vec<Box<dyn Signer>>,
+    ) -> Result<Self, MultiSigError> {
+        let mut members = Vec::with_capacity(signers.len());
+        let mut weight: u32 = 0;
+        for signer in &signers {
+            let index = public_key
+                .member_index(&signer.public_key())
+                .ok_or_else(|| invalid(format!("{} is not a member", signer.address())))?;
+            if members.contains(&index) {
+                return Err(invalid(format!(
+                    "{} signs more than once",
+                    signer.address()
+                )));
+            }
+            weight += public_key.public_keys[index].weight as u32;
+            members.push(index);
+        }
+        if weight < public_key.threshold as u32 {
+            return Err(invalid(format!(
+                "signers weigh {weight}, below the threshold of {}",
+                public_key.threshold
+            )));
+        }
+        Ok(Self {
+            address: public_key.to_sui_address(),
+            public_key,
+            signers,
+        })
     }
 }
 
-pub fn serialize_multisig(multisig: &MultiSigSerialized) -> Result<String, MultiSigError> {
-    Ok(serde_json::to_string(multisig)?)
+impl Signer for MultiSigSigner {
+    fn public_key(&self) -> PublicKey {
+        PublicKey::MultiSig(self.public_key.clone())
+    }
+
+    fn address(&self) -> String {
+        self.address.clone()
+    }
+
+    /// `0x03 || bcs(MultiSig)` over every signer's signature.
+    fn sign_digest(&self, digest: &[u8; 32]) -> Result<Vec<u8>, SignerError> {
+        let signatures = self
+            .signers
+            .iter()
+            .map(|signer| signer.sign_digest(digest))
+            .collect::<Result<Vec<_>, SignerError>>()?;
+        Ok(self.public_key.combine_signatures(&signatures)?.to_bytes())
+    }
 }
 
-pub fn parse_multisig(data: &str) -> Result<MultiSigSerialized, MultiSigError> {
-    Ok(serde_json::from_str(data)?)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keypairs::{ed25519, secp256k1, secp256r1};
+
+    fn members() -> Vec<WeightedPublicKey> {
+        vec![
+            WeightedPublicKey::new(
+                ed25519::Keypair::from_secret_key(&[1; 32])
+                    .unwrap()
+                    .public_key(),
+                1,
+            ),
+            WeightedPublicKey::new(
+                secp256k1::Keypair::from_secret_key(&[1; 32])
+                    .unwrap()
+                    .public_key(),
+                1,
+            ),
+            WeightedPublicKey::new(
+                secp256r1::Keypair::from_secret_key(&[1; 32])
+                    .unwrap()
+                    .public_key(),
+                2,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_public_key_bcs_layout() {
+        let multisig = MultiSigPublicKey::new(members(), 2).unwrap();
+        let bytes = multisig.to_raw_bytes();
+        // Member count, then `variant || key || weight` per member and the
+        // u16 threshold.
+        assert_eq!(bytes[0], 3);
+        assert_eq!(bytes[1], 0);
+        assert_eq!(bytes[34], 1);
+        assert_eq!(bytes[35], 1);
+        assert_eq!(bytes[35 + 34], 1);
+        assert_eq!(bytes[35 + 35], 2);
+        assert_eq!(&bytes[bytes.len() - 2..], &[2, 0]);
+        assert_eq!(bytes.len(), 1 + 34 + 35 + 35 + 2);
+        assert_eq!(MultiSigPublicKey::from_raw_bytes(&bytes).unwrap(), multisig);
+    }
+
+    #[test]
+    fn test_new_rejects_invalid_members() {
+        assert!(MultiSigPublicKey::new(vec![], 1).is_err());
+        assert!(MultiSigPublicKey::new(members(), 0).is_err());
+        assert!(MultiSigPublicKey::new(members(), 5).is_err());
+
+        let mut duplicated = members();
+        duplicated.push(duplicated[0].clone());
+        assert!(MultiSigPublicKey::new(duplicated, 1).is_err());
+
+        let mut zero = members();
+        zero[1].weight = 0;
+        assert!(MultiSigPublicKey::new(zero, 1).is_err());
+
+        let mut short = members();
+        short[0].public_key = PublicKey::Ed25519(vec![1; 31]);
+        assert!(MultiSigPublicKey::new(short, 1).is_err());
+
+        let inner = MultiSigPublicKey::new(members(), 1).unwrap();
+        assert!(
+            MultiSigPublicKey::new(
+                vec![WeightedPublicKey::new(PublicKey::MultiSig(inner), 1)],
+                1
+            )
+            .is_err()
+        );
+
+        let too_many = (0..=MAX_SIGNER_IN_MULTISIG as u8)
+            .map(|byte| {
+                let keypair = ed25519::Keypair::from_secret_key(&[byte; 32]).unwrap();
+                WeightedPublicKey::new(keypair.public_key(), 1)
+            })
+            .collect();
+        assert!(MultiSigPublicKey::new(too_many, 1).is_err());
+    }
+
+    #[test]
+    fn test_from_bytes_checks_bitmap() {
+        let ed = ed25519::Keypair::from_secret_key(&[1; 32]).unwrap();
+        let multisig = MultiSigPublicKey::new(members(), 1).unwrap();
+        let signature = multisig
+            .combine_signatures(&[ed.sign_digest(&[0; 32]).unwrap()])
+            .unwrap();
+        assert_eq!(signature.bitmap(), 1);
+
+        let mut bytes = signature.to_bytes();
+        let bitmap = bytes.len() - multisig.to_raw_bytes().len() - 2;
+        bytes[bitmap] = 0b11;
+        assert!(MultiSig::from_bytes(&bytes).is_err());
+        bytes[bitmap] = 0b1000;
+        assert!(MultiSig::from_bytes(&bytes).is_err());
+    }
 }
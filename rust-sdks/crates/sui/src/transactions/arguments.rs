@@ -1,56 +1,130 @@
-use serde::{Deserialize, Serialize};
+use std::fmt;
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(tag = "$kind")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A handle to a value inside a programmable transaction.
+///
+/// Object and pure inputs share this type, as they do on chain; which one an
+/// `Input` is lives in the [`CallArg`](super::CallArg) it points to.
+///
+/// JSON uses the `{"$kind": ..}` shape of the TypeScript SDK, while BCS uses the
+/// on-chain enum layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Argument {
-    #[allow(non_snake_case)]
-    GasCoin {
-        GasCoin: bool,
-    },
-    #[allow(non_snake_case)]
-    Input {
-        Input: u32,
-        #[serde(skip_serializing_if = "Option::is_none")]
-        r#type: Option<String>,
-    },
-    #[allow(non_snake_case)]
-    Result {
-        Result: u32,
-    },
-    #[allow(non_snake_case)]
-    NestedResult {
-        NestedResult: (u32, u32, u32),
-    },
-    #[allow(non_snake_case)]
-    Pure {
-        Pure: Vec<u8>,
-    },
+    GasCoin,
+    Input(u16),
+    Result(u16),
+    NestedResult(u16, u16),
 }
 
 impl Argument {
     pub fn gas_coin() -> Self {
-        Argument::GasCoin { GasCoin: true }
+        Argument::GasCoin
     }
 
-    pub fn input(index: u32) -> Self {
-        Argument::Input {
-            Input: index,
-            r#type: None,
+    pub fn input(index: u16) -> Self {
+        Argument::Input(index)
+    }
+
+    pub fn result(index: u16) -> Self {
+        Argument::Result(index)
+    }
+
+    pub fn nested_result(index: u16, result_index: u16) -> Self {
+        Argument::NestedResult(index, result_index)
+    }
+
+    /// Returns the `index`-th value of a command result, e.g. one of the coins
+    /// produced by `SplitCoins`.
+    pub fn nested(&self, index: u16) -> Option<Self> {
+        match self {
+            Argument::Result(result) => Some(Argument::NestedResult(*result, index)),
+            _ => None,
         }
     }
+}
 
-    pub fn result(index: u32) -> Self {
-        Argument::Result { Result: index }
+impl fmt::Display for Argument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Argument::GasCoin => write!(f, "GasCoin"),
+            Argument::Input(index) => write!(f, "Input({index})"),
+            Argument::Result(index) => write!(f, "Result({index})"),
+            Argument::NestedResult(index, result) => write!(f, "NestedResult({index}, {result})"),
+        }
     }
+}
+
+#[derive(Serialize, Deserialize)]
+enum BcsArgument {
+    GasCoin,
+    Input(u16),
+    Result(u16),
+    NestedResult(u16, u16),
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "$kind")]
+enum JsonArgument {
+    GasCoin {
+        #[serde(rename = "GasCoin")]
+        gas_coin: bool,
+    },
+    Input {
+        #[serde(rename = "Input")]
+        index: u16,
+        #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+        kind: Option<String>,
+    },
+    Result {
+        #[serde(rename = "Result")]
+        index: u16,
+    },
+    NestedResult {
+        #[serde(rename = "NestedResult")]
+        index: (u16, u16),
+    },
+}
 
-    pub fn nested_result(index1: u32, index2: u32, index3: u32) -> Self {
-        Argument::NestedResult {
-            NestedResult: (index1, index2, index3),
+impl Serialize for Argument {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let arg = match *self {
+                Argument::GasCoin => JsonArgument::GasCoin { gas_coin: true },
+                Argument::Input(index) => JsonArgument::Input { index, kind: None },
+                Argument::Result(index) => JsonArgument::Result { index },
+                Argument::NestedResult(a, b) => JsonArgument::NestedResult { index: (a, b) },
+            };
+            arg.serialize(serializer)
+        } else {
+            let arg = match *self {
+                Argument::GasCoin => BcsArgument::GasCoin,
+                Argument::Input(index) => BcsArgument::Input(index),
+                Argument::Result(index) => BcsArgument::Result(index),
+                Argument::NestedResult(a, b) => BcsArgument::NestedResult(a, b),
+            };
+            arg.serialize(serializer)
         }
     }
+}
 
-    pub fn pure(bytes: Vec<u8>) -> Self {
-        Argument::Pure { Pure: bytes }
+impl<'de> Deserialize<'de> for Argument {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            Ok(match JsonArgument::deserialize(deserializer)? {
+                JsonArgument::GasCoin { .. } => Argument::GasCoin,
+                JsonArgument::Input { index, .. } => Argument::Input(index),
+                JsonArgument::Result { index } => Argument::Result(index),
+                JsonArgument::NestedResult { index: (a, b) } => Argument::NestedResult(a, b),
+            })
+        } else {
+            Ok(match BcsArgument::deserialize(deserializer)? {
+                BcsArgument::GasCoin => Argument::GasCoin,
+                BcsArgument::Input(index) => Argument::Input(index),
+                BcsArgument::Result(index) => Argument::Result(index),
+                BcsArgument::NestedResult(a, b) => Argument::NestedResult(a, b),
+            })
+        }
     }
 }
 
@@ -61,70 +135,44 @@ mod tests {
     #[test]
     fn test_argument_gas_coin() {
         let arg = Argument::gas_coin();
-        match arg {
-            Argument::GasCoin { .. } => (),
-            _ => panic!("Expected GasCoin"),
-        }
+        assert_eq!(arg, Argument::GasCoin);
     }
 
     #[test]
     fn test_argument_input() {
         let arg = Argument::input(5);
-        assert_eq!(
-            arg,
-            Argument::Input {
-                Input: 5,
-                r#type: None
-            }
-        );
+        assert_eq!(arg, Argument::Input(5));
     }
 
     #[test]
     fn test_argument_result() {
         let arg = Argument::result(3);
-        assert_eq!(arg, Argument::Result { Result: 3 });
+        assert_eq!(arg, Argument::Result(3));
     }
 
     #[test]
     fn test_argument_nested_result() {
-        let arg = Argument::nested_result(0, 1, 2);
-        assert_eq!(
-            arg,
-            Argument::NestedResult {
-                NestedResult: (0, 1, 2)
-            }
-        );
-    }
-
-    #[test]
-    fn test_argument_pure() {
-        let arg = Argument::pure(vec![1, 2, 3, 4]);
-        assert_eq!(
-            arg,
-            Argument::Pure {
-                Pure: vec![1, 2, 3, 4]
-            }
-        );
+        let arg = Argument::nested_result(0, 1);
+        assert_eq!(arg, Argument::NestedResult(0, 1));
+        assert_eq!(Argument::result(0).nested(1), Some(arg));
+        assert_eq!(Argument::input(0).nested(1), None);
     }
 
     #[test]
     fn test_serialize_argument() {
         let arg = Argument::input(5);
         let serialized = serde_json::to_string(&arg).unwrap();
-        assert!(serialized.contains("Input"));
+        assert_eq!(serialized, r#"{"$kind":"Input","Input":5}"#);
+
+        let nested = serde_json::to_string(&Argument::nested_result(1, 2)).unwrap();
+        assert_eq!(nested, r#"{"$kind":"NestedResult","NestedResult":[1,2]}"#);
     }
 
     #[test]
     fn test_deserialize_argument() {
         let json = r#"{"$kind":"Input","Input":5,"type":"pure"}"#;
         let arg: Argument = serde_json::from_str(json).unwrap();
-        assert_eq!(
-            arg,
-            Argument::Input {
-                Input: 5,
-                r#type: Some("pure".to_string())
-            }
-        );
+        assert_eq!(arg, Argument::Input(5));
     }
 
     #[test]
@@ -133,9 +181,18 @@ mod tests {
         let serialized = serde_json::to_string(&arg).unwrap();
         assert!(serialized.contains("GasCoin"));
         let deserialized: Argument = serde_json::from_str(&serialized).unwrap();
-        match deserialized {
-            Argument::GasCoin { .. } => (),
-            _ => panic!("Expected GasCoin"),
-        }
+        assert_eq!(deserialized, Argument::GasCoin);
+    }
+
+    #[test]
+    fn test_argument_bcs_layout() {
+        assert_eq!(bcs::to_bytes(&Argument::GasCoin).unwrap(), vec![0]);
+        assert_eq!(bcs::to_bytes(&Argument::Input(1)).unwrap(), vec![1, 1, 0]);
+        assert_eq!(
+            bcs::to_bytes(&Argument::NestedResult(2, 3)).unwrap(),
+            vec![3, 2, 0, 3, 0]
+        );
+        let arg: Argument = bcs::from_bytes(&[2, 4, 0]).unwrap();
+        assert_eq!(arg, Argument::Result(4));
     }
 }
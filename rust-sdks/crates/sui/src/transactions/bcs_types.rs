@@ -0,0 +1,885 @@
+//! BCS layouts of Sui transaction data.
+//!
+//! These mirror the on-chain `TransactionData` structs byte for byte and are
+//! what gets signed and submitted to a fullnode.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::SUI_ADDRESS_LENGTH;
+pub use super::arguments::Argument;
+use super::commands::{self, CommandKind};
+use super::inputs::{self, ObjectKind};
+use super::types::{self as tx_types, TransactionError};
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize, Deserialize,
+)]
+pub struct Address(pub [u8; SUI_ADDRESS_LENGTH]);
+
+impl Address {
+    pub const ZERO: Self = Self([0; SUI_ADDRESS_LENGTH]);
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl FromStr for Address {
+    type Err = TransactionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let raw = trimmed.strip_prefix("0x").unwrap_or(trimmed);
+        if raw.is_empty() || raw.len() > SUI_ADDRESS_LENGTH * 2 {
+            return Err(TransactionError::InvalidAddress(s.to_string()));
+        }
+        let padded = format!("{:0>width$}", raw, width = SUI_ADDRESS_LENGTH * 2);
+        let bytes =
+            hex::decode(padded).map_err(|_| TransactionError::InvalidAddress(s.to_string()))?;
+        let mut out = [0u8; SUI_ADDRESS_LENGTH];
+        out.copy_from_slice(&bytes);
+        Ok(Self(out))
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(self.0))
+    }
+}
+
+/// A 32-byte digest, BCS-encoded as a length-prefixed byte vector and
+/// rendered in Base58.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Digest(pub [u8; 32]);
+
+impl Digest {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Serialize for Digest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Digest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = Vec::<u8>::deserialize(deserializer)?;
+        let len = bytes.len();
+        let digest: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| serde::de::Error::invalid_length(len, &"32 bytes"))?;
+        Ok(Self(digest))
+    }
+}
+
+impl FromStr for Digest {
+    type Err = TransactionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = bs58::decode(s)
+            .into_vec()
+            .map_err(|_| TransactionError::InvalidDigest(s.to_string()))?;
+        let digest: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| TransactionError::InvalidDigest(s.to_string()))?;
+        Ok(Self(digest))
+    }
+}
+
+impl fmt::Display for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", bs58::encode(self.0).into_string())
+    }
+}
+
+pub type ObjectRef = (Address, u64, Digest);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TypeTag {
+    Bool,
+    U8,
+    U64,
+    U128,
+    Address,
+    Signer,
+    Vector(Box<TypeTag>),
+    Struct(Box<StructTag>),
+    U16,
+    U32,
+    U256,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StructTag {
+    pub address: Address,
+    pub module: String,
+    pub name: String,
+    pub type_params: Vec<TypeTag>,
+}
+
+impl FromStr for TypeTag {
+    type Err = TransactionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = TypeTagParser { input: s, pos: 0 };
+        let tag = parser.parse_type_tag()?;
+        parser.skip_whitespace();
+        if parser.pos != s.len() {
+            return Err(TransactionError::InvalidTypeTag(s.to_string()));
+        }
+        Ok(tag)
+    }
+}
+
+impl FromStr for StructTag {
+    type Err = TransactionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<TypeTag>()? {
+            TypeTag::Struct(tag) => Ok(*tag),
+            _ => Err(TransactionError::InvalidTypeTag(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for TypeTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeTag::Bool => write!(f, "bool"),
+            TypeTag::U8 => write!(f, "u8"),
+            TypeTag::U16 => write!(f, "u16"),
+            TypeTag::U32 => write!(f, "u32"),
+            TypeTag::U64 => write!(f, "u64"),
+            TypeTag::U128 => write!(f, "u128"),
+            TypeTag::U256 => write!(f, "u256"),
+            TypeTag::Address => write!(f, "address"),
+            TypeTag::Signer => write!(f, "signer"),
+            TypeTag::Vector(inner) => write!(f, "vector<{inner}>"),
+            TypeTag::Struct(tag) => write!(f, "{tag}"),
+        }
+    }
+}
+
+impl fmt::Display for StructTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}::{}::{}", self.address, self.module, self.name)?;
+        if !self.type_params.is_empty() {
+            let params: Vec<String> = self.type_params.iter().map(ToString::to_string).collect();
+            write!(f, "<{}>", params.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+struct TypeTagParser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl TypeTagParser<'_> {
+    fn error(&self) -> TransactionError {
+        TransactionError::InvalidTypeTag(self.input.to_string())
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.input[self.pos..].starts_with(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.input[self.pos..].starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn ident(&mut self) -> Result<&str, TransactionError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let rest = &self.input[start..];
+        let len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error());
+        }
+        self.pos += len;
+        Ok(&self.input[start..start + len])
+    }
+
+    fn parse_type_tag(&mut self) -> Result<TypeTag, TransactionError> {
+        let ident = self.ident()?.to_string();
+        if self.eat("::") {
+            let address: Address = ident.parse().map_err(|_| self.error())?;
+            let module = self.ident()?.to_string();
+            if !self.eat("::") {
+                return Err(self.error());
+            }
+            let name = self.ident()?.to_string();
+            let mut type_params = Vec::new();
+            if self.eat("<") {
+                loop {
+                    type_params.push(self.parse_type_tag()?);
+                    if self.eat(">") {
+                        break;
+                    }
+                    if !self.eat(",") {
+                        return Err(self.error());
+                    }
+                }
+            }
+            return Ok(TypeTag::Struct(Box::new(StructTag {
+                address,
+                module,
+                name,
+                type_params,
+            })));
+        }
+
+        match ident.as_str() {
+            "bool" => Ok(TypeTag::Bool),
+            "u8" => Ok(TypeTag::U8),
+            "u16" => Ok(TypeTag::U16),
+            "u32" => Ok(TypeTag::U32),
+            "u64" => Ok(TypeTag::U64),
+            "u128" => Ok(TypeTag::U128),
+            "u256" => Ok(TypeTag::U256),
+            "address" => Ok(TypeTag::Address),
+            "signer" => Ok(TypeTag::Signer),
+            "vector" => {
+                if !self.eat("<") {
+                    return Err(self.error());
+                }
+                let inner = self.parse_type_tag()?;
+                if !self.eat(">") {
+                    return Err(self.error());
+                }
+                Ok(TypeTag::Vector(Box::new(inner)))
+            }
+            _ => Err(self.error()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CallArg {
+    Pure(Vec<u8>),
+    Object(ObjectArg),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ObjectArg {
+    ImmOrOwnedObject(ObjectRef),
+    SharedObject {
+        id: Address,
+        initial_shared_version: u64,
+        mutable: bool,
+    },
+    Receiving(ObjectRef),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProgrammableMoveCall {
+    pub package: Address,
+    pub module: String,
+    pub function: String,
+    pub type_arguments: Vec<TypeTag>,
+    pub arguments: Vec<Argument>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Command {
+    MoveCall(ProgrammableMoveCall),
+    TransferObjects(Vec<Argument>, Argument),
+    SplitCoins(Argument, Vec<Argument>),
+    MergeCoins(Argument, Vec<Argument>),
+    Publish(Vec<Vec<u8>>, Vec<Address>),
+    MakeMoveVec(Option<TypeTag>, Vec<Argument>),
+    Upgrade(Vec<Vec<u8>>, Vec<Address>, Address, Argument),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ProgrammableTransaction {
+    pub inputs: Vec<CallArg>,
+    pub commands: Vec<Command>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransactionKind {
+    ProgrammableTransaction(ProgrammableTransaction),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasData {
+    pub payment: Vec<ObjectRef>,
+    pub owner: Address,
+    pub price: u64,
+    pub budget: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TransactionExpiration {
+    #[default]
+    None,
+    Epoch(u64),
+    ValidDuring {
+        min_epoch: Option<u64>,
+        max_epoch: Option<u64>,
+        min_timestamp: Option<u64>,
+        max_timestamp: Option<u64>,
+        chain: Digest,
+        nonce: u32,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionDataV1 {
+    pub kind: TransactionKind,
+    pub sender: Address,
+    pub gas_data: GasData,
+    pub expiration: TransactionExpiration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransactionData {
+    V1(TransactionDataV1),
+}
+
+impl TransactionData {
+    pub fn to_bytes(&self) -> Result<Vec<u8>, TransactionError> {
+        Ok(bcs::to_bytes(self)?)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, TransactionError> {
+        Ok(bcs::from_bytes(bytes)?)
+    }
+
+    pub fn v1(&self) -> &TransactionDataV1 {
+        match self {
+            TransactionData::V1(v1) => v1,
+        }
+    }
+}
+
+fn decode_base64(value: &str) -> Result<Vec<u8>, TransactionError> {
+    use base64::Engine as _;
+    Ok(base64::engine::general_purpose::STANDARD.decode(value)?)
+}
+
+fn encode_base64(bytes: &[u8]) -> String {
+    use base64::Engine as _;
+    base64::engine::general_purpose::STANDARD.encode(bytes)
+}
+
+fn object_ref(object_id: &str, version: u64, digest: &str) -> Result<ObjectRef, TransactionError> {
+    Ok((object_id.parse()?, version, digest.parse()?))
+}
+
+impl TryFrom<&inputs::CallArg> for CallArg {
+    type Error = TransactionError;
+
+    fn try_from(arg: &inputs::CallArg) -> Result<Self, Self::Error> {
+        match arg {
+            inputs::CallArg::Pure(pure) => Ok(CallArg::Pure(decode_base64(&pure.bytes)?)),
+            inputs::CallArg::Object(ObjectKind::ImmOrOwnedObject(obj)) => Ok(CallArg::Object(
+                ObjectArg::ImmOrOwnedObject(object_ref(&obj.object_id, obj.version, &obj.digest)?),
+            )),
+            inputs::CallArg::Object(ObjectKind::SharedObject(obj)) => {
+                Ok(CallArg::Object(ObjectArg::SharedObject {
+                    id: obj.object_id.parse()?,
+                    initial_shared_version: obj.initial_shared_version,
+                    mutable: obj.mutable,
+                }))
+            }
+            inputs::CallArg::Object(ObjectKind::Receiving(obj)) => Ok(CallArg::Object(
+                ObjectArg::Receiving(object_ref(&obj.object_id, obj.version, &obj.digest)?),
+            )),
+            inputs::CallArg::FundsWithdrawal(_) => Err(TransactionError::Unsupported(
+                "FundsWithdrawal inputs".to_string(),
+            )),
+            inputs::CallArg::UnresolvedObject(obj) => {
+                Err(TransactionError::UnresolvedObject(obj.object_id.clone()))
+            }
+            inputs::CallArg::UnresolvedPure(pure) => {
+                Err(TransactionError::UnresolvedPure(pure.value.to_string()))
+            }
+        }
+    }
+}
+
+impl From<&CallArg> for inputs::CallArg {
+    fn from(arg: &CallArg) -> Self {
+        match arg {
+            CallArg::Pure(bytes) => inputs::CallArg::pure(bytes.clone()),
+            CallArg::Object(ObjectArg::ImmOrOwnedObject((id, version, digest))) => {
+                inputs::CallArg::Object(ObjectKind::ImmOrOwnedObject(inputs::ImmOrOwnedObject {
+                    digest: digest.to_string(),
+                    version: *version,
+                    object_id: id.to_string(),
+                }))
+            }
+            CallArg::Object(ObjectArg::SharedObject {
+                id,
+                initial_shared_version,
+                mutable,
+            }) => inputs::CallArg::Object(ObjectKind::SharedObject(inputs::SharedObject {
+                mutable: *mutable,
+                initial_shared_version: *initial_shared_version,
+                object_id: id.to_string(),
+            })),
+            CallArg::Object(ObjectArg::Receiving((id, version, digest))) => {
+                inputs::CallArg::Object(ObjectKind::Receiving(inputs::ReceivingRef {
+                    digest: digest.to_string(),
+                    version: *version,
+                    object_id: id.to_string(),
+                }))
+            }
+        }
+    }
+}
+
+impl TryFrom<&CommandKind> for Command {
+    type Error = TransactionError;
+
+    fn try_from(kind: &CommandKind) -> Result<Self, Self::Error> {
+        let modules = |modules: &[String]| -> Result<Vec<Vec<u8>>, TransactionError> {
+            modules.iter().map(|m| decode_base64(m)).collect()
+        };
+        let addresses = |ids: &[String]| -> Result<Vec<Address>, TransactionError> {
+            ids.iter().map(|id| id.parse()).collect()
+        };
+        match kind {
+            CommandKind::MoveCall(call) => Ok(Command::MoveCall(ProgrammableMoveCall {
+                package: call.package.parse()?,
+                module: call.module.clone(),
+                function: call.function.clone(),
+                type_arguments: call
+                    .type_arguments
+                    .iter()
+                    .map(|t| t.parse())
+                    .collect::<Result<_, _>>()?,
+                arguments: call.arguments.clone(),
+            })),
+            CommandKind::TransferObjects(cmd) => {
+                Ok(Command::TransferObjects(cmd.objects.clone(), cmd.address))
+            }
+            CommandKind::SplitCoins(cmd) => Ok(Command::SplitCoins(cmd.coin, cmd.amounts.clone())),
+            CommandKind::MergeCoins(cmd) => {
+                Ok(Command::MergeCoins(cmd.destination, cmd.sources.clone()))
+            }
+            CommandKind::Publish(cmd) => Ok(Command::Publish(
+                modules(&cmd.modules)?,
+                addresses(&cmd.dependencies)?,
+            )),
+            CommandKind::MakeMoveVec(cmd) => Ok(Command::MakeMoveVec(
+                cmd.type_arg.as_deref().map(str::parse).transpose()?,
+                cmd.elements.clone(),
+            )),
+            CommandKind::Upgrade(cmd) => Ok(Command::Upgrade(
+                modules(&cmd.modules)?,
+                addresses(&cmd.dependencies)?,
+                cmd.package.parse()?,
+                cmd.ticket,
+            )),
+            CommandKind::Intent(intent) => {
+                Err(TransactionError::UnresolvedIntent(intent.name.clone()))
+            }
+        }
+    }
+}
+
+impl From<&Command> for CommandKind {
+    fn from(command: &Command) -> Self {
+        let modules =
+            |modules: &[Vec<u8>]| modules.iter().map(|m| encode_base64(m)).collect::<Vec<_>>();
+        let addresses = |ids: &[Address]| ids.iter().map(ToString::to_string).collect::<Vec<_>>();
+        match command {
+            Command::MoveCall(call) => CommandKind::MoveCall(commands::MoveCall {
+                package: call.package.to_string(),
+                module: call.module.clone(),
+                function: call.function.clone(),
+                type_arguments: call
+                    .type_arguments
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+                arguments: call.arguments.clone(),
+            }),
+            Command::TransferObjects(objects, address) => {
+                CommandKind::TransferObjects(commands::TransferObjects {
+                    objects: objects.clone(),
+                    address: *address,
+                })
+            }
+            Command::SplitCoins(coin, amounts) => CommandKind::SplitCoins(commands::SplitCoins {
+                coin: *coin,
+                amounts: amounts.clone(),
+            }),
+            Command::MergeCoins(destination, sources) => {
+                CommandKind::MergeCoins(commands::MergeCoins {
+                    destination: *destination,
+                    sources: sources.clone(),
+                })
+            }
+            Command::Publish(modules_bytes, dependencies) => {
+                CommandKind::Publish(commands::Publish {
+                    modules: modules(modules_bytes),
+                    dependencies: addresses(dependencies),
+                })
+            }
+            Command::MakeMoveVec(type_arg, elements) => {
+                CommandKind::MakeMoveVec(commands::MakeMoveVec {
+                    type_arg: type_arg.as_ref().map(ToString::to_string),
+                    elements: elements.clone(),
+                })
+            }
+            Command::Upgrade(modules_bytes, dependencies, package, ticket) => {
+                CommandKind::Upgrade(commands::Upgrade {
+                    modules: modules(modules_bytes),
+                    dependencies: addresses(dependencies),
+                    package: package.to_string(),
+                    ticket: *ticket,
+                })
+            }
+        }
+    }
+}
+
+impl TryFrom<&tx_types::TransactionExpiration> for TransactionExpiration {
+    type Error = TransactionError;
+
+    fn try_from(expiration: &tx_types::TransactionExpiration) -> Result<Self, Self::Error> {
+        Ok(match expiration {
+            tx_types::TransactionExpiration::None => Self::None,
+            tx_types::TransactionExpiration::Epoch(epoch) => Self::Epoch(*epoch),
+            tx_types::TransactionExpiration::ValidDuring {
+                min_epoch,
+                max_epoch,
+                min_timestamp,
+                max_timestamp,
+                chain,
+                nonce,
+            } => Self::ValidDuring {
+                min_epoch: *min_epoch,
+                max_epoch: *max_epoch,
+                min_timestamp: *min_timestamp,
+                max_timestamp: *max_timestamp,
+                chain: chain.parse()?,
+                nonce: *nonce,
+            },
+        })
+    }
+}
+
+impl From<&TransactionExpiration> for tx_types::TransactionExpiration {
+    fn from(expiration: &TransactionExpiration) -> Self {
+        match expiration {
+            TransactionExpiration::None => Self::None,
+            TransactionExpiration::Epoch(epoch) => Self::Epoch(*epoch),
+            TransactionExpiration::ValidDuring {
+                min_epoch,
+                max_epoch,
+                min_timestamp,
+                max_timestamp,
+                chain,
+                nonce,
+            } => Self::ValidDuring {
+                min_epoch: *min_epoch,
+                max_epoch: *max_epoch,
+                min_timestamp: *min_timestamp,
+                max_timestamp: *max_timestamp,
+                chain: chain.to_string(),
+                nonce: *nonce,
+            },
+        }
+    }
+}
+
+impl TryFrom<&tx_types::TransactionData> for ProgrammableTransaction {
+    type Error = TransactionError;
+
+    fn try_from(data: &tx_types::TransactionData) -> Result<Self, Self::Error> {
+        // Arguments hold u16 indices, so the builder could not address anything past them.
+        let max = usize::from(u16::MAX) + 1;
+        for (kind, count) in [("inputs", data.inputs.len()), ("commands", data.commands.len())] {
+            if count > max {
+                return Err(TransactionError::TooManyArgumentTargets { kind, count, max });
+            }
+        }
+        let inputs = data
+            .inputs
+            .iter()
+            .map(CallArg::try_from)
+            .collect::<Result<_, _>>()?;
+        let commands = data
+            .commands
+            .iter()
+            .map(|command| Command::try_from(&command.kind))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { inputs, commands })
+    }
+}
+
+impl TryFrom<&tx_types::TransactionData> for TransactionData {
+    type Error = TransactionError;
+
+    fn try_from(data: &tx_types::TransactionData) -> Result<Self, Self::Error> {
+        let sender: Address = data
+            .sender
+            .as_deref()
+            .ok_or(TransactionError::MissingSender)?
+            .parse()?;
+        let gas = &data.gas_data;
+        let budget = gas
+            .budget
+            .as_deref()
+            .ok_or(TransactionError::MissingGasBudget)?
+            .parse()
+            .map_err(|_| TransactionError::InvalidGasData("budget".to_string()))?;
+        let price = gas
+            .price
+            .as_deref()
+            .ok_or(TransactionError::MissingGasPrice)?
+            .parse()
+            .map_err(|_| TransactionError::InvalidGasData("price".to_string()))?;
+        let payment = gas
+            .payment
+            .as_ref()
+            .ok_or(TransactionError::MissingGasPayment)?
+            .iter()
+            .map(|obj| object_ref(&obj.object_id, obj.version, &obj.digest))
+            .collect::<Result<_, _>>()?;
+        let owner = match gas.owner.as_deref() {
+            Some(owner) => owner.parse()?,
+            None => sender,
+        };
+
+        Ok(TransactionData::V1(TransactionDataV1 {
+            kind: TransactionKind::ProgrammableTransaction(ProgrammableTransaction::try_from(
+                data,
+            )?),
+            sender,
+            gas_data: GasData {
+                payment,
+                owner,
+                price,
+                budget,
+            },
+            expiration: match &data.expiration {
+                Some(expiration) => expiration.try_into()?,
+                None => TransactionExpiration::None,
+            },
+        }))
+    }
+}
+
+impl From<&TransactionData> for tx_types::TransactionData {
+    fn from(data: &TransactionData) -> Self {
+        let v1 = data.v1();
+        let TransactionKind::ProgrammableTransaction(ptb) = &v1.kind;
+        let payment = v1
+            .gas_data
+            .payment
+            .iter()
+            .map(|(id, version, digest)| inputs::ObjectRef {
+                object_id: id.to_string(),
+                digest: digest.to_string(),
+                version: *version,
+            })
+            .collect();
+
+        tx_types::TransactionData {
+            sender: Some(v1.sender.to_string()),
+            expiration: Some((&v1.expiration).into()),
+            gas_data: tx_types::GasData {
+                owner: Some(v1.gas_data.owner.to_string()),
+                price: Some(v1.gas_data.price.to_string()),
+                budget: Some(v1.gas_data.budget.to_string()),
+                payment: Some(payment),
+            },
+            inputs: ptb.inputs.iter().map(Into::into).collect(),
+            commands: ptb
+                .commands
+                .iter()
+                .map(|command| commands::Command {
+                    kind: command.into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digest(byte: u8) -> Digest {
+        Digest([byte; 32])
+    }
+
+    #[test]
+    fn test_type_tag_roundtrip() {
+        let tag: TypeTag = "0x2::coin::Coin<0x2::sui::SUI>".parse().unwrap();
+        assert_eq!(
+            tag.to_string(),
+            format!("0x{:0>64}::coin::Coin<0x{:0>64}::sui::SUI>", "2", "2")
+        );
+
+        let nested: TypeTag = "vector<vector<u8>>".parse().unwrap();
+        assert_eq!(
+            nested,
+            TypeTag::Vector(Box::new(TypeTag::Vector(Box::new(TypeTag::U8))))
+        );
+
+        let pair: StructTag = "0x1::pair::Pair<u64, 0x1::string::String>".parse().unwrap();
+        assert_eq!(pair.type_params.len(), 2);
+
+        assert!("0x2::coin".parse::<TypeTag>().is_err());
+        assert!("vector<u8".parse::<TypeTag>().is_err());
+        assert!("u512".parse::<TypeTag>().is_err());
+    }
+
+    #[test]
+    fn test_type_tag_bcs_layout() {
+        assert_eq!(bcs::to_bytes(&TypeTag::U16).unwrap(), vec![8]);
+        assert_eq!(bcs::to_bytes(&TypeTag::U256).unwrap(), vec![10]);
+
+        let sui: TypeTag = "0x2::sui::SUI".parse().unwrap();
+        let mut expected = vec![7];
+        expected.extend_from_slice(&[0u8; 31]);
+        expected.push(2);
+        expected.extend_from_slice(&[3, b's', b'u', b'i', 3, b'S', b'U', b'I', 0]);
+        assert_eq!(bcs::to_bytes(&sui).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_digest_base58_roundtrip() {
+        let d = digest(7);
+        let encoded = d.to_string();
+        assert_eq!(encoded.parse::<Digest>().unwrap(), d);
+        assert!("not-base58!".parse::<Digest>().is_err());
+
+        let bytes = bcs::to_bytes(&d).unwrap();
+        assert_eq!(bytes[0], 32);
+        assert_eq!(bytes.len(), 33);
+    }
+
+    #[test]
+    fn test_address_parse() {
+        let addr: Address = "0x2".parse().unwrap();
+        assert_eq!(addr.0[31], 2);
+        assert_eq!(addr.to_string(), format!("0x{:0>64}", "2"));
+        assert!("0xzz".parse::<Address>().is_err());
+        assert!(format!("0x{}", "1".repeat(65)).parse::<Address>().is_err());
+    }
+
+    #[test]
+    fn test_transaction_data_bcs_layout() {
+        let sender: Address = "0xa".parse().unwrap();
+        let gas_id: Address = "0xb".parse().unwrap();
+        let data = TransactionData::V1(TransactionDataV1 {
+            kind: TransactionKind::ProgrammableTransaction(ProgrammableTransaction {
+                inputs: vec![CallArg::Pure(100u64.to_le_bytes().to_vec())],
+                commands: vec![
+                    Command::SplitCoins(Argument::GasCoin, vec![Argument::Input(0)]),
+                    Command::TransferObjects(vec![Argument::NestedResult(0, 0)], Argument::GasCoin),
+                ],
+            }),
+            sender,
+            gas_data: GasData {
+                payment: vec![(gas_id, 3, digest(1))],
+                owner: sender,
+                price: 1000,
+                budget: 5_000_000,
+            },
+            expiration: TransactionExpiration::None,
+        });
+
+        let mut expected = vec![
+            0x00, // TransactionData::V1
+            0x00, // TransactionKind::ProgrammableTransaction
+            0x01, // inputs
+            0x00, 0x08, 100, 0, 0, 0, 0, 0, 0, 0,    // CallArg::Pure(u64)
+            0x02, // commands
+            0x02, 0x00, 0x01, 0x01, 0x00, 0x00, // SplitCoins(GasCoin, [Input(0)])
+            0x01, 0x01, 0x03, 0x00, 0x00, 0x00, 0x00,
+            0x00, // TransferObjects([NestedResult(0,0)], GasCoin)
+        ];
+        expected.extend_from_slice(sender.as_bytes());
+        expected.push(0x01);
+        expected.extend_from_slice(gas_id.as_bytes());
+        expected.extend_from_slice(&3u64.to_le_bytes());
+        expected.push(32);
+        expected.extend_from_slice(&[1u8; 32]);
+        expected.extend_from_slice(sender.as_bytes());
+        expected.extend_from_slice(&1000u64.to_le_bytes());
+        expected.extend_from_slice(&5_000_000u64.to_le_bytes());
+        expected.push(0x00); // TransactionExpiration::None
+
+        let bytes = data.to_bytes().unwrap();
+        assert_eq!(bytes, expected);
+        assert_eq!(TransactionData::from_bytes(&bytes).unwrap(), data);
+    }
+
+    #[test]
+    fn test_shared_object_and_move_call_layout() {
+        let call = Command::MoveCall(ProgrammableMoveCall {
+            package: "0x2".parse().unwrap(),
+            module: "clock".to_string(),
+            function: "timestamp_ms".to_string(),
+            type_arguments: vec![],
+            arguments: vec![Argument::Input(0)],
+        });
+        let bytes = bcs::to_bytes(&call).unwrap();
+        assert_eq!(bytes[0], 0);
+        assert_eq!(&bytes[33..39], &[5, b'c', b'l', b'o', b'c', b'k']);
+
+        let clock = CallArg::Object(ObjectArg::SharedObject {
+            id: "0x6".parse().unwrap(),
+            initial_shared_version: 1,
+            mutable: false,
+        });
+        let bytes = bcs::to_bytes(&clock).unwrap();
+        assert_eq!(&bytes[..2], &[1, 1]);
+        assert_eq!(bytes[33], 6);
+        assert_eq!(&bytes[34..42], &1u64.to_le_bytes());
+        assert_eq!(bytes[42], 0);
+    }
+
+    #[test]
+    fn test_valid_during_expiration_layout() {
+        let expiration = TransactionExpiration::ValidDuring {
+            min_epoch: Some(5),
+            max_epoch: None,
+            min_timestamp: None,
+            max_timestamp: None,
+            chain: digest(9),
+            nonce: 7,
+        };
+        let mut expected = vec![0x02, 0x01];
+        expected.extend_from_slice(&5u64.to_le_bytes());
+        expected.extend_from_slice(&[0x00, 0x00, 0x00, 32]);
+        expected.extend_from_slice(&[9u8; 32]);
+        expected.extend_from_slice(&7u32.to_le_bytes());
+        assert_eq!(bcs::to_bytes(&expiration).unwrap(), expected);
+
+        let typed: tx_types::TransactionExpiration = (&expiration).into();
+        assert_eq!(TransactionExpiration::try_from(&typed).unwrap(), expiration);
+    }
+
+    #[test]
+    fn test_unknown_transaction_kind_is_rejected() {
+        assert!(TransactionData::from_bytes(&[0x00, 0x01]).is_err());
+    }
+}
@@ -1,15 +1,51 @@
-use serde_json::{json, Value};
+use serde::Serialize;
+use serde_json::Value;
 
-use crate::crypto;
+use crate::cryptography::{AsyncSigner, Signer};
 use crate::keypairs::{ed25519, secp256k1, secp256r1};
 use crate::jsonrpc;
-use super::types::{SignedTransaction, Transaction, TransactionError};
+use super::arguments::Argument;
+use super::bcs_types;
+use super::commands::{Command, MoveCallTarget, TransactionCommands};
+use super::hash;
+use super::inputs::{CallArg, ObjectRef, ReceivingRef, SharedObjectRef};
+use super::pure::PureValue;
+use super::types::{SignedTransaction, Transaction, TransactionError, TransactionExpiration};
 
 impl Transaction {
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Decodes BCS `TransactionData`, e.g. bytes handed over for signing.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, TransactionError> {
+        let data = bcs_types::TransactionData::from_bytes(bytes)?;
+        Ok(Self {
+            data: (&data).into(),
+            ..Self::default()
+        })
+    }
+
+    /// Decodes BCS `TransactionKind` bytes, leaving sender and gas unset.
+    pub fn from_kind(bytes: &[u8]) -> Result<Self, TransactionError> {
+        let bcs_types::TransactionKind::ProgrammableTransaction(ptb) = bcs::from_bytes(bytes)?;
+        let mut tx = Self::new();
+        tx.data.inputs = ptb.inputs.iter().map(Into::into).collect();
+        tx.data.commands = ptb
+            .commands
+            .iter()
+            .map(|command| Command {
+                kind: command.into(),
+            })
+            .collect();
+        Ok(tx)
+    }
+
+    pub fn from_base64(encoded: &str) -> Result<Self, TransactionError> {
+        use base64::Engine as _;
+        Self::from_bytes(&base64::engine::general_purpose::STANDARD.decode(encoded)?)
+    }
+
     pub fn set_sender(&mut self, sender: impl Into<String>) {
         self.data.sender = Some(sender.into());
     }
@@ -32,88 +68,141 @@ impl Transaction {
         self.data.gas_data.owner = Some(owner.into());
     }
 
-    pub fn set_gas_payment(&mut self, payment: Vec<Value>) {
+    pub fn set_gas_payment(&mut self, payment: Vec<ObjectRef>) {
         self.data.gas_data.payment = Some(payment);
     }
 
-    pub fn set_expiration(&mut self, expiration: Value) {
+    pub fn set_expiration(&mut self, expiration: TransactionExpiration) {
         self.data.expiration = Some(expiration);
     }
 
-    pub fn gas() -> Value {
-        json!({"$kind":"GasCoin","GasCoin":true})
+    pub fn gas() -> Argument {
+        Argument::GasCoin
     }
 
-    pub fn add_input(&mut self, input: Value) -> Value {
+    pub fn add_input(&mut self, input: CallArg) -> Argument {
+        // Object inputs may only appear once per transaction; later uses are
+        // merged into the first.
+        let existing = input.object_id().and_then(|object_id| {
+            self.data
+                .inputs
+                .iter()
+                .position(|other| other.object_id() == Some(object_id))
+        });
+        if let Some(index) = existing {
+            self.data.inputs[index].merge_object(input);
+            return Argument::Input(argument_index(index));
+        }
         self.data.inputs.push(input);
-        json!({"$kind":"Input","Input": self.data.inputs.len() - 1})
+        Argument::Input(argument_index(self.data.inputs.len() - 1))
     }
 
-    pub fn object(&mut self, object_id: impl Into<String>) -> Value {
-        self.add_input(json!({
-            "$kind":"UnresolvedObject",
-            "UnresolvedObject":{"objectId": object_id.into()}
-        }))
+    pub fn object(&mut self, object_id: impl Into<String>) -> Argument {
+        self.add_input(CallArg::unresolved_object(&object_id.into()))
     }
 
-    pub fn pure_bytes(&mut self, bytes: &[u8]) -> Value {
-        use base64::Engine as _;
-        self.add_input(json!({
-            "$kind":"Pure",
-            "Pure":{"bytes": base64::engine::general_purpose::STANDARD.encode(bytes)}
-        }))
+    pub fn object_ref(&mut self, object_ref: ObjectRef) -> Argument {
+        self.add_input(CallArg::object_ref(object_ref))
+    }
+
+    pub fn shared_object_ref(&mut self, shared_ref: SharedObjectRef) -> Argument {
+        self.add_input(CallArg::shared_object_ref(shared_ref))
+    }
+
+    pub fn receiving_ref(&mut self, receiving_ref: ReceivingRef) -> Argument {
+        self.add_input(CallArg::receiving_ref(receiving_ref))
+    }
+
+    pub fn pure_bytes(&mut self, bytes: &[u8]) -> Argument {
+        self.add_input(CallArg::pure(bytes.to_vec()))
+    }
+
+    /// A pure input BCS-encoded from any serializable value, e.g. `&5u64`,
+    /// `&Some(vec![1u8])` or a [`bcs_types::Address`].
+    pub fn pure<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<Argument, TransactionError> {
+        Ok(self.pure_bytes(&bcs::to_bytes(value)?))
     }
 
-    pub fn add_command(&mut self, command: Value) -> Value {
+    /// A pure input for Move types without a Rust counterpart, such as
+    /// `u256`, `0x2::object::ID` or `0x1::ascii::String`.
+    pub fn pure_typed(&mut self, value: &PureValue) -> Result<Argument, TransactionError> {
+        Ok(self.pure_bytes(&value.serialize()?))
+    }
+
+    /// A pure input given as a plain value, encoded by [`Transaction::resolve`]
+    /// as the type of the parameter it is passed to. Integers wider than `u64`
+    /// are given as decimal strings.
+    pub fn pure_value(&mut self, value: impl Into<Value>) -> Argument {
+        self.add_input(CallArg::unresolved_pure(value.into()))
+    }
+
+    pub fn add_command(&mut self, command: Command) -> Argument {
         self.data.commands.push(command);
-        json!({"$kind":"Result","Result": self.data.commands.len() - 1})
+        Argument::Result(argument_index(self.data.commands.len() - 1))
     }
 
+    /// Fails with [`TransactionError::InvalidMoveCallTarget`] unless `target`
+    /// has the form `package::module::function`.
     pub fn move_call(
         &mut self,
-        target: &str,
-        arguments: Vec<Value>,
+        target: impl TryInto<MoveCallTarget, Error = TransactionError>,
+        arguments: Vec<Argument>,
         type_arguments: Vec<String>,
-    ) -> Value {
-        let mut parts = target.split("::");
-        let package = parts.next().unwrap_or_default();
-        let module = parts.next().unwrap_or_default();
-        let function = parts.next().unwrap_or_default();
-        self.add_command(json!({
-            "$kind":"MoveCall",
-            "MoveCall":{
-                "package": package,
-                "module": module,
-                "function": function,
-                "arguments": arguments,
-                "typeArguments": type_arguments
-            }
-        }))
-    }
-
-    pub fn transfer_objects(&mut self, objects: Vec<Value>, address: Value) -> Value {
-        self.add_command(json!({
-            "$kind":"TransferObjects",
-            "TransferObjects":{"objects": objects, "address": address}
-        }))
-    }
-
-    pub fn split_coins(&mut self, coin: Value, amounts: Vec<Value>) -> Value {
-        self.add_command(json!({
-            "$kind":"SplitCoins",
-            "SplitCoins":{"coin": coin, "amounts": amounts}
-        }))
-    }
-
-    pub fn merge_coins(&mut self, destination: Value, sources: Vec<Value>) -> Value {
-        self.add_command(json!({
-            "$kind":"MergeCoins",
-            "MergeCoins":{"destination": destination, "sources": sources}
-        }))
+    ) -> Result<Argument, TransactionError> {
+        Ok(self.add_command(TransactionCommands::move_call(
+            target,
+            arguments,
+            type_arguments,
+        )?))
+    }
+
+    pub fn transfer_objects(&mut self, objects: Vec<Argument>, address: Argument) -> Argument {
+        self.add_command(TransactionCommands::transfer_objects(objects, address))
+    }
+
+    pub fn split_coins(&mut self, coin: Argument, amounts: Vec<Argument>) -> Argument {
+        self.add_command(TransactionCommands::split_coins(coin, amounts))
+    }
+
+    pub fn merge_coins(&mut self, destination: Argument, sources: Vec<Argument>) -> Argument {
+        self.add_command(TransactionCommands::merge_coins(destination, sources))
+    }
+
+    pub fn make_move_vec(&mut self, type_arg: Option<String>, elements: Vec<Argument>) -> Argument {
+        self.add_command(TransactionCommands::make_move_vec(type_arg, elements))
+    }
+
+    /// Returns the package's `UpgradeCap`.
+    pub fn publish(&mut self, modules: Vec<Vec<u8>>, dependencies: Vec<String>) -> Argument {
+        self.add_command(TransactionCommands::publish(modules, dependencies))
+    }
+
+    /// Returns the `UpgradeReceipt` to pass to `0x2::package::commit_upgrade`.
+    pub fn upgrade(
+        &mut self,
+        modules: Vec<Vec<u8>>,
+        dependencies: Vec<String>,
+        package_id: impl Into<String>,
+        ticket: Argument,
+    ) -> Argument {
+        self.add_command(TransactionCommands::upgrade(
+            modules,
+            dependencies,
+            package_id.into(),
+            ticket,
+        ))
     }
 
+    /// Serializes the transaction to BCS as it is. Transactions with plugins
+    /// registered are built with [`Transaction::build_async`], which runs
+    /// them first; until then this fails with
+    /// [`TransactionError::PluginsNotRun`].
     pub fn build(&self) -> Result<Vec<u8>, TransactionError> {
-        Ok(serde_json::to_vec(&self.data)?)
+        self.check_plugins_ran()?;
+        bcs_types::TransactionData::try_from(&self.data)?.to_bytes()
     }
 
     pub fn build_base64(&self) -> Result<String, TransactionError> {
@@ -121,56 +210,95 @@ impl Transaction {
         Ok(base64::engine::general_purpose::STANDARD.encode(self.build()?))
     }
 
+    /// BCS `TransactionKind` without sender, gas or expiration, as taken by
+    /// `sui_devInspectTransactionBlock` and gas sponsors.
+    pub fn build_kind(&self) -> Result<Vec<u8>, TransactionError> {
+        self.check_plugins_ran()?;
+        let kind = bcs_types::TransactionKind::ProgrammableTransaction(
+            bcs_types::ProgrammableTransaction::try_from(&self.data)?,
+        );
+        Ok(bcs::to_bytes(&kind)?)
+    }
+
+    pub fn build_kind_base64(&self) -> Result<String, TransactionError> {
+        use base64::Engine as _;
+        Ok(base64::engine::general_purpose::STANDARD.encode(self.build_kind()?))
+    }
+
+    fn check_plugins_ran(&self) -> Result<(), TransactionError> {
+        if self.plugins.is_pending() {
+            return Err(TransactionError::PluginsNotRun(self.plugins.names()));
+        }
+        Ok(())
+    }
+
+    /// Base58 digest the network will assign to this transaction.
+    pub fn digest(&self) -> Result<String, TransactionError> {
+        Ok(hash::encode_digest(hash::transaction_digest(&self.build()?)))
+    }
+
+    /// Signs the built transaction with any account type.
+    pub fn sign(&self, signer: &dyn Signer) -> Result<SignedTransaction, TransactionError> {
+        let tx_bytes = self.build()?;
+        let signature = signer.sign_transaction(&tx_bytes)?;
+        Ok(SignedTransaction::new(&tx_bytes, signature))
+    }
+
+    /// Like [`Transaction::sign`], for signers that sign remotely.
+    pub async fn sign_async(&self, signer: &dyn AsyncSigner) -> Result<SignedTransaction, TransactionError> {
+        let tx_bytes = self.build()?;
+        let signature = signer.sign_transaction(&tx_bytes).await?;
+        Ok(SignedTransaction::new(&tx_bytes, signature))
+    }
+
     pub fn sign_with_ed25519(&self, keypair: &ed25519::Keypair) -> Result<SignedTransaction, TransactionError> {
-        self.sign_with_signer(0x00, |msg| keypair.sign(msg), keypair.public_key_bytes())
+        self.sign(keypair)
     }
 
     pub fn sign_with_secp256k1(
         &self,
         keypair: &secp256k1::Keypair,
     ) -> Result<SignedTransaction, TransactionError> {
-        self.sign_with_signer(0x01, |msg| keypair.sign(msg), keypair.public_key_bytes())
+        self.sign(keypair)
     }
 
     pub fn sign_with_secp256r1(
         &self,
         keypair: &secp256r1::Keypair,
     ) -> Result<SignedTransaction, TransactionError> {
-        self.sign_with_signer(0x02, |msg| keypair.sign(msg), keypair.public_key_bytes())
+        self.sign(keypair)
     }
+}
 
-    fn sign_with_signer<F>(
-        &self,
-        scheme_flag: u8,
-        signer: F,
-        public_key: Vec<u8>,
-    ) -> Result<SignedTransaction, TransactionError>
-    where
-        F: FnOnce(&[u8]) -> Vec<u8>,
-    {
-        let tx_bytes = self.build()?;
-        let msg = crypto::message_with_intent([0, 0, 0], &tx_bytes);
-        let signature = signer(&msg);
-        let serialized_signature = serialize_signature(scheme_flag, &signature, &public_key);
+/// Saturates indices past `u16::MAX`; `build` rejects such transactions with
+/// [`TransactionError::TooManyArgumentTargets`].
+fn argument_index(index: usize) -> u16 {
+    u16::try_from(index).unwrap_or(u16::MAX)
+}
 
+impl SignedTransaction {
+    fn new(tx_bytes: &[u8], signature: String) -> Self {
         use base64::Engine as _;
-        Ok(SignedTransaction {
+        Self {
             tx_bytes_base64: base64::engine::general_purpose::STANDARD.encode(tx_bytes),
-            signatures: vec![serialized_signature],
-        })
+            signatures: vec![signature],
+        }
     }
-}
 
-fn serialize_signature(scheme_flag: u8, signature: &[u8], public_key: &[u8]) -> String {
-    let mut bytes = Vec::with_capacity(1 + signature.len() + public_key.len());
-    bytes.push(scheme_flag);
-    bytes.extend_from_slice(signature);
-    bytes.extend_from_slice(public_key);
-    use base64::Engine as _;
-    base64::engine::general_purpose::STANDARD.encode(bytes)
-}
+    pub fn tx_bytes(&self) -> Result<Vec<u8>, TransactionError> {
+        use base64::Engine as _;
+        Ok(base64::engine::general_purpose::STANDARD.decode(&self.tx_bytes_base64)?)
+    }
+
+    pub fn digest(&self) -> Result<String, TransactionError> {
+        Ok(hash::encode_digest(hash::transaction_digest(&self.tx_bytes()?)))
+    }
+
+    /// The transaction these signatures cover.
+    pub fn transaction(&self) -> Result<Transaction, TransactionError> {
+        Transaction::from_base64(&self.tx_bytes_base64)
+    }
 
-impl SignedTransaction {
     pub async fn execute(
         &self,
         client: &jsonrpc::Client,
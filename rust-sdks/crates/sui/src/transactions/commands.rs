@@ -1,10 +1,15 @@
-use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use super::arguments::Argument;
+use super::json;
 use super::normalize_sui_address;
+use super::types::TransactionError;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "$kind")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 pub enum CommandKind {
     MoveCall(MoveCall),
     TransferObjects(TransferObjects),
@@ -13,10 +18,60 @@ pub enum CommandKind {
     Publish(Publish),
     Upgrade(Upgrade),
     MakeMoveVec(MakeMoveVec),
+    #[serde(rename = "$Intent")]
     Intent(Intent),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl Serialize for CommandKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        json::serialize_kind(self, CommandKind::serialize, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CommandKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        json::deserialize_kind(deserializer, CommandKind::deserialize)
+    }
+}
+
+impl CommandKind {
+    /// The arguments the command refers to, in declaration order.
+    pub fn arguments(&self) -> Vec<&Argument> {
+        match self {
+            CommandKind::MoveCall(cmd) => cmd.arguments.iter().collect(),
+            CommandKind::TransferObjects(cmd) => cmd.objects.iter().chain([&cmd.address]).collect(),
+            CommandKind::SplitCoins(cmd) => [&cmd.coin].into_iter().chain(&cmd.amounts).collect(),
+            CommandKind::MergeCoins(cmd) => {
+                [&cmd.destination].into_iter().chain(&cmd.sources).collect()
+            }
+            CommandKind::MakeMoveVec(cmd) => cmd.elements.iter().collect(),
+            CommandKind::Upgrade(cmd) => vec![&cmd.ticket],
+            CommandKind::Publish(_) | CommandKind::Intent(_) => Vec::new(),
+        }
+    }
+
+    pub fn arguments_mut(&mut self) -> Vec<&mut Argument> {
+        match self {
+            CommandKind::MoveCall(cmd) => cmd.arguments.iter_mut().collect(),
+            CommandKind::TransferObjects(cmd) => {
+                cmd.objects.iter_mut().chain([&mut cmd.address]).collect()
+            }
+            CommandKind::SplitCoins(cmd) => [&mut cmd.coin]
+                .into_iter()
+                .chain(&mut cmd.amounts)
+                .collect(),
+            CommandKind::MergeCoins(cmd) => [&mut cmd.destination]
+                .into_iter()
+                .chain(&mut cmd.sources)
+                .collect(),
+            CommandKind::MakeMoveVec(cmd) => cmd.elements.iter_mut().collect(),
+            CommandKind::Upgrade(cmd) => vec![&mut cmd.ticket],
+            CommandKind::Publish(_) | CommandKind::Intent(_) => Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MoveCall {
     #[serde(rename = "package")]
     pub package: String,
@@ -27,31 +82,31 @@ pub struct MoveCall {
     pub arguments: Vec<Argument>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TransferObjects {
     pub objects: Vec<Argument>,
     pub address: Argument,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SplitCoins {
     pub coin: Argument,
     pub amounts: Vec<Argument>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MergeCoins {
     pub destination: Argument,
     pub sources: Vec<Argument>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Publish {
     pub modules: Vec<String>,
     pub dependencies: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Upgrade {
     pub modules: Vec<String>,
     pub dependencies: Vec<String>,
@@ -60,20 +115,22 @@ pub struct Upgrade {
     pub ticket: Argument,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MakeMoveVec {
     #[serde(rename = "type")]
     pub type_arg: Option<String>,
     pub elements: Vec<Argument>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Intent {
     pub name: String,
     pub inputs: serde_json::Value,
     pub data: serde_json::Value,
 }
 
+/// Restriction an `UpgradeCap` puts on upgrades, from least to most strict.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
 pub enum UpgradePolicy {
     Compatible = 0,
@@ -81,36 +138,89 @@ pub enum UpgradePolicy {
     DepOnly = 192,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct Command {
-    #[serde(flatten)]
     pub kind: CommandKind,
 }
 
+/// A `package::module::function` target of a Move call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveCallTarget {
+    pub package: String,
+    pub module: String,
+    pub function: String,
+}
+
+impl MoveCallTarget {
+    pub fn new(
+        package: impl Into<String>,
+        module: impl Into<String>,
+        function: impl Into<String>,
+    ) -> Self {
+        Self {
+            package: package.into(),
+            module: module.into(),
+            function: function.into(),
+        }
+    }
+}
+
+impl FromStr for MoveCallTarget {
+    type Err = TransactionError;
+
+    fn from_str(target: &str) -> Result<Self, Self::Err> {
+        match target.split("::").collect::<Vec<_>>().as_slice() {
+            [package, module, function]
+                if !package.is_empty() && !module.is_empty() && !function.is_empty() =>
+            {
+                Ok(Self::new(*package, *module, *function))
+            }
+            _ => Err(TransactionError::InvalidMoveCallTarget(target.to_string())),
+        }
+    }
+}
+
+impl TryFrom<&str> for MoveCallTarget {
+    type Error = TransactionError;
+
+    fn try_from(target: &str) -> Result<Self, Self::Error> {
+        target.parse()
+    }
+}
+
+impl TryFrom<&String> for MoveCallTarget {
+    type Error = TransactionError;
+
+    fn try_from(target: &String) -> Result<Self, Self::Error> {
+        target.parse()
+    }
+}
+
+impl fmt::Display for MoveCallTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}::{}::{}", self.package, self.module, self.function)
+    }
+}
+
 pub struct TransactionCommands;
 
 impl TransactionCommands {
     pub fn move_call(
-        target: &str,
+        target: impl TryInto<MoveCallTarget, Error = TransactionError>,
         arguments: Vec<Argument>,
         type_arguments: Vec<String>,
-    ) -> Command {
-        let parts: Vec<&str> = target.split("::").collect();
-        let (package, module, function) = match parts.as_slice() {
-            [p, m, f] => (*p, *m, *f),
-            [p, m] => (*p, *m, ""),
-            [p] => (*p, "", ""),
-            _ => ("", "", ""),
-        };
-        Command {
+    ) -> Result<Command, TransactionError> {
+        let target = target.try_into()?;
+        Ok(Command {
             kind: CommandKind::MoveCall(MoveCall {
-                package: package.to_string(),
-                module: module.to_string(),
-                function: function.to_string(),
+                package: target.package,
+                module: target.module,
+                function: target.function,
                 type_arguments,
                 arguments,
             }),
-        }
+        })
     }
 
     pub fn transfer_objects(objects: Vec<Argument>, address: Argument) -> Command {
@@ -198,7 +308,8 @@ mod tests {
             "0x2::coin::transfer",
             vec![],
             vec!["0x2::coin::Coin".to_string()],
-        );
+        )
+        .unwrap();
         match cmd.kind {
             CommandKind::MoveCall(move_call) => {
                 assert_eq!(move_call.package, "0x2");
@@ -209,10 +320,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_command_arguments() {
+        let mut cmd = TransactionCommands::merge_coins(
+            Argument::Input(0),
+            vec![Argument::Input(1), Argument::Result(0)],
+        );
+        assert_eq!(cmd.kind.arguments().len(), 3);
+        for arg in cmd.kind.arguments_mut() {
+            if let Argument::Input(index) = arg {
+                *index += 1;
+            }
+        }
+        assert_eq!(
+            cmd.kind.arguments(),
+            vec![
+                &Argument::Input(1),
+                &Argument::Input(2),
+                &Argument::Result(0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_move_call_target() {
+        let target: MoveCallTarget = "0x2::coin::zero".parse().unwrap();
+        assert_eq!(target, MoveCallTarget::new("0x2", "coin", "zero"));
+        assert_eq!(target.to_string(), "0x2::coin::zero");
+
+        for malformed in ["0x2::coin", "0x2", "0x2::coin::", "0x2::coin::zero::extra"] {
+            assert!(matches!(
+                MoveCallTarget::try_from(malformed),
+                Err(TransactionError::InvalidMoveCallTarget(ref target)) if target == malformed
+            ));
+        }
+        assert!(TransactionCommands::move_call("0x2::coin", vec![], vec![]).is_err());
+    }
+
     #[test]
     fn test_transfer_objects_command() {
         let address = Argument::input(0);
-        let cmd = TransactionCommands::transfer_objects(vec![], address.clone());
+        let cmd = TransactionCommands::transfer_objects(vec![], address);
         match cmd.kind {
             CommandKind::TransferObjects(_) => (),
             _ => panic!("Expected TransferObjects"),
@@ -222,8 +370,8 @@ mod tests {
     #[test]
     fn test_split_coins_command() {
         let coin = Argument::input(0);
-        let amounts = vec![Argument::pure(vec![1, 2, 3, 4, 5, 6, 7, 8])];
-        let cmd = TransactionCommands::split_coins(coin.clone(), amounts);
+        let amounts = vec![Argument::input(1)];
+        let cmd = TransactionCommands::split_coins(coin, amounts);
         match cmd.kind {
             CommandKind::SplitCoins(_) => (),
             _ => panic!("Expected SplitCoins"),
@@ -232,7 +380,7 @@ mod tests {
 
     #[test]
     fn test_serialize_command() {
-        let cmd = TransactionCommands::move_call("0x2::coin::transfer", vec![], vec![]);
+        let cmd = TransactionCommands::move_call("0x2::coin::transfer", vec![], vec![]).unwrap();
         let serialized = serde_json::to_string(&cmd).unwrap();
         assert!(serialized.contains("MoveCall"));
     }
@@ -0,0 +1,240 @@
+//! Human-readable rendering of a transaction's gas data, inputs and commands.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use super::arguments::Argument;
+use super::commands::CommandKind;
+use super::inputs::{CallArg, ObjectKind};
+use super::types::{Transaction, TransactionExpiration};
+
+/// Types the built-in commands imply for pure inputs, used to decode them
+/// without Move signatures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PureHint {
+    U64,
+    Address,
+}
+
+fn pure_hints(tx: &Transaction) -> HashMap<u16, PureHint> {
+    let mut hints = HashMap::new();
+    for command in &tx.data.commands {
+        match &command.kind {
+            CommandKind::SplitCoins(split) => {
+                for amount in &split.amounts {
+                    if let Argument::Input(index) = amount {
+                        hints.insert(*index, PureHint::U64);
+                    }
+                }
+            }
+            CommandKind::TransferObjects(transfer) => {
+                if let Argument::Input(index) = transfer.address {
+                    hints.insert(index, PureHint::Address);
+                }
+            }
+            _ => {}
+        }
+    }
+    hints
+}
+
+fn render_pure(encoded: &str, hint: Option<PureHint>) -> String {
+    use base64::Engine as _;
+    let Ok(bytes) = base64::engine::general_purpose::STANDARD.decode(encoded) else {
+        return format!("Pure {encoded} (invalid base64)");
+    };
+    match (hint, bytes.len()) {
+        (Some(PureHint::U64), 8) => {
+            let mut le = [0u8; 8];
+            le.copy_from_slice(&bytes);
+            format!("Pure u64 {}", u64::from_le_bytes(le))
+        }
+        (Some(PureHint::Address), 32) => format!("Pure address 0x{}", hex::encode(&bytes)),
+        _ => format!("Pure 0x{} ({} bytes)", hex::encode(&bytes), bytes.len()),
+    }
+}
+
+fn render_input(input: &CallArg, hint: Option<PureHint>) -> String {
+    match input {
+        CallArg::Pure(pure) => render_pure(&pure.bytes, hint),
+        CallArg::Object(ObjectKind::ImmOrOwnedObject(obj)) => format!(
+            "ImmOrOwnedObject {} (version {}, digest {})",
+            obj.object_id, obj.version, obj.digest
+        ),
+        CallArg::Object(ObjectKind::SharedObject(obj)) => format!(
+            "SharedObject {} (initial version {}, {})",
+            obj.object_id,
+            obj.initial_shared_version,
+            if obj.mutable { "mutable" } else { "immutable" }
+        ),
+        CallArg::Object(ObjectKind::Receiving(obj)) => format!(
+            "Receiving {} (version {}, digest {})",
+            obj.object_id, obj.version, obj.digest
+        ),
+        CallArg::FundsWithdrawal(funds) => format!(
+            "FundsWithdrawal {} (reservation {})",
+            funds.type_arg, funds.reservation.reservation_number
+        ),
+        CallArg::UnresolvedObject(obj) => format!("UnresolvedObject {}", obj.object_id),
+        CallArg::UnresolvedPure(pure) => format!("UnresolvedPure {}", pure.value),
+    }
+}
+
+fn join(arguments: &[Argument]) -> String {
+    arguments
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn render_command(command: &CommandKind) -> String {
+    match command {
+        CommandKind::MoveCall(call) => {
+            let type_arguments = if call.type_arguments.is_empty() {
+                String::new()
+            } else {
+                format!("<{}>", call.type_arguments.join(", "))
+            };
+            format!(
+                "MoveCall {}::{}::{}{type_arguments}({})",
+                call.package,
+                call.module,
+                call.function,
+                join(&call.arguments)
+            )
+        }
+        CommandKind::TransferObjects(transfer) => format!(
+            "TransferObjects([{}], {})",
+            join(&transfer.objects),
+            transfer.address
+        ),
+        CommandKind::SplitCoins(split) => {
+            format!("SplitCoins({}, [{}])", split.coin, join(&split.amounts))
+        }
+        CommandKind::MergeCoins(merge) => format!(
+            "MergeCoins({}, [{}])",
+            merge.destination,
+            join(&merge.sources)
+        ),
+        CommandKind::Publish(publish) => format!(
+            "Publish({} modules, dependencies [{}])",
+            publish.modules.len(),
+            publish.dependencies.join(", ")
+        ),
+        CommandKind::Upgrade(upgrade) => format!(
+            "Upgrade {}({} modules, dependencies [{}], ticket {})",
+            upgrade.package,
+            upgrade.modules.len(),
+            upgrade.dependencies.join(", "),
+            upgrade.ticket
+        ),
+        CommandKind::MakeMoveVec(make) => format!(
+            "MakeMoveVec<{}>([{}])",
+            make.type_arg.as_deref().unwrap_or("_"),
+            join(&make.elements)
+        ),
+        CommandKind::Intent(intent) => format!("Intent {} {}", intent.name, intent.data),
+    }
+}
+
+impl fmt::Display for Transaction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let data = &self.data;
+        let unset = |value: Option<&str>| value.unwrap_or("<unset>").to_string();
+        writeln!(f, "Sender: {}", unset(data.sender.as_deref()))?;
+        let gas = &data.gas_data;
+        writeln!(
+            f,
+            "Gas: owner {}, price {}, budget {}",
+            unset(gas.owner.as_deref().or(data.sender.as_deref())),
+            unset(gas.price.as_deref()),
+            unset(gas.budget.as_deref())
+        )?;
+        match &gas.payment {
+            Some(payment) => {
+                for coin in payment {
+                    writeln!(
+                        f,
+                        "  Payment: {} (version {}, digest {})",
+                        coin.object_id, coin.version, coin.digest
+                    )?;
+                }
+            }
+            None => writeln!(f, "  Payment: <unset>")?,
+        }
+        match &data.expiration {
+            None | Some(TransactionExpiration::None) => {}
+            Some(TransactionExpiration::Epoch(epoch)) => writeln!(f, "Expiration: epoch {epoch}")?,
+            Some(TransactionExpiration::ValidDuring {
+                min_epoch,
+                max_epoch,
+                chain,
+                nonce,
+                ..
+            }) => {
+                let epoch = |e: &Option<u64>| e.map_or("_".to_string(), |e| e.to_string());
+                writeln!(
+                    f,
+                    "Expiration: valid during epochs {}..={} on chain {chain} (nonce {nonce})",
+                    epoch(min_epoch),
+                    epoch(max_epoch)
+                )?
+            }
+        }
+
+        let hints = pure_hints(self);
+        writeln!(f, "Inputs:")?;
+        for (index, input) in data.inputs.iter().enumerate() {
+            let hint = hints.get(&(index as u16)).copied();
+            writeln!(f, "  Input({index}): {}", render_input(input, hint))?;
+        }
+        writeln!(f, "Commands:")?;
+        for (index, command) in data.commands.iter().enumerate() {
+            writeln!(f, "  Result({index}): {}", render_command(&command.kind))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_transaction() {
+        let mut tx = Transaction::new();
+        tx.set_sender("0x1");
+        tx.set_gas_price(1000);
+        let amount = tx.pure_bytes(&100u64.to_le_bytes());
+        let coins = tx.split_coins(Transaction::gas(), vec![amount]);
+        let recipient = tx.pure_bytes(&[0xab; 32]);
+        tx.transfer_objects(vec![coins], recipient);
+        tx.move_call(
+            "0x2::coin::value",
+            vec![Argument::NestedResult(0, 0)],
+            vec!["0x2::sui::SUI".to_string()],
+        )
+        .unwrap();
+
+        let rendered = tx.to_string();
+        assert!(rendered.contains("Sender: 0x1\n"));
+        assert!(rendered.contains("Gas: owner 0x1, price 1000, budget <unset>\n"));
+        assert!(rendered.contains("  Input(0): Pure u64 100\n"));
+        assert!(rendered.contains(&format!("  Input(1): Pure address 0x{}\n", "ab".repeat(32))));
+        assert!(rendered.contains("  Result(0): SplitCoins(GasCoin, [Input(0)])\n"));
+        assert!(rendered.contains("  Result(1): TransferObjects([Result(0)], Input(1))\n"));
+        assert!(rendered.contains(
+            "  Result(2): MoveCall 0x2::coin::value<0x2::sui::SUI>(NestedResult(0, 0))\n"
+        ));
+    }
+
+    #[test]
+    fn test_render_pure_without_hint() {
+        assert_eq!(render_pure("AQI=", None), "Pure 0x0102 (2 bytes)");
+        assert_eq!(
+            render_pure("AQI=", Some(PureHint::U64)),
+            "Pure 0x0102 (2 bytes)"
+        );
+    }
+}
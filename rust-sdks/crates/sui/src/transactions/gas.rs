@@ -0,0 +1,518 @@
+use serde_json::Value;
+
+use super::bcs_types;
+use super::inputs::ObjectRef;
+use super::json::json_u64;
+use super::resolve::BoxFuture;
+use super::types::{Transaction, TransactionError};
+use crate::jsonrpc;
+use crate::types::GasCost;
+
+pub const SUI_COIN_TYPE: &str = "0x2::sui::SUI";
+
+#[derive(Debug, thiserror::Error)]
+pub enum GasError {
+    #[error(transparent)]
+    JsonRpc(#[from] jsonrpc::JsonRpcError),
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
+    #[error("invalid gas response: {0}")]
+    InvalidResponse(String),
+    #[error("dry run failed: {0}")]
+    DryRunFailed(String),
+    #[error("insufficient SUI for gas: {owner} needs {required} MIST, found {available} MIST")]
+    InsufficientGas {
+        owner: String,
+        required: u64,
+        available: u64,
+    },
+    #[error(
+        "{owner} has enough SUI for gas but its first {max} coins hold {selected} of the {required} MIST needed; merge its coins first"
+    )]
+    TooManyGasCoins {
+        owner: String,
+        max: usize,
+        selected: u64,
+        required: u64,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct GasConfig {
+    /// Budget used for the estimating dry run.
+    pub max_budget: u64,
+    /// Extra computation units added on top of the dry run, multiplied by the
+    /// gas price.
+    pub safe_overhead: u64,
+    /// Upper bound on the number of coins used as gas payment.
+    pub max_gas_objects: usize,
+}
+
+impl Default for GasConfig {
+    fn default() -> Self {
+        Self {
+            max_budget: 50_000_000_000,
+            safe_overhead: 1000,
+            max_gas_objects: 256,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedCoin {
+    pub object_ref: ObjectRef,
+    pub balance: u64,
+}
+
+/// A page of coins and the cursor of the next page, if any.
+pub type CoinPage = (Vec<OwnedCoin>, Option<String>);
+
+/// Lists the coins an address owns.
+pub trait CoinProvider: Send + Sync {
+    /// One page of the owner's coins of `coin_type`.
+    fn coins(
+        &self,
+        owner: String,
+        coin_type: String,
+        cursor: Option<String>,
+    ) -> BoxFuture<'_, Result<CoinPage, GasError>>;
+}
+
+/// Source of the chain data needed to fill in gas price, budget and payment.
+pub trait GasProvider: CoinProvider {
+    fn reference_gas_price(&self) -> BoxFuture<'_, Result<u64, GasError>>;
+
+    fn dry_run(&self, tx_bytes: Vec<u8>) -> BoxFuture<'_, Result<GasCost, GasError>>;
+}
+
+impl CoinProvider for jsonrpc::Client {
+    fn coins(
+        &self,
+        owner: String,
+        coin_type: String,
+        cursor: Option<String>,
+    ) -> BoxFuture<'_, Result<CoinPage, GasError>> {
+        Box::pin(async move {
+            let page = self
+                .get_coins(&owner, Some(&coin_type), cursor.as_deref(), None)
+                .await?;
+            let coins = page
+                .get("data")
+                .and_then(Value::as_array)
+                .ok_or_else(|| GasError::InvalidResponse("missing coin data".to_string()))?
+                .iter()
+                .map(parse_coin)
+                .collect::<Result<Vec<_>, _>>()?;
+            let next_cursor = match page.get("hasNextPage").and_then(Value::as_bool) {
+                Some(true) => page
+                    .get("nextCursor")
+                    .and_then(Value::as_str)
+                    .map(str::to_string),
+                _ => None,
+            };
+            Ok((coins, next_cursor))
+        })
+    }
+}
+
+impl GasProvider for jsonrpc::Client {
+    fn reference_gas_price(&self) -> BoxFuture<'_, Result<u64, GasError>> {
+        Box::pin(async move {
+            let price = self.get_reference_gas_price().await?;
+            price
+                .parse()
+                .map_err(|_| GasError::InvalidResponse(format!("gas price {price}")))
+        })
+    }
+
+    fn dry_run(&self, tx_bytes: Vec<u8>) -> BoxFuture<'_, Result<GasCost, GasError>> {
+        Box::pin(async move {
+            use base64::Engine as _;
+            let result = self
+                .dry_run_transaction_block(
+                    &base64::engine::general_purpose::STANDARD.encode(tx_bytes),
+                )
+                .await?;
+            let effects = result
+                .get("effects")
+                .ok_or_else(|| GasError::InvalidResponse("missing effects".to_string()))?;
+            if effects.pointer("/status/status").and_then(Value::as_str) != Some("success") {
+                let error = effects
+                    .pointer("/status/error")
+                    .and_then(Value::as_str)
+                    .unwrap_or("unknown error");
+                return Err(GasError::DryRunFailed(error.to_string()));
+            }
+            let cost = |field: &str| {
+                effects
+                    .get("gasUsed")
+                    .and_then(|gas| gas.get(field))
+                    .and_then(json_u64)
+                    .ok_or_else(|| GasError::InvalidResponse(format!("gasUsed.{field}")))
+            };
+            Ok(GasCost::new(
+                cost("computationCost")?,
+                cost("storageCost")?,
+                cost("storageRebate")?,
+                cost("nonRefundableStorageFee").unwrap_or(0),
+            ))
+        })
+    }
+}
+
+fn parse_coin(value: &Value) -> Result<OwnedCoin, GasError> {
+    let field = |name: &str| {
+        value
+            .get(name)
+            .ok_or_else(|| GasError::InvalidResponse(format!("coin {name}")))
+    };
+    let invalid = |name: &str| GasError::InvalidResponse(format!("coin {name}"));
+    Ok(OwnedCoin {
+        object_ref: ObjectRef {
+            object_id: field("coinObjectId")?
+                .as_str()
+                .ok_or_else(|| invalid("coinObjectId"))?
+                .to_string(),
+            digest: field("digest")?
+                .as_str()
+                .ok_or_else(|| invalid("digest"))?
+                .to_string(),
+            version: json_u64(field("version")?).ok_or_else(|| invalid("version"))?,
+        },
+        balance: json_u64(field("balance")?).ok_or_else(|| invalid("balance"))?,
+    })
+}
+
+impl Transaction {
+    /// Fills in whichever of gas price, budget and payment are missing.
+    ///
+    /// The price is the reference gas price, the budget comes from a dry run
+    /// plus `config.safe_overhead` computation units, and payment coins are the
+    /// gas owner's SUI coins not already used as inputs. Inputs must be resolved
+    /// first since the dry run needs the full transaction.
+    pub async fn resolve_gas(
+        &mut self,
+        provider: &dyn GasProvider,
+        config: &GasConfig,
+    ) -> Result<(), GasError> {
+        let owner = self
+            .data
+            .gas_data
+            .owner
+            .clone()
+            .or_else(|| self.data.sender.clone())
+            .map(|owner| super::normalize_sui_address(&owner))
+            .ok_or(TransactionError::MissingSender)?;
+
+        let price = match &self.data.gas_data.price {
+            Some(price) => price
+                .parse()
+                .map_err(|_| TransactionError::InvalidGasData("price".to_string()))?,
+            None => {
+                let price = provider.reference_gas_price().await?;
+                self.set_gas_price(price);
+                price
+            }
+        };
+
+        let budget = match &self.data.gas_data.budget {
+            Some(budget) => budget
+                .parse()
+                .map_err(|_| TransactionError::InvalidGasData("budget".to_string()))?,
+            None => {
+                let budget = self.estimate_budget(provider, config, price).await?;
+                self.set_gas_budget(budget);
+                budget
+            }
+        };
+
+        if self.data.gas_data.payment.is_none() {
+            let payment = self
+                .select_gas_payment(provider, config, &owner, budget)
+                .await?;
+            self.set_gas_payment(payment);
+        }
+        Ok(())
+    }
+
+    async fn estimate_budget(
+        &self,
+        provider: &dyn GasProvider,
+        config: &GasConfig,
+        price: u64,
+    ) -> Result<u64, GasError> {
+        let mut dry_run = self.data.clone();
+        dry_run.gas_data.budget = Some(config.max_budget.to_string());
+        // The node substitutes a mock gas coin when no payment is given.
+        dry_run.gas_data.payment.get_or_insert_with(Vec::new);
+        let bytes = bcs_types::TransactionData::try_from(&dry_run)?.to_bytes()?;
+        let cost = provider.dry_run(bytes).await?;
+
+        let computation = cost
+            .computation_cost
+            .saturating_add(config.safe_overhead.saturating_mul(price));
+        let budget = computation
+            .saturating_add(cost.storage_cost)
+            .saturating_sub(cost.storage_rebate);
+        Ok(budget.max(computation))
+    }
+
+    async fn select_gas_payment(
+        &self,
+        provider: &dyn GasProvider,
+        config: &GasConfig,
+        owner: &str,
+        budget: u64,
+    ) -> Result<Vec<ObjectRef>, GasError> {
+        let used_as_input: Vec<String> = self
+            .data
+            .inputs
+            .iter()
+            .filter_map(|input| input.object_id().map(str::to_string))
+            .collect();
+
+        let mut payment = Vec::new();
+        let mut selected: u64 = 0;
+        // Every usable coin seen, to tell a low balance from one spread over
+        // more than `max_gas_objects` coins.
+        let mut available: u64 = 0;
+        let mut cursor = None;
+        loop {
+            let (coins, next_cursor) = provider
+                .coins(owner.to_string(), SUI_COIN_TYPE.to_string(), cursor)
+                .await?;
+            for coin in coins {
+                let object_id = super::normalize_sui_address(&coin.object_ref.object_id);
+                if used_as_input.contains(&object_id) {
+                    continue;
+                }
+                available = available.saturating_add(coin.balance);
+                if selected < budget && payment.len() < config.max_gas_objects {
+                    selected = selected.saturating_add(coin.balance);
+                    payment.push(coin.object_ref);
+                }
+            }
+            if selected >= budget || available >= budget {
+                break;
+            }
+            match next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        if selected < budget {
+            if available >= budget {
+                return Err(GasError::TooManyGasCoins {
+                    owner: owner.to_string(),
+                    max: config.max_gas_objects,
+                    selected,
+                    required: budget,
+                });
+            }
+            return Err(GasError::InsufficientGas {
+                owner: owner.to_string(),
+                required: budget,
+                available,
+            });
+        }
+        Ok(payment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    struct MockGas {
+        price: u64,
+        pages: Vec<Vec<OwnedCoin>>,
+        cost: GasCost,
+        dry_runs: Mutex<Vec<bcs_types::TransactionData>>,
+    }
+
+    impl CoinProvider for MockGas {
+        fn coins(
+            &self,
+            _owner: String,
+            _coin_type: String,
+            cursor: Option<String>,
+        ) -> BoxFuture<'_, Result<CoinPage, GasError>> {
+            Box::pin(async move {
+                let page: usize = cursor.map(|c| c.parse().unwrap()).unwrap_or(0);
+                let next = (page + 1 < self.pages.len()).then(|| (page + 1).to_string());
+                Ok((self.pages[page].clone(), next))
+            })
+        }
+    }
+
+    impl GasProvider for MockGas {
+        fn reference_gas_price(&self) -> BoxFuture<'_, Result<u64, GasError>> {
+            Box::pin(async move { Ok(self.price) })
+        }
+
+        fn dry_run(&self, tx_bytes: Vec<u8>) -> BoxFuture<'_, Result<GasCost, GasError>> {
+            Box::pin(async move {
+                let data = bcs_types::TransactionData::from_bytes(&tx_bytes)?;
+                self.dry_runs.lock().unwrap().push(data);
+                Ok(self.cost)
+            })
+        }
+    }
+
+    fn coin(id: &str, balance: u64) -> OwnedCoin {
+        OwnedCoin {
+            object_ref: ObjectRef {
+                object_id: super::super::normalize_sui_address(id),
+                digest: "11111111111111111111111111111111".to_string(),
+                version: 1,
+            },
+            balance,
+        }
+    }
+
+    fn transaction() -> Transaction {
+        let mut tx = Transaction::new();
+        tx.set_sender("0x1");
+        let amount = tx.pure_bytes(&1u64.to_le_bytes());
+        tx.split_coins(Transaction::gas(), vec![amount]);
+        tx
+    }
+
+    #[tokio::test]
+    async fn test_resolve_gas() {
+        let provider = MockGas {
+            price: 750,
+            pages: vec![
+                vec![coin("0xa", 1_000_000), coin("0xb", 1_000_000)],
+                vec![coin("0xc", 5_000_000)],
+            ],
+            cost: GasCost::new(1_000_000, 2_000_000, 500_000, 0),
+            dry_runs: Mutex::new(Vec::new()),
+        };
+
+        let mut tx = transaction();
+        tx.resolve_gas(&provider, &GasConfig::default())
+            .await
+            .unwrap();
+
+        let dry_runs = provider.dry_runs.lock().unwrap();
+        assert_eq!(dry_runs.len(), 1);
+        assert_eq!(dry_runs[0].v1().gas_data.price, 750);
+        assert!(dry_runs[0].v1().gas_data.payment.is_empty());
+
+        assert_eq!(tx.data.gas_data.price.as_deref(), Some("750"));
+        // 1_000_000 + 1000 * 750 overhead + 2_000_000 storage - 500_000 rebate
+        assert_eq!(tx.data.gas_data.budget.as_deref(), Some("3250000"));
+        let payment = tx.data.gas_data.payment.as_ref().unwrap();
+        assert_eq!(payment.len(), 3);
+        assert!(tx.build().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_gas_skips_input_coins_and_caps_objects() {
+        let provider = MockGas {
+            price: 1000,
+            pages: vec![vec![coin("0xa", 10), coin("0xb", 10), coin("0xc", 10)]],
+            cost: GasCost::new(0, 0, 0, 0),
+            dry_runs: Mutex::new(Vec::new()),
+        };
+
+        let mut tx = transaction();
+        tx.set_gas_budget(100);
+        tx.object_ref(coin("0xa", 10).object_ref);
+        let config = GasConfig {
+            max_gas_objects: 1,
+            ..GasConfig::default()
+        };
+        let err = tx.resolve_gas(&provider, &config).await.unwrap_err();
+        assert!(matches!(
+            err,
+            GasError::InsufficientGas {
+                required: 100,
+                available: 20,
+                ..
+            }
+        ));
+        assert!(provider.dry_runs.lock().unwrap().is_empty());
+
+        tx.set_gas_budget(20);
+        tx.resolve_gas(&provider, &GasConfig::default())
+            .await
+            .unwrap();
+        let payment = tx.data.gas_data.payment.as_ref().unwrap();
+        let ids: Vec<&str> = payment.iter().map(|c| c.object_id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                coin("0xb", 0).object_ref.object_id.as_str(),
+                coin("0xc", 0).object_ref.object_id.as_str()
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_gas_reports_fragmented_balance() {
+        let provider = MockGas {
+            price: 1000,
+            pages: vec![
+                vec![coin("0xa", 10), coin("0xb", 10)],
+                vec![coin("0xc", 10), coin("0xd", 10)],
+            ],
+            cost: GasCost::new(0, 0, 0, 0),
+            dry_runs: Mutex::new(Vec::new()),
+        };
+        let config = GasConfig {
+            max_gas_objects: 2,
+            ..GasConfig::default()
+        };
+
+        let mut tx = transaction();
+        tx.set_gas_budget(35);
+        let err = tx.resolve_gas(&provider, &config).await.unwrap_err();
+        assert!(matches!(
+            err,
+            GasError::TooManyGasCoins {
+                max: 2,
+                selected: 20,
+                required: 35,
+                ..
+            }
+        ));
+
+        tx.set_gas_budget(45);
+        let err = tx.resolve_gas(&provider, &config).await.unwrap_err();
+        assert!(matches!(
+            err,
+            GasError::InsufficientGas {
+                required: 45,
+                available: 40,
+                ..
+            }
+        ));
+
+        tx.set_gas_budget(20);
+        tx.resolve_gas(&provider, &config).await.unwrap();
+        assert_eq!(tx.data.gas_data.payment.as_ref().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_gas_requires_sender() {
+        let provider = MockGas {
+            price: 1000,
+            pages: vec![vec![]],
+            cost: GasCost::new(0, 0, 0, 0),
+            dry_runs: Mutex::new(Vec::new()),
+        };
+        let mut tx = Transaction::new();
+        let err = tx
+            .resolve_gas(&provider, &GasConfig::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            GasError::Transaction(TransactionError::MissingSender)
+        ));
+    }
+}
@@ -1,24 +1,57 @@
-use sha2::{Digest, Sha256};
+use fastcrypto::hash::{Blake2b256, HashFunction};
 
+use super::bcs_types::Digest;
+
+pub const TRANSACTION_DATA_PREFIX: &str = "TransactionData";
+pub const TRANSACTION_EFFECTS_PREFIX: &str = "TransactionEffects";
+pub const CHECKPOINT_SUMMARY_PREFIX: &str = "CheckpointSummary";
+pub const CHECKPOINT_CONTENTS_PREFIX: &str = "CheckpointContents";
+
+/// Blake2b-256 over `"{type_name}::"` followed by the BCS bytes, the way Sui
+/// digests every signed or certified structure.
+pub fn digest_with_prefix(type_name: &str, bcs_bytes: &[u8]) -> [u8; 32] {
+    let mut hasher = Blake2b256::default();
+    hasher.update(type_name.as_bytes());
+    hasher.update(b"::");
+    hasher.update(bcs_bytes);
+    hasher.finalize().digest
+}
+
+/// Digest of BCS-encoded `TransactionData`.
 pub fn transaction_digest(tx_data: &[u8]) -> [u8; 32] {
-    let digest = Sha256::digest(tx_data);
-    let mut result = [0u8; 32];
-    result.copy_from_slice(&digest);
-    result
+    digest_with_prefix(TRANSACTION_DATA_PREFIX, tx_data)
+}
+
+/// Digest of BCS-encoded `TransactionEffects`, e.g. `rawEffects` from the RPC.
+pub fn transaction_effects_digest(effects: &[u8]) -> [u8; 32] {
+    digest_with_prefix(TRANSACTION_EFFECTS_PREFIX, effects)
+}
+
+/// Digest of a BCS-encoded `CheckpointSummary`.
+pub fn checkpoint_digest(summary: &[u8]) -> [u8; 32] {
+    digest_with_prefix(CHECKPOINT_SUMMARY_PREFIX, summary)
+}
+
+/// Digest of BCS-encoded `CheckpointContents`.
+pub fn checkpoint_contents_digest(contents: &[u8]) -> [u8; 32] {
+    digest_with_prefix(CHECKPOINT_CONTENTS_PREFIX, contents)
+}
+
+/// Base58 rendering used by explorers and the RPC.
+pub fn encode_digest(digest: [u8; 32]) -> String {
+    Digest(digest).to_string()
 }
 
 pub fn transaction_message(tx_data: &[u8]) -> Vec<u8> {
     tx_data.to_vec()
 }
 
+/// Blake2b-256 over the intent-prefixed message, which is what gets signed.
 pub fn hash_with_intent(intent: [u8; 3], message: &[u8]) -> [u8; 32] {
-    let mut result = Vec::with_capacity(3 + message.len());
-    result.extend_from_slice(&intent);
-    result.extend_from_slice(message);
-    let digest = Sha256::digest(result);
-    let mut hash = [0u8; 32];
-    hash.copy_from_slice(&digest);
-    hash
+    let mut hasher = Blake2b256::default();
+    hasher.update(intent);
+    hasher.update(message);
+    hasher.finalize().digest
 }
 
 #[cfg(test)]
@@ -30,6 +63,24 @@ mod tests {
         let tx_data = b"test transaction data";
         let digest = transaction_digest(tx_data);
         assert_eq!(digest.len(), 32);
+        assert_eq!(
+            encode_digest(digest),
+            "DCJ4Yr9GccVKKEp3sMSfBAWm2SYL8XB3DvMyzrbaBe8w"
+        );
+    }
+
+    #[test]
+    fn test_effects_and_checkpoint_digests() {
+        let bytes = b"test transaction data";
+        assert_eq!(
+            encode_digest(transaction_effects_digest(bytes)),
+            "7eHKwrSGhkdNdGn24b99ebqvcX93hUm2phdY7BcKQ5gB"
+        );
+        assert_eq!(
+            encode_digest(checkpoint_digest(bytes)),
+            "BHyQkQTP3Fga2dvAbaXEq43TbKEf9HKpkmdoVTEVAo68"
+        );
+        assert_ne!(checkpoint_contents_digest(bytes), checkpoint_digest(bytes));
     }
 
     #[test]
@@ -45,6 +96,10 @@ mod tests {
         let message = b"test message";
         let hash = hash_with_intent(intent, message);
         assert_eq!(hash.len(), 32);
+        assert_eq!(
+            hex::encode(hash),
+            "f64d5f5c44bd8d2b13479b6a6cb188125314c171a95e91fd3337afe0c0131349"
+        );
     }
 
     #[test]
@@ -1,6 +1,7 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use super::arguments::Argument;
+use super::json::{self, option_u64_string, u64_string};
 use super::pure::PureValue;
 
 fn normalize_sui_address(addr: &str) -> String {
@@ -18,70 +19,131 @@ fn normalize_sui_address(addr: &str) -> String {
     format!("0x{raw}")
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "$kind")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 pub enum CallArg {
     Pure(Pure),
     Object(ObjectKind),
     FundsWithdrawal(FundsWithdrawal),
+    UnresolvedObject(UnresolvedObject),
+    UnresolvedPure(UnresolvedPure),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Pure {
     pub bytes: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "$kind")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 pub enum ObjectKind {
     ImmOrOwnedObject(ImmOrOwnedObject),
     SharedObject(SharedObject),
     Receiving(ReceivingRef),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl Serialize for CallArg {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        json::serialize_kind(self, CallArg::serialize, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CallArg {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        json::deserialize_kind(deserializer, CallArg::deserialize)
+    }
+}
+
+impl Serialize for ObjectKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        json::serialize_kind(self, ObjectKind::serialize, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        json::deserialize_kind(deserializer, ObjectKind::deserialize)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ImmOrOwnedObject {
     pub digest: String,
+    #[serde(with = "u64_string")]
     pub version: u64,
     #[serde(rename = "objectId")]
     pub object_id: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SharedObject {
     pub mutable: bool,
-    #[serde(rename = "initialSharedVersion")]
+    #[serde(rename = "initialSharedVersion", with = "u64_string")]
     pub initial_shared_version: u64,
     #[serde(rename = "objectId")]
     pub object_id: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ReceivingRef {
     pub digest: String,
+    #[serde(with = "u64_string")]
     pub version: u64,
     #[serde(rename = "objectId")]
     pub object_id: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ObjectRef {
     #[serde(rename = "objectId")]
     pub object_id: String,
     pub digest: String,
+    #[serde(with = "u64_string")]
     pub version: u64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SharedObjectRef {
     #[serde(rename = "objectId")]
     pub object_id: String,
     pub mutable: bool,
-    #[serde(rename = "initialSharedVersion")]
+    #[serde(rename = "initialSharedVersion", with = "u64_string")]
     pub initial_shared_version: u64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// An object input that still needs its version, digest or ownership looked up
+/// before the transaction can be built.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct UnresolvedObject {
+    #[serde(rename = "objectId")]
+    pub object_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "option_u64_string"
+    )]
+    pub version: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digest: Option<String>,
+    #[serde(
+        rename = "initialSharedVersion",
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "option_u64_string"
+    )]
+    pub initial_shared_version: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mutable: Option<bool>,
+}
+
+/// A pure input given as a plain value, encoded once the type of the parameter
+/// it is passed to is known.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnresolvedPure {
+    pub value: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FundsWithdrawal {
     pub reservation: Reservation,
     #[serde(rename = "typeArg")]
@@ -90,13 +152,13 @@ pub struct FundsWithdrawal {
     pub withdraw_from: WithdrawFrom,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Reservation {
     #[serde(rename = "reservationNumber")]
     pub reservation_number: u64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "$kind")]
 pub enum WithdrawFrom {
     CallArg(Argument),
@@ -119,6 +181,10 @@ impl CallArg {
         })
     }
 
+    pub fn unresolved_pure(value: serde_json::Value) -> Self {
+        CallArg::UnresolvedPure(UnresolvedPure { value })
+    }
+
     pub fn object_ref(object_ref: ObjectRef) -> Self {
         CallArg::Object(ObjectKind::ImmOrOwnedObject(ImmOrOwnedObject {
             digest: object_ref.digest,
@@ -136,7 +202,62 @@ impl CallArg {
     }
 
     pub fn receiving_ref(receiving_ref: ReceivingRef) -> Self {
-        CallArg::Object(ObjectKind::Receiving(receiving_ref))
+        CallArg::Object(ObjectKind::Receiving(ReceivingRef {
+            object_id: normalize_sui_address(&receiving_ref.object_id),
+            ..receiving_ref
+        }))
+    }
+
+    pub fn unresolved_object(object_id: &str) -> Self {
+        CallArg::UnresolvedObject(UnresolvedObject {
+            object_id: normalize_sui_address(object_id),
+            ..Default::default()
+        })
+    }
+
+    /// The normalized object id of an object input, resolved or not.
+    pub fn object_id(&self) -> Option<&str> {
+        match self {
+            CallArg::Object(ObjectKind::ImmOrOwnedObject(obj)) => Some(&obj.object_id),
+            CallArg::Object(ObjectKind::SharedObject(obj)) => Some(&obj.object_id),
+            CallArg::Object(ObjectKind::Receiving(obj)) => Some(&obj.object_id),
+            CallArg::UnresolvedObject(obj) => Some(&obj.object_id),
+            CallArg::Pure(_) | CallArg::FundsWithdrawal(_) | CallArg::UnresolvedPure(_) => None,
+        }
+    }
+
+    /// Folds a second use of the same object into this input, as the
+    /// TypeScript SDK does: shared objects become mutable if either use is,
+    /// and a resolved reference replaces an unresolved one.
+    pub(crate) fn merge_object(&mut self, other: CallArg) {
+        match (&mut *self, other) {
+            (
+                CallArg::Object(ObjectKind::SharedObject(shared)),
+                CallArg::Object(ObjectKind::SharedObject(SharedObject { mutable, .. })),
+            ) => shared.mutable |= mutable,
+            (
+                CallArg::Object(ObjectKind::SharedObject(shared)),
+                CallArg::UnresolvedObject(UnresolvedObject { mutable, .. }),
+            ) => shared.mutable |= mutable.unwrap_or(false),
+            (CallArg::UnresolvedObject(unresolved), CallArg::UnresolvedObject(other)) => {
+                unresolved.version = unresolved.version.or(other.version);
+                unresolved.digest = unresolved.digest.take().or(other.digest);
+                unresolved.initial_shared_version = unresolved
+                    .initial_shared_version
+                    .or(other.initial_shared_version);
+                unresolved.mutable = match (unresolved.mutable, other.mutable) {
+                    (None, None) => None,
+                    (a, b) => Some(a.unwrap_or(false) || b.unwrap_or(false)),
+                };
+            }
+            (CallArg::UnresolvedObject(unresolved), mut resolved @ CallArg::Object(_)) => {
+                if let CallArg::Object(ObjectKind::SharedObject(shared)) = &mut resolved {
+                    shared.mutable |= unresolved.mutable.unwrap_or(false);
+                }
+                *self = resolved;
+            }
+            _ => {}
+        }
     }
 
     pub fn funds_withdrawal(
@@ -192,6 +313,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_unresolved_object() {
+        let call_arg = CallArg::unresolved_object("0x6");
+        assert_eq!(
+            call_arg.object_id(),
+            Some("0x0000000000000000000000000000000000000000000000000000000000000006")
+        );
+        assert_eq!(CallArg::pure(vec![1]).object_id(), None);
+
+        let serialized = serde_json::to_string(&call_arg).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"$kind":"UnresolvedObject","UnresolvedObject":{"objectId":"0x0000000000000000000000000000000000000000000000000000000000000006"}}"#
+        );
+    }
+
+    #[test]
+    fn test_merge_object() {
+        let shared = |mutable| {
+            CallArg::shared_object_ref(SharedObjectRef {
+                object_id: "0x2".to_string(),
+                mutable,
+                initial_shared_version: 1,
+            })
+        };
+        let mut input = shared(false);
+        input.merge_object(shared(true));
+        assert_eq!(input, shared(true));
+        input.merge_object(shared(false));
+        assert_eq!(input, shared(true));
+
+        let mut input = CallArg::UnresolvedObject(UnresolvedObject {
+            object_id: normalize_sui_address("0x2"),
+            mutable: Some(true),
+            ..Default::default()
+        });
+        input.merge_object(shared(false));
+        assert_eq!(input, shared(true));
+
+        let owned = CallArg::object_ref(ObjectRef {
+            object_id: "0x2".to_string(),
+            digest: "digest".to_string(),
+            version: 1,
+        });
+        let mut input = CallArg::unresolved_object("0x2");
+        input.merge_object(owned.clone());
+        assert_eq!(input, owned);
+        input.merge_object(CallArg::unresolved_object("0x2"));
+        assert_eq!(input, owned);
+    }
+
     #[test]
     fn test_pure_call_arg() {
         let call_arg = CallArg::pure(vec![1, 2, 3, 4]);
@@ -210,7 +382,7 @@ mod tests {
 
     #[test]
     fn test_deserialize_call_arg() {
-        let json = r#"{"$kind":"Pure","bytes":"AQIDBA=="}"#;
+        let json = r#"{"$kind":"Pure","Pure":{"bytes":"AQIDBA=="}}"#;
         let call_arg: CallArg = serde_json::from_str(json).unwrap();
         match call_arg {
             CallArg::Pure(_) => (),
@@ -0,0 +1,235 @@
+//! JSON in the serialized transaction v2 format of the TypeScript SDK, so
+//! partially built transactions can be passed between the two.
+
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use super::types::{Transaction, TransactionData, TransactionError};
+
+/// The only serialized transaction version written and read.
+const TRANSACTION_JSON_VERSION: u64 = 2;
+
+pub(crate) fn json_u64(value: &Value) -> Option<u64> {
+    match value {
+        Value::Number(n) => n.as_u64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Writes an enum of newtype variants as `{"$kind": "Variant", "Variant": ..}`
+/// given its externally tagged serializer.
+pub(crate) fn serialize_kind<T, S: Serializer>(
+    value: &T,
+    externally_tagged: fn(&T, serde_json::value::Serializer) -> Result<Value, serde_json::Error>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut value =
+        externally_tagged(value, serde_json::value::Serializer).map_err(S::Error::custom)?;
+    if let Value::Object(map) = &mut value
+        && let Some(kind) = map.keys().next().cloned()
+    {
+        map.insert("$kind".to_string(), Value::String(kind));
+    }
+    value.serialize(serializer)
+}
+
+/// Reads the `{"$kind": "Variant", "Variant": ..}` shape with the enum's
+/// externally tagged deserializer.
+pub(crate) fn deserialize_kind<'de, T, D: Deserializer<'de>>(
+    deserializer: D,
+    externally_tagged: fn(Value) -> Result<T, serde_json::Error>,
+) -> Result<T, D::Error> {
+    let mut value = Value::deserialize(deserializer)?;
+    if let Value::Object(map) = &mut value
+        && let Some(Value::String(kind)) = map.remove("$kind")
+    {
+        let inner = map.remove(&kind).unwrap_or(Value::Bool(true));
+        value = Value::Object([(kind, inner)].into_iter().collect());
+    }
+    externally_tagged(value).map_err(D::Error::custom)
+}
+
+/// `u64`s are written as strings and read from strings or numbers, like the
+/// TypeScript SDK's `JsonU64`.
+pub(crate) mod u64_string {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        json_u64(&value).ok_or_else(|| D::Error::custom(format!("invalid u64: {value}")))
+    }
+}
+
+pub(crate) mod option_u64_string {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => serializer.collect_str(value),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<u64>, D::Error> {
+        match Value::deserialize(deserializer)? {
+            Value::Null => Ok(None),
+            value => json_u64(&value)
+                .map(Some)
+                .ok_or_else(|| D::Error::custom(format!("invalid u64: {value}"))),
+        }
+    }
+}
+
+/// Reads a `JsonU64` into the string the gas data keeps it as.
+pub(crate) fn deserialize_option_u64_str<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    Ok(option_u64_string::deserialize(deserializer)?.map(|value| value.to_string()))
+}
+
+#[derive(Serialize)]
+struct SerializedTransaction<'a> {
+    version: u64,
+    #[serde(flatten)]
+    data: &'a TransactionData,
+}
+
+impl Transaction {
+    /// The transaction as the TypeScript SDK's `Transaction.toJSON()` writes it.
+    /// Unresolved inputs and intents are kept, so it can be finished elsewhere.
+    pub fn to_json(&self) -> Result<String, TransactionError> {
+        Ok(serde_json::to_string(&SerializedTransaction {
+            version: TRANSACTION_JSON_VERSION,
+            data: &self.data,
+        })?)
+    }
+
+    /// Reads a transaction written by [`Transaction::to_json`] or the
+    /// TypeScript SDK.
+    pub fn from_json(json: &str) -> Result<Self, TransactionError> {
+        let value: Value = serde_json::from_str(json)?;
+        match value.get("version").and_then(json_u64) {
+            Some(TRANSACTION_JSON_VERSION) => {}
+            Some(version) => {
+                return Err(TransactionError::Unsupported(format!(
+                    "transaction json version {version}"
+                )));
+            }
+            None => {
+                return Err(TransactionError::Unsupported(
+                    "transaction json without a version".to_string(),
+                ));
+            }
+        }
+        Ok(Self {
+            data: serde_json::from_value(value)?,
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::transactions::{
+        Argument, CallArg, CommandKind, ObjectKind, ObjectRef, TransactionExpiration,
+    };
+
+    const OBJECT: &str = "0x0000000000000000000000000000000000000000000000000000000000000006";
+
+    #[test]
+    fn test_call_args_use_kind_tags() {
+        let pure = serde_json::to_value(CallArg::pure(vec![1, 2])).unwrap();
+        assert_eq!(pure, json!({"$kind": "Pure", "Pure": {"bytes": "AQI="}}));
+
+        let object = CallArg::object_ref(ObjectRef {
+            object_id: "0x6".to_string(),
+            digest: "11111111111111111111111111111111".to_string(),
+            version: 7,
+        });
+        let value = serde_json::to_value(&object).unwrap();
+        assert_eq!(
+            value,
+            json!({"$kind": "Object", "Object": {
+                "$kind": "ImmOrOwnedObject",
+                "ImmOrOwnedObject": {
+                    "objectId": OBJECT,
+                    "version": "7",
+                    "digest": "11111111111111111111111111111111"
+                }
+            }})
+        );
+        assert_eq!(serde_json::from_value::<CallArg>(value).unwrap(), object);
+    }
+
+    #[test]
+    fn test_reads_numeric_u64s() {
+        let arg: CallArg = serde_json::from_value(json!({"$kind": "Object", "Object": {
+            "$kind": "SharedObject",
+            "SharedObject": {"objectId": OBJECT, "initialSharedVersion": 1, "mutable": false}
+        }}))
+        .unwrap();
+        let CallArg::Object(ObjectKind::SharedObject(shared)) = arg else {
+            panic!("expected SharedObject");
+        };
+        assert_eq!(shared.initial_shared_version, 1);
+
+        let data: TransactionData = serde_json::from_value(json!({
+            "gasData": {"budget": 5000, "price": "1000", "owner": null, "payment": null},
+            "inputs": [],
+            "commands": []
+        }))
+        .unwrap();
+        assert_eq!(data.gas_data.budget.as_deref(), Some("5000"));
+        assert_eq!(data.gas_data.price.as_deref(), Some("1000"));
+    }
+
+    #[test]
+    fn test_transaction_json_round_trip() {
+        let mut tx = Transaction::new();
+        tx.set_sender("0x1");
+        tx.set_expiration(TransactionExpiration::Epoch(9));
+        let coin = tx.object("0x6");
+        let amount = tx.pure_value(100);
+        let split = tx.split_coins(coin, vec![amount]);
+        tx.transfer_objects(vec![split], Argument::GasCoin);
+
+        let json = tx.to_json().unwrap();
+        let value: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["version"], 2);
+        assert_eq!(
+            value["gasData"],
+            json!({"owner": null, "price": null, "budget": null, "payment": null})
+        );
+        assert_eq!(value["inputs"][1]["$kind"], "UnresolvedPure");
+        assert_eq!(value["commands"][0]["$kind"], "SplitCoins");
+
+        let restored = Transaction::from_json(&json).unwrap();
+        assert_eq!(restored.data.inputs, tx.data.inputs);
+        assert_eq!(restored.data.commands, tx.data.commands);
+        assert_eq!(restored.data.expiration, tx.data.expiration);
+        assert!(matches!(
+            restored.data.commands[1].kind,
+            CommandKind::TransferObjects(_)
+        ));
+    }
+
+    #[test]
+    fn test_rejects_other_versions() {
+        let err =
+            Transaction::from_json(r#"{"version":1,"inputs":[],"transactions":[]}"#).unwrap_err();
+        assert!(matches!(err, TransactionError::Unsupported(_)));
+        assert!(Transaction::from_json(r#"{"inputs":[]}"#).is_err());
+    }
+}
@@ -0,0 +1,525 @@
+//! Protocol limits a transaction has to stay within, checked locally before it
+//! is sent so violations are reported against the offending command or input.
+
+use serde_json::Value;
+
+use super::commands::CommandKind;
+use super::inputs::CallArg;
+use super::json::json_u64;
+use super::types::{Transaction, TransactionError};
+use crate::jsonrpc;
+
+#[derive(Debug, thiserror::Error)]
+pub enum LimitError {
+    #[error(transparent)]
+    JsonRpc(#[from] jsonrpc::JsonRpcError),
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
+    #[error("invalid protocol config: {0}")]
+    InvalidConfig(String),
+    #[error("transaction has {count} commands, the limit is {max}")]
+    TooManyCommands { count: usize, max: u64 },
+    #[error("transaction has {count} object inputs, the limit is {max}")]
+    TooManyInputObjects { count: usize, max: u64 },
+    #[error("pure input {index} is {size} bytes, the limit is {max}")]
+    PureArgumentTooLarge { index: usize, size: usize, max: u64 },
+    #[error("command {index} ({kind}) has {count} arguments, the limit is {max}")]
+    TooManyArguments {
+        index: usize,
+        kind: &'static str,
+        count: usize,
+        max: u64,
+    },
+    #[error("command {index} ({kind}) has no {operand}")]
+    EmptyOperand {
+        index: usize,
+        kind: &'static str,
+        operand: &'static str,
+    },
+    #[error("command {index} ({kind}) has {count} type arguments, the limit is {max}")]
+    TooManyTypeArguments {
+        index: usize,
+        kind: &'static str,
+        count: usize,
+        max: u64,
+    },
+    #[error("transaction pays gas with {count} objects, the limit is {max}")]
+    TooManyGasObjects { count: usize, max: u64 },
+    #[error("transaction is {size} bytes, the limit is {max}")]
+    TransactionTooLarge { size: usize, max: u64 },
+}
+
+/// The subset of `sui_getProtocolConfig` attributes a programmable transaction
+/// is checked against. Defaults are the mainnet values. Like the validator,
+/// commands, arguments and type arguments must stay strictly below their limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionLimits {
+    /// `max_programmable_tx_commands`
+    pub max_commands: u64,
+    /// `max_input_objects`, counting gas payment objects.
+    pub max_input_objects: u64,
+    /// `max_pure_argument_size`
+    pub max_pure_argument_size: u64,
+    /// `max_arguments`
+    pub max_arguments: u64,
+    /// `max_type_arguments`
+    pub max_type_arguments: u64,
+    /// `max_gas_payment_objects`
+    pub max_gas_objects: u64,
+    /// `max_tx_size_bytes`
+    pub max_tx_size_bytes: u64,
+}
+
+impl Default for TransactionLimits {
+    fn default() -> Self {
+        Self {
+            max_commands: 1024,
+            max_input_objects: 2048,
+            max_pure_argument_size: 16 * 1024,
+            max_arguments: 512,
+            max_type_arguments: 16,
+            max_gas_objects: 256,
+            max_tx_size_bytes: 128 * 1024,
+        }
+    }
+}
+
+fn attribute(config: &Value, name: &str, default: u64) -> Result<u64, LimitError> {
+    match config["attributes"].get(name) {
+        None | Some(Value::Null) => Ok(default),
+        Some(Value::Object(value)) => value
+            .values()
+            .next()
+            .and_then(json_u64)
+            .ok_or_else(|| LimitError::InvalidConfig(format!("{name}: {value:?}"))),
+        Some(value) => Err(LimitError::InvalidConfig(format!("{name}: {value}"))),
+    }
+}
+
+/// Length of the vector operand `Command::validity_check` bounds by
+/// `max_arguments`; the coin, destination and recipient are not counted.
+fn vector_operand(kind: &CommandKind) -> usize {
+    match kind {
+        CommandKind::MoveCall(call) => call.arguments.len(),
+        CommandKind::TransferObjects(transfer) => transfer.objects.len(),
+        CommandKind::SplitCoins(split) => split.amounts.len(),
+        CommandKind::MergeCoins(merge) => merge.sources.len(),
+        CommandKind::MakeMoveVec(make) => make.elements.len(),
+        CommandKind::Publish(_) | CommandKind::Upgrade(_) | CommandKind::Intent(_) => 0,
+    }
+}
+
+/// The operand `Command::validity_check` requires to be non-empty, if it is.
+/// `MakeMoveVec` may only be empty when its element type is given.
+fn empty_operand(kind: &CommandKind) -> Option<&'static str> {
+    match kind {
+        CommandKind::TransferObjects(transfer) if transfer.objects.is_empty() => Some("objects"),
+        CommandKind::SplitCoins(split) if split.amounts.is_empty() => Some("amounts"),
+        CommandKind::MergeCoins(merge) if merge.sources.is_empty() => Some("sources"),
+        CommandKind::MakeMoveVec(make) if make.type_arg.is_none() && make.elements.is_empty() => {
+            Some("elements")
+        }
+        CommandKind::Publish(publish) if publish.modules.is_empty() => Some("modules"),
+        CommandKind::Upgrade(upgrade) if upgrade.modules.is_empty() => Some("modules"),
+        _ => None,
+    }
+}
+
+fn command_kind(kind: &CommandKind) -> &'static str {
+    match kind {
+        CommandKind::MoveCall(_) => "MoveCall",
+        CommandKind::TransferObjects(_) => "TransferObjects",
+        CommandKind::SplitCoins(_) => "SplitCoins",
+        CommandKind::MergeCoins(_) => "MergeCoins",
+        CommandKind::Publish(_) => "Publish",
+        CommandKind::Upgrade(_) => "Upgrade",
+        CommandKind::MakeMoveVec(_) => "MakeMoveVec",
+        CommandKind::Intent(_) => "$Intent",
+    }
+}
+
+impl TransactionLimits {
+    /// Reads the limits from a `sui_getProtocolConfig` response. Attributes
+    /// the config leaves unset keep their defaults.
+    pub fn from_protocol_config(config: &Value) -> Result<Self, LimitError> {
+        let defaults = Self::default();
+        Ok(Self {
+            max_commands: attribute(
+                config,
+                "max_programmable_tx_commands",
+                defaults.max_commands,
+            )?,
+            max_input_objects: attribute(config, "max_input_objects", defaults.max_input_objects)?,
+            max_pure_argument_size: attribute(
+                config,
+                "max_pure_argument_size",
+                defaults.max_pure_argument_size,
+            )?,
+            max_arguments: attribute(config, "max_arguments", defaults.max_arguments)?,
+            max_type_arguments: attribute(
+                config,
+                "max_type_arguments",
+                defaults.max_type_arguments,
+            )?,
+            max_gas_objects: attribute(
+                config,
+                "max_gas_payment_objects",
+                defaults.max_gas_objects,
+            )?,
+            max_tx_size_bytes: attribute(config, "max_tx_size_bytes", defaults.max_tx_size_bytes)?,
+        })
+    }
+
+    /// The limits of the network's current protocol version.
+    pub async fn load(client: &jsonrpc::Client) -> Result<Self, LimitError> {
+        Self::from_protocol_config(&client.get_protocol_config(None).await?)
+    }
+
+    /// Checks the commands, inputs and gas payment of `tx`, which does not need
+    /// to be fully resolved yet.
+    pub fn check(&self, tx: &Transaction) -> Result<(), LimitError> {
+        let data = &tx.data;
+        if data.commands.len() as u64 >= self.max_commands {
+            return Err(LimitError::TooManyCommands {
+                count: data.commands.len(),
+                max: self.max_commands,
+            });
+        }
+
+        let gas_objects = data.gas_data.payment.as_ref().map_or(0, Vec::len);
+        if gas_objects as u64 > self.max_gas_objects {
+            return Err(LimitError::TooManyGasObjects {
+                count: gas_objects,
+                max: self.max_gas_objects,
+            });
+        }
+
+        let objects = data
+            .inputs
+            .iter()
+            .filter(|input| input.object_id().is_some())
+            .count()
+            + gas_objects;
+        if objects as u64 > self.max_input_objects {
+            return Err(LimitError::TooManyInputObjects {
+                count: objects,
+                max: self.max_input_objects,
+            });
+        }
+
+        for (index, input) in data.inputs.iter().enumerate() {
+            if let CallArg::Pure(pure) = input {
+                use base64::Engine as _;
+                let size = base64::engine::general_purpose::STANDARD
+                    .decode(&pure.bytes)
+                    .map_err(TransactionError::from)?
+                    .len();
+                if size as u64 > self.max_pure_argument_size {
+                    return Err(LimitError::PureArgumentTooLarge {
+                        index,
+                        size,
+                        max: self.max_pure_argument_size,
+                    });
+                }
+            }
+        }
+
+        for (index, command) in data.commands.iter().enumerate() {
+            let kind = command_kind(&command.kind);
+            if let Some(operand) = empty_operand(&command.kind) {
+                return Err(LimitError::EmptyOperand {
+                    index,
+                    kind,
+                    operand,
+                });
+            }
+            let count = vector_operand(&command.kind);
+            if count as u64 >= self.max_arguments {
+                return Err(LimitError::TooManyArguments {
+                    index,
+                    kind,
+                    count,
+                    max: self.max_arguments,
+                });
+            }
+            if let CommandKind::MoveCall(call) = &command.kind
+                && call.type_arguments.len() as u64 >= self.max_type_arguments
+            {
+                return Err(LimitError::TooManyTypeArguments {
+                    index,
+                    kind,
+                    count: call.type_arguments.len(),
+                    max: self.max_type_arguments,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the size of the BCS `TransactionData` about to be signed.
+    pub fn check_size(&self, tx_bytes: &[u8]) -> Result<(), LimitError> {
+        if tx_bytes.len() as u64 > self.max_tx_size_bytes {
+            return Err(LimitError::TransactionTooLarge {
+                size: tx_bytes.len(),
+                max: self.max_tx_size_bytes,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Transaction {
+    /// Builds the transaction, checking it against `limits` first.
+    pub fn build_checked(&self, limits: &TransactionLimits) -> Result<Vec<u8>, LimitError> {
+        limits.check(self)?;
+        let bytes = self.build()?;
+        limits.check_size(&bytes)?;
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::transactions::{Argument, ObjectRef, TransactionCommands};
+
+    fn limits() -> TransactionLimits {
+        TransactionLimits {
+            max_commands: 2,
+            max_input_objects: 2,
+            max_pure_argument_size: 8,
+            max_arguments: 3,
+            max_type_arguments: 1,
+            max_gas_objects: 1,
+            max_tx_size_bytes: 64,
+        }
+    }
+
+    fn gas(id: &str) -> ObjectRef {
+        ObjectRef {
+            object_id: id.to_string(),
+            digest: "11111111111111111111111111111111".to_string(),
+            version: 1,
+        }
+    }
+
+    #[test]
+    fn test_from_protocol_config() {
+        let limits = TransactionLimits::from_protocol_config(&json!({
+            "protocolVersion": "70",
+            "attributes": {
+                "max_programmable_tx_commands": {"u32": "1024"},
+                "max_input_objects": {"u64": "2048"},
+                "max_pure_argument_size": {"u32": "16384"},
+                "max_type_arguments": {"u32": "16"},
+                "max_gas_payment_objects": {"u32": "256"},
+                "max_tx_size_bytes": {"u64": "65536"},
+                "max_arguments": null
+            }
+        }))
+        .unwrap();
+        assert_eq!(limits.max_tx_size_bytes, 65536);
+        assert_eq!(limits.max_arguments, 512);
+        assert_eq!(
+            TransactionLimits::from_protocol_config(&json!({})).unwrap(),
+            TransactionLimits::default()
+        );
+        assert!(matches!(
+            TransactionLimits::from_protocol_config(&json!({
+                "attributes": {"max_tx_size_bytes": "big"}
+            })),
+            Err(LimitError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_check_names_offending_input_and_command() {
+        let mut tx = Transaction::new();
+        let small = tx.pure_bytes(&[1; 8]);
+        tx.pure_bytes(&[1; 9]);
+        let err = limits().check(&tx).unwrap_err();
+        assert!(matches!(
+            err,
+            LimitError::PureArgumentTooLarge {
+                index: 1,
+                size: 9,
+                ..
+            }
+        ));
+
+        let mut tx = Transaction::new();
+        tx.split_coins(Argument::GasCoin, vec![small; 3]);
+        let err = limits().check(&tx).unwrap_err();
+        assert!(matches!(
+            err,
+            LimitError::TooManyArguments {
+                index: 0,
+                kind: "SplitCoins",
+                count: 3,
+                ..
+            }
+        ));
+        tx.data.commands.remove(0);
+        tx.move_call(
+            "0x2::coin::zero",
+            vec![],
+            vec!["0x2::sui::SUI".to_string(), "0x2::sui::SUI".to_string()],
+        )
+        .unwrap();
+        let err = limits().check(&tx).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "command 0 (MoveCall) has 2 type arguments, the limit is 1"
+        );
+
+        tx.move_call("0x2::coin::zero", vec![], vec![]).unwrap();
+        tx.move_call("0x2::coin::zero", vec![], vec![]).unwrap();
+        assert!(matches!(
+            limits().check(&tx),
+            Err(LimitError::TooManyCommands { count: 3, max: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_check_limits_are_exclusive() {
+        let limits = limits();
+        let mut tx = Transaction::new();
+        let amount = tx.pure_bytes(&[1; 8]);
+        let recipient = tx.pure_bytes(&[2; 8]);
+        let coins = tx.split_coins(Argument::GasCoin, vec![amount; 2]);
+        assert!(limits.check(&tx).is_ok());
+
+        // The split coin and the recipient are not part of the vector operand.
+        tx.transfer_objects(vec![coins; 2], recipient);
+        assert!(matches!(
+            limits.check(&tx),
+            Err(LimitError::TooManyCommands { count: 2, max: 2 })
+        ));
+        tx.data.commands.remove(0);
+        assert!(limits.check(&tx).is_ok());
+
+        tx.data.commands.clear();
+        tx.split_coins(Argument::GasCoin, vec![amount; 3]);
+        assert!(matches!(
+            limits.check(&tx),
+            Err(LimitError::TooManyArguments { count: 3, max: 3, .. })
+        ));
+        tx.data.commands.clear();
+        tx.merge_coins(Argument::GasCoin, vec![amount; 2]);
+        assert!(limits.check(&tx).is_ok());
+        tx.data.commands.clear();
+        tx.merge_coins(Argument::GasCoin, vec![amount; 3]);
+        assert!(matches!(
+            limits.check(&tx),
+            Err(LimitError::TooManyArguments { kind: "MergeCoins", count: 3, .. })
+        ));
+
+        tx.data.commands.clear();
+        tx.move_call("0x2::coin::zero", vec![amount; 2], vec![]).unwrap();
+        assert!(limits.check(&tx).is_ok());
+        tx.data.commands.clear();
+        tx.move_call("0x2::coin::zero", vec![amount; 3], vec![]).unwrap();
+        assert!(matches!(
+            limits.check(&tx),
+            Err(LimitError::TooManyArguments { kind: "MoveCall", count: 3, .. })
+        ));
+
+        let limits = TransactionLimits {
+            max_type_arguments: 2,
+            ..limits
+        };
+        tx.data.commands.clear();
+        tx.move_call("0x2::coin::zero", vec![], vec!["0x2::sui::SUI".to_string()]).unwrap();
+        assert!(limits.check(&tx).is_ok());
+        tx.data.commands.clear();
+        tx.move_call("0x2::coin::zero", vec![], vec!["0x2::sui::SUI".to_string(); 2]).unwrap();
+        assert!(matches!(
+            limits.check(&tx),
+            Err(LimitError::TooManyTypeArguments { count: 2, max: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_check_rejects_empty_operands() {
+        let limits = TransactionLimits::default();
+        let mut tx = Transaction::new();
+        let amount = tx.pure_bytes(&[1; 8]);
+        let coins = tx.split_coins(Argument::GasCoin, vec![amount]);
+        tx.make_move_vec(Some("u64".to_string()), vec![]);
+        assert!(limits.check(&tx).is_ok());
+
+        let empty = [
+            (TransactionCommands::transfer_objects(vec![], amount), "TransferObjects", "objects"),
+            (TransactionCommands::split_coins(coins, vec![]), "SplitCoins", "amounts"),
+            (TransactionCommands::merge_coins(coins, vec![]), "MergeCoins", "sources"),
+            (TransactionCommands::make_move_vec(None, vec![]), "MakeMoveVec", "elements"),
+            (TransactionCommands::publish(vec![], vec![]), "Publish", "modules"),
+            (
+                TransactionCommands::upgrade(vec![], vec![], "0x2".to_string(), coins),
+                "Upgrade",
+                "modules",
+            ),
+        ];
+        for (command, expected_kind, expected_operand) in empty {
+            let mut tx = tx.clone();
+            tx.add_command(command);
+            let err = limits.check(&tx).unwrap_err();
+            assert!(
+                matches!(
+                    err,
+                    LimitError::EmptyOperand { index: 2, kind, operand }
+                        if kind == expected_kind && operand == expected_operand
+                ),
+                "{err}"
+            );
+        }
+        let mut tx = tx.clone();
+        tx.add_command(TransactionCommands::split_coins(coins, vec![]));
+        assert_eq!(
+            limits.check(&tx).unwrap_err().to_string(),
+            "command 2 (SplitCoins) has no amounts"
+        );
+    }
+
+    #[test]
+    fn test_check_counts_gas_objects() {
+        let mut tx = Transaction::new();
+        tx.object("0x6");
+        tx.set_gas_payment(vec![gas("0x5"), gas("0x7")]);
+        assert!(matches!(
+            limits().check(&tx),
+            Err(LimitError::TooManyGasObjects { count: 2, max: 1 })
+        ));
+
+        tx.set_gas_payment(vec![gas("0x5")]);
+        tx.object("0x8");
+        assert!(matches!(
+            limits().check(&tx),
+            Err(LimitError::TooManyInputObjects { count: 3, max: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_build_checked_limits_size() {
+        let mut tx = Transaction::new();
+        tx.set_sender("0x1");
+        tx.set_gas_budget(1_000_000);
+        tx.set_gas_price(1000);
+        tx.set_gas_payment(vec![gas("0x5")]);
+        let amount = tx.pure_bytes(&[1; 8]);
+        tx.split_coins(Argument::GasCoin, vec![amount]);
+
+        let size = tx.build().unwrap().len();
+        assert!(matches!(
+            tx.build_checked(&limits()),
+            Err(LimitError::TransactionTooLarge { max: 64, .. })
+        ));
+        let limits = TransactionLimits {
+            max_tx_size_bytes: size as u64,
+            ..limits()
+        };
+        assert_eq!(tx.build_checked(&limits).unwrap().len(), size);
+    }
+}
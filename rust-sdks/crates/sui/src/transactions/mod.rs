@@ -1,23 +1,43 @@
 pub mod arguments;
+pub mod bcs_types;
 pub mod builder;
 pub mod commands;
+pub mod display;
+pub mod gas;
 pub mod hash;
 pub mod inputs;
+mod json;
+pub mod limits;
+pub mod normalized;
 pub mod object;
 pub mod object_cache;
+pub mod package;
 pub mod plugins;
 pub mod pure;
 pub mod resolve;
 pub mod serializer;
+pub mod sponsor;
 pub mod types;
 pub mod utils;
 
 pub use arguments::Argument;
-pub use commands::{Command, CommandKind, TransactionCommands};
-pub use inputs::{ObjectKind, CallArg, ObjectRef, SharedObjectRef, TransactionInput};
+pub use commands::{Command, CommandKind, MoveCallTarget, TransactionCommands, UpgradePolicy};
+pub use gas::{CoinProvider, GasConfig, GasError, GasProvider, OwnedCoin};
+pub use inputs::{
+    ObjectKind, CallArg, ObjectRef, ReceivingRef, SharedObjectRef, TransactionInput,
+    UnresolvedObject, UnresolvedPure,
+};
+pub use limits::{LimitError, TransactionLimits};
 pub use object::SuiObject;
+pub use package::{CompiledPackage, PackageError};
+pub use normalized::{NormalizedMoveFunction, NormalizedType};
 pub use pure::PureValue;
-pub use types::{GasData, SignedTransaction, Transaction, TransactionData, TransactionError};
+pub use plugins::{PluginError, TransactionBuilder, TransactionPlugin};
+pub use resolve::{ObjectOwner, ObjectProvider, ResolveError, ResolvedObject};
+pub use types::{
+    GasData, SignedTransaction, Transaction, TransactionData, TransactionError,
+    TransactionExpiration,
+};
 
 pub const SUI_ADDRESS_LENGTH: usize = 32;
 
@@ -0,0 +1,248 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::bcs_types::TypeTag;
+use super::normalize_sui_address;
+
+/// A Move type as returned by `sui_getNormalizedMoveFunction`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NormalizedType {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    U256,
+    Address,
+    Signer,
+    Struct {
+        address: String,
+        module: String,
+        name: String,
+        #[serde(rename = "typeArguments", default)]
+        type_arguments: Vec<NormalizedType>,
+    },
+    Vector(Box<NormalizedType>),
+    TypeParameter(u16),
+    Reference(Box<NormalizedType>),
+    MutableReference(Box<NormalizedType>),
+}
+
+impl NormalizedType {
+    /// The type behind a reference, or the type itself for by-value parameters.
+    pub fn inner(&self) -> &NormalizedType {
+        match self {
+            NormalizedType::Reference(inner) | NormalizedType::MutableReference(inner) => inner,
+            other => other,
+        }
+    }
+
+    /// Substitutes `TypeParameter`s with the call's type arguments. Parameters
+    /// without a matching argument are left as they are.
+    pub fn instantiate(&self, type_arguments: &[NormalizedType]) -> NormalizedType {
+        match self {
+            NormalizedType::TypeParameter(index) => type_arguments
+                .get(*index as usize)
+                .cloned()
+                .unwrap_or_else(|| self.clone()),
+            NormalizedType::Struct {
+                address,
+                module,
+                name,
+                type_arguments: args,
+            } => NormalizedType::Struct {
+                address: address.clone(),
+                module: module.clone(),
+                name: name.clone(),
+                type_arguments: args.iter().map(|a| a.instantiate(type_arguments)).collect(),
+            },
+            NormalizedType::Vector(inner) => {
+                NormalizedType::Vector(Box::new(inner.instantiate(type_arguments)))
+            }
+            NormalizedType::Reference(inner) => {
+                NormalizedType::Reference(Box::new(inner.instantiate(type_arguments)))
+            }
+            NormalizedType::MutableReference(inner) => {
+                NormalizedType::MutableReference(Box::new(inner.instantiate(type_arguments)))
+            }
+            other => other.clone(),
+        }
+    }
+
+    pub fn is_struct(&self, address: &str, module: &str, name: &str) -> bool {
+        match self {
+            NormalizedType::Struct {
+                address: a,
+                module: m,
+                name: n,
+                ..
+            } => {
+                normalize_sui_address(a) == normalize_sui_address(address)
+                    && m == module
+                    && n == name
+            }
+            _ => false,
+        }
+    }
+
+    pub fn is_tx_context(&self) -> bool {
+        self.inner().is_struct("0x2", "tx_context", "TxContext")
+    }
+
+    pub fn is_receiving(&self) -> bool {
+        self.is_struct("0x2", "transfer", "Receiving")
+    }
+}
+
+impl From<&TypeTag> for NormalizedType {
+    fn from(tag: &TypeTag) -> Self {
+        match tag {
+            TypeTag::Bool => NormalizedType::Bool,
+            TypeTag::U8 => NormalizedType::U8,
+            TypeTag::U16 => NormalizedType::U16,
+            TypeTag::U32 => NormalizedType::U32,
+            TypeTag::U64 => NormalizedType::U64,
+            TypeTag::U128 => NormalizedType::U128,
+            TypeTag::U256 => NormalizedType::U256,
+            TypeTag::Address => NormalizedType::Address,
+            TypeTag::Signer => NormalizedType::Signer,
+            TypeTag::Vector(inner) => NormalizedType::Vector(Box::new(inner.as_ref().into())),
+            TypeTag::Struct(tag) => NormalizedType::Struct {
+                address: tag.address.to_string(),
+                module: tag.module.clone(),
+                name: tag.name.clone(),
+                type_arguments: tag.type_params.iter().map(Into::into).collect(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for NormalizedType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NormalizedType::Bool => write!(f, "bool"),
+            NormalizedType::U8 => write!(f, "u8"),
+            NormalizedType::U16 => write!(f, "u16"),
+            NormalizedType::U32 => write!(f, "u32"),
+            NormalizedType::U64 => write!(f, "u64"),
+            NormalizedType::U128 => write!(f, "u128"),
+            NormalizedType::U256 => write!(f, "u256"),
+            NormalizedType::Address => write!(f, "address"),
+            NormalizedType::Signer => write!(f, "signer"),
+            NormalizedType::Struct {
+                address,
+                module,
+                name,
+                type_arguments,
+            } => {
+                write!(f, "{address}::{module}::{name}")?;
+                if !type_arguments.is_empty() {
+                    let args: Vec<String> =
+                        type_arguments.iter().map(ToString::to_string).collect();
+                    write!(f, "<{}>", args.join(", "))?;
+                }
+                Ok(())
+            }
+            NormalizedType::Vector(inner) => write!(f, "vector<{inner}>"),
+            NormalizedType::TypeParameter(index) => write!(f, "T{index}"),
+            NormalizedType::Reference(inner) => write!(f, "&{inner}"),
+            NormalizedType::MutableReference(inner) => write!(f, "&mut {inner}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedMoveFunction {
+    #[serde(default)]
+    pub visibility: String,
+    #[serde(rename = "isEntry", default)]
+    pub is_entry: bool,
+    #[serde(rename = "typeParameters", default)]
+    pub type_parameters: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub parameters: Vec<NormalizedType>,
+    #[serde(rename = "return", default)]
+    pub return_types: Vec<NormalizedType>,
+}
+
+impl NormalizedMoveFunction {
+    /// Parameters supplied by the caller, i.e. without a trailing `TxContext`.
+    pub fn user_parameters(&self) -> &[NormalizedType] {
+        match self.parameters.last() {
+            Some(last) if last.is_tx_context() => &self.parameters[..self.parameters.len() - 1],
+            _ => &self.parameters,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_normalized_function() {
+        let function: NormalizedMoveFunction = serde_json::from_value(json!({
+            "visibility": "Public",
+            "isEntry": false,
+            "typeParameters": [{"abilities": []}],
+            "parameters": [
+                {"MutableReference": {"Struct": {
+                    "address": "0x2", "module": "coin", "name": "Coin",
+                    "typeArguments": [{"TypeParameter": 0}]
+                }}},
+                "U64",
+                {"Vector": "U8"},
+                {"MutableReference": {"Struct": {
+                    "address": "0x2", "module": "tx_context", "name": "TxContext",
+                    "typeArguments": []
+                }}}
+            ],
+            "return": []
+        }))
+        .unwrap();
+
+        assert_eq!(function.parameters.len(), 4);
+        assert_eq!(function.user_parameters().len(), 3);
+        assert_eq!(function.parameters[1], NormalizedType::U64);
+        assert_eq!(
+            function.parameters[2],
+            NormalizedType::Vector(Box::new(NormalizedType::U8))
+        );
+        assert!(
+            function.parameters[0]
+                .inner()
+                .is_struct("0x2", "coin", "Coin")
+        );
+    }
+
+    #[test]
+    fn test_receiving_type() {
+        let receiving: NormalizedType = serde_json::from_value(json!({"Struct": {
+            "address": "0x0000000000000000000000000000000000000000000000000000000000000002",
+            "module": "transfer", "name": "Receiving",
+            "typeArguments": [{"TypeParameter": 0}]
+        }}))
+        .unwrap();
+        assert!(receiving.is_receiving());
+        assert!(!NormalizedType::Address.is_receiving());
+    }
+
+    #[test]
+    fn test_normalized_type_from_type_tag() {
+        let tag: TypeTag = "0x1::option::Option<vector<u64>>".parse().unwrap();
+        let ty = NormalizedType::from(&tag);
+        assert!(ty.is_struct("0x1", "option", "Option"));
+        assert_eq!(
+            ty.to_string(),
+            format!("0x{:0>64}::option::Option<vector<u64>>", "1")
+        );
+        assert_eq!(
+            NormalizedType::MutableReference(Box::new(NormalizedType::TypeParameter(0)))
+                .to_string(),
+            "&mut T0"
+        );
+    }
+}
@@ -0,0 +1,185 @@
+//! Publishing and upgrading Move packages compiled with
+//! `sui move build --dump-bytecode-as-base64`.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::arguments::Argument;
+use super::commands::UpgradePolicy;
+use super::pure::PureValue;
+use super::types::{Transaction, TransactionError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum PackageError {
+    #[error("read package failed: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid package json: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("invalid module bytecode: {0}")]
+    Base64(#[from] base64::DecodeError),
+    #[error("invalid package digest: expected 32 bytes, got {0}")]
+    InvalidDigest(usize),
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
+}
+
+/// Output of `sui move build --dump-bytecode-as-base64`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompiledPackage {
+    /// Base64 bytecode of every module.
+    pub modules: Vec<String>,
+    /// Ids of every package the modules link against, including transitive ones.
+    pub dependencies: Vec<String>,
+    /// Digest the upgrade ticket commits to.
+    #[serde(default)]
+    pub digest: Vec<u8>,
+}
+
+impl CompiledPackage {
+    pub fn from_json(json: &str) -> Result<Self, PackageError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, PackageError> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn module_bytes(&self) -> Result<Vec<Vec<u8>>, PackageError> {
+        use base64::Engine as _;
+        self.modules
+            .iter()
+            .map(|module| Ok(base64::engine::general_purpose::STANDARD.decode(module)?))
+            .collect()
+    }
+
+    pub fn digest_bytes(&self) -> Result<[u8; 32], PackageError> {
+        self.digest
+            .as_slice()
+            .try_into()
+            .map_err(|_| PackageError::InvalidDigest(self.digest.len()))
+    }
+}
+
+impl Transaction {
+    /// Publishes `package` and transfers its `UpgradeCap` to `recipient`.
+    pub fn publish_package(
+        &mut self,
+        package: &CompiledPackage,
+        recipient: impl Into<String>,
+    ) -> Result<Argument, PackageError> {
+        let upgrade_cap = self.publish(package.module_bytes()?, package.dependencies.clone());
+        let recipient = self.pure_typed(&PureValue::Address(recipient.into()))?;
+        self.transfer_objects(vec![upgrade_cap], recipient);
+        Ok(upgrade_cap)
+    }
+
+    /// Upgrades `package_id` to `package`: authorizes the upgrade with
+    /// `upgrade_cap` under `policy`, runs it, and commits the receipt back to
+    /// the cap.
+    pub fn upgrade_package(
+        &mut self,
+        package: &CompiledPackage,
+        package_id: impl Into<String>,
+        upgrade_cap: Argument,
+        policy: UpgradePolicy,
+    ) -> Result<Argument, PackageError> {
+        let policy = self.pure_typed(&PureValue::U8(policy as u8))?;
+        let digest = self.pure_typed(&PureValue::Vec(package.digest_bytes()?.to_vec()))?;
+        let ticket = self.move_call(
+            "0x2::package::authorize_upgrade",
+            vec![upgrade_cap, policy, digest],
+            vec![],
+        )?;
+        let receipt = self.upgrade(
+            package.module_bytes()?,
+            package.dependencies.clone(),
+            package_id,
+            ticket,
+        );
+        Ok(self.move_call(
+            "0x2::package::commit_upgrade",
+            vec![upgrade_cap, receipt],
+            vec![],
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transactions::CommandKind;
+
+    fn package() -> CompiledPackage {
+        CompiledPackage::from_json(&format!(
+            r#"{{"modules":["oRzrCwYAAAA=","AQI="],"dependencies":["0x1","0x2"],"digest":{:?}}}"#,
+            [7u8; 32]
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_compiled_package_from_json() {
+        let package = package();
+        assert_eq!(package.module_bytes().unwrap()[1], vec![1, 2]);
+        assert_eq!(package.digest_bytes().unwrap(), [7u8; 32]);
+
+        let no_digest = CompiledPackage::from_json(r#"{"modules":[],"dependencies":[]}"#).unwrap();
+        assert!(matches!(
+            no_digest.digest_bytes(),
+            Err(PackageError::InvalidDigest(0))
+        ));
+        assert!(CompiledPackage::from_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_publish_package_transfers_upgrade_cap() {
+        let mut tx = Transaction::new();
+        let cap = tx.publish_package(&package(), "0xa").unwrap();
+        assert_eq!(cap, Argument::Result(0));
+        let CommandKind::Publish(publish) = &tx.data.commands[0].kind else {
+            panic!("expected Publish");
+        };
+        assert_eq!(publish.modules, vec!["oRzrCwYAAAA=", "AQI="]);
+        assert_eq!(publish.dependencies[1], format!("0x{:0>64}", "2"));
+        let CommandKind::TransferObjects(transfer) = &tx.data.commands[1].kind else {
+            panic!("expected TransferObjects");
+        };
+        assert_eq!(transfer.objects, vec![cap]);
+    }
+
+    #[test]
+    fn test_upgrade_package_runs_ticket_and_receipt() {
+        let mut tx = Transaction::new();
+        let cap = tx.object_ref(crate::transactions::ObjectRef {
+            object_id: "0xc".to_string(),
+            digest: "11111111111111111111111111111111".to_string(),
+            version: 3,
+        });
+        tx.upgrade_package(&package(), "0xb", cap, UpgradePolicy::Additive)
+            .unwrap();
+
+        let kinds: Vec<_> = tx.data.commands.iter().map(|c| &c.kind).collect();
+        let CommandKind::MoveCall(authorize) = kinds[0] else {
+            panic!("expected authorize_upgrade");
+        };
+        assert_eq!(authorize.function, "authorize_upgrade");
+        assert_eq!(authorize.arguments[0], cap);
+        let CommandKind::Upgrade(upgrade) = kinds[1] else {
+            panic!("expected Upgrade");
+        };
+        assert_eq!(upgrade.ticket, Argument::Result(0));
+        assert_eq!(upgrade.package, format!("0x{:0>64}", "b"));
+        let CommandKind::MoveCall(commit) = kinds[2] else {
+            panic!("expected commit_upgrade");
+        };
+        assert_eq!(commit.function, "commit_upgrade");
+        assert_eq!(commit.arguments, vec![cap, Argument::Result(1)]);
+
+        let crate::transactions::CallArg::Pure(policy) = &tx.data.inputs[1] else {
+            panic!("expected pure policy");
+        };
+        assert_eq!(policy.bytes, "gA==");
+        tx.build_kind().expect("upgrade kind serializes");
+    }
+}
@@ -1,35 +1,324 @@
 pub mod plugin;
 
-pub use plugin::TransactionPlugin;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::intents::{CoinWithBalanceResolver, IntentError};
+use crate::jsonrpc;
+
+use super::gas::{GasConfig, GasError};
+use super::resolve::{CachingObjectProvider, ResolveError};
+use super::types::{Transaction, TransactionError};
+
+pub use plugin::{GasPlugin, NamedPackagesPlugin, ResolveInputsPlugin, TransactionPlugin};
+
+#[derive(Debug, thiserror::Error)]
+pub enum PluginError {
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
+    #[error(transparent)]
+    Resolve(#[from] ResolveError),
+    #[error(transparent)]
+    Gas(#[from] GasError),
+    #[error(transparent)]
+    Intent(#[from] IntentError),
+    #[error("unresolved named package: {0}")]
+    UnresolvedNamedPackage(String),
+    #[error("plugin {plugin} failed: {message}")]
+    Plugin { plugin: String, message: String },
+}
 
 pub trait TransactionBuilder {
     fn add_plugin(&mut self, plugin: Box<dyn TransactionPlugin>);
     fn remove_plugin(&mut self, name: &str);
 }
 
+/// Plugins registered on a [`Transaction`], in the order they were added.
+#[derive(Clone, Default)]
+pub struct TransactionPlugins {
+    plugins: Vec<Arc<dyn TransactionPlugin>>,
+    /// Whether plugins were added since the pipeline last ran.
+    pending: bool,
+}
+
+impl TransactionPlugins {
+    pub fn names(&self) -> Vec<String> {
+        self.plugins.iter().map(|plugin| plugin.name()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.plugins.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+
+    /// Whether [`Transaction::prepare`] still has to run before building.
+    pub fn is_pending(&self) -> bool {
+        self.pending && !self.plugins.is_empty()
+    }
+}
+
+impl fmt::Debug for TransactionPlugins {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
+impl TransactionBuilder for Transaction {
+    fn add_plugin(&mut self, plugin: Box<dyn TransactionPlugin>) {
+        self.plugins.plugins.push(Arc::from(plugin));
+        self.plugins.pending = true;
+    }
+
+    fn remove_plugin(&mut self, name: &str) {
+        self.plugins.plugins.retain(|plugin| plugin.name() != name);
+    }
+}
+
+impl Transaction {
+    /// Registers the input, intent and gas plugins backed by `client`. The
+    /// input plugin caches Move signatures, so preparing the transaction again
+    /// skips those lookups.
+    pub fn add_client_plugins(&mut self, client: &jsonrpc::Client) {
+        let provider = CachingObjectProvider::new(client.clone());
+        self.add_plugin(Box::new(ResolveInputsPlugin::new(Arc::new(provider))));
+        let client = Arc::new(client.clone());
+        self.add_plugin(Box::new(CoinWithBalanceResolver::new(client.clone())));
+        self.add_plugin(Box::new(GasPlugin::new(client, GasConfig::default())));
+    }
+
+    /// Runs every plugin hook stage by stage: named packages, intents,
+    /// inputs, gas and finally validation. Within a stage plugins run in the
+    /// order they were added.
+    pub async fn prepare(&mut self) -> Result<(), PluginError> {
+        let plugins = self.plugins.plugins.clone();
+        for plugin in &plugins {
+            plugin.resolve_named_packages(self).await?;
+        }
+        for plugin in &plugins {
+            plugin.resolve_intents(self).await?;
+        }
+        for plugin in &plugins {
+            plugin.resolve_inputs(self).await?;
+        }
+        for plugin in &plugins {
+            plugin.resolve_gas(self).await?;
+        }
+        for plugin in &plugins {
+            plugin.validate(self).await?;
+        }
+        self.plugins.pending = false;
+        Ok(())
+    }
+
+    /// Runs the plugin pipeline, then serializes the transaction to BCS. This
+    /// is the way to build transactions that have plugins registered.
+    pub async fn build_async(&mut self) -> Result<Vec<u8>, PluginError> {
+        self.prepare().await?;
+        Ok(self.build()?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::sync::Mutex;
+
     use super::*;
+    use crate::transactions::resolve::BoxFuture;
+    use crate::transactions::{CommandKind, ObjectRef};
+
+    struct RecordingPlugin {
+        name: String,
+        calls: Arc<Mutex<Vec<String>>>,
+    }
 
-    #[test]
-    fn test_plugin_trait() {
-        struct TestPlugin;
+    impl RecordingPlugin {
+        fn record(&self, stage: &str) {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("{}:{stage}", self.name));
+        }
+    }
+
+    impl TransactionPlugin for RecordingPlugin {
+        fn name(&self) -> String {
+            self.name.clone()
+        }
+
+        fn resolve_named_packages<'a>(
+            &'a self,
+            _tx: &'a mut Transaction,
+        ) -> BoxFuture<'a, Result<(), PluginError>> {
+            self.record("packages");
+            Box::pin(async { Ok(()) })
+        }
+
+        fn resolve_intents<'a>(
+            &'a self,
+            _tx: &'a mut Transaction,
+        ) -> BoxFuture<'a, Result<(), PluginError>> {
+            self.record("intents");
+            Box::pin(async { Ok(()) })
+        }
+
+        fn resolve_inputs<'a>(
+            &'a self,
+            _tx: &'a mut Transaction,
+        ) -> BoxFuture<'a, Result<(), PluginError>> {
+            self.record("inputs");
+            Box::pin(async { Ok(()) })
+        }
 
-        impl TransactionPlugin for TestPlugin {
-            fn name(&self) -> String {
-                "test".to_string()
-            }
+        fn resolve_gas<'a>(
+            &'a self,
+            _tx: &'a mut Transaction,
+        ) -> BoxFuture<'a, Result<(), PluginError>> {
+            self.record("gas");
+            Box::pin(async { Ok(()) })
+        }
+
+        fn validate<'a>(&'a self, _tx: &'a Transaction) -> BoxFuture<'a, Result<(), PluginError>> {
+            self.record("validate");
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    struct SponsorPlugin;
 
-            fn before_build(&mut self, _tx: &mut serde_json::Value) -> Result<(), String> {
+    impl TransactionPlugin for SponsorPlugin {
+        fn name(&self) -> String {
+            "sponsor".to_string()
+        }
+
+        fn resolve_gas<'a>(
+            &'a self,
+            tx: &'a mut Transaction,
+        ) -> BoxFuture<'a, Result<(), PluginError>> {
+            Box::pin(async move {
+                tx.set_gas_owner("0x99");
+                tx.set_gas_price(1000);
+                tx.set_gas_budget(1_000_000);
+                tx.set_gas_payment(vec![ObjectRef {
+                    object_id: "0x5".to_string(),
+                    digest: "11111111111111111111111111111111".to_string(),
+                    version: 1,
+                }]);
                 Ok(())
-            }
+            })
+        }
+    }
+
+    struct PolicyPlugin;
 
-            fn after_build(&mut self, _tx: &serde_json::Value) -> Result<(), String> {
+    impl TransactionPlugin for PolicyPlugin {
+        fn name(&self) -> String {
+            "policy".to_string()
+        }
+
+        fn validate<'a>(&'a self, tx: &'a Transaction) -> BoxFuture<'a, Result<(), PluginError>> {
+            Box::pin(async move {
+                if tx.data.commands.len() > 1 {
+                    return Err(PluginError::Plugin {
+                        plugin: self.name(),
+                        message: "too many commands".to_string(),
+                    });
+                }
                 Ok(())
-            }
+            })
         }
+    }
+
+    fn recording(name: &str, calls: &Arc<Mutex<Vec<String>>>) -> Box<dyn TransactionPlugin> {
+        Box::new(RecordingPlugin {
+            name: name.to_string(),
+            calls: calls.clone(),
+        })
+    }
 
-        let plugin: Box<dyn TransactionPlugin> = Box::new(TestPlugin {});
-        assert_eq!(plugin.name(), "test");
+    #[tokio::test]
+    async fn test_pipeline_runs_stages_in_order() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let mut tx = Transaction::new();
+        tx.add_plugin(recording("a", &calls));
+        tx.add_plugin(recording("b", &calls));
+
+        tx.prepare().await.unwrap();
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![
+                "a:packages",
+                "b:packages",
+                "a:intents",
+                "b:intents",
+                "a:inputs",
+                "b:inputs",
+                "a:gas",
+                "b:gas",
+                "a:validate",
+                "b:validate",
+            ]
+        );
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_remove_plugin() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let mut tx = Transaction::new();
+        tx.add_plugin(recording("a", &calls));
+        tx.add_plugin(recording("b", &calls));
+        tx.remove_plugin("a");
+
+        assert_eq!(tx.plugins.names(), vec!["b"]);
+        assert_eq!(format!("{:?}", tx.plugins), r#"["b"]"#);
+        tx.prepare().await.unwrap();
+        assert!(calls.lock().unwrap().iter().all(|c| c.starts_with("b:")));
+    }
+
+    #[tokio::test]
+    async fn test_custom_plugins_during_build() {
+        let mut tx = Transaction::new();
+        tx.set_sender("0x1");
+        tx.add_plugin(Box::new(SponsorPlugin));
+        tx.add_plugin(Box::new(PolicyPlugin));
+        let amount = tx.pure_bytes(&1u64.to_le_bytes());
+        tx.split_coins(Transaction::gas(), vec![amount]);
+
+        let bytes = tx.build_async().await.unwrap();
+        assert_eq!(bytes, tx.build().unwrap());
+        assert_eq!(tx.data.gas_data.owner.as_deref(), Some("0x99"));
+
+        tx.move_call("0x2::coin::value", vec![], vec![]).unwrap();
+        let err = tx.build_async().await.unwrap_err();
+        assert!(matches!(err, PluginError::Plugin { ref plugin, .. } if plugin == "policy"));
+        assert!(matches!(tx.data.commands[1].kind, CommandKind::MoveCall(_)));
+    }
+
+    #[tokio::test]
+    async fn test_build_requires_pipeline() {
+        let mut tx = Transaction::new();
+        tx.set_sender("0x1");
+        let amount = tx.pure_bytes(&1u64.to_le_bytes());
+        tx.split_coins(Transaction::gas(), vec![amount]);
+        tx.add_plugin(Box::new(SponsorPlugin));
+
+        let err = tx.build().unwrap_err();
+        assert!(matches!(
+            err,
+            TransactionError::PluginsNotRun(ref names) if names == &["sponsor"]
+        ));
+        assert!(tx.build_kind().is_err());
+
+        tx.build_async().await.unwrap();
+        assert!(tx.build().is_ok());
+
+        tx.add_plugin(Box::new(PolicyPlugin));
+        assert!(tx.build().is_err());
+        tx.remove_plugin("policy");
+        assert!(tx.build().is_err());
+        tx.prepare().await.unwrap();
+        assert!(tx.build().is_ok());
+    }
+}
@@ -1,9 +1,148 @@
+use std::sync::Arc;
+
+use super::PluginError;
+use crate::intents::{CoinWithBalanceResolver, IntentResolver};
+use crate::transactions::gas::{GasConfig, GasProvider};
+use crate::transactions::resolve::{BoxFuture, ObjectProvider};
+use crate::transactions::{CommandKind, Transaction};
+
+/// A step in the [`Transaction::prepare`] pipeline. Every hook defaults to a
+/// no-op, so plugins only implement the stages they care about.
 pub trait TransactionPlugin: Send + Sync {
     fn name(&self) -> String;
 
-    fn before_build(&mut self, tx: &mut serde_json::Value) -> Result<(), String>;
+    fn resolve_named_packages<'a>(
+        &'a self,
+        _tx: &'a mut Transaction,
+    ) -> BoxFuture<'a, Result<(), PluginError>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn resolve_intents<'a>(
+        &'a self,
+        _tx: &'a mut Transaction,
+    ) -> BoxFuture<'a, Result<(), PluginError>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn resolve_inputs<'a>(
+        &'a self,
+        _tx: &'a mut Transaction,
+    ) -> BoxFuture<'a, Result<(), PluginError>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn resolve_gas<'a>(
+        &'a self,
+        _tx: &'a mut Transaction,
+    ) -> BoxFuture<'a, Result<(), PluginError>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn validate<'a>(&'a self, _tx: &'a Transaction) -> BoxFuture<'a, Result<(), PluginError>> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Lets one plugin instance be registered on many transactions.
+impl<P: TransactionPlugin + ?Sized> TransactionPlugin for Arc<P> {
+    fn name(&self) -> String {
+        (**self).name()
+    }
+
+    fn resolve_named_packages<'a>(
+        &'a self,
+        tx: &'a mut Transaction,
+    ) -> BoxFuture<'a, Result<(), PluginError>> {
+        (**self).resolve_named_packages(tx)
+    }
+
+    fn resolve_intents<'a>(
+        &'a self,
+        tx: &'a mut Transaction,
+    ) -> BoxFuture<'a, Result<(), PluginError>> {
+        (**self).resolve_intents(tx)
+    }
+
+    fn resolve_inputs<'a>(
+        &'a self,
+        tx: &'a mut Transaction,
+    ) -> BoxFuture<'a, Result<(), PluginError>> {
+        (**self).resolve_inputs(tx)
+    }
+
+    fn resolve_gas<'a>(
+        &'a self,
+        tx: &'a mut Transaction,
+    ) -> BoxFuture<'a, Result<(), PluginError>> {
+        (**self).resolve_gas(tx)
+    }
+
+    fn validate<'a>(&'a self, tx: &'a Transaction) -> BoxFuture<'a, Result<(), PluginError>> {
+        (**self).validate(tx)
+    }
+}
+
+/// Resolves unresolved object and pure inputs through an [`ObjectProvider`].
+pub struct ResolveInputsPlugin {
+    provider: Arc<dyn ObjectProvider>,
+}
+
+impl ResolveInputsPlugin {
+    pub fn new(provider: Arc<dyn ObjectProvider>) -> Self {
+        Self { provider }
+    }
+}
 
-    fn after_build(&mut self, tx: &serde_json::Value) -> Result<(), String>;
+impl TransactionPlugin for ResolveInputsPlugin {
+    fn name(&self) -> String {
+        "ResolveInputs".to_string()
+    }
+
+    fn resolve_inputs<'a>(
+        &'a self,
+        tx: &'a mut Transaction,
+    ) -> BoxFuture<'a, Result<(), PluginError>> {
+        Box::pin(async move { Ok(tx.resolve(self.provider.as_ref()).await?) })
+    }
+}
+
+impl TransactionPlugin for CoinWithBalanceResolver {
+    fn name(&self) -> String {
+        IntentResolver::name(self).to_string()
+    }
+
+    fn resolve_intents<'a>(
+        &'a self,
+        tx: &'a mut Transaction,
+    ) -> BoxFuture<'a, Result<(), PluginError>> {
+        Box::pin(async move { Ok(IntentResolver::resolve(self, tx).await?) })
+    }
+}
+
+/// Fills in gas price, budget and payment through a [`GasProvider`].
+pub struct GasPlugin {
+    provider: Arc<dyn GasProvider>,
+    config: GasConfig,
+}
+
+impl GasPlugin {
+    pub fn new(provider: Arc<dyn GasProvider>, config: GasConfig) -> Self {
+        Self { provider, config }
+    }
+}
+
+impl TransactionPlugin for GasPlugin {
+    fn name(&self) -> String {
+        "Gas".to_string()
+    }
+
+    fn resolve_gas<'a>(
+        &'a self,
+        tx: &'a mut Transaction,
+    ) -> BoxFuture<'a, Result<(), PluginError>> {
+        Box::pin(async move { Ok(tx.resolve_gas(self.provider.as_ref(), &self.config).await?) })
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -21,6 +160,19 @@ impl NamedPackagesPlugin {
     pub fn add_package(&mut self, name: String, address: String) {
         self.packages.push((name, address));
     }
+
+    fn address(&self, package: &str) -> Option<&str> {
+        self.packages
+            .iter()
+            .find(|(name, _)| name == package)
+            .map(|(_, address)| address.as_str())
+    }
+
+    fn replace_in_type(&self, type_str: &str) -> String {
+        map_type_packages(type_str, |package| {
+            self.address(package).unwrap_or(package).to_string()
+        })
+    }
 }
 
 impl Default for NamedPackagesPlugin {
@@ -34,57 +186,100 @@ impl TransactionPlugin for NamedPackagesPlugin {
         "NamedPackages".to_string()
     }
 
-    fn before_build(&mut self, tx: &mut serde_json::Value) -> Result<(), String> {
-        replace_named_packages(tx, &self.packages);
-        Ok(())
+    fn resolve_named_packages<'a>(
+        &'a self,
+        tx: &'a mut Transaction,
+    ) -> BoxFuture<'a, Result<(), PluginError>> {
+        Box::pin(async move {
+            for command in &mut tx.data.commands {
+                match &mut command.kind {
+                    CommandKind::MoveCall(call) => {
+                        if let Some(address) = self.address(&call.package) {
+                            call.package = address.to_string();
+                        }
+                        for type_arg in &mut call.type_arguments {
+                            *type_arg = self.replace_in_type(type_arg);
+                        }
+                    }
+                    CommandKind::MakeMoveVec(make) => {
+                        if let Some(type_arg) = &mut make.type_arg {
+                            *type_arg = self.replace_in_type(type_arg);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Ok(())
+        })
     }
 
-    fn after_build(&mut self, tx: &serde_json::Value) -> Result<(), String> {
-        if let Some(name) = find_unresolved_named_package(tx) {
-            return Err(format!("unresolved named package: {name}"));
-        }
-        Ok(())
+    fn validate<'a>(&'a self, tx: &'a Transaction) -> BoxFuture<'a, Result<(), PluginError>> {
+        Box::pin(async move {
+            match find_unresolved_named_package(tx) {
+                Some(name) => Err(PluginError::UnresolvedNamedPackage(name)),
+                None => Ok(()),
+            }
+        })
     }
 }
 
-fn replace_named_packages(node: &mut serde_json::Value, packages: &[(String, String)]) {
-    match node {
-        serde_json::Value::Object(map) => {
-            for value in map.values_mut() {
-                replace_named_packages(value, packages);
+/// Rewrites the package of every struct path in a type string, e.g. both
+/// `mysten/sui` occurrences in `mysten/sui::coin::Coin<mysten/sui::sui::SUI>`.
+fn map_type_packages(type_str: &str, mut f: impl FnMut(&str) -> String) -> String {
+    let mut out = String::with_capacity(type_str.len());
+    let mut segment = String::new();
+    let mut flush = |segment: &mut String, out: &mut String| {
+        match segment.split_once("::") {
+            Some((package, rest)) => {
+                out.push_str(&f(package));
+                out.push_str("::");
+                out.push_str(rest);
             }
+            None => out.push_str(segment),
         }
-        serde_json::Value::Array(arr) => {
-            for value in arr.iter_mut() {
-                replace_named_packages(value, packages);
-            }
+        segment.clear();
+    };
+    for c in type_str.chars() {
+        if matches!(c, '<' | '>' | ',' | ' ') {
+            flush(&mut segment, &mut out);
+            out.push(c);
+        } else {
+            segment.push(c);
         }
-        serde_json::Value::String(s) => {
-            for (name, address) in packages {
-                let needle = format!("{name}::");
-                if s.contains(&needle) {
-                    *s = s.replace(&needle, &format!("{address}::"));
-                }
-            }
-        }
-        _ => {}
     }
+    flush(&mut segment, &mut out);
+    out
+}
+
+fn is_named_package(package: &str) -> bool {
+    package.contains('/')
 }
 
-fn find_unresolved_named_package(node: &serde_json::Value) -> Option<String> {
-    match node {
-        serde_json::Value::Object(map) => map.values().find_map(find_unresolved_named_package),
-        serde_json::Value::Array(arr) => arr.iter().find_map(find_unresolved_named_package),
-        serde_json::Value::String(s) => {
-            if let Some((package, _)) = s.split_once("::") {
-                if package.contains('/') {
-                    return Some(package.to_string());
+fn find_unresolved_named_package(tx: &Transaction) -> Option<String> {
+    let mut unresolved = None;
+    let mut check = |type_str: &str| {
+        map_type_packages(type_str, |package| {
+            if unresolved.is_none() && is_named_package(package) {
+                unresolved = Some(package.to_string());
+            }
+            package.to_string()
+        });
+    };
+    for command in &tx.data.commands {
+        match &command.kind {
+            CommandKind::MoveCall(call) => {
+                check(&format!("{}::", call.package));
+                call.type_arguments.iter().for_each(|t| check(t));
+            }
+            CommandKind::MakeMoveVec(make) => {
+                if let Some(type_arg) = &make.type_arg {
+                    check(type_arg);
                 }
             }
-            None
+            _ => {}
         }
-        _ => None,
     }
+    unresolved
 }
 
 #[cfg(test)]
@@ -101,20 +296,56 @@ mod tests {
         assert_eq!(plugin.packages[0].1, "0x2");
     }
 
-    #[test]
-    fn test_plugin_lifecycle() {
+    #[tokio::test]
+    async fn test_plugin_lifecycle() {
         let mut plugin = NamedPackagesPlugin::new();
         plugin.add_package("mysten/sui".to_string(), "0x2".to_string());
 
-        let mut tx = serde_json::json!({
-            "package": "mysten/sui::coin::Coin",
-            "typeArguments": ["mysten/sui::coin::Coin<mysten/sui::sui::SUI>"]
-        });
+        let mut tx = Transaction::new();
+        tx.move_call(
+            "mysten/sui::coin::zero",
+            vec![],
+            vec!["mysten/sui::coin::Coin<mysten/sui::sui::SUI>".to_string()],
+        )
+        .unwrap();
+        tx.make_move_vec(Some("mysten/sui::sui::SUI".to_string()), vec![]);
+
+        assert!(plugin.resolve_named_packages(&mut tx).await.is_ok());
+        assert!(plugin.validate(&tx).await.is_ok());
+        let CommandKind::MoveCall(call) = &tx.data.commands[0].kind else {
+            panic!("expected MoveCall");
+        };
+        assert_eq!(call.package, "0x2");
+        assert_eq!(call.type_arguments[0], "0x2::coin::Coin<0x2::sui::SUI>");
+        let CommandKind::MakeMoveVec(make) = &tx.data.commands[1].kind else {
+            panic!("expected MakeMoveVec");
+        };
+        assert_eq!(make.type_arg.as_deref(), Some("0x2::sui::SUI"));
+    }
+
+    #[tokio::test]
+    async fn test_unresolved_named_package() {
+        let plugin = NamedPackagesPlugin::new();
+        let mut tx = Transaction::new();
+        tx.move_call(
+            "0x2::coin::zero",
+            vec![],
+            vec!["0x2::coin::Coin<deepbook/core::deep::DEEP>".to_string()],
+        )
+        .unwrap();
 
-        assert!(plugin.before_build(&mut tx).is_ok());
-        assert!(plugin.after_build(&tx).is_ok());
-        assert_eq!(tx["package"], "0x2::coin::Coin");
-        assert_eq!(tx["typeArguments"][0], "0x2::coin::Coin<0x2::sui::SUI>");
+        let err = plugin.validate(&tx).await.unwrap_err();
+        assert!(
+            matches!(err, PluginError::UnresolvedNamedPackage(name) if name == "deepbook/core")
+        );
+    }
+
+    #[test]
+    fn test_map_type_packages_only_rewrites_packages() {
+        let mapped = map_type_packages("sui::coin::Coin<0x2::sui::SUI>", |package| {
+            if package == "sui" { "0x2" } else { package }.to_string()
+        });
+        assert_eq!(mapped, "0x2::coin::Coin<0x2::sui::SUI>");
     }
 
     #[test]
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use super::bcs_types::Address;
+
+/// A Move value that can be passed as a pure input.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum PureValue {
     Bool(bool),
     U8(u8),
@@ -8,28 +11,66 @@ pub enum PureValue {
     U32(u32),
     U64(u64),
     U128(u128),
+    /// Decimal or `0x`-prefixed hex.
     U256(String),
     Address(String),
+    /// `0x2::object::ID`, laid out like an address.
+    ID(String),
+    /// `0x1::string::String`.
     String(String),
+    /// `0x1::ascii::String`.
+    AsciiString(String),
+    /// `vector<u8>`.
     Vec(Vec<u8>),
+    Vector(Vec<PureValue>),
+    Option(Option<Box<PureValue>>),
 }
 
 impl PureValue {
     pub fn serialize(&self) -> Result<Vec<u8>, bcs::Error> {
-        use bcs;
+        let mut out = Vec::new();
+        self.write(&mut out)?;
+        Ok(out)
+    }
 
+    fn write(&self, out: &mut Vec<u8>) -> Result<(), bcs::Error> {
         match self {
-            PureValue::Bool(v) => bcs::to_bytes(v),
-            PureValue::U8(v) => bcs::to_bytes(v),
-            PureValue::U16(v) => bcs::to_bytes(v),
-            PureValue::U32(v) => bcs::to_bytes(v),
-            PureValue::U64(v) => bcs::to_bytes(v),
-            PureValue::U128(v) => bcs::to_bytes(v),
-            PureValue::U256(v) => bcs::to_bytes(v),
-            PureValue::Address(addr) => bcs::to_bytes(addr.as_bytes()),
-            PureValue::String(s) => bcs::to_bytes(s),
-            PureValue::Vec(v) => bcs::to_bytes(v),
+            PureValue::Bool(v) => out.push(*v as u8),
+            PureValue::U8(v) => out.push(*v),
+            PureValue::U16(v) => out.extend_from_slice(&v.to_le_bytes()),
+            PureValue::U32(v) => out.extend_from_slice(&v.to_le_bytes()),
+            PureValue::U64(v) => out.extend_from_slice(&v.to_le_bytes()),
+            PureValue::U128(v) => out.extend_from_slice(&v.to_le_bytes()),
+            PureValue::U256(v) => out.extend_from_slice(
+                &parse_u256(v).ok_or_else(|| invalid(format!("invalid u256: {v}")))?,
+            ),
+            PureValue::Address(addr) | PureValue::ID(addr) => {
+                let address: Address = addr
+                    .parse()
+                    .map_err(|_| invalid(format!("invalid address: {addr}")))?;
+                out.extend_from_slice(address.as_bytes());
+            }
+            PureValue::String(s) => write_bytes(s.as_bytes(), out),
+            PureValue::AsciiString(s) => {
+                if !s.is_ascii() {
+                    return Err(invalid(format!("not an ASCII string: {s}")));
+                }
+                write_bytes(s.as_bytes(), out);
+            }
+            PureValue::Vec(v) => write_bytes(v, out),
+            PureValue::Vector(items) => {
+                write_uleb128(items.len(), out);
+                for item in items {
+                    item.write(out)?;
+                }
+            }
+            PureValue::Option(None) => out.push(0),
+            PureValue::Option(Some(value)) => {
+                out.push(1);
+                value.write(out)?;
+            }
         }
+        Ok(())
     }
 
     pub fn bool(v: bool) -> Self {
@@ -56,17 +97,78 @@ impl PureValue {
         PureValue::U128(v)
     }
 
+    pub fn u256(v: impl Into<String>) -> Self {
+        PureValue::U256(v.into())
+    }
+
     pub fn vec(v: Vec<u8>) -> Self {
         PureValue::Vec(v)
     }
 
+    pub fn vector(values: Vec<PureValue>) -> Self {
+        PureValue::Vector(values)
+    }
+
+    pub fn option(value: Option<PureValue>) -> Self {
+        PureValue::Option(value.map(Box::new))
+    }
+
     pub fn address(s: String) -> Self {
         PureValue::Address(s)
     }
 
+    pub fn id(s: impl Into<String>) -> Self {
+        PureValue::ID(s.into())
+    }
+
     pub fn string(s: String) -> Self {
         PureValue::String(s)
     }
+
+    pub fn ascii_string(s: impl Into<String>) -> Self {
+        PureValue::AsciiString(s.into())
+    }
+}
+
+fn invalid(message: String) -> bcs::Error {
+    bcs::Error::Custom(message)
+}
+
+fn write_bytes(bytes: &[u8], out: &mut Vec<u8>) {
+    write_uleb128(bytes.len(), out);
+    out.extend_from_slice(bytes);
+}
+
+pub(crate) fn write_uleb128(mut len: usize, out: &mut Vec<u8>) {
+    while len >= 0x80 {
+        out.push((len as u8 & 0x7f) | 0x80);
+        len >>= 7;
+    }
+    out.push(len as u8);
+}
+
+/// Parses a decimal or `0x`-prefixed hex u256 into little-endian bytes.
+pub(crate) fn parse_u256(digits: &str) -> Option<[u8; 32]> {
+    let (digits, radix) = match digits.strip_prefix("0x") {
+        Some(hex) => (hex, 16u32),
+        None => (digits, 10u32),
+    };
+    if digits.is_empty() {
+        return None;
+    }
+    let mut out = [0u8; 32];
+    for c in digits.chars() {
+        let mut carry = c.to_digit(radix)?;
+        for byte in out.iter_mut() {
+            let v = u32::from(*byte) * radix + carry;
+            *byte = v as u8;
+            carry = v >> 8;
+        }
+        if carry != 0 {
+            return None;
+        }
+    }
+    Some(out)
 }
 
 #[cfg(test)]
@@ -113,6 +215,7 @@ mod tests {
         let val = PureValue::string("hello".to_string());
         let serialized = val.serialize().unwrap();
         assert!(serialized.len() > 0);
+        assert_eq!(serialized, bcs::to_bytes("hello").unwrap());
     }
 
     #[test]
@@ -120,5 +223,63 @@ mod tests {
         let val = PureValue::address("0x1".to_string());
         let serialized = val.serialize().unwrap();
         assert!(!serialized.is_empty());
+        let mut expected = [0u8; 32];
+        expected[31] = 1;
+        assert_eq!(serialized, expected);
+        assert_eq!(PureValue::id("0x1").serialize().unwrap(), expected);
+        assert!(PureValue::address("0xzz".to_string()).serialize().is_err());
+    }
+
+    #[test]
+    fn test_serialize_u256() {
+        let mut expected = [0u8; 32];
+        expected[0] = 0xff;
+        expected[1] = 0x01;
+        assert_eq!(PureValue::u256("511").serialize().unwrap(), expected);
+        assert_eq!(PureValue::u256("0x1ff").serialize().unwrap(), expected);
+        assert!(
+            PureValue::u256("0x1".to_string() + &"0".repeat(64))
+                .serialize()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_serialize_strings() {
+        assert_eq!(
+            PureValue::ascii_string("sui").serialize().unwrap(),
+            vec![3, b's', b'u', b'i']
+        );
+        assert!(PureValue::ascii_string("suí").serialize().is_err());
+        assert_eq!(
+            PureValue::string("suí".to_string()).serialize().unwrap(),
+            bcs::to_bytes("suí").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_serialize_option_and_vectors() {
+        assert_eq!(
+            PureValue::option(Some(PureValue::u64(7)))
+                .serialize()
+                .unwrap(),
+            bcs::to_bytes(&Some(7u64)).unwrap()
+        );
+        assert_eq!(PureValue::option(None).serialize().unwrap(), vec![0]);
+
+        let nested = PureValue::vector(vec![
+            PureValue::vector(vec![PureValue::u16(1), PureValue::u16(2)]),
+            PureValue::vector(vec![]),
+        ]);
+        assert_eq!(
+            nested.serialize().unwrap(),
+            bcs::to_bytes(&vec![vec![1u16, 2], vec![]]).unwrap()
+        );
+
+        let long = PureValue::vec(vec![0; 200]);
+        assert_eq!(
+            long.serialize().unwrap(),
+            bcs::to_bytes(&vec![0u8; 200]).unwrap()
+        );
     }
 }
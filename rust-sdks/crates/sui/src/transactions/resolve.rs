@@ -1,4 +1,681 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Mutex;
+
+use serde_json::{Value, json};
+
+use super::arguments::Argument;
+use super::bcs_types::{self, TypeTag};
+use super::commands::{CommandKind, MoveCallTarget};
+use super::inputs::{CallArg, ObjectRef, ReceivingRef, SharedObjectRef, UnresolvedObject};
+use super::json::json_u64;
 use super::normalize_sui_address;
+use super::normalized::{NormalizedMoveFunction, NormalizedType};
+use super::pure::{parse_u256, write_uleb128};
+use super::types::Transaction;
+use crate::jsonrpc;
+
+/// `sui_multiGetObjects` accepts at most this many ids per request.
+pub const MAX_OBJECTS_PER_FETCH: usize = 50;
+
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ResolveError {
+    #[error(transparent)]
+    JsonRpc(#[from] jsonrpc::JsonRpcError),
+    #[error("object {0} not found")]
+    ObjectNotFound(String),
+    #[error("invalid object response: {0}")]
+    InvalidObject(String),
+    #[error("invalid move function {0}")]
+    InvalidFunction(String),
+    #[error("invalid pure value: {0}")]
+    InvalidPure(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ObjectOwner {
+    AddressOwner(String),
+    ObjectOwner(String),
+    Shared { initial_shared_version: u64 },
+    Immutable,
+    ConsensusAddressOwner { start_version: u64, owner: String },
+}
+
+/// The on-chain state of an object needed to turn it into a transaction input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedObject {
+    pub object_id: String,
+    pub version: u64,
+    pub digest: String,
+    pub owner: ObjectOwner,
+}
+
+impl ResolvedObject {
+    /// The version to reference the object by when it is accessed through consensus.
+    pub fn initial_shared_version(&self) -> Option<u64> {
+        match self.owner {
+            ObjectOwner::Shared {
+                initial_shared_version,
+            } => Some(initial_shared_version),
+            ObjectOwner::ConsensusAddressOwner { start_version, .. } => Some(start_version),
+            _ => None,
+        }
+    }
+
+    /// Parses one entry of a `sui_multiGetObjects` / `sui_getObject` response
+    /// requested with `showOwner`.
+    pub fn from_json(value: &Value) -> Result<Self, ResolveError> {
+        let data = match value.get("data") {
+            Some(data) if !data.is_null() => data,
+            _ => {
+                let object_id = value
+                    .pointer("/error/object_id")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+                return Err(ResolveError::ObjectNotFound(object_id.to_string()));
+            }
+        };
+        let object_id = data
+            .get("objectId")
+            .and_then(Value::as_str)
+            .ok_or_else(|| ResolveError::InvalidObject("missing objectId".to_string()))?;
+        let invalid = |field: &str| ResolveError::InvalidObject(format!("{object_id}: {field}"));
+        let version = data
+            .get("version")
+            .and_then(json_u64)
+            .ok_or_else(|| invalid("version"))?;
+        let digest = data
+            .get("digest")
+            .and_then(Value::as_str)
+            .ok_or_else(|| invalid("digest"))?;
+        let owner = data
+            .get("owner")
+            .and_then(parse_owner)
+            .ok_or_else(|| invalid("owner"))?;
+
+        Ok(Self {
+            object_id: normalize_sui_address(object_id),
+            version,
+            digest: digest.to_string(),
+            owner,
+        })
+    }
+
+    /// Parses an `{owner, reference}` entry of transaction effects, e.g. from
+    /// `created`, `mutated` or `gasObject`.
+    pub fn from_effects_ref(value: &Value) -> Result<Self, ResolveError> {
+        let invalid = || ResolveError::InvalidObject(format!("invalid effects entry: {value}"));
+        let reference = value.get("reference").ok_or_else(invalid)?;
+        Ok(Self {
+            object_id: normalize_sui_address(
+                reference
+                    .get("objectId")
+                    .and_then(Value::as_str)
+                    .ok_or_else(invalid)?,
+            ),
+            version: reference
+                .get("version")
+                .and_then(json_u64)
+                .ok_or_else(invalid)?,
+            digest: reference
+                .get("digest")
+                .and_then(Value::as_str)
+                .ok_or_else(invalid)?
+                .to_string(),
+            owner: value.get("owner").and_then(parse_owner).ok_or_else(invalid)?,
+        })
+    }
+
+    pub fn object_ref(&self) -> ObjectRef {
+        ObjectRef {
+            object_id: self.object_id.clone(),
+            digest: self.digest.clone(),
+            version: self.version,
+        }
+    }
+}
+
+fn parse_owner(value: &Value) -> Option<ObjectOwner> {
+    if value.as_str() == Some("Immutable") {
+        return Some(ObjectOwner::Immutable);
+    }
+    if let Some(owner) = value.get("AddressOwner").and_then(Value::as_str) {
+        return Some(ObjectOwner::AddressOwner(owner.to_string()));
+    }
+    if let Some(owner) = value.get("ObjectOwner").and_then(Value::as_str) {
+        return Some(ObjectOwner::ObjectOwner(owner.to_string()));
+    }
+    if let Some(shared) = value.get("Shared") {
+        return Some(ObjectOwner::Shared {
+            initial_shared_version: shared.get("initial_shared_version").and_then(json_u64)?,
+        });
+    }
+    let consensus = value.get("ConsensusAddressOwner")?;
+    Some(ObjectOwner::ConsensusAddressOwner {
+        start_version: consensus.get("start_version").and_then(json_u64)?,
+        owner: consensus.get("owner")?.as_str()?.to_string(),
+    })
+}
+
+/// Source of the on-chain data needed to resolve transaction inputs.
+///
+/// Both methods take every id or target at once so implementations can answer
+/// them with as few requests as possible. Results are returned in request order.
+pub trait ObjectProvider: Send + Sync {
+    fn get_objects(
+        &self,
+        object_ids: Vec<String>,
+    ) -> BoxFuture<'_, Result<Vec<ResolvedObject>, ResolveError>>;
+
+    fn get_move_functions(
+        &self,
+        targets: Vec<MoveCallTarget>,
+    ) -> BoxFuture<'_, Result<Vec<NormalizedMoveFunction>, ResolveError>>;
+}
+
+impl ObjectProvider for jsonrpc::Client {
+    fn get_objects(
+        &self,
+        object_ids: Vec<String>,
+    ) -> BoxFuture<'_, Result<Vec<ResolvedObject>, ResolveError>> {
+        Box::pin(async move {
+            let mut objects = Vec::with_capacity(object_ids.len());
+            for chunk in object_ids.chunks(MAX_OBJECTS_PER_FETCH) {
+                let response = self
+                    .multi_get_objects(chunk.to_vec(), Some(json!({"showOwner": true})))
+                    .await?;
+                let entries = response
+                    .as_array()
+                    .filter(|entries| entries.len() == chunk.len())
+                    .ok_or_else(|| {
+                        ResolveError::InvalidObject(
+                            "unexpected multiGetObjects response".to_string(),
+                        )
+                    })?;
+                for (object_id, entry) in chunk.iter().zip(entries) {
+                    objects.push(ResolvedObject::from_json(entry).map_err(|e| match e {
+                        ResolveError::ObjectNotFound(_) => {
+                            ResolveError::ObjectNotFound(object_id.clone())
+                        }
+                        other => other,
+                    })?);
+                }
+            }
+            Ok(objects)
+        })
+    }
+
+    fn get_move_functions(
+        &self,
+        targets: Vec<MoveCallTarget>,
+    ) -> BoxFuture<'_, Result<Vec<NormalizedMoveFunction>, ResolveError>> {
+        Box::pin(async move {
+            let calls = targets
+                .iter()
+                .map(|target| {
+                    (
+                        "sui_getNormalizedMoveFunction",
+                        vec![
+                            Value::String(target.package.clone()),
+                            Value::String(target.module.clone()),
+                            Value::String(target.function.clone()),
+                        ],
+                    )
+                })
+                .collect();
+            let results = self.batch_call(calls).await?;
+            targets
+                .iter()
+                .zip(results)
+                .map(|(target, result)| {
+                    serde_json::from_value(result)
+                        .map_err(|e| ResolveError::InvalidFunction(format!("{target}: {e}")))
+                })
+                .collect()
+        })
+    }
+}
+
+/// Wraps an [`ObjectProvider`] and caches normalized Move functions per
+/// package, so later transactions calling the same packages skip the lookup.
+/// Object data is never cached since versions change with every transaction.
+pub struct CachingObjectProvider<P> {
+    inner: P,
+    functions: Mutex<HashMap<String, HashMap<String, NormalizedMoveFunction>>>,
+}
+
+impl<P> CachingObjectProvider<P> {
+    pub fn new(inner: P) -> Self {
+        Self {
+            inner,
+            functions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    pub fn cached_function(&self, target: &MoveCallTarget) -> Option<NormalizedMoveFunction> {
+        let functions = self.functions.lock().expect("function cache poisoned");
+        functions
+            .get(&target.package)?
+            .get(&format!("{}::{}", target.module, target.function))
+            .cloned()
+    }
+
+    pub fn clear(&self) {
+        self.functions
+            .lock()
+            .expect("function cache poisoned")
+            .clear();
+    }
+}
+
+impl<P: ObjectProvider> ObjectProvider for CachingObjectProvider<P> {
+    fn get_objects(
+        &self,
+        object_ids: Vec<String>,
+    ) -> BoxFuture<'_, Result<Vec<ResolvedObject>, ResolveError>> {
+        self.inner.get_objects(object_ids)
+    }
+
+    fn get_move_functions(
+        &self,
+        targets: Vec<MoveCallTarget>,
+    ) -> BoxFuture<'_, Result<Vec<NormalizedMoveFunction>, ResolveError>> {
+        Box::pin(async move {
+            let missing: Vec<MoveCallTarget> = targets
+                .iter()
+                .filter(|target| self.cached_function(target).is_none())
+                .cloned()
+                .collect();
+            if !missing.is_empty() {
+                let fetched = self.inner.get_move_functions(missing.clone()).await?;
+                let mut functions = self.functions.lock().expect("function cache poisoned");
+                for (target, function) in missing.into_iter().zip(fetched) {
+                    functions
+                        .entry(target.package)
+                        .or_default()
+                        .insert(format!("{}::{}", target.module, target.function), function);
+                }
+            }
+            targets
+                .iter()
+                .map(|target| {
+                    self.cached_function(target).ok_or_else(|| {
+                        ResolveError::InvalidFunction(format!("{target}: missing signature"))
+                    })
+                })
+                .collect()
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct InputUsage {
+    mutable: bool,
+    receiving: bool,
+}
+
+/// An unresolved input passed to a command, with the type it is expected to
+/// have there. `None` means the command takes an object by value.
+type InputUse = (usize, Option<NormalizedType>);
+
+impl Transaction {
+    /// Resolves every `UnresolvedPure` and `UnresolvedObject` input.
+    ///
+    /// The Move functions taking unresolved inputs are fetched in a single
+    /// `get_move_functions` call and object data in a single `get_objects` call.
+    /// Pure values are encoded as the parameter type they are passed to, and the
+    /// same types decide object mutability and whether an object is received.
+    pub async fn resolve(&mut self, provider: &dyn ObjectProvider) -> Result<(), ResolveError> {
+        if self.data.inputs.iter().all(|input| !is_unresolved(input)) {
+            return Ok(());
+        }
+        let uses = self.input_uses(provider).await?;
+        self.resolve_pure_inputs(&uses)?;
+        self.resolve_object_inputs(provider, &uses).await
+    }
+
+    fn resolve_pure_inputs(&mut self, uses: &[InputUse]) -> Result<(), ResolveError> {
+        let mut encoded: HashMap<usize, Vec<u8>> = HashMap::new();
+        for (index, ty) in uses {
+            let CallArg::UnresolvedPure(pure) = &self.data.inputs[*index] else {
+                continue;
+            };
+            let ty = ty.as_ref().ok_or_else(|| {
+                ResolveError::InvalidPure(format!("input {index} is used as an object"))
+            })?;
+            let bytes = encode_pure_value(ty.inner(), &pure.value)?;
+            match encoded.get(index) {
+                Some(previous) if *previous != bytes => {
+                    return Err(ResolveError::InvalidPure(format!(
+                        "input {index} is used with conflicting types"
+                    )));
+                }
+                _ => {
+                    encoded.insert(*index, bytes);
+                }
+            }
+        }
+        for (index, bytes) in encoded {
+            self.data.inputs[index] = CallArg::pure(bytes);
+        }
+        Ok(())
+    }
+
+    async fn resolve_object_inputs(
+        &mut self,
+        provider: &dyn ObjectProvider,
+        uses: &[InputUse],
+    ) -> Result<(), ResolveError> {
+        let mut usage: HashMap<usize, InputUsage> = HashMap::new();
+        for (index, ty) in uses {
+            let entry = usage.entry(*index).or_default();
+            match ty {
+                Some(NormalizedType::Reference(_)) => {}
+                Some(ty) if ty.is_receiving() => entry.receiving = true,
+                _ => entry.mutable = true,
+            }
+        }
+
+        let object_ids: Vec<String> = self
+            .data
+            .inputs
+            .iter()
+            .filter_map(|input| match input {
+                CallArg::UnresolvedObject(object) if needs_fetch(object) => {
+                    Some(object.object_id.clone())
+                }
+                _ => None,
+            })
+            .collect();
+        let objects: HashMap<String, ResolvedObject> = if object_ids.is_empty() {
+            HashMap::new()
+        } else {
+            provider
+                .get_objects(object_ids)
+                .await?
+                .into_iter()
+                .map(|object| (normalize_sui_address(&object.object_id), object))
+                .collect()
+        };
+
+        for (index, input) in self.data.inputs.iter_mut().enumerate() {
+            let CallArg::UnresolvedObject(object) = input else {
+                continue;
+            };
+            let usage = usage.get(&index).copied().unwrap_or_default();
+            *input = resolve_input(object, objects.get(&object.object_id), usage)?;
+        }
+        Ok(())
+    }
+
+    fn unresolved_input(&self, argument: &Argument) -> Option<usize> {
+        match argument {
+            Argument::Input(index) => self
+                .data
+                .inputs
+                .get(*index as usize)
+                .filter(|input| is_unresolved(input))
+                .map(|_| *index as usize),
+            _ => None,
+        }
+    }
+
+    async fn input_uses(
+        &self,
+        provider: &dyn ObjectProvider,
+    ) -> Result<Vec<InputUse>, ResolveError> {
+        let mut targets: Vec<MoveCallTarget> = Vec::new();
+        for command in &self.data.commands {
+            if let CommandKind::MoveCall(call) = &command.kind {
+                let target = MoveCallTarget::new(&call.package, &call.module, &call.function);
+                let uses_unresolved = call
+                    .arguments
+                    .iter()
+                    .any(|arg| self.unresolved_input(arg).is_some());
+                if uses_unresolved && !targets.contains(&target) {
+                    targets.push(target);
+                }
+            }
+        }
+        let functions = if targets.is_empty() {
+            Vec::new()
+        } else {
+            provider.get_move_functions(targets.clone()).await?
+        };
+
+        let mut uses = Vec::new();
+        for command in &self.data.commands {
+            let (arguments, ty): (Vec<&Argument>, Option<NormalizedType>) = match &command.kind {
+                CommandKind::MoveCall(call) => {
+                    let target = MoveCallTarget::new(&call.package, &call.module, &call.function);
+                    let Some(position) = targets.iter().position(|t| *t == target) else {
+                        continue;
+                    };
+                    let function = functions.get(position).ok_or_else(|| {
+                        ResolveError::InvalidFunction(format!("{target}: missing signature"))
+                    })?;
+                    let parameters = function.user_parameters();
+                    if parameters.len() != call.arguments.len() {
+                        return Err(ResolveError::InvalidFunction(format!(
+                            "{target}: expected {} arguments, got {}",
+                            parameters.len(),
+                            call.arguments.len()
+                        )));
+                    }
+                    let type_arguments = call
+                        .type_arguments
+                        .iter()
+                        .map(|ty| {
+                            let tag = ty.parse::<TypeTag>().map_err(|_| {
+                                ResolveError::InvalidFunction(format!(
+                                    "{target}: invalid type argument {ty}"
+                                ))
+                            })?;
+                            Ok(NormalizedType::from(&tag))
+                        })
+                        .collect::<Result<Vec<_>, ResolveError>>()?;
+                    for (arg, parameter) in call.arguments.iter().zip(parameters) {
+                        if let Some(index) = self.unresolved_input(arg) {
+                            uses.push((index, Some(parameter.instantiate(&type_arguments))));
+                        }
+                    }
+                    continue;
+                }
+                CommandKind::TransferObjects(cmd) => {
+                    if let Some(index) = self.unresolved_input(&cmd.address) {
+                        uses.push((index, Some(NormalizedType::Address)));
+                    }
+                    (cmd.objects.iter().collect(), None)
+                }
+                CommandKind::SplitCoins(cmd) => {
+                    for amount in &cmd.amounts {
+                        if let Some(index) = self.unresolved_input(amount) {
+                            uses.push((index, Some(NormalizedType::U64)));
+                        }
+                    }
+                    (vec![&cmd.coin], None)
+                }
+                CommandKind::MergeCoins(cmd) => (
+                    [&cmd.destination].into_iter().chain(&cmd.sources).collect(),
+                    None,
+                ),
+                CommandKind::MakeMoveVec(cmd) => {
+                    let ty = cmd
+                        .type_arg
+                        .as_deref()
+                        .map(|ty| {
+                            ty.parse::<TypeTag>().map_err(|_| {
+                                ResolveError::InvalidPure(format!("invalid vector type {ty}"))
+                            })
+                        })
+                        .transpose()?
+                        .map(|tag| NormalizedType::from(&tag));
+                    (cmd.elements.iter().collect(), ty)
+                }
+                CommandKind::Upgrade(cmd) => (vec![&cmd.ticket], None),
+                CommandKind::Publish(_) | CommandKind::Intent(_) => continue,
+            };
+            for arg in arguments {
+                if let Some(index) = self.unresolved_input(arg) {
+                    uses.push((index, ty.clone()));
+                }
+            }
+        }
+        Ok(uses)
+    }
+}
+
+fn is_unresolved(input: &CallArg) -> bool {
+    matches!(
+        input,
+        CallArg::UnresolvedObject(_) | CallArg::UnresolvedPure(_)
+    )
+}
+
+/// Encodes a plain JSON value as the BCS of the given Move type.
+pub fn encode_pure_value(ty: &NormalizedType, value: &Value) -> Result<Vec<u8>, ResolveError> {
+    let mut out = Vec::new();
+    write_pure_value(ty, value, &mut out)?;
+    Ok(out)
+}
+
+fn write_pure_value(
+    ty: &NormalizedType,
+    value: &Value,
+    out: &mut Vec<u8>,
+) -> Result<(), ResolveError> {
+    let mismatch = || ResolveError::InvalidPure(format!("expected {ty}, got {value}"));
+    match ty {
+        NormalizedType::Bool => out.push(value.as_bool().ok_or_else(mismatch)? as u8),
+        NormalizedType::U8 => out.push(json_uint(value).ok_or_else(mismatch)?),
+        NormalizedType::U16 => {
+            let v: u16 = json_uint(value).ok_or_else(mismatch)?;
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        NormalizedType::U32 => {
+            let v: u32 = json_uint(value).ok_or_else(mismatch)?;
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        NormalizedType::U64 => {
+            let v: u64 = json_uint(value).ok_or_else(mismatch)?;
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        NormalizedType::U128 => {
+            let v: u128 = json_uint(value).ok_or_else(mismatch)?;
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        NormalizedType::U256 => out.extend_from_slice(&json_u256(value).ok_or_else(mismatch)?),
+        NormalizedType::Address => {
+            out.extend_from_slice(&json_address(value).ok_or_else(mismatch)?)
+        }
+        NormalizedType::Vector(inner) => {
+            let items = value.as_array().ok_or_else(mismatch)?;
+            write_uleb128(items.len(), out);
+            for item in items {
+                write_pure_value(inner, item, out)?;
+            }
+        }
+        NormalizedType::Struct { type_arguments, .. } => {
+            if ty.is_struct("0x1", "string", "String") || ty.is_struct("0x1", "ascii", "String") {
+                let s = value.as_str().ok_or_else(mismatch)?;
+                if ty.is_struct("0x1", "ascii", "String") && !s.is_ascii() {
+                    return Err(mismatch());
+                }
+                write_uleb128(s.len(), out);
+                out.extend_from_slice(s.as_bytes());
+            } else if ty.is_struct("0x2", "object", "ID") {
+                out.extend_from_slice(&json_address(value).ok_or_else(mismatch)?);
+            } else if ty.is_struct("0x1", "option", "Option") {
+                let inner = type_arguments.first().ok_or_else(mismatch)?;
+                if value.is_null() {
+                    out.push(0);
+                } else {
+                    out.push(1);
+                    write_pure_value(inner, value, out)?;
+                }
+            } else {
+                return Err(ResolveError::InvalidPure(format!(
+                    "{ty} is not a pure type"
+                )));
+            }
+        }
+        _ => {
+            return Err(ResolveError::InvalidPure(format!(
+                "{ty} is not a pure type"
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn json_uint<T: TryFrom<u128>>(value: &Value) -> Option<T> {
+    let v = match value {
+        Value::Number(n) => u128::from(n.as_u64()?),
+        Value::String(s) => s.parse().ok()?,
+        _ => return None,
+    };
+    T::try_from(v).ok()
+}
+
+fn json_u256(value: &Value) -> Option<[u8; 32]> {
+    match value {
+        Value::Number(n) => parse_u256(&n.as_u64()?.to_string()),
+        Value::String(s) => parse_u256(s),
+        _ => None,
+    }
+}
+
+fn json_address(value: &Value) -> Option<[u8; 32]> {
+    let address: bcs_types::Address = value.as_str()?.parse().ok()?;
+    Some(address.0)
+}
+
+fn needs_fetch(object: &UnresolvedObject) -> bool {
+    object.initial_shared_version.is_none() && (object.version.is_none() || object.digest.is_none())
+}
+
+fn resolve_input(
+    object: &UnresolvedObject,
+    fetched: Option<&ResolvedObject>,
+    usage: InputUsage,
+) -> Result<CallArg, ResolveError> {
+    let mutable = object.mutable.unwrap_or(false) || usage.mutable;
+    let initial_shared_version = object
+        .initial_shared_version
+        .or_else(|| fetched.and_then(ResolvedObject::initial_shared_version));
+    if let Some(initial_shared_version) = initial_shared_version {
+        return Ok(CallArg::shared_object_ref(SharedObjectRef {
+            object_id: object.object_id.clone(),
+            mutable,
+            initial_shared_version,
+        }));
+    }
+
+    let (version, digest) = match (fetched, object.version, &object.digest) {
+        (Some(fetched), _, _) => (fetched.version, fetched.digest.clone()),
+        (None, Some(version), Some(digest)) => (version, digest.clone()),
+        _ => return Err(ResolveError::ObjectNotFound(object.object_id.clone())),
+    };
+    if usage.receiving {
+        return Ok(CallArg::receiving_ref(ReceivingRef {
+            digest,
+            version,
+            object_id: object.object_id.clone(),
+        }));
+    }
+    Ok(CallArg::object_ref(ObjectRef {
+        object_id: object.object_id.clone(),
+        digest,
+        version,
+    }))
+}
 
 pub fn resolve_address(addr: &str) -> String {
     normalize_sui_address(addr)
@@ -19,6 +696,7 @@ pub fn resolve_type_parameter(type_arg: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::FromStr;
 
     #[test]
     fn test_resolve_address() {
@@ -41,4 +719,404 @@ mod tests {
         let resolved = resolve_type_parameter(type_arg);
         assert_eq!(resolved, type_arg);
     }
+
+    struct MockProvider {
+        objects: Vec<ResolvedObject>,
+        function: NormalizedMoveFunction,
+        object_calls: std::sync::Mutex<Vec<Vec<String>>>,
+        function_calls: std::sync::Mutex<Vec<Vec<MoveCallTarget>>>,
+    }
+
+    impl ObjectProvider for MockProvider {
+        fn get_objects(
+            &self,
+            object_ids: Vec<String>,
+        ) -> BoxFuture<'_, Result<Vec<ResolvedObject>, ResolveError>> {
+            Box::pin(async move {
+                self.object_calls.lock().unwrap().push(object_ids.clone());
+                object_ids
+                    .iter()
+                    .map(|id| {
+                        self.objects
+                            .iter()
+                            .find(|o| &o.object_id == id)
+                            .cloned()
+                            .ok_or_else(|| ResolveError::ObjectNotFound(id.clone()))
+                    })
+                    .collect()
+            })
+        }
+
+        fn get_move_functions(
+            &self,
+            targets: Vec<MoveCallTarget>,
+        ) -> BoxFuture<'_, Result<Vec<NormalizedMoveFunction>, ResolveError>> {
+            Box::pin(async move {
+                let functions = targets.iter().map(|_| self.function.clone()).collect();
+                self.function_calls.lock().unwrap().push(targets);
+                Ok(functions)
+            })
+        }
+    }
+
+    fn object(id: &str, version: u64, owner: ObjectOwner) -> ResolvedObject {
+        ResolvedObject {
+            object_id: normalize_sui_address(id),
+            version,
+            digest: "11111111111111111111111111111111".to_string(),
+            owner,
+        }
+    }
+
+    fn function(parameters: serde_json::Value) -> NormalizedMoveFunction {
+        serde_json::from_value(json!({"parameters": parameters, "return": []})).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_resolve_objects() {
+        let provider = MockProvider {
+            objects: vec![
+                object(
+                    "0x6",
+                    9,
+                    ObjectOwner::Shared {
+                        initial_shared_version: 1,
+                    },
+                ),
+                object(
+                    "0xa",
+                    4,
+                    ObjectOwner::Shared {
+                        initial_shared_version: 3,
+                    },
+                ),
+                object("0xb", 5, ObjectOwner::AddressOwner("0x1".to_string())),
+                object("0xc", 6, ObjectOwner::ObjectOwner("0xb".to_string())),
+            ],
+            function: function(json!([
+                {"Reference": {"Struct": {"address": "0x2", "module": "clock", "name": "Clock", "typeArguments": []}}},
+                {"MutableReference": {"Struct": {"address": "0x2", "module": "pool", "name": "Pool", "typeArguments": []}}},
+                {"Struct": {"address": "0x2", "module": "coin", "name": "Coin", "typeArguments": []}},
+                {"Struct": {"address": "0x2", "module": "transfer", "name": "Receiving", "typeArguments": []}},
+                {"MutableReference": {"Struct": {"address": "0x2", "module": "tx_context", "name": "TxContext", "typeArguments": []}}}
+            ])),
+            object_calls: Default::default(),
+            function_calls: Default::default(),
+        };
+
+        let mut tx = Transaction::new();
+        let clock = tx.object("0x6");
+        let pool = tx.object("0xa");
+        let coin = tx.object("0xb");
+        let receiving = tx.object("0xc");
+        tx.move_call(
+            "0x2::pool::swap",
+            vec![clock, pool, coin, receiving],
+            vec![],
+        )
+        .unwrap();
+        tx.move_call(
+            "0x2::pool::swap",
+            vec![clock, pool, coin, receiving],
+            vec![],
+        )
+        .unwrap();
+
+        tx.resolve(&provider).await.unwrap();
+
+        assert_eq!(provider.object_calls.lock().unwrap().len(), 1);
+        assert_eq!(provider.object_calls.lock().unwrap()[0].len(), 4);
+        assert_eq!(provider.function_calls.lock().unwrap().len(), 1);
+        assert_eq!(provider.function_calls.lock().unwrap()[0].len(), 1);
+
+        assert_eq!(
+            tx.data.inputs[0],
+            CallArg::shared_object_ref(SharedObjectRef {
+                object_id: "0x6".to_string(),
+                mutable: false,
+                initial_shared_version: 1,
+            })
+        );
+        assert_eq!(
+            tx.data.inputs[1],
+            CallArg::shared_object_ref(SharedObjectRef {
+                object_id: "0xa".to_string(),
+                mutable: true,
+                initial_shared_version: 3,
+            })
+        );
+        assert_eq!(
+            tx.data.inputs[2],
+            CallArg::object_ref(ObjectRef {
+                object_id: "0xb".to_string(),
+                digest: "11111111111111111111111111111111".to_string(),
+                version: 5,
+            })
+        );
+        assert_eq!(
+            tx.data.inputs[3],
+            CallArg::receiving_ref(ReceivingRef {
+                object_id: "0xc".to_string(),
+                digest: "11111111111111111111111111111111".to_string(),
+                version: 6,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_objects_used_by_builtin_commands() {
+        let provider = MockProvider {
+            objects: vec![object(
+                "0x6",
+                2,
+                ObjectOwner::Shared {
+                    initial_shared_version: 1,
+                },
+            )],
+            function: function(json!([])),
+            object_calls: Default::default(),
+            function_calls: Default::default(),
+        };
+
+        let mut tx = Transaction::new();
+        let shared = tx.object("0x6");
+        let recipient = tx.pure_bytes(&[0; 32]);
+        tx.transfer_objects(vec![shared], recipient);
+
+        tx.resolve(&provider).await.unwrap();
+
+        assert!(provider.function_calls.lock().unwrap().is_empty());
+        assert!(matches!(
+            &tx.data.inputs[0],
+            CallArg::Object(crate::transactions::ObjectKind::SharedObject(obj)) if obj.mutable
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_objects_missing_object() {
+        let provider = MockProvider {
+            objects: vec![],
+            function: function(json!([])),
+            object_calls: Default::default(),
+            function_calls: Default::default(),
+        };
+
+        let mut tx = Transaction::new();
+        let coin = tx.object("0xb");
+        let recipient = tx.pure_bytes(&[0; 32]);
+        tx.transfer_objects(vec![coin], recipient);
+
+        let err = tx.resolve(&provider).await.unwrap_err();
+        assert!(
+            matches!(err, ResolveError::ObjectNotFound(id) if id == normalize_sui_address("0xb"))
+        );
+    }
+
+    #[test]
+    fn test_resolved_object_from_json() {
+        let object = ResolvedObject::from_json(&json!({"data": {
+            "objectId": "0x6",
+            "version": "12",
+            "digest": "11111111111111111111111111111111",
+            "owner": {"Shared": {"initial_shared_version": 1}}
+        }}))
+        .unwrap();
+        assert_eq!(object.version, 12);
+        assert_eq!(object.initial_shared_version(), Some(1));
+
+        let immutable = ResolvedObject::from_json(&json!({"data": {
+            "objectId": "0x2",
+            "version": 1,
+            "digest": "11111111111111111111111111111111",
+            "owner": "Immutable"
+        }}))
+        .unwrap();
+        assert_eq!(immutable.owner, ObjectOwner::Immutable);
+
+        let missing =
+            ResolvedObject::from_json(&json!({"error": {"code": "notExists", "object_id": "0x9"}}));
+        assert!(matches!(missing, Err(ResolveError::ObjectNotFound(id)) if id == "0x9"));
+    }
+
+    #[test]
+    fn test_encode_pure_value() {
+        let u64_ty = NormalizedType::U64;
+        assert_eq!(
+            encode_pure_value(&u64_ty, &json!(5)).unwrap(),
+            5u64.to_le_bytes()
+        );
+        assert_eq!(
+            encode_pure_value(&u64_ty, &json!("18446744073709551615")).unwrap(),
+            u64::MAX.to_le_bytes()
+        );
+        assert!(encode_pure_value(&NormalizedType::U8, &json!(256)).is_err());
+        assert!(encode_pure_value(&NormalizedType::Bool, &json!(1)).is_err());
+        assert_eq!(
+            encode_pure_value(
+                &NormalizedType::U128,
+                &json!("340282366920938463463374607431768211455")
+            )
+            .unwrap(),
+            u128::MAX.to_le_bytes()
+        );
+
+        let mut u256 = [0u8; 32];
+        u256[0] = 1;
+        u256[1] = 1;
+        assert_eq!(
+            encode_pure_value(&NormalizedType::U256, &json!("257")).unwrap(),
+            u256
+        );
+        assert_eq!(
+            encode_pure_value(&NormalizedType::U256, &json!("0x101")).unwrap(),
+            u256
+        );
+        assert!(
+            encode_pure_value(
+                &NormalizedType::U256,
+                &json!(format!("0x1{}", "0".repeat(64)))
+            )
+            .is_err()
+        );
+
+        let address = encode_pure_value(&NormalizedType::Address, &json!("0x2")).unwrap();
+        assert_eq!(address.len(), 32);
+        assert_eq!(address[31], 2);
+
+        let vector = NormalizedType::Vector(Box::new(NormalizedType::U16));
+        assert_eq!(
+            encode_pure_value(&vector, &json!([1, 2])).unwrap(),
+            vec![2, 1, 0, 2, 0]
+        );
+
+        let string: NormalizedType =
+            NormalizedType::from(&"0x1::string::String".parse::<TypeTag>().unwrap());
+        assert_eq!(
+            encode_pure_value(&string, &json!("hé")).unwrap(),
+            vec![3, b'h', 0xc3, 0xa9]
+        );
+        let ascii = NormalizedType::from(&"0x1::ascii::String".parse::<TypeTag>().unwrap());
+        assert!(encode_pure_value(&ascii, &json!("hé")).is_err());
+
+        let option = NormalizedType::from(&"0x1::option::Option<u8>".parse::<TypeTag>().unwrap());
+        assert_eq!(encode_pure_value(&option, &json!(null)).unwrap(), vec![0]);
+        assert_eq!(encode_pure_value(&option, &json!(7)).unwrap(), vec![1, 7]);
+
+        let coin =
+            NormalizedType::from(&"0x2::coin::Coin<0x2::sui::SUI>".parse::<TypeTag>().unwrap());
+        assert!(matches!(
+            encode_pure_value(&coin, &json!(1)),
+            Err(ResolveError::InvalidPure(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_pure_values() {
+        let provider = CachingObjectProvider::new(MockProvider {
+            objects: vec![],
+            function: function(json!([
+                "U64",
+                {"Vector": "Address"},
+                {"Struct": {"address": "0x1", "module": "option", "name": "Option", "typeArguments": [{"TypeParameter": 0}]}},
+                {"MutableReference": {"Struct": {"address": "0x2", "module": "tx_context", "name": "TxContext", "typeArguments": []}}}
+            ])),
+            object_calls: Default::default(),
+            function_calls: Default::default(),
+        });
+
+        let mut tx = Transaction::new();
+        let amount = tx.pure_value(10u64);
+        let recipients = tx.pure_value(vec!["0x1", "0x2"]);
+        let limit = tx.pure_value(Some(3u32));
+        tx.move_call(
+            "0x3::pool::deposit",
+            vec![amount, recipients, limit],
+            vec!["u32".to_string()],
+        )
+        .unwrap();
+        let split = tx.pure_value(1000u64);
+        tx.split_coins(Transaction::gas(), vec![split]);
+
+        tx.resolve(&provider).await.unwrap();
+
+        assert_eq!(
+            tx.data.inputs[0],
+            CallArg::pure(10u64.to_le_bytes().to_vec())
+        );
+        let mut addresses = vec![2u8];
+        addresses.extend(bcs_types::Address::from_str("0x1").unwrap().0);
+        addresses.extend(bcs_types::Address::from_str("0x2").unwrap().0);
+        assert_eq!(tx.data.inputs[1], CallArg::pure(addresses));
+        assert_eq!(tx.data.inputs[2], CallArg::pure(vec![1, 3, 0, 0, 0]));
+        assert_eq!(
+            tx.data.inputs[3],
+            CallArg::pure(1000u64.to_le_bytes().to_vec())
+        );
+
+        let mut again = Transaction::new();
+        let amount = again.pure_value(1u64);
+        let recipients = again.pure_value(Vec::<String>::new());
+        let limit = again.pure_value(Option::<u32>::None);
+        again.move_call(
+            "0x3::pool::deposit",
+            vec![amount, recipients, limit],
+            vec!["u32".to_string()],
+        )
+        .unwrap();
+        again.resolve(&provider).await.unwrap();
+        assert_eq!(provider.inner().function_calls.lock().unwrap().len(), 1);
+        assert_eq!(again.data.inputs[2], CallArg::pure(vec![0]));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_pure_value_mismatch() {
+        let provider = MockProvider {
+            objects: vec![],
+            function: function(json!(["Bool", "U8"])),
+            object_calls: Default::default(),
+            function_calls: Default::default(),
+        };
+
+        let mut tx = Transaction::new();
+        let flag = tx.pure_value("yes");
+        let small = tx.pure_value(1u8);
+        tx.move_call("0x3::m::f", vec![flag, small], vec![]).unwrap();
+        assert!(matches!(
+            tx.resolve(&provider).await,
+            Err(ResolveError::InvalidPure(_))
+        ));
+
+        let mut tx = Transaction::new();
+        let small = tx.pure_value(1u8);
+        tx.move_call("0x3::m::f", vec![small], vec![]).unwrap();
+        assert!(matches!(
+            tx.resolve(&provider).await,
+            Err(ResolveError::InvalidFunction(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_rejects_invalid_type_argument() {
+        let provider = MockProvider {
+            objects: vec![],
+            function: function(json!([{"TypeParameter": 1}])),
+            object_calls: Default::default(),
+            function_calls: Default::default(),
+        };
+
+        let mut tx = Transaction::new();
+        let value = tx.pure_value(1u64);
+        tx.move_call(
+            "0x3::m::f",
+            vec![value],
+            vec!["0x2::not a type".to_string(), "u64".to_string()],
+        )
+        .unwrap();
+        match tx.resolve(&provider).await {
+            Err(ResolveError::InvalidFunction(message)) => {
+                assert!(message.contains("0x2::not a type"), "{message}")
+            }
+            other => panic!("expected InvalidFunction, got {other:?}"),
+        }
+    }
 }
@@ -1,48 +1,69 @@
+use crate::transactions::bcs_types;
+use crate::transactions::TransactionError;
+
 pub struct TransactionSerializer;
 
 #[derive(Debug, thiserror::Error)]
 pub enum SerializerError {
     #[error("json encode failed: {0}")]
     JsonEncode(#[from] serde_json::Error),
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
 }
 
 impl TransactionSerializer {
     pub fn serialize_transaction_data_v1(
         data: &crate::transactions::TransactionData,
     ) -> Result<Vec<u8>, SerializerError> {
-        Ok(serde_json::to_vec(data)?)
+        Ok(bcs_types::TransactionData::try_from(data)?.to_bytes()?)
     }
 
     pub fn serialize_transaction_data_v2(
         data: &crate::transactions::TransactionData,
     ) -> Result<Vec<u8>, SerializerError> {
-        // Keep parity with current transaction data model (JSON-like), not BCS structs.
-        Ok(serde_json::to_vec(data)?)
+        // V2 is the builder's data model; on the wire it is still `TransactionData::V1`.
+        Self::serialize_transaction_data_v1(data)
     }
 
     pub fn deserialize_transaction_data_v1(
         bytes: &[u8],
     ) -> Result<crate::transactions::TransactionData, SerializerError> {
-        Ok(serde_json::from_slice(bytes)?)
+        let data = bcs_types::TransactionData::from_bytes(bytes)?;
+        Ok(crate::transactions::TransactionData::from(&data))
     }
 
     pub fn deserialize_transaction_data_v2(
         bytes: &[u8],
     ) -> Result<crate::transactions::TransactionData, SerializerError> {
-        Ok(serde_json::from_slice(bytes)?)
+        Self::deserialize_transaction_data_v1(bytes)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::transactions::ObjectRef;
+
+    fn sample_data() -> crate::transactions::TransactionData {
+        let mut tx = crate::transactions::Transaction::new();
+        tx.set_sender("0x1");
+        tx.set_gas_budget(10_000_000);
+        tx.set_gas_price(1000);
+        tx.set_gas_payment(vec![ObjectRef {
+            object_id: "0x5".to_string(),
+            digest: "11111111111111111111111111111111".to_string(),
+            version: 1,
+        }]);
+        let amount = tx.pure_bytes(&100u64.to_le_bytes());
+        let coin = tx.split_coins(crate::transactions::Transaction::gas(), vec![amount]);
+        let recipient = tx.pure_bytes(&[0u8; 32]);
+        tx.transfer_objects(vec![coin], recipient);
+        tx.data
+    }
 
     #[test]
     fn test_serialize_transaction_data_v1() {
-        let tx_data = crate::transactions::TransactionData {
-            sender: Some("0x1".to_string()),
-            ..Default::default()
-        };
+        let tx_data = sample_data();
 
         let serialized = TransactionSerializer::serialize_transaction_data_v1(&tx_data);
         assert!(serialized.is_ok());
@@ -51,15 +72,35 @@ mod tests {
 
     #[test]
     fn test_serialize_deserialize_transaction_data() {
-        let tx_data = crate::transactions::TransactionData {
-            sender: Some("0x1".to_string()),
-            ..Default::default()
-        };
+        let tx_data = sample_data();
 
         let serialized = TransactionSerializer::serialize_transaction_data_v1(&tx_data).unwrap();
         let deserialized =
             TransactionSerializer::deserialize_transaction_data_v1(&serialized).unwrap();
 
-        assert_eq!(deserialized.sender, tx_data.sender);
+        assert_eq!(
+            deserialized.sender.as_deref(),
+            Some("0x0000000000000000000000000000000000000000000000000000000000000001")
+        );
+        assert_eq!(deserialized.inputs, tx_data.inputs);
+        assert_eq!(deserialized.commands, tx_data.commands);
+        assert_eq!(
+            TransactionSerializer::serialize_transaction_data_v2(&deserialized).unwrap(),
+            serialized
+        );
+    }
+
+    #[test]
+    fn test_serialize_requires_gas_data() {
+        let tx_data = crate::transactions::TransactionData {
+            sender: Some("0x1".to_string()),
+            ..Default::default()
+        };
+
+        let err = TransactionSerializer::serialize_transaction_data_v1(&tx_data).unwrap_err();
+        assert!(matches!(
+            err,
+            SerializerError::Transaction(TransactionError::MissingGasBudget)
+        ));
     }
 }
@@ -0,0 +1,154 @@
+//! Sponsored transactions, where a gas station pays for a user's transaction.
+//!
+//! The user builds only the [`TransactionKind`](super::bcs_types::TransactionKind)
+//! with [`Transaction::build_kind`]. The sponsor wraps it with
+//! [`Transaction::sponsored`], attaches its own gas and signs. The user then
+//! checks the result with [`Transaction::check_sponsored`], signs the same
+//! bytes, and merges both signatures before executing.
+
+use super::normalize_sui_address;
+use super::types::{SignedTransaction, Transaction, TransactionError};
+
+impl Transaction {
+    /// Wraps a user's kind bytes with `sender`, leaving gas to `sponsor`.
+    pub fn sponsored(
+        kind: &[u8],
+        sender: impl Into<String>,
+        sponsor: impl Into<String>,
+    ) -> Result<Self, TransactionError> {
+        let mut tx = Self::from_kind(kind)?;
+        tx.set_sender(sender);
+        tx.set_gas_owner(sponsor);
+        Ok(tx)
+    }
+
+    /// Whether someone other than the sender pays for gas.
+    pub fn is_sponsored(&self) -> bool {
+        match (&self.data.gas_data.owner, &self.data.sender) {
+            (Some(owner), Some(sender)) => {
+                normalize_sui_address(owner) != normalize_sui_address(sender)
+            }
+            (Some(_), None) => true,
+            _ => false,
+        }
+    }
+
+    /// Checks that a sponsor returned the programmable transaction the user
+    /// built, for the expected sender, with a gas owner other than the
+    /// sender. The gas payment objects themselves are not inspected.
+    pub fn check_sponsored(&self, kind: &[u8], sender: &str) -> Result<(), TransactionError> {
+        if self.build_kind()? != kind {
+            return Err(TransactionError::SponsorMismatch(
+                "programmable transaction differs".to_string(),
+            ));
+        }
+        let sender = normalize_sui_address(sender);
+        if self.data.sender.as_deref().map(normalize_sui_address) != Some(sender.clone()) {
+            return Err(TransactionError::SponsorMismatch(
+                "sender differs".to_string(),
+            ));
+        }
+        if !self.is_sponsored() {
+            return Err(TransactionError::SponsorMismatch(
+                "gas is paid by the sender".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl SignedTransaction {
+    /// Adds the signatures of `other`, which must cover the same bytes, e.g.
+    /// the sponsor's signature to the sender's.
+    pub fn add_signatures(&mut self, other: SignedTransaction) -> Result<(), TransactionError> {
+        if self.tx_bytes()? != other.tx_bytes()? {
+            return Err(TransactionError::SignedBytesMismatch);
+        }
+        for signature in other.signatures {
+            if !self.signatures.contains(&signature) {
+                self.signatures.push(signature);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keypairs::ed25519::Keypair;
+    use crate::transactions::ObjectRef;
+
+    fn kind() -> Vec<u8> {
+        let mut tx = Transaction::new();
+        let amount = tx.pure_bytes(&7u64.to_le_bytes());
+        tx.split_coins(Transaction::gas(), vec![amount]);
+        tx.build_kind().unwrap()
+    }
+
+    fn sponsored(kind: &[u8]) -> Transaction {
+        let mut tx = Transaction::sponsored(kind, "0x1", "0x2").unwrap();
+        tx.set_gas_price(1000);
+        tx.set_gas_budget(1_000_000);
+        tx.set_gas_payment(vec![ObjectRef {
+            object_id: "0x3".to_string(),
+            digest: "11111111111111111111111111111111".to_string(),
+            version: 1,
+        }]);
+        tx
+    }
+
+    #[test]
+    fn test_is_sponsored() {
+        let mut tx = Transaction::new();
+        assert!(!tx.is_sponsored());
+        tx.set_sender("0x1");
+        tx.set_gas_owner("0x0000000000000000000000000000000000000000000000000000000000000001");
+        assert!(!tx.is_sponsored());
+        tx.set_gas_owner("0x2");
+        assert!(tx.is_sponsored());
+    }
+
+    #[test]
+    fn test_check_sponsored() {
+        let kind = kind();
+        let tx = sponsored(&kind);
+        assert!(tx.check_sponsored(&kind, "0x1").is_ok());
+        assert!(matches!(
+            tx.check_sponsored(&kind, "0x4"),
+            Err(TransactionError::SponsorMismatch(_))
+        ));
+
+        let mut other = Transaction::new();
+        other.split_coins(Transaction::gas(), vec![]);
+        assert!(matches!(
+            tx.check_sponsored(&other.build_kind().unwrap(), "0x1"),
+            Err(TransactionError::SponsorMismatch(_))
+        ));
+
+        let mut self_paid = sponsored(&kind);
+        self_paid.set_gas_owner("0x1");
+        assert!(matches!(
+            self_paid.check_sponsored(&kind, "0x1"),
+            Err(TransactionError::SponsorMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_add_signatures() {
+        let tx = sponsored(&kind());
+        let mut signed = tx.sign_with_ed25519(&Keypair::generate()).unwrap();
+        let sponsor = tx.sign_with_ed25519(&Keypair::generate()).unwrap();
+        signed.add_signatures(sponsor.clone()).unwrap();
+        signed.add_signatures(sponsor).unwrap();
+        assert_eq!(signed.signatures.len(), 2);
+
+        let mut other = sponsored(&kind());
+        other.set_gas_budget(2_000_000);
+        let other = other.sign_with_ed25519(&Keypair::generate()).unwrap();
+        assert!(matches!(
+            signed.add_signatures(other),
+            Err(TransactionError::SignedBytesMismatch)
+        ));
+    }
+}
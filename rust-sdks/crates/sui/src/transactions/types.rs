@@ -1,22 +1,167 @@
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Value, json};
+
+use super::commands::Command;
+use super::inputs::{CallArg, ObjectRef};
+use super::json::{deserialize_option_u64_str, json_u64};
+use super::plugins::TransactionPlugins;
+use crate::cryptography::SignerError;
 
 #[derive(Debug, thiserror::Error)]
 pub enum TransactionError {
     #[error("serialize transaction failed: {0}")]
     Serialize(#[from] serde_json::Error),
+    #[error("bcs encode failed: {0}")]
+    Bcs(#[from] bcs::Error),
+    #[error("invalid base64: {0}")]
+    Base64(#[from] base64::DecodeError),
+    #[error("missing transaction sender")]
+    MissingSender,
+    #[error("missing gas budget")]
+    MissingGasBudget,
+    #[error("missing gas price")]
+    MissingGasPrice,
+    #[error("missing gas payment")]
+    MissingGasPayment,
+    #[error("invalid gas data: {0}")]
+    InvalidGasData(String),
+    #[error("invalid address: {0}")]
+    InvalidAddress(String),
+    #[error("invalid digest: {0}")]
+    InvalidDigest(String),
+    #[error("invalid type tag: {0}")]
+    InvalidTypeTag(String),
+    #[error("invalid move call target: {0}")]
+    InvalidMoveCallTarget(String),
+    #[error("invalid expiration: {0}")]
+    InvalidExpiration(String),
+    #[error("object {0} is not resolved")]
+    UnresolvedObject(String),
+    #[error("pure value {0} is not resolved")]
+    UnresolvedPure(String),
+    #[error("intent {0} is not resolved")]
+    UnresolvedIntent(String),
+    #[error("signatures cover different transaction bytes")]
+    SignedBytesMismatch,
+    #[error("sponsored transaction does not match: {0}")]
+    SponsorMismatch(String),
+    #[error("sign transaction failed: {0}")]
+    Sign(#[from] SignerError),
+    #[error("unsupported: {0}")]
+    Unsupported(String),
+    #[error("plugins {0:?} have not run; build with build_async")]
+    PluginsNotRun(Vec<String>),
+    #[error("too many {kind}: {count} exceeds the {max} arguments can reference")]
+    TooManyArgumentTargets {
+        kind: &'static str,
+        count: usize,
+        max: usize,
+    },
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GasData {
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
     pub owner: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "deserialize_option_u64_str")]
     pub price: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "deserialize_option_u64_str")]
     pub budget: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub payment: Option<Vec<Value>>,
+    #[serde(default)]
+    pub payment: Option<Vec<ObjectRef>>,
+}
+
+/// When a transaction stops being valid, in the `$kind` JSON shape of the
+/// TypeScript SDK.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(try_from = "Value", into = "Value")]
+pub enum TransactionExpiration {
+    #[default]
+    None,
+    Epoch(u64),
+    /// Replay protection for transactions without owned gas coins; `chain`
+    /// is the Base58 genesis checkpoint digest.
+    ValidDuring {
+        min_epoch: Option<u64>,
+        max_epoch: Option<u64>,
+        min_timestamp: Option<u64>,
+        max_timestamp: Option<u64>,
+        chain: String,
+        nonce: u32,
+    },
+}
+
+fn json_optional_u64(value: &Value, key: &str) -> Result<Option<u64>, String> {
+    match value.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(v) => json_u64(v)
+            .map(Some)
+            .ok_or_else(|| format!("invalid {key}")),
+    }
+}
+
+impl TryFrom<Value> for TransactionExpiration {
+    type Error = String;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value.get("$kind").and_then(Value::as_str) {
+            None if value.is_null() => Ok(Self::None),
+            Some("None") => Ok(Self::None),
+            Some("Epoch") => value
+                .get("Epoch")
+                .and_then(json_u64)
+                .map(Self::Epoch)
+                .ok_or_else(|| format!("invalid expiration: {value}")),
+            Some("ValidDuring") => {
+                let inner = &value["ValidDuring"];
+                let chain = inner["chain"]
+                    .as_str()
+                    .ok_or_else(|| format!("invalid expiration: {value}"))?;
+                let nonce = json_u64(&inner["nonce"])
+                    .and_then(|nonce| u32::try_from(nonce).ok())
+                    .ok_or_else(|| format!("invalid expiration: {value}"))?;
+                Ok(Self::ValidDuring {
+                    min_epoch: json_optional_u64(inner, "minEpoch")?,
+                    max_epoch: json_optional_u64(inner, "maxEpoch")?,
+                    min_timestamp: json_optional_u64(inner, "minTimestamp")?,
+                    max_timestamp: json_optional_u64(inner, "maxTimestamp")?,
+                    chain: chain.to_string(),
+                    nonce,
+                })
+            }
+            _ => Err(format!("invalid expiration: {value}")),
+        }
+    }
+}
+
+impl From<TransactionExpiration> for Value {
+    fn from(expiration: TransactionExpiration) -> Self {
+        let optional = |v: Option<u64>| v.map(|v| Value::String(v.to_string()));
+        match expiration {
+            TransactionExpiration::None => json!({"$kind": "None", "None": true}),
+            TransactionExpiration::Epoch(epoch) => {
+                json!({"$kind": "Epoch", "Epoch": epoch.to_string()})
+            }
+            TransactionExpiration::ValidDuring {
+                min_epoch,
+                max_epoch,
+                min_timestamp,
+                max_timestamp,
+                chain,
+                nonce,
+            } => json!({
+                "$kind": "ValidDuring",
+                "ValidDuring": {
+                    "minEpoch": optional(min_epoch),
+                    "maxEpoch": optional(max_epoch),
+                    "minTimestamp": optional(min_timestamp),
+                    "maxTimestamp": optional(max_timestamp),
+                    "chain": chain,
+                    "nonce": nonce,
+                },
+            }),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -24,18 +169,19 @@ pub struct TransactionData {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sender: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration: Option<Value>,
+    pub expiration: Option<TransactionExpiration>,
     #[serde(default, rename = "gasData")]
     pub gas_data: GasData,
     #[serde(default)]
-    pub inputs: Vec<Value>,
+    pub inputs: Vec<CallArg>,
     #[serde(default)]
-    pub commands: Vec<Value>,
+    pub commands: Vec<Command>,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct Transaction {
     pub data: TransactionData,
+    pub plugins: TransactionPlugins,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
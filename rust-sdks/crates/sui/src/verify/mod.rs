@@ -3,7 +3,9 @@ use fastcrypto::secp256k1::{Secp256k1PublicKey, Secp256k1Signature};
 use fastcrypto::secp256r1::{Secp256r1PublicKey, Secp256r1Signature};
 use fastcrypto::traits::{ToFromBytes, VerifyingKey};
 
-use crate::crypto::{self, SignatureScheme};
+use crate::crypto::SignatureScheme;
+use crate::cryptography::signer;
+use crate::keypairs::passkey::{PasskeyAuthenticator, PasskeyError, PasskeyPublicKey};
 
 #[derive(Debug, thiserror::Error)]
 pub enum VerifyError {
@@ -13,6 +15,10 @@ pub enum VerifyError {
     InvalidPublicKey,
     #[error("invalid signature bytes")]
     InvalidSignature,
+    #[error("bcs encode failed: {0}")]
+    Bcs(#[from] bcs::Error),
+    #[error(transparent)]
+    Passkey(#[from] PasskeyError),
 }
 
 pub fn verify_signature(
@@ -40,22 +46,57 @@ pub fn verify_signature(
     }
 }
 
+/// Checks a signature over the Blake2b-256 digest of the BCS-encoded message
+/// with the personal message intent.
 pub fn verify_personal_message(
     scheme: SignatureScheme,
     public_key_bytes: &[u8],
     message: &[u8],
     signature: &[u8],
 ) -> Result<bool, VerifyError> {
-    let msg = crypto::message_with_intent([3, 0, 0], message);
-    verify_signature(scheme, public_key_bytes, &msg, signature)
+    let digest = signer::personal_message_digest(message)?;
+    verify_signature(scheme, public_key_bytes, &digest, signature)
 }
 
+/// Checks a signature over the Blake2b-256 digest of the transaction intent
+/// message.
 pub fn verify_transaction(
     scheme: SignatureScheme,
     public_key_bytes: &[u8],
     tx_bytes: &[u8],
     signature: &[u8],
 ) -> Result<bool, VerifyError> {
-    let msg = crypto::message_with_intent([0, 0, 0], tx_bytes);
-    verify_signature(scheme, public_key_bytes, &msg, signature)
+    let digest = signer::transaction_digest(tx_bytes);
+    verify_signature(scheme, public_key_bytes, &digest, signature)
+}
+
+fn verify_passkey(
+    public_key: &PasskeyPublicKey,
+    digest: &[u8; 32],
+    signature: &[u8],
+) -> Result<bool, VerifyError> {
+    let authenticator = PasskeyAuthenticator::from_bytes(signature)?;
+    if authenticator.public_key()? != *public_key {
+        return Ok(false);
+    }
+    Ok(authenticator.verify(digest)?)
+}
+
+/// Checks a serialized passkey signature, `0x06 || bcs(PasskeyAuthenticator)`,
+/// over a transaction.
+pub fn verify_passkey_transaction(
+    public_key: &PasskeyPublicKey,
+    tx_bytes: &[u8],
+    signature: &[u8],
+) -> Result<bool, VerifyError> {
+    verify_passkey(public_key, &signer::transaction_digest(tx_bytes), signature)
+}
+
+/// Checks a serialized passkey signature over a personal message.
+pub fn verify_passkey_personal_message(
+    public_key: &PasskeyPublicKey,
+    message: &[u8],
+    signature: &[u8],
+) -> Result<bool, VerifyError> {
+    verify_passkey(public_key, &signer::personal_message_digest(message)?, signature)
 }
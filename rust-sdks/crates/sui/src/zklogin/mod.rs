@@ -2,6 +2,9 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use sha2::{Digest, Sha256};
 
+use crate::crypto;
+use crate::cryptography::{PublicKey, Signer, SignerError};
+
 pub const NONCE_LENGTH: usize = 27;
 pub const MAX_HEADER_LEN_B64: usize = 248;
 pub const MAX_PADDED_UNSIGNED_JWT_LEN: usize = 64 * 25;
@@ -22,6 +25,12 @@ pub enum ZkLoginError {
     Base64(#[from] base64::DecodeError),
     #[error("json decode failed: {0}")]
     Json(#[from] serde_json::Error),
+    #[error("bcs failed: {0}")]
+    Bcs(#[from] bcs::Error),
+    #[error("invalid zkLogin signature flag: 0x{0:02x}")]
+    InvalidSignatureFlag(u8),
+    #[error("deriving the zkLogin address seed needs the Poseidon hash, which is not implemented; use compute_zklogin_address_from_seed")]
+    AddressSeedUnavailable,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,15 +44,49 @@ pub struct ComputeZkLoginAddressOptions {
     pub legacy_address: Option<bool>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Groth16 proof points as decimal strings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZkLoginProofPoints {
+    pub a: Vec<String>,
+    pub b: Vec<Vec<String>>,
+    pub c: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZkLoginIssBase64Details {
+    pub value: String,
+    pub index_mod_4: u8,
+}
+
+/// The proof returned by the zkLogin prover, in its JSON shape. Field order is
+/// the BCS layout.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZkLoginInputs {
+    pub proof_points: ZkLoginProofPoints,
+    pub iss_base64_details: ZkLoginIssBase64Details,
+    pub header_base64: String,
+    pub address_seed: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ZkLoginSignatureExtended {
-    pub inputs: Value,
+    pub inputs: ZkLoginInputs,
     #[serde(rename = "maxEpoch")]
     pub max_epoch: u64,
+    /// Base64 serialized signature of the ephemeral key.
     #[serde(rename = "userSignature")]
     pub user_signature: String,
 }
 
+#[derive(Serialize, Deserialize)]
+struct ZkLoginAuthenticator {
+    inputs: ZkLoginInputs,
+    max_epoch: u64,
+    user_signature: Vec<u8>,
+}
+
 pub fn generate_randomness() -> [u8; 16] {
     rand::random::<[u8; 16]>()
 }
@@ -115,67 +158,277 @@ pub fn decode_jwt(jwt: &str) -> Result<serde_json::Map<String, Value>, ZkLoginEr
     }
 }
 
-pub fn compute_zklogin_address_from_seed(seed: &[u8], iss: &str, aud: &str) -> String {
-    let mut hasher = Sha256::new();
-    // Match TS ordering: scheme flag + normalized issuer length + issuer + seed-like payload.
-    hasher.update([0x05]); // zkLogin scheme flag
-    let normalized_iss = iss.trim().to_lowercase();
-    hasher.update([(normalized_iss.len() & 0xff) as u8]);
-    hasher.update(normalized_iss.as_bytes());
-    hasher.update(seed);
-    hasher.update(aud.as_bytes());
-    format!("0x{}", hex::encode(hasher.finalize()))
+/// Public identifier of a zkLogin account: the normalized issuer and the
+/// address seed derived from the user salt and JWT claims.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZkLoginPublicIdentifier {
+    pub iss: String,
+    pub address_seed: [u8; 32],
+}
+
+impl ZkLoginPublicIdentifier {
+    pub fn new(iss: &str, address_seed: [u8; 32]) -> Self {
+        Self {
+            iss: normalize_zklogin_issuer(iss),
+            address_seed,
+        }
+    }
+
+    /// `iss length || iss || address seed (32 bytes, big-endian)`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        zklogin_identifier_bytes(&self.iss, &self.address_seed)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ZkLoginError> {
+        let (&iss_len, rest) = bytes.split_first().ok_or(ZkLoginError::InvalidInput)?;
+        if rest.len() != iss_len as usize + 32 {
+            return Err(ZkLoginError::InvalidInput);
+        }
+        let (iss, address_seed) = rest.split_at(iss_len as usize);
+        Ok(Self {
+            iss: String::from_utf8(iss.to_vec()).map_err(|_| ZkLoginError::InvalidInput)?,
+            address_seed: address_seed.try_into().expect("32 bytes remain"),
+        })
+    }
+
+    pub fn to_sui_address(&self) -> String {
+        crypto::to_sui_address(crypto::ZKLOGIN_SCHEME_FLAG, &self.to_bytes())
+    }
+}
+
+/// Decodes a base64url slice cut out of a JWT at character offset
+/// `index_mod_4`, dropping the bits that belong to its neighbours.
+fn decode_base64_claim(value: &str, index_mod_4: u8) -> Result<String, ZkLoginError> {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+    if value.len() < 2 {
+        return Err(ZkLoginError::InvalidInput);
+    }
+    let mut bits = Vec::with_capacity(value.len() * 6);
+    for c in value.bytes() {
+        let sextet = ALPHABET.iter().position(|&a| a == c).ok_or(ZkLoginError::InvalidInput)?;
+        bits.extend((0..6).rev().map(|shift| (sextet >> shift) & 1 == 1));
+    }
+    let skip = match index_mod_4 % 4 {
+        0 => 0,
+        1 => 2,
+        2 => 4,
+        _ => return Err(ZkLoginError::InvalidInput),
+    };
+    let trim = match (index_mod_4 as usize + value.len() - 1) % 4 {
+        3 => 0,
+        2 => 2,
+        1 => 4,
+        _ => return Err(ZkLoginError::InvalidInput),
+    };
+    let bits = &bits[skip..bits.len() - trim];
+    if bits.len() % 8 != 0 {
+        return Err(ZkLoginError::InvalidInput);
+    }
+    let bytes = bits
+        .chunks(8)
+        .map(|byte| byte.iter().fold(0u8, |acc, &bit| (acc << 1) | bit as u8))
+        .collect();
+    String::from_utf8(bytes).map_err(|_| ZkLoginError::InvalidInput)
+}
+
+impl ZkLoginIssBase64Details {
+    /// The `iss` claim this slice of the JWT payload carries.
+    pub fn iss(&self) -> Result<String, ZkLoginError> {
+        let claim = decode_base64_claim(&self.value, self.index_mod_4)?;
+        let claim = claim
+            .strip_suffix(',')
+            .or_else(|| claim.strip_suffix('}'))
+            .ok_or(ZkLoginError::InvalidJwt)?;
+        let claims: serde_json::Map<String, Value> = serde_json::from_str(&format!("{{{claim}}}"))?;
+        match (claims.len(), claims.get("iss")) {
+            (1, Some(Value::String(iss))) => Ok(iss.clone()),
+            _ => Err(ZkLoginError::InvalidJwt),
+        }
+    }
+}
+
+impl ZkLoginInputs {
+    /// The identifier of the account the proof is for.
+    pub fn public_identifier(&self) -> Result<ZkLoginPublicIdentifier, ZkLoginError> {
+        Ok(ZkLoginPublicIdentifier::new(
+            &self.iss_base64_details.iss()?,
+            parse_address_seed(&self.address_seed)?,
+        ))
+    }
+}
+
+pub fn normalize_zklogin_issuer(iss: &str) -> String {
+    if iss == "accounts.google.com" {
+        "https://accounts.google.com".to_string()
+    } else {
+        iss.to_string()
+    }
 }
 
-pub fn jwt_to_address(jwt: &str, user_salt: &str, legacy_address: bool) -> Result<String, ZkLoginError> {
+/// Parses a decimal address seed, as returned by the salt service, into its
+/// 32-byte big-endian form.
+pub fn parse_address_seed(decimal: &str) -> Result<[u8; 32], ZkLoginError> {
+    if decimal.is_empty() || !decimal.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ZkLoginError::InvalidInput);
+    }
+    let mut out = [0u8; 32];
+    for digit in decimal.bytes() {
+        let mut carry = (digit - b'0') as u32;
+        for byte in out.iter_mut().rev() {
+            let value = *byte as u32 * 10 + carry;
+            *byte = value as u8;
+            carry = value >> 8;
+        }
+        if carry != 0 {
+            return Err(ZkLoginError::InvalidInput);
+        }
+    }
+    Ok(out)
+}
+
+fn zklogin_identifier_bytes(iss: &str, address_seed: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(1 + iss.len() + address_seed.len());
+    bytes.push(iss.len() as u8);
+    bytes.extend_from_slice(iss.as_bytes());
+    bytes.extend_from_slice(address_seed);
+    bytes
+}
+
+/// Address for a big-endian address seed. Legacy addresses hash the seed
+/// without its leading zero bytes instead of padding it to 32 bytes.
+pub fn compute_zklogin_address_from_seed(
+    address_seed: &[u8],
+    iss: &str,
+    legacy_address: bool,
+) -> Result<String, ZkLoginError> {
+    let start = address_seed
+        .iter()
+        .position(|b| *b != 0)
+        .unwrap_or(address_seed.len());
+    let trimmed = &address_seed[start..];
+    if trimmed.len() > 32 {
+        return Err(ZkLoginError::InvalidInput);
+    }
+    let mut seed = trimmed.to_vec();
+    if !legacy_address {
+        seed = [vec![0u8; 32 - trimmed.len()], seed].concat();
+    }
+    let iss = normalize_zklogin_issuer(iss);
+    Ok(crypto::to_sui_address(
+        crypto::ZKLOGIN_SCHEME_FLAG,
+        &zklogin_identifier_bytes(&iss, &seed),
+    ))
+}
+
+/// Validates `jwt`, but does not derive an address: the address seed is a
+/// Poseidon hash of the salt and JWT claims, which this crate does not
+/// implement. Pass the salt service's address seed (see
+/// [`parse_address_seed`]) to [`compute_zklogin_address_from_seed`] instead.
+pub fn jwt_to_address(jwt: &str, _user_salt: &str, _legacy_address: bool) -> Result<String, ZkLoginError> {
     length_checks(jwt)?;
     let payload = decode_jwt(jwt)?;
-    let iss = payload
-        .get("iss")
-        .and_then(Value::as_str)
-        .ok_or(ZkLoginError::InvalidJwt)?;
-    let aud = payload
-        .get("aud")
-        .and_then(Value::as_str)
-        .ok_or(ZkLoginError::InvalidJwt)?;
-    let sub = payload
-        .get("sub")
-        .and_then(Value::as_str)
-        .ok_or(ZkLoginError::InvalidJwt)?;
-
-    let mut seed_hasher = Sha256::new();
-    seed_hasher.update(user_salt.as_bytes());
-    seed_hasher.update(b":sub:");
-    seed_hasher.update(sub.as_bytes());
-    seed_hasher.update(b":");
-    seed_hasher.update(aud.as_bytes());
-    let mut seed = seed_hasher.finalize().to_vec();
-    if legacy_address {
-        seed.truncate(31);
-    }
-    Ok(compute_zklogin_address_from_seed(&seed, iss, aud))
+    for claim in ["iss", "aud", "sub"] {
+        payload
+            .get(claim)
+            .and_then(Value::as_str)
+            .ok_or(ZkLoginError::InvalidJwt)?;
+    }
+    Err(ZkLoginError::AddressSeedUnavailable)
 }
 
+/// Fails with [`ZkLoginError::AddressSeedUnavailable`] for the same reason as
+/// [`jwt_to_address`]; the user salt alone is not an address seed.
 pub fn compute_zklogin_address(opts: ComputeZkLoginAddressOptions) -> Result<String, ZkLoginError> {
-    if let Some(jwt) = opts.jwt {
-        jwt_to_address(&jwt, &opts.user_salt, opts.legacy_address.unwrap_or(false))
-    } else {
-        Ok(compute_zklogin_address_from_seed(
-            opts.user_salt.as_bytes(),
-            opts.iss.as_deref().unwrap_or_default(),
-            opts.aud.as_deref().unwrap_or_default(),
-        ))
+    match opts.jwt {
+        Some(jwt) => jwt_to_address(&jwt, &opts.user_salt, opts.legacy_address.unwrap_or(false)),
+        None => Err(ZkLoginError::AddressSeedUnavailable),
     }
 }
 
+fn zklogin_signature_bytes(
+    inputs: &ZkLoginInputs,
+    max_epoch: u64,
+    user_signature: &[u8],
+) -> Result<Vec<u8>, ZkLoginError> {
+    let authenticator = bcs::to_bytes(&ZkLoginAuthenticator {
+        inputs: inputs.clone(),
+        max_epoch,
+        user_signature: user_signature.to_vec(),
+    })?;
+    Ok([vec![crypto::ZKLOGIN_SCHEME_FLAG], authenticator].concat())
+}
+
+/// Base64 `0x05 || bcs(ZkLoginAuthenticator)`.
 pub fn get_zklogin_signature(input: &ZkLoginSignatureExtended) -> Result<String, ZkLoginError> {
-    let bytes = serde_json::to_vec(input)?;
     use base64::Engine as _;
+    let user_signature = base64::engine::general_purpose::STANDARD.decode(&input.user_signature)?;
+    let bytes = zklogin_signature_bytes(&input.inputs, input.max_epoch, &user_signature)?;
     Ok(base64::engine::general_purpose::STANDARD.encode(bytes))
 }
 
 pub fn parse_zklogin_signature(signature: &str) -> Result<ZkLoginSignatureExtended, ZkLoginError> {
     use base64::Engine as _;
-    let bytes = base64::engine::general_purpose::STANDARD.decode(signature)?;
-    Ok(serde_json::from_slice(&bytes)?)
+    parse_zklogin_signature_bytes(&base64::engine::general_purpose::STANDARD.decode(signature)?)
+}
+
+/// Reads `0x05 || bcs(ZkLoginAuthenticator)`.
+pub fn parse_zklogin_signature_bytes(bytes: &[u8]) -> Result<ZkLoginSignatureExtended, ZkLoginError> {
+    use base64::Engine as _;
+    match bytes.first() {
+        Some(&crypto::ZKLOGIN_SCHEME_FLAG) => {}
+        Some(&flag) => return Err(ZkLoginError::InvalidSignatureFlag(flag)),
+        None => return Err(ZkLoginError::InvalidInput),
+    }
+    let authenticator: ZkLoginAuthenticator = bcs::from_bytes(&bytes[1..])?;
+    Ok(ZkLoginSignatureExtended {
+        inputs: authenticator.inputs,
+        max_epoch: authenticator.max_epoch,
+        user_signature: base64::engine::general_purpose::STANDARD
+            .encode(authenticator.user_signature),
+    })
+}
+
+/// Signs for a zkLogin address with the ephemeral key the proof was issued
+/// for.
+pub struct ZkLoginSigner {
+    identifier: ZkLoginPublicIdentifier,
+    inputs: ZkLoginInputs,
+    max_epoch: u64,
+    ephemeral: Box<dyn Signer>,
+}
+
+impl ZkLoginSigner {
+    pub fn new(
+        iss: &str,
+        inputs: ZkLoginInputs,
+        max_epoch: u64,
+        ephemeral: Box<dyn Signer>,
+    ) -> Result<Self, ZkLoginError> {
+        let address_seed = parse_address_seed(&inputs.address_seed)?;
+        Ok(Self {
+            identifier: ZkLoginPublicIdentifier::new(iss, address_seed),
+            inputs,
+            max_epoch,
+            ephemeral,
+        })
+    }
+}
+
+impl Signer for ZkLoginSigner {
+    fn public_key(&self) -> PublicKey {
+        PublicKey::ZkLogin(self.identifier.clone())
+    }
+
+    fn address(&self) -> String {
+        self.identifier.to_sui_address()
+    }
+
+    fn sign_digest(&self, digest: &[u8; 32]) -> Result<Vec<u8>, SignerError> {
+        let user_signature = self.ephemeral.sign_digest(digest)?;
+        Ok(zklogin_signature_bytes(
+            &self.inputs,
+            self.max_epoch,
+            &user_signature,
+        )?)
+    }
 }
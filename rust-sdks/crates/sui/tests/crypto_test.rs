@@ -1,4 +1,68 @@
+use base64::Engine as _;
 use sui::crypto::{hash_with_intent, message_with_intent, SignatureScheme};
+use sui::cryptography::PublicKey;
+use sui::keypairs::passkey::PasskeyPublicKey;
+use sui::keypairs::{ed25519, secp256k1, secp256r1};
+use sui::multisig::{MultiSigPublicKey, WeightedPublicKey};
+use sui::zklogin;
+
+// Public key vectors from the Sui TypeScript SDK keypair tests:
+// (raw base64, flagged Sui public key, address).
+const ED25519_KEYS: &[(&str, &str, &str)] = &[(
+    "0PTAfQmNiabgbak9U/stWZzKc5nsRqokda2qnV2DTfg=",
+    "AND0wH0JjYmm4G2pPVP7LVmcynOZ7EaqJHWtqp1dg034",
+    "0x7e8fd489c3d3cd9cc7cbcc577dc5d6de831e654edd9997d95c412d013e6eea23",
+)];
+const SECP256K1_KEYS: &[(&str, &str, &str)] = &[
+    (
+        "AwTC3jVFRxXc3RJIFgoQcv486QdqwYa8vBp4bgSq0gsI",
+        "AQMEwt41RUcV3N0SSBYKEHL+POkHasGGvLwaeG4EqtILCA==",
+        "0xcdce00b4326fb908fdac83c35bcfbda323bfcc0618b47c66ccafbdced850efaa",
+    ),
+    (
+        "A1F2CtldIGolO92Pm9yuxWXs5E07aX+6ZEHAnSuKOhii",
+        "AQNRdgrZXSBqJTvdj5vcrsVl7ORNO2l/umRBwJ0rijoYog==",
+        "0xb588e58ed8967b6a6f9dbce76386283d374cf7389fb164189551257e32b023b2",
+    ),
+    (
+        "Ak5rsa5Od4T6YFN/V3VIhZ/azMMYPkUilKQwc+RiaId+",
+        "AQJOa7GuTneE+mBTf1d1SIWf2szDGD5FIpSkMHPkYmiHfg==",
+        "0x694dd74af1e82b968822a82fb5e315f6d20e8697d5d03c0b15e0178c1a1fcfa0",
+    ),
+    (
+        "A4XbJ3fLvV/8ONsnLHAW1nORKsoCYsHaXv9FK1beMtvY",
+        "AQOF2yd3y71f/DjbJyxwFtZzkSrKAmLB2l7/RStW3jLb2A==",
+        "0x78acc6ca0003457737d755ade25a6f3a144e5e44ed6f8e6af4982c5cc75e55e7",
+    ),
+];
+
+// secp256r1 private key from the same tests.
+const SECP256R1_PRIVATE_KEY: &str = "AiWmZXUcFpUF75H082F2RVJAABS5kcrvb8o09IPH9yUw";
+
+// Private keys from the Sui TypeScript SDK keypair tests, with their addresses.
+const ED25519_PRIVATE_KEY: (&str, &str) = (
+    "suiprivkey1qqqscjyyr64jea849dfv9cukurqj2swx0m3rr4hr7sw955jy07tzgcde5ut",
+    "0xe69e896ca10f5a77732769803cc2b5707f0ab9d4407afb5e4b4464b89769af14",
+);
+const SECP256K1_PRIVATE_KEY: (&str, &str, &str) = (
+    "suiprivkey1qyqr6yvxdqkh32ep4pk9caqvphmk9epn6rhkczcrhaeermsyvwsg783y9am",
+    "AQA9EYZoLXirIahsXHQMDfdi5DPQ72wLA79zke4EY6CP",
+    "0x9e8f732575cc5386f8df3c784cd3ed1b53ce538da79926b2ad54dcc1197d2532",
+);
+
+fn base64_decode(encoded: &str) -> Vec<u8> {
+    base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .expect("base64")
+}
+
+/// Address per the Sui spec, spelled out independently of the crate:
+/// Blake2b-256 over the flag followed by the given bytes.
+fn spec_address(flag: u8, bytes: &[u8]) -> String {
+    let mut payload = vec![flag];
+    payload.extend_from_slice(bytes);
+    format!("0x{}", hex::encode(sui::crypto::blake2b256(&payload)))
+}
 
 #[test]
 fn intent_message_and_hash_are_deterministic() {
@@ -8,9 +72,13 @@ fn intent_message_and_hash_are_deterministic() {
     assert_eq!(&payload[0..3], &intent);
     assert_eq!(&payload[3..], message);
 
-    let digest1 = hash_with_intent(intent, message);
-    let digest2 = hash_with_intent(intent, message);
-    assert_eq!(digest1, digest2);
+    let digest = hash_with_intent(intent, b"test message");
+    assert_eq!(
+        hex::encode(digest),
+        "f64d5f5c44bd8d2b13479b6a6cb188125314c171a95e91fd3337afe0c0131349"
+    );
+    assert_eq!(digest, sui::crypto::blake2b256(&message_with_intent(intent, b"test message")));
+    assert_eq!(digest, sui::transactions::hash::hash_with_intent(intent, b"test message"));
 }
 
 #[test]
@@ -19,3 +87,111 @@ fn signature_scheme_flags_match_sui_convention() {
     assert_eq!(SignatureScheme::Secp256k1.flag(), 0x01);
     assert_eq!(SignatureScheme::Secp256r1.flag(), 0x02);
 }
+
+#[test]
+fn public_keys_match_typescript_vectors() {
+    for (scheme, vectors) in [
+        (SignatureScheme::Ed25519, ED25519_KEYS),
+        (SignatureScheme::Secp256k1, SECP256K1_KEYS),
+    ] {
+        for (raw, sui_public_key, address) in vectors {
+            let bytes = base64_decode(raw);
+            assert_eq!(
+                sui::crypto::to_sui_public_key(scheme.flag(), &bytes),
+                *sui_public_key
+            );
+            assert_eq!(
+                PublicKey::from_bytes(scheme, bytes).to_sui_address(),
+                *address
+            );
+        }
+    }
+}
+
+#[test]
+fn passkey_and_multisig_addresses_follow_sui_layout() {
+    let r1 = secp256r1::Keypair::from_sui_private_key(SECP256R1_PRIVATE_KEY).expect("secp256r1");
+    let passkey = PasskeyPublicKey::new(&r1.public_key_bytes()).expect("passkey");
+    let passkey_address = spec_address(0x06, &r1.public_key_bytes());
+    assert_eq!(passkey.to_sui_address(), passkey_address);
+    assert_eq!(
+        PublicKey::Passkey(passkey).to_sui_address(),
+        passkey_address
+    );
+
+    // threshold (u16 LE), then each member as flag || public key || weight.
+    let ed = base64_decode(ED25519_KEYS[0].0);
+    let k1 = base64_decode(SECP256K1_KEYS[0].0);
+    let mut layout = 2_u16.to_le_bytes().to_vec();
+    for (flag, bytes, weight) in [
+        (0x00, &ed, 1),
+        (0x01, &k1, 1),
+        (0x02, &r1.public_key_bytes(), 2),
+    ] {
+        layout.push(flag);
+        layout.extend_from_slice(bytes);
+        layout.push(weight);
+    }
+    let multisig_address = spec_address(0x03, &layout);
+
+    let multisig = MultiSigPublicKey::new(
+        vec![
+            WeightedPublicKey::new(PublicKey::from_bytes(SignatureScheme::Ed25519, ed), 1),
+            WeightedPublicKey::new(PublicKey::from_bytes(SignatureScheme::Secp256k1, k1), 1),
+            WeightedPublicKey::new(
+                PublicKey::from_bytes(SignatureScheme::Secp256r1, r1.public_key_bytes()),
+                2,
+            ),
+        ],
+        2,
+    )
+    .expect("multisig");
+    assert_eq!(multisig.to_sui_address(), multisig_address);
+    assert_eq!(
+        PublicKey::MultiSig(multisig).to_sui_address(),
+        multisig_address
+    );
+}
+
+#[test]
+fn zklogin_address_matches_sui_test_vector() {
+    // From the Sui TypeScript SDK zkLogin address tests.
+    let seed = zklogin::parse_address_seed(
+        "13322897930163218532266430409510394316985274769125667290600321564259466511711",
+    )
+    .expect("seed");
+    let expected = "0xf7badc2b245c7f74d7509a4aa357ecf80a29e7713fb4c44b0e7541ec43885ee1";
+    assert_eq!(
+        zklogin::compute_zklogin_address_from_seed(&seed, "https://accounts.google.com", false)
+            .expect("address"),
+        expected
+    );
+    let identifier = zklogin::ZkLoginPublicIdentifier::new("accounts.google.com", seed);
+    assert_eq!(
+        PublicKey::ZkLogin(identifier).to_sui_address(),
+        expected
+    );
+    assert!(zklogin::parse_address_seed("12a").is_err());
+}
+
+#[test]
+fn private_keys_import_from_bech32_and_legacy_base64() {
+    let (bech32, legacy, address) = SECP256K1_PRIVATE_KEY;
+    for encoded in [bech32, legacy] {
+        let restored = secp256k1::Keypair::from_sui_private_key(encoded).expect("restore");
+        assert_eq!(restored.to_sui_address(), address);
+        assert_eq!(restored.to_sui_private_key(), bech32);
+    }
+    let (bech32_ed, ed_address) = ED25519_PRIVATE_KEY;
+    let ed = ed25519::Keypair::from_sui_private_key(bech32_ed).expect("ed25519");
+    assert_eq!(ed.to_sui_address(), ed_address);
+
+    assert!(matches!(
+        ed25519::Keypair::from_sui_private_key(bech32),
+        Err(ed25519::KeypairError::InvalidScheme(0x01))
+    ));
+    assert!(matches!(
+        secp256r1::Keypair::from_sui_private_key("suiprivkey1qqqqqqqq"),
+        Err(secp256r1::KeypairError::PrivateKey(_))
+    ));
+}
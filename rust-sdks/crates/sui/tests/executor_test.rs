@@ -0,0 +1,529 @@
+use std::sync::Arc;
+
+use httpmock::Method::POST;
+use httpmock::MockServer;
+use httpmock::prelude::HttpMockRequest;
+use serde_json::{Value, json};
+use sui::executor::{
+    ParallelExecutorConfig, ParallelTransactionExecutor, SerialTransactionExecutor,
+};
+use sui::jsonrpc::Client as JsonRpcClient;
+use sui::keypairs::ed25519::Keypair;
+use sui::intents::coin_with_balance;
+use sui::transactions::bcs_types::Digest;
+use sui::transactions::{CallArg, Transaction};
+
+const OBJECT_ID: &str = "0x0000000000000000000000000000000000000000000000000000000000000010";
+const GAS_ID: &str = "0x0000000000000000000000000000000000000000000000000000000000000005";
+
+fn effects_ref(object_id: &str, version: u64, owner: &str) -> Value {
+    json!({
+        "owner": {"AddressOwner": owner},
+        "reference": {
+            "objectId": object_id,
+            "version": version,
+            "digest": Digest([version as u8; 32]).to_string()
+        }
+    })
+}
+
+fn mock_reads<'a>(server: &'a MockServer, owner: &str) -> [httpmock::Mock<'a>; 4] {
+    let objects = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"sui_multiGetObjects\"");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({"jsonrpc": "2.0", "id": 1, "result": [
+                {"data": {
+                    "objectId": OBJECT_ID,
+                    "version": "3",
+                    "digest": "11111111111111111111111111111111",
+                    "owner": {"AddressOwner": owner}
+                }}
+            ]}));
+    });
+    let price = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"suix_getReferenceGasPrice\"");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"jsonrpc":"2.0","id":1,"result":"1000"}"#);
+    });
+    let dry_run = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"sui_dryRunTransactionBlock\"");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({"jsonrpc": "2.0", "id": 2, "result": {"effects": {
+                "status": {"status": "success"},
+                "gasUsed": {
+                    "computationCost": "1000000",
+                    "storageCost": "2000000",
+                    "storageRebate": "1000000",
+                    "nonRefundableStorageFee": "10000"
+                }
+            }}}));
+    });
+    let coins = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"suix_getCoins\"");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({"jsonrpc": "2.0", "id": 3, "result": {
+                "data": [{
+                    "coinType": "0x2::sui::SUI",
+                    "coinObjectId": GAS_ID,
+                    "version": "7",
+                    "digest": "11111111111111111111111111111111",
+                    "balance": "100000000"
+                }],
+                "nextCursor": null,
+                "hasNextPage": false
+            }}));
+    });
+    [objects, price, dry_run, coins]
+}
+
+fn transfer(object_id: &str) -> Transaction {
+    let mut tx = Transaction::new();
+    let object = tx.object(object_id);
+    let recipient = tx.pure_bytes(&[0x22; 32]);
+    tx.transfer_objects(vec![object], recipient);
+    tx
+}
+
+#[tokio::test]
+async fn serial_executor_reuses_versions_from_effects() {
+    let keypair = Keypair::generate();
+    let owner = keypair.to_sui_address();
+    let server = MockServer::start();
+    let [objects, price, dry_run, coins] = mock_reads(&server, &owner);
+    let execute = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"sui_executeTransactionBlock\"")
+            .body_contains("\"showEffects\":true");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({"jsonrpc": "2.0", "id": 4, "result": {
+                "digest": "tx",
+                "effects": {
+                    "status": {"status": "success"},
+                    "mutated": [effects_ref(OBJECT_ID, 8, &owner)],
+                    "gasObject": effects_ref(GAS_ID, 8, &owner)
+                }
+            }}));
+    });
+
+    let executor = SerialTransactionExecutor::new(
+        JsonRpcClient::new(server.url("/"), "testnet"),
+        Arc::new(keypair),
+    );
+    let first = executor.execute(transfer("0x10")).await.expect("first");
+    assert!(first.success);
+    assert_eq!(first.digest, "tx");
+    let gas_coin = executor.gas_coin().expect("gas coin");
+    assert_eq!((gas_coin.object_id.as_str(), gas_coin.version), (GAS_ID, 8));
+    assert_eq!(
+        executor.cache().get_object("0x10").expect("cached").version,
+        8
+    );
+
+    let second = executor.execute(transfer("0x10")).await.expect("second");
+    assert!(second.success);
+    execute.assert_hits(2);
+    dry_run.assert_hits(2);
+    objects.assert_hits(1);
+    price.assert_hits(1);
+    coins.assert_hits(1);
+}
+
+#[tokio::test]
+async fn serial_executor_clears_cache_after_failure() {
+    let keypair = Keypair::generate();
+    let owner = keypair.to_sui_address();
+    let server = MockServer::start();
+    let [objects, _price, _dry_run, coins] = mock_reads(&server, &owner);
+    let execute = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"sui_executeTransactionBlock\"");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({"jsonrpc": "2.0", "id": 4, "result": {
+                "digest": "tx",
+                "effects": {
+                    "status": {"status": "failure", "error": "InsufficientGas"},
+                    "mutated": [effects_ref(OBJECT_ID, 8, &owner)],
+                    "gasObject": effects_ref(GAS_ID, 8, &owner)
+                }
+            }}));
+    });
+
+    let executor = SerialTransactionExecutor::new(
+        JsonRpcClient::new(server.url("/"), "testnet"),
+        Arc::new(keypair),
+    );
+    let result = executor.execute(transfer("0x10")).await.expect("execute");
+    assert!(!result.success);
+    assert_eq!(result.error.as_deref(), Some("InsufficientGas"));
+    assert!(executor.gas_coin().is_none());
+    assert!(executor.cache().get_object("0x10").is_none());
+
+    executor.execute(transfer("0x10")).await.expect("retry");
+    execute.assert_hits(2);
+    objects.assert_hits(2);
+    coins.assert_hits(2);
+}
+
+const COIN_A: &str = "0x00000000000000000000000000000000000000000000000000000000000000aa";
+const COIN_B: &str = "0x00000000000000000000000000000000000000000000000000000000000000bb";
+
+/// The gas coin paying for the transaction in a `sui_executeTransactionBlock`
+/// request.
+fn gas_payment(req: &HttpMockRequest) -> Option<String> {
+    use base64::Engine as _;
+    let body: Value = serde_json::from_slice(req.body.as_ref()?).ok()?;
+    if body["method"] != "sui_executeTransactionBlock" {
+        return None;
+    }
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(body["params"][0].as_str()?)
+        .ok()?;
+    let tx = Transaction::from_bytes(&bytes).ok()?;
+    Some(tx.data.gas_data.payment?.first()?.object_id.clone())
+}
+
+fn paid_by_source(req: &HttpMockRequest) -> bool {
+    gas_payment(req).as_deref() == Some(GAS_ID)
+}
+
+fn paid_by_coin_a(req: &HttpMockRequest) -> bool {
+    gas_payment(req).as_deref() == Some(COIN_A)
+}
+
+fn paid_by_coin_b(req: &HttpMockRequest) -> bool {
+    gas_payment(req).as_deref() == Some(COIN_B)
+}
+
+fn mock_object<'a>(server: &'a MockServer, object_id: &str, owner: &str) -> httpmock::Mock<'a> {
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"sui_multiGetObjects\"")
+            .body_contains(object_id);
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({"jsonrpc": "2.0", "id": 1, "result": [
+                {"data": {
+                    "objectId": object_id,
+                    "version": "3",
+                    "digest": "11111111111111111111111111111111",
+                    "owner": {"AddressOwner": owner}
+                }}
+            ]}));
+    })
+}
+
+fn mock_execute<'a>(
+    server: &'a MockServer,
+    matcher: fn(&HttpMockRequest) -> bool,
+    effects: Value,
+) -> httpmock::Mock<'a> {
+    server.mock(|when, then| {
+        when.method(POST).path("/").matches(matcher);
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({"jsonrpc": "2.0", "id": 4, "result": {
+                "digest": "tx",
+                "effects": effects
+            }}));
+    })
+}
+
+#[tokio::test]
+async fn parallel_executor_pays_from_refilled_coin_pool() {
+    let keypair = Keypair::generate();
+    let owner = keypair.to_sui_address();
+    let server = MockServer::start();
+    let [mut objects, price, dry_run, coins] = mock_reads(&server, &owner);
+    objects.delete();
+    let first = mock_object(&server, OBJECT_ID, &owner);
+    let second_id = "0x0000000000000000000000000000000000000000000000000000000000000011";
+    let second = mock_object(&server, second_id, &owner);
+    let refill = mock_execute(
+        &server,
+        paid_by_source,
+        json!({
+            "status": {"status": "success"},
+            "created": [effects_ref(COIN_A, 8, &owner), effects_ref(COIN_B, 8, &owner)],
+            "gasObject": effects_ref(GAS_ID, 8, &owner)
+        }),
+    );
+    let gas_used = json!({
+        "computationCost": "1000000",
+        "storageCost": "1000000",
+        "storageRebate": "500000"
+    });
+    let paid_by_a = mock_execute(
+        &server,
+        paid_by_coin_a,
+        json!({
+            "status": {"status": "success"},
+            "gasUsed": gas_used,
+            "gasObject": effects_ref(COIN_A, 9, &owner)
+        }),
+    );
+    let paid_by_b = mock_execute(
+        &server,
+        paid_by_coin_b,
+        json!({
+            "status": {"status": "success"},
+            "gasUsed": gas_used,
+            "gasObject": effects_ref(COIN_B, 9, &owner)
+        }),
+    );
+
+    let executor = ParallelTransactionExecutor::new(
+        JsonRpcClient::new(server.url("/"), "testnet"),
+        Arc::new(keypair),
+        ParallelExecutorConfig {
+            workers: 2,
+            coin_batch_size: 2,
+            initial_coin_balance: 40_000_000,
+            minimum_coin_balance: 10_000_000,
+            default_gas_budget: 10_000_000,
+        },
+    );
+    let (a, b, c) = tokio::join!(
+        executor.execute(transfer(OBJECT_ID)),
+        executor.execute(transfer(second_id)),
+        executor.execute(transfer(OBJECT_ID)),
+    );
+    for result in [a, b, c] {
+        assert!(result.expect("execute").success);
+    }
+
+    refill.assert_hits(1);
+    assert_eq!(paid_by_a.hits() + paid_by_b.hits(), 3);
+    assert_eq!(executor.available_coins(), 2);
+    coins.assert_hits(1);
+    price.assert_hits(1);
+    dry_run.assert_hits(1);
+    first.assert_hits(1);
+    second.assert_hits(1);
+}
+
+#[tokio::test]
+async fn parallel_executor_reports_insufficient_balance_for_refill() {
+    let keypair = Keypair::generate();
+    let owner = keypair.to_sui_address();
+    let server = MockServer::start();
+    let _reads = mock_reads(&server, &owner);
+
+    let executor = ParallelTransactionExecutor::new(
+        JsonRpcClient::new(server.url("/"), "testnet"),
+        Arc::new(keypair),
+        ParallelExecutorConfig::default(),
+    );
+    let err = executor
+        .execute(transfer(OBJECT_ID))
+        .await
+        .expect_err("pool cannot be funded");
+    assert!(err.to_string().contains("insufficient SUI for gas"));
+}
+
+#[tokio::test]
+async fn parallel_executor_keeps_spent_gas_coin_out_of_pool() {
+    let keypair = Keypair::generate();
+    let owner = keypair.to_sui_address();
+    let server = MockServer::start();
+    let _reads = mock_reads(&server, &owner);
+    let refill = mock_execute(
+        &server,
+        paid_by_source,
+        json!({
+            "status": {"status": "success"},
+            "created": [effects_ref(COIN_A, 8, &owner), effects_ref(COIN_B, 8, &owner)],
+            "gasObject": effects_ref(GAS_ID, 8, &owner)
+        }),
+    );
+    let paid_by_a = mock_execute(
+        &server,
+        paid_by_coin_a,
+        json!({"status": {"status": "success"}, "gasObject": effects_ref(COIN_A, 9, &owner)}),
+    );
+    let paid_by_b = mock_execute(
+        &server,
+        paid_by_coin_b,
+        json!({"status": {"status": "success"}, "gasObject": effects_ref(COIN_B, 9, &owner)}),
+    );
+
+    let executor = ParallelTransactionExecutor::new(
+        JsonRpcClient::new(server.url("/"), "testnet"),
+        Arc::new(keypair),
+        ParallelExecutorConfig {
+            workers: 1,
+            coin_batch_size: 2,
+            initial_coin_balance: 40_000_000,
+            minimum_coin_balance: 10_000_000,
+            default_gas_budget: 10_000_000,
+        },
+    );
+    let mut airdrop = Transaction::new();
+    let amount = airdrop.pure_bytes(&1_000u64.to_le_bytes());
+    let coins = airdrop.split_coins(Transaction::gas(), vec![amount]);
+    let recipient = airdrop.pure_bytes(&[0x22; 32]);
+    airdrop.transfer_objects(vec![coins], recipient);
+
+    assert!(executor.execute(airdrop).await.expect("execute").success);
+    refill.assert_hits(1);
+    assert_eq!(paid_by_a.hits() + paid_by_b.hits(), 1);
+    assert_eq!(executor.available_coins(), 1);
+}
+
+const USDC: &str = "0x2::usdc::USDC";
+const USDC_1: &str = "0x00000000000000000000000000000000000000000000000000000000000000c1";
+const USDC_2: &str = "0x00000000000000000000000000000000000000000000000000000000000000c2";
+
+fn coin_page(coin_type: &str, coins: &[(&str, u64)]) -> Value {
+    let data: Vec<Value> = coins
+        .iter()
+        .map(|(object_id, balance)| {
+            json!({
+                "coinType": coin_type,
+                "coinObjectId": object_id,
+                "version": "7",
+                "digest": "11111111111111111111111111111111",
+                "balance": balance.to_string()
+            })
+        })
+        .collect();
+    json!({"jsonrpc": "2.0", "id": 3, "result": {
+        "data": data,
+        "nextCursor": null,
+        "hasNextPage": false
+    }})
+}
+
+/// Whether an executed transaction takes the given coin as an input.
+fn spends(req: &HttpMockRequest, coin: &str) -> bool {
+    use base64::Engine as _;
+    let Some(body) = req.body.as_ref() else {
+        return false;
+    };
+    let Ok(body) = serde_json::from_slice::<Value>(body) else {
+        return false;
+    };
+    let Some(bytes) = body["params"][0]
+        .as_str()
+        .and_then(|tx| base64::engine::general_purpose::STANDARD.decode(tx).ok())
+    else {
+        return false;
+    };
+    body["method"] == "sui_executeTransactionBlock"
+        && Transaction::from_bytes(&bytes).is_ok_and(|tx| {
+            tx.data
+                .inputs
+                .iter()
+                .filter_map(CallArg::object_id)
+                .any(|id| id == coin)
+        })
+}
+
+fn spends_usdc_1(req: &HttpMockRequest) -> bool {
+    spends(req, USDC_1)
+}
+
+fn spends_usdc_2(req: &HttpMockRequest) -> bool {
+    spends(req, USDC_2)
+}
+
+#[tokio::test]
+async fn parallel_executor_gives_concurrent_intents_distinct_coins() {
+    let keypair = Keypair::generate();
+    let owner = keypair.to_sui_address();
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"suix_getReferenceGasPrice\"");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"jsonrpc":"2.0","id":1,"result":"1000"}"#);
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"suix_getCoins\"")
+            .body_contains("::sui::SUI");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(coin_page("0x2::sui::SUI", &[(GAS_ID, 100_000_000)]));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"sui_dryRunTransactionBlock\"");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({"jsonrpc": "2.0", "id": 2, "result": {"effects": {
+                "status": {"status": "success"},
+                "gasUsed": {
+                    "computationCost": "1000000",
+                    "storageCost": "2000000",
+                    "storageRebate": "1000000"
+                }
+            }}}));
+    });
+    let usdc = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"suix_getCoins\"")
+            .body_contains("::usdc::USDC");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(coin_page(USDC, &[(USDC_1, 10), (USDC_2, 10)]));
+    });
+    mock_execute(
+        &server,
+        paid_by_source,
+        json!({
+            "status": {"status": "success"},
+            "created": [effects_ref(COIN_A, 8, &owner), effects_ref(COIN_B, 8, &owner)],
+            "gasObject": effects_ref(GAS_ID, 8, &owner)
+        }),
+    );
+    let first = mock_execute(&server, spends_usdc_1, json!({"status": {"status": "success"}}));
+    let second = mock_execute(&server, spends_usdc_2, json!({"status": {"status": "success"}}));
+
+    let executor = ParallelTransactionExecutor::new(
+        JsonRpcClient::new(server.url("/"), "testnet"),
+        Arc::new(keypair),
+        ParallelExecutorConfig {
+            workers: 2,
+            coin_batch_size: 2,
+            initial_coin_balance: 40_000_000,
+            minimum_coin_balance: 10_000_000,
+            default_gas_budget: 10_000_000,
+        },
+    );
+    let payment = || {
+        let mut tx = Transaction::new();
+        let coin = tx.coin_with_balance(coin_with_balance(5).coin_type(USDC.to_string()));
+        let recipient = tx.pure_bytes(&[0x22; 32]);
+        tx.transfer_objects(vec![coin], recipient);
+        tx
+    };
+    let (a, b) = tokio::join!(executor.execute(payment()), executor.execute(payment()));
+    assert!(a.expect("first").success);
+    assert!(b.expect("second").success);
+
+    first.assert_hits(1);
+    second.assert_hits(1);
+    usdc.assert_hits(2);
+}
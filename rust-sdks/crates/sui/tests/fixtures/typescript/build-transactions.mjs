@@ -0,0 +1,63 @@
+// Builds the transactions checked by `transaction_bytes_match_typescript_fixtures`
+// in tests/transactions_test.rs with the TypeScript SDK and writes their bytes
+// to transactions.json next to this script:
+//
+//     npm install @mysten/sui && node build-transactions.mjs
+
+import { writeFileSync } from 'node:fs';
+
+import { Inputs, Transaction } from '@mysten/sui/transactions';
+import { normalizeSuiAddress, toBase64 } from '@mysten/sui/utils';
+
+const DIGEST = '11111111111111111111111111111111';
+
+function withGas(tx) {
+	tx.setSender(normalizeSuiAddress('0x1'));
+	tx.setGasPrice(1000);
+	tx.setGasBudget(1_000_000);
+	tx.setGasPayment([{ objectId: normalizeSuiAddress('0x5'), version: 7, digest: DIGEST }]);
+	return tx;
+}
+
+function splitAndTransfer() {
+	const tx = withGas(new Transaction());
+	const [first, second] = tx.splitCoins(tx.gas, [100, 200]);
+	tx.transferObjects([first, second], normalizeSuiAddress('0x2'));
+	return tx;
+}
+
+function moveCallSharedReceiving() {
+	const tx = withGas(new Transaction());
+	const parent = tx.object(
+		Inputs.SharedObjectRef({
+			objectId: normalizeSuiAddress('0xaa'),
+			initialSharedVersion: 3,
+			mutable: true,
+		}),
+	);
+	const received = tx.object(
+		Inputs.ReceivingRef({ objectId: normalizeSuiAddress('0xbb'), version: 8, digest: DIGEST }),
+	);
+	const clock = tx.object(
+		Inputs.SharedObjectRef({
+			objectId: normalizeSuiAddress('0x6'),
+			initialSharedVersion: 1,
+			mutable: false,
+		}),
+	);
+	tx.moveCall({
+		target: `${normalizeSuiAddress('0x42')}::vault::receive`,
+		typeArguments: ['0x2::sui::SUI'],
+		arguments: [parent, received, clock, tx.pure.u64(5)],
+	});
+	return tx;
+}
+
+const fixtures = {
+	split_and_transfer: toBase64(await splitAndTransfer().build()),
+	move_call_shared_receiving: toBase64(await moveCallSharedReceiving().build()),
+};
+writeFileSync(
+	new URL('./transactions.json', import.meta.url),
+	`${JSON.stringify(fixtures, null, '\t')}\n`,
+);
@@ -291,6 +291,12 @@ async fn jsonrpc_extended_read_methods() {
         .expect("dry_run_transaction_block");
     assert_eq!(r["ok"], true);
 
+    let r = client
+        .dev_inspect_transaction_block(owner, "AA==")
+        .await
+        .expect("dev_inspect_transaction_block");
+    assert_eq!(r["ok"], true);
+
     let r = client
         .get_dynamic_fields(object_id, None, Some(5))
         .await
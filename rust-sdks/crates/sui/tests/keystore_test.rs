@@ -0,0 +1,207 @@
+use std::path::PathBuf;
+
+use sui::crypto::SignatureScheme;
+use sui::cryptography::{Keypair, Signer};
+use sui::keystore::{
+    ClientConfig, EncryptedKeystore, FileKeystore, KdfParams, KeystoreError, KeystoreLocation,
+};
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("sui-keystore-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("temp dir");
+    dir
+}
+
+#[test]
+fn keystore_reads_cli_files() {
+    use base64::Engine as _;
+    let dir = temp_dir("cli");
+    let ed = Keypair::from_secret_key(SignatureScheme::Ed25519, &[1; 32]).expect("ed25519");
+    let k1 = Keypair::from_secret_key(SignatureScheme::Secp256k1, &[1; 32]).expect("secp256k1");
+    let encode = |flag: u8, bytes: &[u8]| {
+        base64::engine::general_purpose::STANDARD.encode([&[flag][..], bytes].concat())
+    };
+
+    std::fs::write(
+        dir.join("sui.keystore"),
+        serde_json::to_string(&[encode(0, &[1; 32]), encode(1, &[1; 32])]).expect("json"),
+    )
+    .expect("write keystore");
+    std::fs::write(
+        dir.join("sui.aliases"),
+        serde_json::json!([
+            {"alias": "admiring-jade", "public_key_base64": encode(0, &ed.public_key_bytes())},
+            {"alias": "ops", "public_key_base64": encode(1, &k1.public_key_bytes())}
+        ])
+        .to_string(),
+    )
+    .expect("write aliases");
+    std::fs::write(
+        dir.join("client.yaml"),
+        format!(
+            "---\nkeystore:\n  File: {}\nenvs:\n  - alias: testnet\n    rpc: \"https://fullnode.testnet.sui.io:443\"\n    ws: ~\n    basic_auth: ~\nactive_env: testnet\nactive_address: \"{}\"\n",
+            dir.join("sui.keystore").display(),
+            k1.address()
+        ),
+    )
+    .expect("write config");
+
+    let config = ClientConfig::load(dir.join("client.yaml")).expect("config");
+    assert_eq!(
+        config.rpc_url().expect("rpc"),
+        "https://fullnode.testnet.sui.io:443"
+    );
+    assert_eq!(
+        config.active_signer().expect("signer").address(),
+        k1.address()
+    );
+
+    let keystore = config.keystore().expect("keystore");
+    assert_eq!(keystore.addresses().len(), 2);
+    assert_eq!(keystore.alias(&ed.address()), Some("admiring-jade"));
+    assert_eq!(keystore.signer("ops").expect("ops").address(), k1.address());
+    let signatures: Vec<_> = keystore
+        .signers()
+        .map(|(_, signer)| signer.sign_transaction(b"tx").expect("sign"))
+        .collect();
+    assert_eq!(signatures.len(), 2);
+
+    std::fs::remove_dir_all(dir).expect("cleanup");
+}
+
+#[test]
+fn keystore_saves_in_cli_format() {
+    let dir = temp_dir("save");
+    let path = dir.join("sui_config").join("sui.keystore");
+    let mut keystore = FileKeystore::load(&path).expect("missing keystore is empty");
+    let r1 = Keypair::generate(SignatureScheme::Secp256r1);
+    let address = keystore
+        .add(r1, Some("passkey-test".to_string()))
+        .expect("add");
+    keystore.save().expect("save");
+
+    let keys: Vec<String> =
+        serde_json::from_str(&std::fs::read_to_string(&path).expect("read")).expect("json");
+    assert_eq!(keys.len(), 1);
+    let aliases: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(path.with_file_name("sui.aliases")).expect("read aliases"),
+    )
+    .expect("json");
+    assert_eq!(aliases[0]["alias"], "passkey-test");
+    assert!(aliases[0]["public_key_base64"].is_string());
+    let restored = FileKeystore::load(&path).expect("reload");
+    assert_eq!(restored.resolve("passkey-test").expect("alias"), address);
+
+    let mut config = ClientConfig {
+        keystore: KeystoreLocation::File(path),
+        envs: vec![],
+        active_env: None,
+        active_address: Some(address.clone()),
+        extra: Default::default(),
+    };
+    config
+        .set_active_env("localnet", Some("http://127.0.0.1:9000"))
+        .expect("env");
+    config.save(dir.join("client.yaml")).expect("save config");
+    let reloaded = ClientConfig::load(dir.join("client.yaml")).expect("reload config");
+    assert_eq!(reloaded.active_signer().expect("signer").address(), address);
+    assert_eq!(reloaded.rpc_url().expect("rpc"), "http://127.0.0.1:9000");
+
+    std::fs::remove_dir_all(dir).expect("cleanup");
+}
+
+#[test]
+fn encrypted_keystore_round_trip() {
+    let dir = temp_dir("encrypted");
+    let path = dir.join("hot-wallet.json");
+    let light_scrypt = KdfParams::Scrypt {
+        log_n: 4,
+        r: 8,
+        p: 1,
+    };
+    let mut keystore = EncryptedKeystore::create(&path, "first", light_scrypt).expect("create");
+    let mut addresses = Vec::new();
+    for scheme in [
+        SignatureScheme::Ed25519,
+        SignatureScheme::Secp256k1,
+        SignatureScheme::Secp256r1,
+    ] {
+        let keypair = Keypair::generate(scheme);
+        addresses.push(keypair.address());
+        keystore.add(&keypair, None).expect("add");
+    }
+    keystore
+        .add(
+            &Keypair::generate(SignatureScheme::Ed25519),
+            Some("hot".to_string()),
+        )
+        .expect("add hot");
+    keystore.save().expect("save");
+    assert!(matches!(
+        EncryptedKeystore::create(&path, "other", light_scrypt),
+        Err(KeystoreError::AlreadyExists(_))
+    ));
+
+    let contents = std::fs::read_to_string(&path).expect("read");
+    let value: serde_json::Value = serde_json::from_str(&contents).expect("json");
+    assert_eq!(value["version"], 1);
+    assert_eq!(value["kdf"]["name"], "scrypt");
+    let keypair = keystore.signer(&addresses[1]).expect("signer");
+    assert!(!contents.contains(&keypair.to_sui_private_key()));
+
+    assert!(matches!(
+        EncryptedKeystore::unlock(&path, "wrong"),
+        Err(KeystoreError::WrongPassword)
+    ));
+    let mut keystore = EncryptedKeystore::unlock(&path, "first").expect("unlock");
+    let entries = keystore.list().expect("list");
+    assert_eq!(entries.len(), 4);
+    assert_eq!(entries[1].scheme, SignatureScheme::Secp256k1);
+    assert_eq!(entries[1].alias.as_deref(), Some("key-1"));
+    assert_eq!(entries[3].alias.as_deref(), Some("hot"));
+    assert_eq!(
+        keystore.signer(&addresses[2]).expect("signer").scheme(),
+        SignatureScheme::Secp256r1
+    );
+
+    let light_argon2 = KdfParams::Argon2id {
+        m_cost: 64,
+        t_cost: 1,
+        p_cost: 1,
+    };
+    keystore
+        .change_password("second", Some(light_argon2))
+        .expect("change password");
+    assert!(matches!(
+        EncryptedKeystore::unlock(&path, "first"),
+        Err(KeystoreError::WrongPassword)
+    ));
+    let mut keystore = EncryptedKeystore::unlock(&path, "second").expect("unlock");
+    assert_eq!(keystore.kdf(), light_argon2);
+    for address in &addresses {
+        let signer = keystore.signer(address).expect("signer");
+        assert_eq!(&signer.address(), address);
+        signer.sign_transaction(b"tx").expect("sign");
+    }
+    assert_eq!(
+        keystore.list().expect("list")[3].alias.as_deref(),
+        Some("hot")
+    );
+
+    keystore.remove("hot").expect("remove");
+    keystore.save().expect("save");
+    let keystore = EncryptedKeystore::unlock(&path, "second").expect("unlock");
+    assert_eq!(keystore.list().expect("list").len(), 3);
+    assert!(matches!(
+        keystore.signer("hot"),
+        Err(KeystoreError::UnknownKey(_))
+    ));
+
+    std::fs::write(&path, contents.replace("\"version\": 1", "\"version\": 2")).expect("write");
+    assert!(matches!(
+        EncryptedKeystore::unlock(&path, "first"),
+        Err(KeystoreError::UnsupportedVersion(2))
+    ));
+    std::fs::remove_dir_all(dir).expect("cleanup");
+}
@@ -0,0 +1,152 @@
+use sui::crypto::SignatureScheme;
+use sui::cryptography::{Keypair, KeypairError, MnemonicError, Signer};
+use sui::cryptography::{mnemonics, private_key};
+use sui::keypairs::{ed25519, secp256k1, secp256r1};
+
+// Vectors shared with the TypeScript SDK's keypair tests.
+const PHRASE: &str = "film crazy soon outside stand loop subway crumble thrive popular green nuclear struggle pistol arm wife phrase warfare march wheat nephew ask sunny firm";
+const R1_PHRASE: &str =
+    "act wing dilemma glory episode region allow mad tourist humble muffin oblige";
+
+// More TypeScript SDK vectors: (phrase, address), and for secp256r1
+// (phrase, private key).
+const ED25519_MNEMONICS: &[(&str, &str)] = &[
+    (
+        "organ crash swim stick traffic remember army arctic mesh slice swear summer police vast chaos cradle squirrel hood useless evidence pet hub soap lake",
+        "0xe69e896ca10f5a77732769803cc2b5707f0ab9d4407afb5e4b4464b89769af14",
+    ),
+    (
+        "require decline left thought grid priority false tiny gasp angle royal system attack beef setup reward aunt skill wasp tray vital bounce inflict level",
+        "0x1ada6e6f3f3e4055096f606c746690f1108fcc2ca479055cc434a3e1d3f758aa",
+    ),
+];
+const SECP256K1_MNEMONICS: &[(&str, &str)] = &[
+    (
+        "film crazy soon outside stand loop subway crumble thrive popular green nuclear struggle pistol arm wife phrase warfare march wheat nephew ask sunny firm",
+        "0x9e8f732575cc5386f8df3c784cd3ed1b53ce538da79926b2ad54dcc1197d2532",
+    ),
+    (
+        "require decline left thought grid priority false tiny gasp angle royal system attack beef setup reward aunt skill wasp tray vital bounce inflict level",
+        "0x9fd5a804ed6b46d36949ff7434247f0fd594673973ece24aede6b86a7b5dae01",
+    ),
+    (
+        "organ crash swim stick traffic remember army arctic mesh slice swear summer police vast chaos cradle squirrel hood useless evidence pet hub soap lake",
+        "0x60287d7c38dee783c2ab1077216124011774be6b0764d62bd05f32c88979d5c5",
+    ),
+];
+const SECP256R1_MNEMONICS: &[(&str, &str)] = &[
+    (
+        "act wing dilemma glory episode region allow mad tourist humble muffin oblige",
+        "AiWmZXUcFpUF75H082F2RVJAABS5kcrvb8o09IPH9yUw",
+    ),
+    (
+        "flag rebel cabbage captain minimum purpose long already valley horn enrich salt",
+        "AjaB6aLp4fQabx4NglfGz2Bf01TGKArV80NEOnqDwqNN",
+    ),
+    (
+        "area renew bar language pudding trial small host remind supreme cabbage era",
+        "AtSIEzVpJv+bJH3XptEq63vsuK+te1KRSY7JsiuJfcdK",
+    ),
+];
+
+fn base64(bytes: &[u8]) -> String {
+    use base64::Engine as _;
+    base64::engine::general_purpose::STANDARD.encode(bytes)
+}
+
+#[test]
+fn mnemonic_derives_wallet_addresses() {
+    let ed = ed25519::Keypair::derive_keypair(PHRASE, None).expect("ed25519");
+    assert_eq!(
+        base64(&ed.public_key_bytes()),
+        "ImR/7u82MGC9QgWhZxoV8QoSNnZZGLG19jjYLzPPxGk="
+    );
+    assert_eq!(
+        ed.to_sui_address(),
+        "0xa2d14fad60c56049ecf75246a481934691214ce413e6a8ae2fe6834c173a6133"
+    );
+
+    let k1 = secp256k1::Keypair::derive_keypair(PHRASE, None).expect("secp256k1");
+    assert_eq!(
+        private_key::encode_base64_private_key(SignatureScheme::Secp256k1, &k1.secret_key_bytes())
+            .unwrap(),
+        "AQA9EYZoLXirIahsXHQMDfdi5DPQ72wLA79zke4EY6CP"
+    );
+    assert_eq!(
+        k1.to_sui_address(),
+        "0x9e8f732575cc5386f8df3c784cd3ed1b53ce538da79926b2ad54dcc1197d2532"
+    );
+
+    let r1 = secp256r1::Keypair::derive_keypair(R1_PHRASE, None).expect("secp256r1");
+    assert_eq!(
+        private_key::encode_base64_private_key(SignatureScheme::Secp256r1, &r1.secret_key_bytes())
+            .unwrap(),
+        "AiWmZXUcFpUF75H082F2RVJAABS5kcrvb8o09IPH9yUw"
+    );
+
+    let any = Keypair::derive_keypair(SignatureScheme::Secp256k1, PHRASE, None).expect("any");
+    assert_eq!(any.address(), k1.to_sui_address());
+}
+
+#[test]
+fn mnemonics_match_typescript_vectors() {
+    for (phrase, address) in ED25519_MNEMONICS {
+        let keypair = ed25519::Keypair::derive_keypair(phrase, None).expect("ed25519");
+        assert_eq!(keypair.to_sui_address(), *address);
+    }
+    for (phrase, address) in SECP256K1_MNEMONICS {
+        let keypair = secp256k1::Keypair::derive_keypair(phrase, None).expect("secp256k1");
+        assert_eq!(keypair.to_sui_address(), *address);
+    }
+    for (phrase, private_key) in SECP256R1_MNEMONICS {
+        let keypair = secp256r1::Keypair::derive_keypair(phrase, None).expect("secp256r1");
+        assert_eq!(
+            private_key::encode_base64_private_key(
+                SignatureScheme::Secp256r1,
+                &keypair.secret_key_bytes()
+            )
+            .unwrap(),
+            *private_key
+        );
+    }
+}
+
+#[test]
+fn mnemonic_derives_other_accounts() {
+    let first = ed25519::Keypair::derive_keypair(PHRASE, None).expect("first");
+    let second =
+        ed25519::Keypair::derive_keypair(PHRASE, Some("m/44'/784'/1'/0'/0'")).expect("second");
+    assert_ne!(first.to_sui_address(), second.to_sui_address());
+
+    let seed = mnemonics::mnemonic_to_seed(PHRASE, "").expect("seed");
+    let from_seed = ed25519::Keypair::derive_keypair_from_seed(&seed, Some("m/44'/784'/1'/0'/0'"))
+        .expect("from seed");
+    assert_eq!(from_seed.to_sui_address(), second.to_sui_address());
+}
+
+#[test]
+fn mnemonic_rejects_paths_of_other_schemes() {
+    let err = ed25519::Keypair::derive_keypair(
+        PHRASE,
+        Some(mnemonics::DEFAULT_SECP256K1_DERIVATION_PATH),
+    )
+    .err()
+    .expect("bip32 path for ed25519");
+    assert!(matches!(
+        err,
+        ed25519::KeypairError::Mnemonic(MnemonicError::InvalidPath(_))
+    ));
+    assert!(
+        secp256r1::Keypair::derive_keypair(
+            PHRASE,
+            Some(mnemonics::DEFAULT_SECP256K1_DERIVATION_PATH)
+        )
+        .is_err()
+    );
+    assert!(matches!(
+        Keypair::derive_keypair(SignatureScheme::Secp256k1, "not a mnemonic", None),
+        Err(KeypairError::Secp256k1(secp256k1::KeypairError::Mnemonic(
+            MnemonicError::Mnemonic(_)
+        )))
+    ));
+}
@@ -1,58 +1,180 @@
 use base64::Engine as _;
-use sui::crypto::SignatureScheme;
-use sui::keypairs::ed25519;
+use serde_json::json;
+use sui::cryptography::{PublicKey, Signer};
+use sui::keypairs::passkey::{PasskeySigner, SoftwareAuthenticator};
+use sui::keypairs::{ed25519, secp256k1, secp256r1};
 use sui::multisig::{
-    parse_multisig, serialize_multisig, MultiSigEntry, MultiSigPublicKey, MultiSigSerialized,
-    WeightedPublicKey,
+    parse_multisig, serialize_multisig, CompressedSignature, MultiSigError, MultiSigPublicKey,
+    MultiSigSigner, WeightedPublicKey,
 };
+use sui::zklogin::{self, ZkLoginPublicIdentifier, ZkLoginSigner};
+
+fn zklogin_signer() -> ZkLoginSigner {
+    let inputs: zklogin::ZkLoginInputs = serde_json::from_value(json!({
+        "proofPoints": {
+            "a": ["1", "2", "1"],
+            "b": [["3", "4"], ["5", "6"], ["1", "0"]],
+            "c": ["7", "8", "1"]
+        },
+        "issBase64Details": {"value": "yJpc3MiOiJodHRwczovL2lzc3VlciIs", "indexMod4": 1},
+        "headerBase64": "eyJhbGciOiJSUzI1NiJ9",
+        "addressSeed": "1234567890"
+    }))
+    .expect("prover inputs");
+    let ephemeral = ed25519::Keypair::from_secret_key(&[9; 32]).expect("ephemeral");
+    ZkLoginSigner::new("https://issuer", inputs, 10, Box::new(ephemeral)).expect("zklogin signer")
+}
+
+struct Treasury {
+    ed: ed25519::Keypair,
+    k1: secp256k1::Keypair,
+    r1: secp256r1::Keypair,
+    passkey: PasskeySigner,
+    zklogin: ZkLoginSigner,
+    public_key: MultiSigPublicKey,
+}
+
+/// A 3-of-5 multisig with a member of every kind.
+fn treasury() -> Treasury {
+    let ed = ed25519::Keypair::from_secret_key(&[1; 32]).expect("ed25519");
+    let k1 = secp256k1::Keypair::from_secret_key(&[2; 32]).expect("secp256k1");
+    let r1 = secp256r1::Keypair::from_secret_key(&[3; 32]).expect("secp256r1");
+    let authenticator = SoftwareAuthenticator::from_secret_key(&[4; 32], "sui.io", "https://sui.io")
+        .expect("authenticator");
+    let passkey = PasskeySigner::new(authenticator.public_key(), Box::new(authenticator));
+    let zklogin = zklogin_signer();
+    let public_key = MultiSigPublicKey::new(
+        [
+            ed.public_key(),
+            k1.public_key(),
+            r1.public_key(),
+            passkey.public_key(),
+            zklogin.public_key(),
+        ]
+        .into_iter()
+        .map(|public_key| WeightedPublicKey::new(public_key, 1))
+        .collect(),
+        3,
+    )
+    .expect("multisig");
+    Treasury {
+        ed,
+        k1,
+        r1,
+        passkey,
+        zklogin,
+        public_key,
+    }
+}
+
+fn decode(signature: &str) -> Vec<u8> {
+    base64::engine::general_purpose::STANDARD
+        .decode(signature)
+        .expect("base64")
+}
 
 #[test]
-fn multisig_serialize_parse_and_verify() {
-    let k1 = ed25519::Keypair::generate();
-    let k2 = ed25519::Keypair::generate();
-
-    let pk1 = base64::engine::general_purpose::STANDARD.encode(k1.public_key_bytes());
-    let pk2 = base64::engine::general_purpose::STANDARD.encode(k2.public_key_bytes());
-
-    let ms = MultiSigPublicKey {
-        public_keys: vec![
-            WeightedPublicKey {
-                scheme: SignatureScheme::Ed25519,
-                public_key: pk1.clone(),
-                weight: 1,
-            },
-            WeightedPublicKey {
-                scheme: SignatureScheme::Ed25519,
-                public_key: pk2.clone(),
-                weight: 1,
-            },
-        ],
-        threshold: 2,
-    };
-
-    let msg = b"hello-multisig";
-    let sig1 = base64::engine::general_purpose::STANDARD.encode(k1.sign(msg));
-    let sig2 = base64::engine::general_purpose::STANDARD.encode(k2.sign(msg));
-
-    let serialized = serialize_multisig(&MultiSigSerialized {
-        signatures: vec![
-            MultiSigEntry {
-                public_key: pk1,
-                signature: sig1,
-            },
-            MultiSigEntry {
-                public_key: pk2,
-                signature: sig2,
-            },
+fn multisig_combines_partial_signatures_in_any_order() {
+    let treasury = treasury();
+    let tx = b"tx-bytes";
+    let partials = vec![
+        treasury.passkey.sign_transaction(tx).expect("passkey"),
+        treasury.k1.sign_transaction(tx).expect("k1"),
+        treasury.ed.sign_transaction(tx).expect("ed"),
+    ];
+
+    let multisig = treasury
+        .public_key
+        .combine_partial_signatures(&partials)
+        .expect("combine");
+    assert_eq!(multisig.bitmap(), 0b1011);
+    assert_eq!(multisig.signer_indices(), vec![0, 1, 3]);
+    assert!(matches!(multisig.signatures()[0], CompressedSignature::Ed25519(_)));
+    assert!(matches!(multisig.signatures()[2], CompressedSignature::Passkey(_)));
+
+    let serialized = serialize_multisig(&multisig);
+    let bytes = decode(&serialized);
+    assert_eq!(bytes[0], 0x03);
+    assert_eq!(parse_multisig(&serialized).expect("parse"), multisig);
+    assert!(treasury.public_key.verify_transaction(tx, &bytes).expect("verify"));
+    assert!(!treasury.public_key.verify_transaction(b"other", &bytes).expect("verify other"));
+
+    let below_threshold = treasury
+        .public_key
+        .combine_partial_signatures(&partials[1..])
+        .expect("combine two");
+    assert!(!treasury
+        .public_key
+        .verify_transaction(tx, &below_threshold.to_bytes())
+        .expect("verify two"));
+
+    let outsider = ed25519::Keypair::generate().sign_transaction(tx).expect("outsider");
+    assert!(treasury
+        .public_key
+        .combine_partial_signatures(&[partials[0].clone(), outsider])
+        .is_err());
+    assert!(treasury
+        .public_key
+        .combine_partial_signatures(&[partials[1].clone(), partials[1].clone()])
+        .is_err());
+}
+
+#[test]
+fn multisig_signer_matches_address_and_verifies() {
+    let treasury = treasury();
+    let public_key = treasury.public_key.clone();
+    let address = public_key.to_sui_address();
+    assert_eq!(
+        PublicKey::MultiSig(public_key.clone()).to_sui_address(),
+        address
+    );
+    assert_eq!(
+        MultiSigPublicKey::from_raw_bytes(&public_key.to_raw_bytes()).expect("raw bytes"),
+        public_key
+    );
+
+    let signer = MultiSigSigner::new(
+        public_key.clone(),
+        vec![
+            Box::new(treasury.r1),
+            Box::new(treasury.passkey),
+            Box::new(treasury.ed),
         ],
-        bitmap: vec![0, 1],
-        threshold: 2,
-    })
-    .expect("serialize");
+    )
+    .expect("signer");
+    assert_eq!(signer.address(), address);
+    let signature = decode(&signer.sign_personal_message(b"hello").expect("sign"));
+    assert!(public_key.verify_personal_message(b"hello", &signature).expect("verify"));
 
-    let parsed = parse_multisig(&serialized).expect("parse");
-    assert_eq!(parsed.signatures.len(), 2);
+    let other = MultiSigPublicKey::new(public_key.public_keys().to_vec(), 2).expect("2-of-5");
+    assert_ne!(other.to_sui_address(), address);
+    assert!(!other.verify_personal_message(b"hello", &signature).expect("verify other"));
 
-    let ok = ms.verify(msg, &serialized).expect("verify multisig");
-    assert!(ok);
+    assert!(MultiSigSigner::new(public_key, vec![Box::new(treasury.k1)]).is_err());
+}
+
+#[test]
+fn multisig_accepts_zklogin_members() {
+    let treasury = treasury();
+    let tx = b"tx-bytes";
+    let multisig = treasury
+        .public_key
+        .combine_partial_signatures(&[
+            treasury.zklogin.sign_transaction(tx).expect("zklogin"),
+            treasury.ed.sign_transaction(tx).expect("ed"),
+            treasury.r1.sign_transaction(tx).expect("r1"),
+        ])
+        .expect("combine");
+    assert_eq!(multisig.signer_indices(), vec![0, 2, 4]);
+    assert_eq!(
+        treasury.public_key.public_keys()[4].public_key,
+        PublicKey::ZkLogin(ZkLoginPublicIdentifier::new(
+            "https://issuer",
+            zklogin::parse_address_seed("1234567890").expect("seed")
+        ))
+    );
+    assert!(matches!(
+        treasury.public_key.verify_transaction(tx, &multisig.to_bytes()),
+        Err(MultiSigError::ZkLoginVerification)
+    ));
 }
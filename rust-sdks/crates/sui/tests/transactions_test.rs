@@ -3,7 +3,20 @@ use httpmock::MockServer;
 use serde_json::json;
 use sui::jsonrpc::Client as JsonRpcClient;
 use sui::keypairs::ed25519::Keypair;
-use sui::transactions::Transaction;
+use sui::transactions::plugins::NamedPackagesPlugin;
+use sui::transactions::{
+    Argument, CallArg, GasConfig, GasError, LimitError, ObjectRef, ReceivingRef, SharedObjectRef,
+    Transaction, TransactionBuilder, TransactionError, TransactionExpiration, TransactionLimits,
+};
+use sui::transactions::{PureValue, bcs_types};
+
+fn gas_coin() -> ObjectRef {
+    ObjectRef {
+        object_id: "0x5".to_string(),
+        digest: "11111111111111111111111111111111".to_string(),
+        version: 7,
+    }
+}
 
 #[test]
 fn transaction_build_and_sign() {
@@ -11,6 +24,7 @@ fn transaction_build_and_sign() {
     tx.set_sender("0x1");
     tx.set_gas_budget(1_000_000);
     tx.set_gas_price(1000);
+    tx.set_gas_payment(vec![gas_coin()]);
 
     let amount = tx.pure_bytes(&100u64.to_le_bytes());
     let split = tx.split_coins(Transaction::gas(), vec![amount]);
@@ -20,15 +34,274 @@ fn transaction_build_and_sign() {
     let bytes = tx.build().expect("build");
     assert!(!bytes.is_empty());
 
+    let decoded =
+        sui::transactions::bcs_types::TransactionData::from_bytes(&bytes).expect("decode");
+    let v1 = decoded.v1();
+    assert_eq!(v1.sender.to_string(), format!("0x{:0>64}", "1"));
+    assert_eq!(v1.gas_data.owner, v1.sender);
+    assert_eq!(v1.gas_data.budget, 1_000_000);
+    assert_eq!(v1.gas_data.price, 1000);
+    assert_eq!(v1.gas_data.payment[0].1, 7);
+
     let b64 = tx.build_base64().expect("b64");
     assert!(!b64.is_empty());
 
     let kp = Keypair::generate();
     let signed = tx.sign_with_ed25519(&kp).expect("sign");
     assert_eq!(signed.signatures.len(), 1);
+    assert_eq!(signed.tx_bytes_base64, b64);
     assert!(!signed.tx_bytes_base64.is_empty());
 }
 
+#[test]
+fn transaction_roundtrips_through_bytes() {
+    let mut tx = Transaction::new();
+    tx.set_sender("0x1");
+    tx.set_gas_budget(1_000_000);
+    tx.set_gas_price(1000);
+    tx.set_gas_payment(vec![gas_coin()]);
+    let amount = tx.pure_bytes(&100u64.to_le_bytes());
+    let coins = tx.split_coins(Transaction::gas(), vec![amount]);
+    let recipient = tx.pure_bytes(&[0x22; 32]);
+    tx.transfer_objects(vec![coins], recipient);
+
+    let bytes = tx.build().expect("build");
+    let decoded = Transaction::from_bytes(&bytes).expect("from bytes");
+    assert_eq!(decoded.build().expect("rebuild"), bytes);
+    assert_eq!(decoded.data.commands, tx.data.commands);
+
+    let from_b64 = Transaction::from_base64(&tx.build_base64().expect("b64")).expect("from b64");
+    assert_eq!(
+        from_b64.digest().expect("digest"),
+        tx.digest().expect("digest")
+    );
+
+    let rendered = decoded.to_string();
+    assert!(rendered.contains(&format!("Sender: 0x{:0>64}", "1")));
+    assert!(rendered.contains("price 1000, budget 1000000"));
+    assert!(rendered.contains("  Input(0): Pure u64 100"));
+    assert!(rendered.contains("  Result(1): TransferObjects([Result(0)], Input(1))"));
+    assert!(!rendered.contains("Expiration"));
+
+    assert!(Transaction::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    assert!(Transaction::from_base64("not base64!").is_err());
+}
+
+#[test]
+fn transaction_expiration_is_typed() {
+    let mut tx = Transaction::new();
+    tx.set_sender("0x1");
+    tx.set_gas_budget(1_000_000);
+    tx.set_gas_price(1000);
+    tx.set_gas_payment(vec![gas_coin()]);
+    tx.set_expiration(TransactionExpiration::Epoch(42));
+
+    let decoded = Transaction::from_bytes(&tx.build().expect("build")).expect("from bytes");
+    assert_eq!(
+        decoded.data.expiration,
+        Some(TransactionExpiration::Epoch(42))
+    );
+    assert!(decoded.to_string().contains("Expiration: epoch 42\n"));
+
+    let json = serde_json::to_value(&tx.data).expect("to json");
+    assert_eq!(json["expiration"], json!({"$kind": "Epoch", "Epoch": "42"}));
+
+    let chain = sui::transactions::hash::encode_digest([3u8; 32]);
+    let valid_during = json!({
+        "$kind": "ValidDuring",
+        "ValidDuring": {
+            "minEpoch": "10",
+            "maxEpoch": 11,
+            "minTimestamp": null,
+            "maxTimestamp": null,
+            "chain": chain,
+            "nonce": 9,
+        },
+    });
+    let expiration: TransactionExpiration =
+        serde_json::from_value(valid_during).expect("valid during");
+    assert_eq!(
+        expiration,
+        TransactionExpiration::ValidDuring {
+            min_epoch: Some(10),
+            max_epoch: Some(11),
+            min_timestamp: None,
+            max_timestamp: None,
+            chain: chain.clone(),
+            nonce: 9,
+        }
+    );
+    tx.set_expiration(expiration.clone());
+    let decoded = Transaction::from_bytes(&tx.build().expect("build")).expect("from bytes");
+    assert_eq!(decoded.data.expiration, Some(expiration));
+
+    tx.set_expiration(TransactionExpiration::ValidDuring {
+        min_epoch: None,
+        max_epoch: None,
+        min_timestamp: None,
+        max_timestamp: None,
+        chain: "not a digest".to_string(),
+        nonce: 0,
+    });
+    assert!(matches!(
+        tx.build(),
+        Err(TransactionError::InvalidDigest(_))
+    ));
+    assert!(serde_json::from_value::<TransactionExpiration>(json!({"$kind": "Later"})).is_err());
+}
+
+#[test]
+fn transaction_builds_kind_bytes_without_gas() {
+    use base64::Engine as _;
+    let mut tx = Transaction::new();
+    let amount = tx.pure_bytes(&100u64.to_le_bytes());
+    let coins = tx.split_coins(Transaction::gas(), vec![amount]);
+    let recipient = tx.pure_bytes(&[0x22; 32]);
+    tx.transfer_objects(vec![coins], recipient);
+
+    let kind = tx.build_kind().expect("build kind");
+    assert_eq!(kind[0], 0x00);
+    assert!(matches!(tx.build(), Err(TransactionError::MissingSender)));
+
+    let decoded = Transaction::from_kind(&kind).expect("from kind");
+    assert_eq!(decoded.data.commands, tx.data.commands);
+    assert_eq!(decoded.data.inputs, tx.data.inputs);
+    assert!(decoded.data.sender.is_none());
+
+    tx.set_sender("0x1");
+    tx.set_gas_budget(1_000_000);
+    tx.set_gas_price(1000);
+    tx.set_gas_payment(vec![gas_coin()]);
+    let full = tx.build().expect("build");
+    assert_eq!(&full[1..1 + kind.len()], kind.as_slice());
+    assert_eq!(
+        tx.build_kind_base64().expect("kind b64"),
+        base64::engine::general_purpose::STANDARD.encode(&kind)
+    );
+}
+
+#[test]
+fn transaction_json_matches_typescript_format() {
+    let object = "0x0000000000000000000000000000000000000000000000000000000000000006";
+    let serialized = json!({
+        "version": 2,
+        "sender": "0x0000000000000000000000000000000000000000000000000000000000000001",
+        "expiration": {"$kind": "None", "None": true},
+        "gasData": {"budget": null, "price": null, "owner": null, "payment": null},
+        "inputs": [
+            {"$kind": "UnresolvedObject", "UnresolvedObject": {"objectId": object}},
+            {"$kind": "Pure", "Pure": {"bytes": "ZAAAAAAAAAA="}}
+        ],
+        "commands": [
+            {"$kind": "$Intent", "$Intent": {
+                "name": "CoinWithBalance",
+                "inputs": {},
+                "data": {"type": "gas", "balance": "100"}
+            }},
+            {"$kind": "MoveCall", "MoveCall": {
+                "package": "0x0000000000000000000000000000000000000000000000000000000000000002",
+                "module": "coin",
+                "function": "join",
+                "typeArguments": [],
+                "arguments": [{"$kind": "Input", "Input": 0}, {"$kind": "Result", "Result": 0}]
+            }}
+        ]
+    });
+
+    let tx = Transaction::from_json(&serialized.to_string()).expect("from json");
+    assert_eq!(tx.data.inputs[0].object_id(), Some(object));
+    assert!(
+        tx.build_kind().is_err(),
+        "inputs and intents are still unresolved"
+    );
+
+    let round_trip: serde_json::Value =
+        serde_json::from_str(&tx.to_json().expect("to json")).expect("json");
+    assert_eq!(round_trip, serialized);
+}
+
+#[test]
+fn transaction_digest_matches_signed_transaction() {
+    let mut tx = Transaction::new();
+    tx.set_sender("0x1");
+    tx.set_gas_budget(1_000_000);
+    tx.set_gas_price(1000);
+    tx.set_gas_payment(vec![gas_coin()]);
+    let amount = tx.pure_bytes(&100u64.to_le_bytes());
+    tx.split_coins(Transaction::gas(), vec![amount]);
+
+    let bytes = tx.build().expect("build");
+    let digest = tx.digest().expect("digest");
+    assert_eq!(
+        digest,
+        sui::transactions::hash::encode_digest(sui::transactions::hash::transaction_digest(&bytes))
+    );
+    assert!(
+        digest
+            .parse::<sui::transactions::bcs_types::Digest>()
+            .is_ok()
+    );
+
+    let signed = tx.sign_with_ed25519(&Keypair::generate()).expect("sign");
+    assert_eq!(signed.digest().expect("signed digest"), digest);
+    assert_eq!(
+        signed
+            .transaction()
+            .expect("transaction")
+            .build()
+            .expect("build"),
+        bytes
+    );
+}
+
+#[tokio::test]
+async fn transaction_sign_accepts_any_signer() {
+    use sui::crypto::SignatureScheme;
+    use sui::cryptography::{Keypair as AnyKeypair, Signer};
+
+    let mut tx = Transaction::new();
+    tx.set_gas_budget(1_000_000);
+    tx.set_gas_price(1000);
+    tx.set_gas_payment(vec![gas_coin()]);
+    let amount = tx.pure_bytes(&100u64.to_le_bytes());
+    tx.split_coins(Transaction::gas(), vec![amount]);
+
+    for scheme in [
+        SignatureScheme::Ed25519,
+        SignatureScheme::Secp256k1,
+        SignatureScheme::Secp256r1,
+    ] {
+        let keypair = AnyKeypair::generate(scheme);
+        tx.set_sender(keypair.address());
+        let signed = tx.sign(&keypair).expect("sign");
+        assert_eq!(
+            signed.signatures[0],
+            keypair
+                .sign_transaction(&tx.build().expect("build"))
+                .expect("sign bytes")
+        );
+
+        let signer: &dyn sui::cryptography::AsyncSigner = &keypair;
+        let signed_async = tx.sign_async(signer).await.expect("sign async");
+        assert_eq!(signed_async.tx_bytes_base64, signed.tx_bytes_base64);
+
+        use base64::Engine as _;
+        let signature = base64::engine::general_purpose::STANDARD
+            .decode(&signed.signatures[0])
+            .expect("base64");
+        assert_eq!(signature[0], scheme.flag());
+        assert!(
+            sui::verify::verify_transaction(
+                scheme,
+                &signature[65..],
+                &tx.build().expect("build"),
+                &signature[1..65],
+            )
+            .expect("verify")
+        );
+    }
+}
+
 #[tokio::test]
 async fn signed_transaction_execute_calls_jsonrpc() {
     let server = MockServer::start();
@@ -44,6 +317,8 @@ async fn signed_transaction_execute_calls_jsonrpc() {
     let mut tx = Transaction::new();
     tx.set_sender("0x1");
     tx.set_gas_budget(1);
+    tx.set_gas_price(1);
+    tx.set_gas_payment(vec![gas_coin()]);
     let amount = tx.pure_bytes(&1u64.to_le_bytes());
     tx.split_coins(Transaction::gas(), vec![amount]);
 
@@ -62,3 +337,562 @@ async fn signed_transaction_execute_calls_jsonrpc() {
 
     assert_eq!(out["digest"], "abc");
 }
+
+#[tokio::test]
+async fn sponsored_transaction_collects_both_signatures() {
+    let user = Keypair::generate();
+    let sponsor = Keypair::generate();
+    let user_address = user.to_sui_address();
+    let sponsor_address = sponsor.to_sui_address();
+
+    let mut tx = Transaction::new();
+    let amount = tx.pure_bytes(&100u64.to_le_bytes());
+    let coins = tx.split_coins(Transaction::gas(), vec![amount]);
+    let recipient = tx.pure_bytes(&[0x22; 32]);
+    tx.transfer_objects(vec![coins], recipient);
+    let kind = tx.build_kind().expect("build kind");
+
+    let mut sponsored =
+        Transaction::sponsored(&kind, &user_address, &sponsor_address).expect("sponsored");
+    sponsored.set_gas_budget(1_000_000);
+    sponsored.set_gas_price(1000);
+    sponsored.set_gas_payment(vec![gas_coin()]);
+    let sponsor_signed = sponsored.sign_with_ed25519(&sponsor).expect("sponsor sign");
+
+    let received = sponsor_signed.transaction().expect("transaction");
+    received
+        .check_sponsored(&kind, &user_address)
+        .expect("check sponsored");
+    let mut signed = received.sign_with_ed25519(&user).expect("user sign");
+    signed
+        .add_signatures(sponsor_signed.clone())
+        .expect("add sponsor signature");
+    assert_eq!(signed.signatures.len(), 2);
+    assert_eq!(signed.signatures[1], sponsor_signed.signatures[0]);
+
+    let server = MockServer::start();
+    let execute = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"sui_executeTransactionBlock\"")
+            .body_contains(sponsor_signed.signatures[0].as_str())
+            .body_contains(signed.signatures[0].as_str());
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"jsonrpc":"2.0","id":1,"result":{"digest":"abc"}}"#);
+    });
+    let rpc = JsonRpcClient::new(server.url("/"), "testnet");
+    let out = signed.execute(&rpc, None, None).await.expect("execute");
+    assert_eq!(out["digest"], "abc");
+    execute.assert_hits(1);
+}
+
+#[test]
+fn transaction_build_requires_sender_and_gas() {
+    let mut tx = Transaction::new();
+    assert!(matches!(tx.build(), Err(TransactionError::MissingSender)));
+
+    tx.set_sender("0x1");
+    assert!(matches!(
+        tx.build(),
+        Err(TransactionError::MissingGasBudget)
+    ));
+
+    tx.set_gas_budget(1_000_000);
+    assert!(matches!(tx.build(), Err(TransactionError::MissingGasPrice)));
+
+    tx.set_gas_price(1000);
+    assert!(matches!(
+        tx.build(),
+        Err(TransactionError::MissingGasPayment)
+    ));
+
+    tx.set_gas_payment(vec![gas_coin()]);
+    tx.object("0x6");
+    assert!(matches!(
+        tx.build(),
+        Err(TransactionError::UnresolvedObject(_))
+    ));
+}
+
+#[test]
+fn transaction_builder_returns_typed_arguments() {
+    let mut tx = Transaction::new();
+    let clock = tx.object("0x6");
+    let amount = tx.pure_bytes(&5u64.to_le_bytes());
+    assert_eq!(clock, Argument::Input(0));
+    assert_eq!(amount, Argument::Input(1));
+    assert_eq!(
+        tx.object("0x0000000000000000000000000000000000000000000000000000000000000006"),
+        clock
+    );
+
+    let coins = tx.split_coins(Transaction::gas(), vec![amount, amount]);
+    assert_eq!(coins, Argument::Result(0));
+    let second = coins.nested(1).expect("nested result");
+    let call = tx.move_call(
+        "0x2::coin::value",
+        vec![second],
+        vec!["0x2::sui::SUI".to_string()],
+    )
+    .expect("move call");
+    assert_eq!(call, Argument::Result(1));
+
+    let sui::transactions::CommandKind::MoveCall(move_call) = &tx.data.commands[1].kind else {
+        panic!("expected MoveCall");
+    };
+    assert_eq!(move_call.function, "value");
+    assert_eq!(move_call.arguments, vec![Argument::NestedResult(0, 1)]);
+}
+
+#[test]
+fn transaction_merges_repeated_object_inputs() {
+    let mut tx = Transaction::new();
+    let pool = |mutable| SharedObjectRef {
+        object_id: "0xaa".to_string(),
+        mutable,
+        initial_shared_version: 3,
+    };
+    let read = tx.shared_object_ref(pool(false));
+    assert_eq!(tx.shared_object_ref(pool(true)), read);
+    assert_eq!(tx.data.inputs.len(), 1);
+    assert_eq!(tx.data.inputs[0], CallArg::shared_object_ref(pool(true)));
+
+    let coin = tx.object("0x5");
+    assert_eq!(tx.object_ref(gas_coin()), coin);
+    assert_eq!(tx.data.inputs[1], CallArg::object_ref(gas_coin()));
+}
+
+#[tokio::test]
+async fn transaction_resolves_inputs_through_jsonrpc() {
+    let server = MockServer::start();
+    let functions = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"sui_getNormalizedMoveFunction\"");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!([{"jsonrpc": "2.0", "id": 1, "result": {
+                "visibility": "Public",
+                "isEntry": false,
+                "typeParameters": [],
+                "parameters": [
+                    {"Reference": {"Struct": {"address": "0x2", "module": "clock", "name": "Clock", "typeArguments": []}}},
+                    {"Struct": {"address": "0x2", "module": "coin", "name": "Coin", "typeArguments": []}},
+                    "U64"
+                ],
+                "return": []
+            }}]));
+    });
+    let objects = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"sui_multiGetObjects\"")
+            .body_contains("\"showOwner\":true");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({"jsonrpc": "2.0", "id": 2, "result": [
+                {"data": {
+                    "objectId": "0x0000000000000000000000000000000000000000000000000000000000000006",
+                    "version": "100",
+                    "digest": "11111111111111111111111111111111",
+                    "owner": {"Shared": {"initial_shared_version": 1}}
+                }},
+                {"data": {
+                    "objectId": "0x00000000000000000000000000000000000000000000000000000000000000bb",
+                    "version": "8",
+                    "digest": "11111111111111111111111111111111",
+                    "owner": {"AddressOwner": "0x0000000000000000000000000000000000000000000000000000000000000001"}
+                }}
+            ]}));
+    });
+
+    let mut tx = Transaction::new();
+    tx.set_sender("0x1");
+    tx.set_gas_budget(1_000_000);
+    tx.set_gas_price(1000);
+    tx.set_gas_payment(vec![gas_coin()]);
+    let clock = tx.object("0x6");
+    let coin = tx.object("0xbb");
+    let amount = tx.pure_value(5u64);
+    tx.move_call("0x2::coin::burn", vec![clock, coin, amount], vec![]).expect("move call");
+
+    let rpc = JsonRpcClient::new(server.url("/"), "testnet");
+    tx.resolve(&rpc).await.expect("resolve");
+    functions.assert_hits(1);
+    objects.assert_hits(1);
+
+    let bytes = tx.build().expect("build");
+    let decoded =
+        sui::transactions::bcs_types::TransactionData::from_bytes(&bytes).expect("decode");
+    let sui::transactions::bcs_types::TransactionKind::ProgrammableTransaction(ptb) =
+        &decoded.v1().kind;
+    assert_eq!(
+        ptb.inputs[0],
+        sui::transactions::bcs_types::CallArg::Object(
+            sui::transactions::bcs_types::ObjectArg::SharedObject {
+                id: "0x6".parse().unwrap(),
+                initial_shared_version: 1,
+                mutable: false,
+            }
+        )
+    );
+    assert!(matches!(
+        ptb.inputs[1],
+        sui::transactions::bcs_types::CallArg::Object(
+            sui::transactions::bcs_types::ObjectArg::ImmOrOwnedObject((_, 8, _))
+        )
+    ));
+    assert_eq!(
+        ptb.inputs[2],
+        sui::transactions::bcs_types::CallArg::Pure(5u64.to_le_bytes().to_vec())
+    );
+}
+
+#[tokio::test]
+async fn transaction_resolves_gas_through_jsonrpc() {
+    let server = MockServer::start();
+    let price = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"suix_getReferenceGasPrice\"");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"jsonrpc":"2.0","id":1,"result":"1000"}"#);
+    });
+    let dry_run = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"sui_dryRunTransactionBlock\"");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({"jsonrpc": "2.0", "id": 2, "result": {"effects": {
+                "status": {"status": "success"},
+                "gasUsed": {
+                    "computationCost": "1000000",
+                    "storageCost": "2000000",
+                    "storageRebate": "1000000",
+                    "nonRefundableStorageFee": "10000"
+                }
+            }}}));
+    });
+    let coins = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"suix_getCoins\"")
+            .body_contains("0x2::sui::SUI");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({"jsonrpc": "2.0", "id": 3, "result": {
+                "data": [{
+                    "coinType": "0x2::sui::SUI",
+                    "coinObjectId": "0x5",
+                    "version": "7",
+                    "digest": "11111111111111111111111111111111",
+                    "balance": "100000000"
+                }],
+                "nextCursor": null,
+                "hasNextPage": false
+            }}));
+    });
+
+    let mut tx = Transaction::new();
+    tx.set_sender("0x1");
+    let amount = tx.pure_bytes(&1u64.to_le_bytes());
+    tx.split_coins(Transaction::gas(), vec![amount]);
+
+    let rpc = JsonRpcClient::new(server.url("/"), "testnet");
+    tx.resolve_gas(&rpc, &GasConfig::default())
+        .await
+        .expect("resolve gas");
+    price.assert_hits(1);
+    dry_run.assert_hits(1);
+    coins.assert_hits(1);
+
+    let bytes = tx.build().expect("build");
+    let decoded =
+        sui::transactions::bcs_types::TransactionData::from_bytes(&bytes).expect("decode");
+    assert_eq!(decoded.v1().gas_data.price, 1000);
+    assert_eq!(decoded.v1().gas_data.budget, 3_000_000);
+    assert_eq!(decoded.v1().gas_data.payment[0].1, 7);
+}
+
+#[tokio::test]
+async fn transaction_gas_reports_insufficient_balance() {
+    let server = MockServer::start();
+    let _coins = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"suix_getCoins\"");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"jsonrpc":"2.0","id":1,"result":{"data":[],"nextCursor":null,"hasNextPage":false}}"#);
+    });
+
+    let mut tx = Transaction::new();
+    tx.set_sender("0x1");
+    tx.set_gas_price(1000);
+    tx.set_gas_budget(5_000_000);
+
+    let rpc = JsonRpcClient::new(server.url("/"), "testnet");
+    let err = tx
+        .resolve_gas(&rpc, &GasConfig::default())
+        .await
+        .expect_err("no coins");
+    assert!(matches!(
+        err,
+        GasError::InsufficientGas {
+            required: 5_000_000,
+            available: 0,
+            ..
+        }
+    ));
+}
+
+#[tokio::test]
+async fn transaction_build_async_runs_client_plugins() {
+    let server = MockServer::start();
+    let functions = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"sui_getNormalizedMoveFunction\"")
+            .body_contains("\"0x2\"");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!([{"jsonrpc": "2.0", "id": 1, "result": {
+                "visibility": "Public",
+                "isEntry": true,
+                "typeParameters": [],
+                "parameters": ["U64"],
+                "return": []
+            }}]));
+    });
+    let price = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"suix_getReferenceGasPrice\"");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"jsonrpc":"2.0","id":1,"result":"1000"}"#);
+    });
+    let _dry_run = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"sui_dryRunTransactionBlock\"");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({"jsonrpc": "2.0", "id": 2, "result": {"effects": {
+                "status": {"status": "success"},
+                "gasUsed": {
+                    "computationCost": "1000000",
+                    "storageCost": "0",
+                    "storageRebate": "0",
+                    "nonRefundableStorageFee": "0"
+                }
+            }}}));
+    });
+    let _coins = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"suix_getCoins\"");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({"jsonrpc": "2.0", "id": 3, "result": {
+                "data": [{
+                    "coinType": "0x2::sui::SUI",
+                    "coinObjectId": "0x5",
+                    "version": "7",
+                    "digest": "11111111111111111111111111111111",
+                    "balance": "100000000"
+                }],
+                "nextCursor": null,
+                "hasNextPage": false
+            }}));
+    });
+
+    let mut named = NamedPackagesPlugin::new();
+    named.add_package("mysten/sui".to_string(), "0x2".to_string());
+
+    let mut tx = Transaction::new();
+    tx.set_sender("0x1");
+    tx.add_plugin(Box::new(named));
+    tx.add_client_plugins(&JsonRpcClient::new(server.url("/"), "testnet"));
+    let amount = tx.pure_value(5u64);
+    tx.move_call("mysten/sui::example::run", vec![amount], vec![]).expect("move call");
+
+    let bytes = tx.build_async().await.expect("build");
+    functions.assert_hits(1);
+    price.assert_hits(1);
+
+    let decoded =
+        sui::transactions::bcs_types::TransactionData::from_bytes(&bytes).expect("decode");
+    assert_eq!(decoded.v1().gas_data.budget, 2_000_000);
+    assert_eq!(decoded.v1().gas_data.payment[0].1, 7);
+}
+
+#[test]
+fn transaction_pure_inputs_use_bcs_layouts() {
+    let mut tx = Transaction::new();
+    let amount = tx.pure(&100u64).expect("u64");
+    let names = tx
+        .pure(&vec!["a".to_string(), "bc".to_string()])
+        .expect("strings");
+    let maybe = tx.pure(&Some(vec![1u8, 2])).expect("option");
+    let owner: bcs_types::Address = "0x2".parse().expect("address");
+    let recipient = tx.pure(&owner).expect("address");
+    let id = tx.pure_typed(&PureValue::id("0x6")).expect("id");
+    let big = tx.pure_typed(&PureValue::u256("1")).expect("u256");
+    assert_eq!(amount, Argument::Input(0));
+    assert_eq!(big, Argument::Input(5));
+
+    let pure = |arg: Argument| {
+        let Argument::Input(index) = arg else {
+            panic!("expected input");
+        };
+        let sui::transactions::CallArg::Pure(pure) = &tx.data.inputs[index as usize] else {
+            panic!("expected pure input");
+        };
+        use base64::Engine as _;
+        base64::engine::general_purpose::STANDARD
+            .decode(&pure.bytes)
+            .expect("base64")
+    };
+    assert_eq!(pure(amount), 100u64.to_le_bytes());
+    assert_eq!(pure(names), vec![2, 1, b'a', 2, b'b', b'c']);
+    assert_eq!(pure(maybe), vec![1, 2, 1, 2]);
+    assert_eq!(pure(recipient), owner.as_bytes());
+    assert_eq!(pure(id)[31], 6);
+    assert_eq!(pure(big)[0], 1);
+    assert!(tx.pure_typed(&PureValue::ascii_string("é")).is_err());
+}
+
+#[tokio::test]
+async fn transaction_limits_load_from_protocol_config() {
+    let server = MockServer::start();
+    let config = server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_contains("\"method\":\"sui_getProtocolConfig\"");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({"jsonrpc": "2.0", "id": 1, "result": {
+                "protocolVersion": "70",
+                "attributes": {
+                    "max_programmable_tx_commands": {"u32": "2"},
+                    "max_tx_size_bytes": {"u64": "131072"}
+                }
+            }}));
+    });
+
+    let limits = TransactionLimits::load(&JsonRpcClient::new(server.url("/"), "testnet"))
+        .await
+        .expect("limits");
+    config.assert_hits(1);
+    assert_eq!(limits.max_commands, 2);
+
+    let mut tx = Transaction::new();
+    tx.move_call("0x2::coin::zero", vec![], vec![]).expect("move call");
+    tx.move_call("0x2::coin::zero", vec![], vec![]).expect("move call");
+    let err = tx.build_checked(&limits).expect_err("too many commands");
+    assert!(matches!(
+        err,
+        LimitError::TooManyCommands { count: 2, max: 2 }
+    ));
+}
+
+fn with_gas(mut tx: Transaction) -> Transaction {
+    tx.set_sender("0x1");
+    tx.set_gas_price(1000);
+    tx.set_gas_budget(1_000_000);
+    tx.set_gas_payment(vec![gas_coin()]);
+    tx
+}
+
+/// `tx.splitCoins(tx.gas, [100, 200])` and transfer of both coins to `0x2`.
+fn split_and_transfer() -> Transaction {
+    let mut tx = with_gas(Transaction::new());
+    let first = tx.pure(&100u64).expect("u64");
+    let second = tx.pure(&200u64).expect("u64");
+    let coins = tx.split_coins(Transaction::gas(), vec![first, second]);
+    let recipient = tx
+        .pure(&"0x2".parse::<bcs_types::Address>().expect("address"))
+        .expect("address");
+    tx.transfer_objects(
+        vec![coins.nested(0).expect("nested"), coins.nested(1).expect("nested")],
+        recipient,
+    );
+    tx
+}
+
+/// A MoveCall taking a mutable shared object, a receiving object, the clock
+/// and a pure value.
+fn move_call_shared_receiving() -> Transaction {
+    let mut tx = with_gas(Transaction::new());
+    let parent = tx.shared_object_ref(SharedObjectRef {
+        object_id: "0xaa".to_string(),
+        mutable: true,
+        initial_shared_version: 3,
+    });
+    let received = tx.receiving_ref(ReceivingRef {
+        digest: "11111111111111111111111111111111".to_string(),
+        version: 8,
+        object_id: "0xbb".to_string(),
+    });
+    let clock = tx.shared_object_ref(SharedObjectRef {
+        object_id: "0x6".to_string(),
+        mutable: false,
+        initial_shared_version: 1,
+    });
+    let amount = tx.pure(&5u64).expect("u64");
+    tx.move_call(
+        "0x42::vault::receive",
+        vec![parent, received, clock, amount],
+        vec!["0x2::sui::SUI".to_string()],
+    )
+    .expect("move call");
+    tx
+}
+
+/// Compares against `Transaction.build()` output of `@mysten/sui`, written by
+/// tests/fixtures/typescript/build-transactions.mjs.
+#[test]
+fn transaction_bytes_match_typescript_fixtures() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/typescript/transactions.json");
+    let fixtures: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).expect("read fixtures"))
+            .expect("fixtures json");
+
+    for (name, tx) in [
+        ("split_and_transfer", split_and_transfer()),
+        ("move_call_shared_receiving", move_call_shared_receiving()),
+    ] {
+        let expected = fixtures[name].as_str().expect(name);
+        assert_eq!(tx.build_base64().expect(name), expected, "{name}");
+
+        let decoded = Transaction::from_base64(expected).expect(name);
+        assert_eq!(decoded.build_base64().expect(name), expected, "{name}");
+        assert_eq!(decoded.data.commands.len(), tx.data.commands.len(), "{name}");
+    }
+}
+
+#[test]
+fn transaction_rejects_inputs_past_u16_indices() {
+    let mut tx = Transaction::new();
+    for _ in 0..=u16::MAX {
+        tx.pure_bytes(&[1]);
+    }
+    assert_eq!(tx.pure_bytes(&[1]), Argument::Input(u16::MAX));
+    let err = tx.build_kind().expect_err("too many inputs");
+    assert!(matches!(
+        err,
+        TransactionError::TooManyArgumentTargets {
+            kind: "inputs",
+            count: 65_537,
+            max: 65_536,
+        }
+    ));
+
+    tx.data.inputs.pop();
+    assert!(tx.build_kind().is_ok());
+}
@@ -44,10 +44,41 @@ fn verify_secp_signatures() {
 fn verify_transaction_intent() {
     let kp = ed25519::Keypair::generate();
     let tx = b"tx-bytes";
-    let intent_msg = sui::crypto::message_with_intent([0, 0, 0], tx);
-    let sig = kp.sign(&intent_msg);
+    let sig = kp.sign(&sui::cryptography::signer::transaction_digest(tx));
 
     let ok = verify::verify_transaction(SignatureScheme::Ed25519, &kp.public_key_bytes(), tx, &sig)
         .expect("verify tx");
     assert!(ok);
 }
+
+#[test]
+fn verify_passkey_transaction() {
+    use base64::Engine as _;
+    use sui::cryptography::Signer;
+    use sui::keypairs::passkey::{PasskeySigner, SoftwareAuthenticator};
+
+    let authenticator = SoftwareAuthenticator::new("sui.io", "https://sui.io");
+    let public_key = authenticator.public_key();
+    let signer = PasskeySigner::new(public_key.clone(), Box::new(authenticator));
+    let tx = b"tx-bytes";
+    let signature = base64::engine::general_purpose::STANDARD
+        .decode(signer.sign_transaction(tx).expect("sign"))
+        .expect("base64");
+    assert_eq!(signature[0], 0x06);
+
+    assert!(verify::verify_passkey_transaction(&public_key, tx, &signature).expect("verify tx"));
+    assert!(
+        !verify::verify_passkey_transaction(&public_key, b"other", &signature)
+            .expect("verify other")
+    );
+    let other = SoftwareAuthenticator::new("sui.io", "https://sui.io").public_key();
+    assert!(!verify::verify_passkey_transaction(&other, tx, &signature).expect("verify other key"));
+
+    let signature = base64::engine::general_purpose::STANDARD
+        .decode(signer.sign_personal_message(b"hello").expect("sign"))
+        .expect("base64");
+    assert!(
+        verify::verify_passkey_personal_message(&public_key, b"hello", &signature)
+            .expect("verify message")
+    );
+}
@@ -1,6 +1,23 @@
+use base64::Engine as _;
 use serde_json::json;
+use sui::cryptography::Signer;
+use sui::keypairs::ed25519;
 use sui::zklogin;
 
+fn inputs() -> zklogin::ZkLoginInputs {
+    serde_json::from_value(json!({
+        "proofPoints": {
+            "a": ["1", "2", "1"],
+            "b": [["3", "4"], ["5", "6"], ["1", "0"]],
+            "c": ["7", "8", "1"]
+        },
+        "issBase64Details": {"value": "yJpc3MiOiJodHRwczovL2lzc3VlciIs", "indexMod4": 1},
+        "headerBase64": "eyJhbGciOiJSUzI1NiJ9",
+        "addressSeed": "1234567890"
+    }))
+    .expect("prover inputs")
+}
+
 #[test]
 fn generate_nonce_and_randomness() {
     let randomness = zklogin::generate_randomness();
@@ -19,24 +36,89 @@ fn decode_jwt_payload() {
 
 #[test]
 fn compute_address_and_signature_roundtrip() {
-    let addr = zklogin::compute_zklogin_address(zklogin::ComputeZkLoginAddressOptions {
+    let err = zklogin::compute_zklogin_address(zklogin::ComputeZkLoginAddressOptions {
         iss: Some("https://issuer".to_string()),
         aud: Some("sui".to_string()),
         user_salt: "salt123".to_string(),
         jwt: None,
         legacy_address: Some(false),
     })
-    .expect("compute address");
-    assert!(addr.starts_with("0x"));
+    .unwrap_err();
+    assert!(matches!(err, zklogin::ZkLoginError::AddressSeedUnavailable));
+    // header: {"alg":"none"}, payload: {"iss":"https://issuer","aud":"sui","sub":"1"}
+    let jwt = "eyJhbGciOiJub25lIn0.eyJpc3MiOiJodHRwczovL2lzc3VlciIsImF1ZCI6InN1aSIsInN1YiI6IjEifQ.sig";
+    assert!(matches!(
+        zklogin::jwt_to_address(jwt, "salt123", false),
+        Err(zklogin::ZkLoginError::AddressSeedUnavailable)
+    ));
 
+    let user_signature = base64::engine::general_purpose::STANDARD.encode([0u8; 97]);
     let sig = zklogin::get_zklogin_signature(&zklogin::ZkLoginSignatureExtended {
-        inputs: json!({"proof": "abc"}),
+        inputs: inputs(),
         max_epoch: 123,
-        user_signature: "user_sig".to_string(),
+        user_signature: user_signature.clone(),
     })
     .expect("serialize zklogin sig");
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(&sig)
+        .expect("base64");
+    assert_eq!(bytes[0], 0x05);
 
     let parsed = zklogin::parse_zklogin_signature(&sig).expect("parse zklogin sig");
     assert_eq!(parsed.max_epoch, 123);
-    assert_eq!(parsed.user_signature, "user_sig");
+    assert_eq!(parsed.user_signature, user_signature);
+    assert_eq!(parsed.inputs, inputs());
+}
+
+#[test]
+fn zklogin_signer_wraps_ephemeral_signature() {
+    let ephemeral = ed25519::Keypair::from_secret_key(&[3u8; 32]).expect("keypair");
+    let expected_user_signature = ephemeral.sign_transaction(b"tx").expect("ephemeral");
+    let signer = zklogin::ZkLoginSigner::new(
+        "accounts.google.com",
+        inputs(),
+        10,
+        Box::new(ephemeral),
+    )
+    .expect("signer");
+
+    let seed = zklogin::parse_address_seed("1234567890").expect("seed");
+    assert_eq!(
+        signer.address(),
+        zklogin::compute_zklogin_address_from_seed(&seed, "accounts.google.com", false)
+            .expect("address")
+    );
+
+    let parsed = zklogin::parse_zklogin_signature(&signer.sign_transaction(b"tx").expect("sign"))
+        .expect("parse");
+    assert_eq!(parsed.max_epoch, 10);
+    assert_eq!(parsed.user_signature, expected_user_signature);
+}
+
+#[test]
+fn public_identifier_from_inputs() {
+    let google = zklogin::ZkLoginIssBase64Details {
+        value: "yJpc3MiOiJodHRwczovL2FjY291bnRzLmdvb2dsZS5jb20iLC".to_string(),
+        index_mod_4: 1,
+    };
+    assert_eq!(google.iss().expect("iss"), "https://accounts.google.com");
+    let misaligned = zklogin::ZkLoginIssBase64Details {
+        index_mod_4: 2,
+        ..google
+    };
+    assert!(misaligned.iss().is_err());
+
+    let identifier = inputs().public_identifier().expect("identifier");
+    assert_eq!(
+        identifier,
+        zklogin::ZkLoginPublicIdentifier::new(
+            "https://issuer",
+            zklogin::parse_address_seed("1234567890").expect("seed")
+        )
+    );
+    assert_eq!(
+        zklogin::ZkLoginPublicIdentifier::from_bytes(&identifier.to_bytes()).expect("from bytes"),
+        identifier
+    );
+    assert!(zklogin::ZkLoginPublicIdentifier::from_bytes(&identifier.to_bytes()[1..]).is_err());
 }
@@ -1,4 +1,4 @@
-use sui::transactions::Transaction;
+use sui::transactions::{Argument, Transaction, TransactionError};
 
 pub fn move_target(package_id: &str, module: &str, function: &str) -> String {
     format!("{}::{}::{}", package_id, module, function)
@@ -9,8 +9,8 @@ pub fn move_call(
     package_id: &str,
     module: &str,
     function: &str,
-    args: Vec<serde_json::Value>,
+    args: Vec<Argument>,
     type_args: Vec<String>,
-) -> serde_json::Value {
+) -> Result<Argument, TransactionError> {
     tx.move_call(&move_target(package_id, module, function), args, type_args)
 }
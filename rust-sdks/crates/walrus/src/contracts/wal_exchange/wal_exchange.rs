@@ -1,4 +1,4 @@
-use sui::transactions::Transaction;
+use sui::transactions::{Argument, Transaction, TransactionError};
 
 #[derive(Debug, Clone, Copy)]
 pub struct WalExchange<'a> {
@@ -15,135 +15,135 @@ impl<'a> WalExchange<'a> {
         &self,
         tx: &mut Transaction,
         function: &str,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         tx.move_call(&self.target(function), args, type_args)
     }
 
     pub fn newExchangeRate(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "new_exchange_rate", args, type_args)
     }
 
     pub fn _new(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "new", args, type_args)
     }
 
     pub fn newFunded(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "new_funded", args, type_args)
     }
 
     pub fn addWal(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "add_wal", args, type_args)
     }
 
     pub fn addSui(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "add_sui", args, type_args)
     }
 
     pub fn addAllWal(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "add_all_wal", args, type_args)
     }
 
     pub fn addAllSui(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "add_all_sui", args, type_args)
     }
 
     pub fn withdrawWal(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "withdraw_wal", args, type_args)
     }
 
     pub fn withdrawSui(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "withdraw_sui", args, type_args)
     }
 
     pub fn setExchangeRate(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "set_exchange_rate", args, type_args)
     }
 
     pub fn exchangeAllForWal(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "exchange_all_for_wal", args, type_args)
     }
 
     pub fn exchangeForWal(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "exchange_for_wal", args, type_args)
     }
 
     pub fn exchangeAllForSui(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "exchange_all_for_sui", args, type_args)
     }
 
     pub fn exchangeForSui(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "exchange_for_sui", args, type_args)
     }
 }
@@ -1,4 +1,4 @@
-use sui::transactions::Transaction;
+use sui::transactions::{Argument, Transaction, TransactionError};
 
 #[derive(Debug, Clone, Copy)]
 pub struct Contract<'a> {
@@ -15,27 +15,27 @@ impl<'a> Contract<'a> {
         &self,
         tx: &mut Transaction,
         function: &str,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         tx.move_call(&self.target(function), args, type_args)
     }
 
     pub fn _new(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "new", args, type_args)
     }
 
     pub fn popMax(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "pop_max", args, type_args)
     }
 }
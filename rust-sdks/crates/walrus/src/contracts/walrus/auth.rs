@@ -1,4 +1,4 @@
-use sui::transactions::Transaction;
+use sui::transactions::{Argument, Transaction, TransactionError};
 
 #[derive(Debug, Clone, Copy)]
 pub struct Contract<'a> {
@@ -15,36 +15,36 @@ impl<'a> Contract<'a> {
         &self,
         tx: &mut Transaction,
         function: &str,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         tx.move_call(&self.target(function), args, type_args)
     }
 
     pub fn authenticateSender(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "authenticate_sender", args, type_args)
     }
 
     pub fn authorizedAddress(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "authorized_address", args, type_args)
     }
 
     pub fn authorizedObject(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "authorized_object", args, type_args)
     }
 }
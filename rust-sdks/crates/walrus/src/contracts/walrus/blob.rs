@@ -1,4 +1,4 @@
-use sui::transactions::Transaction;
+use sui::transactions::{Argument, Transaction, TransactionError};
 
 #[derive(Debug, Clone, Copy)]
 pub struct Contract<'a> {
@@ -15,171 +15,171 @@ impl<'a> Contract<'a> {
         &self,
         tx: &mut Transaction,
         function: &str,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         tx.move_call(&self.target(function), args, type_args)
     }
 
     pub fn objectId(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "object_id", args, type_args)
     }
 
     pub fn registeredEpoch(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "registered_epoch", args, type_args)
     }
 
     pub fn blobId(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "blob_id", args, type_args)
     }
 
     pub fn size(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "size", args, type_args)
     }
 
     pub fn encodingType(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "encoding_type", args, type_args)
     }
 
     pub fn certifiedEpoch(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "certified_epoch", args, type_args)
     }
 
     pub fn storage(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "storage", args, type_args)
     }
 
     pub fn isDeletable(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "is_deletable", args, type_args)
     }
 
     pub fn encodedSize(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "encoded_size", args, type_args)
     }
 
     pub fn endEpoch(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "end_epoch", args, type_args)
     }
 
     pub fn deriveBlobId(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "derive_blob_id", args, type_args)
     }
 
     pub fn burn(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "burn", args, type_args)
     }
 
     pub fn addMetadata(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "add_metadata", args, type_args)
     }
 
     pub fn addOrReplaceMetadata(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "add_or_replace_metadata", args, type_args)
     }
 
     pub fn takeMetadata(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "take_metadata", args, type_args)
     }
 
     pub fn insertOrUpdateMetadataPair(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "insert_or_update_metadata_pair", args, type_args)
     }
 
     pub fn removeMetadataPair(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "remove_metadata_pair", args, type_args)
     }
 
     pub fn removeMetadataPairIfExists(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "remove_metadata_pair_if_exists", args, type_args)
     }
 }
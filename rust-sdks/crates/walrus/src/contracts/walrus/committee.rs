@@ -1,4 +1,4 @@
-use sui::transactions::Transaction;
+use sui::transactions::{Argument, Transaction, TransactionError};
 
 #[derive(Debug, Clone, Copy)]
 pub struct Contract<'a> {
@@ -15,45 +15,45 @@ impl<'a> Contract<'a> {
         &self,
         tx: &mut Transaction,
         function: &str,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         tx.move_call(&self.target(function), args, type_args)
     }
 
     pub fn shards(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "shards", args, type_args)
     }
 
     pub fn size(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "size", args, type_args)
     }
 
     pub fn inner(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "inner", args, type_args)
     }
 
     pub fn toInner(
         &self,
         tx: &mut Transaction,
-        args: Vec<serde_json::Value>,
+        args: Vec<Argument>,
         type_args: Vec<String>,
-    ) -> serde_json::Value {
+    ) -> Result<Argument, TransactionError> {
         self.move_call(tx, "to_inner", args, type_args)
     }
 }